@@ -0,0 +1,51 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+#![feature(generic_const_exprs, test)]
+#![allow(incomplete_features)]
+
+extern crate test;
+
+use fixed::FixedI32;
+use test::{black_box, Bencher};
+
+type Src = FixedI32<8>;
+type Dst = FixedI32<16>;
+
+fn inputs() -> Vec<Src> {
+    (0..1024)
+        .map(|i: i32| Src::from_bits(i.wrapping_mul(0x0001_2345)))
+        .collect()
+}
+
+#[bench]
+fn checked_from_num(b: &mut Bencher) {
+    let inputs = inputs();
+    b.iter(|| {
+        for &src in &inputs {
+            black_box(Dst::checked_from_num(black_box(src)));
+        }
+    });
+}
+
+#[bench]
+fn const_checked_from_fixed(b: &mut Bencher) {
+    let inputs = inputs();
+    b.iter(|| {
+        for &src in &inputs {
+            black_box(Dst::const_checked_from_fixed(black_box(src)));
+        }
+    });
+}
@@ -14,7 +14,8 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
-    types::extra::{If, True},
+    traits::AddFrac,
+    types::extra::{max_frac, If, True},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
 };
@@ -150,6 +151,33 @@ macro_rules! pass_assign {
     };
 }
 
+macro_rules! pass_bits {
+    (impl $Imp:ident<$Inner:ty> for $Fixed:ident { $method:ident }) => {
+        impl<const FRAC: i32> $Imp<$Inner> for $Fixed<FRAC> {
+            type Output = $Fixed<FRAC>;
+            #[inline]
+            fn $method(self, rhs: $Inner) -> $Fixed<FRAC> {
+                Self::from_bits(self.to_bits().$method(rhs))
+            }
+        }
+
+        refs! { impl $Imp<$Inner> for $Fixed { $method } }
+    };
+}
+
+macro_rules! pass_bits_assign {
+    (impl $Imp:ident<$Inner:ty> for $Fixed:ident { $method:ident }) => {
+        impl<const FRAC: i32> $Imp<$Inner> for $Fixed<FRAC> {
+            #[inline]
+            fn $method(&mut self, rhs: $Inner) {
+                self.bits.$method(rhs)
+            }
+        }
+
+        refs_assign! { impl $Imp<$Inner> for $Fixed { $method } }
+    };
+}
+
 macro_rules! pass_one {
     (impl $Imp:ident for $Fixed:ident { $method:ident }) => {
         impl<const FRAC: i32> $Imp for $Fixed<FRAC> {
@@ -336,6 +364,12 @@ macro_rules! fixed_arith {
         pass_assign! { impl BitOrAssign for $Fixed { bitor_assign } }
         pass! { impl BitXor for $Fixed { bitxor } }
         pass_assign! { impl BitXorAssign for $Fixed { bitxor_assign } }
+        pass_bits! { impl BitAnd<$Inner> for $Fixed { bitand } }
+        pass_bits_assign! { impl BitAndAssign<$Inner> for $Fixed { bitand_assign } }
+        pass_bits! { impl BitOr<$Inner> for $Fixed { bitor } }
+        pass_bits_assign! { impl BitOrAssign<$Inner> for $Fixed { bitor_assign } }
+        pass_bits! { impl BitXor<$Inner> for $Fixed { bitxor } }
+        pass_bits_assign! { impl BitXorAssign<$Inner> for $Fixed { bitxor_assign } }
 
         impl<const FRAC: i32> Mul<$Inner> for $Fixed<FRAC> {
             type Output = $Fixed<FRAC>;
@@ -642,9 +676,396 @@ fixed_arith! { FixedI32(i32, 32, NonZeroI32), Signed }
 fixed_arith! { FixedI64(i64, 64, NonZeroI64), Signed }
 fixed_arith! { FixedI128(i128, 128, NonZeroI128), Signed }
 
+// Multiplication of a signed fixed-point number and its unsigned sibling with the
+// same number of fractional bits gives the signed type, in either order.
+macro_rules! mul_unsigned {
+    ($Fixed:ident, $UFixed:ident) => {
+        impl<const FRAC: i32> Mul<$UFixed<FRAC>> for $Fixed<FRAC> {
+            type Output = $Fixed<FRAC>;
+            #[inline]
+            fn mul(self, rhs: $UFixed<FRAC>) -> $Fixed<FRAC> {
+                self.mul_unsigned(rhs)
+            }
+        }
+
+        impl<const FRAC: i32> Mul<&$UFixed<FRAC>> for $Fixed<FRAC> {
+            type Output = $Fixed<FRAC>;
+            #[inline]
+            fn mul(self, rhs: &$UFixed<FRAC>) -> $Fixed<FRAC> {
+                self.mul_unsigned(*rhs)
+            }
+        }
+
+        impl<const FRAC: i32> Mul<$UFixed<FRAC>> for &$Fixed<FRAC> {
+            type Output = $Fixed<FRAC>;
+            #[inline]
+            fn mul(self, rhs: $UFixed<FRAC>) -> $Fixed<FRAC> {
+                (*self).mul_unsigned(rhs)
+            }
+        }
+
+        impl<const FRAC: i32> Mul<&$UFixed<FRAC>> for &$Fixed<FRAC> {
+            type Output = $Fixed<FRAC>;
+            #[inline]
+            fn mul(self, rhs: &$UFixed<FRAC>) -> $Fixed<FRAC> {
+                (*self).mul_unsigned(*rhs)
+            }
+        }
+
+        impl<const FRAC: i32> Mul<$Fixed<FRAC>> for $UFixed<FRAC> {
+            type Output = $Fixed<FRAC>;
+            #[inline]
+            fn mul(self, rhs: $Fixed<FRAC>) -> $Fixed<FRAC> {
+                rhs.mul_unsigned(self)
+            }
+        }
+
+        impl<const FRAC: i32> Mul<&$Fixed<FRAC>> for $UFixed<FRAC> {
+            type Output = $Fixed<FRAC>;
+            #[inline]
+            fn mul(self, rhs: &$Fixed<FRAC>) -> $Fixed<FRAC> {
+                (*rhs).mul_unsigned(self)
+            }
+        }
+
+        impl<const FRAC: i32> Mul<$Fixed<FRAC>> for &$UFixed<FRAC> {
+            type Output = $Fixed<FRAC>;
+            #[inline]
+            fn mul(self, rhs: $Fixed<FRAC>) -> $Fixed<FRAC> {
+                rhs.mul_unsigned(*self)
+            }
+        }
+
+        impl<const FRAC: i32> Mul<&$Fixed<FRAC>> for &$UFixed<FRAC> {
+            type Output = $Fixed<FRAC>;
+            #[inline]
+            fn mul(self, rhs: &$Fixed<FRAC>) -> $Fixed<FRAC> {
+                (*rhs).mul_unsigned(*self)
+            }
+        }
+
+        impl<const FRAC: i32> MulAssign<$UFixed<FRAC>> for $Fixed<FRAC> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $UFixed<FRAC>) {
+                *self = self.mul_unsigned(rhs);
+            }
+        }
+
+        impl<const FRAC: i32> MulAssign<&$UFixed<FRAC>> for $Fixed<FRAC> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: &$UFixed<FRAC>) {
+                *self = self.mul_unsigned(*rhs);
+            }
+        }
+    };
+}
+
+mul_unsigned! { FixedI8, FixedU8 }
+mul_unsigned! { FixedI16, FixedU16 }
+mul_unsigned! { FixedI32, FixedU32 }
+mul_unsigned! { FixedI64, FixedU64 }
+
+// Addition of two fixed-point numbers with the same underlying integer type but
+// possibly different numbers of fractional bits.
+macro_rules! add_frac {
+    ($Fixed:ident) => {
+        impl<const FRAC: i32, const RHS_FRAC: i32> AddFrac<$Fixed<RHS_FRAC>> for $Fixed<FRAC>
+        where
+            [(); max_frac(FRAC, RHS_FRAC) as usize]:,
+        {
+            type Output = $Fixed<{ max_frac(FRAC, RHS_FRAC) }>;
+            #[inline]
+            #[track_caller]
+            fn add_frac(self, rhs: $Fixed<RHS_FRAC>) -> Self::Output {
+                $Fixed::<FRAC>::add_frac(self, rhs)
+            }
+            #[inline]
+            fn checked_add_frac(self, rhs: $Fixed<RHS_FRAC>) -> Option<Self::Output> {
+                $Fixed::<FRAC>::checked_add_frac(self, rhs)
+            }
+            #[inline]
+            fn overflowing_add_frac(self, rhs: $Fixed<RHS_FRAC>) -> (Self::Output, bool) {
+                $Fixed::<FRAC>::overflowing_add_frac(self, rhs)
+            }
+        }
+    };
+}
+
+add_frac! { FixedI8 }
+add_frac! { FixedI16 }
+add_frac! { FixedI32 }
+add_frac! { FixedI64 }
+add_frac! { FixedI128 }
+add_frac! { FixedU8 }
+add_frac! { FixedU16 }
+add_frac! { FixedU32 }
+add_frac! { FixedU64 }
+add_frac! { FixedU128 }
+
+// Expanded inside the module of each integer type.
+//
+//   * Defines:
+//       - pub const fn overflowing_mul_add2
+//
+// overflowing_mul_add2 computes add + (m1 × m2 >> s1) + (m3 × m4 >> s2), where
+// each product is rounded down on its own just like in overflowing_mul_add. It
+// returns the wrapped result, together with how the exact result compares to
+// the representable range: Less if it is below MIN, Greater if it is above MAX,
+// and Equal if there is no overflow.
+macro_rules! mul_add2 {
+    ($Single:ident, Unsigned, $Unsigned:ty) => {
+        #[inline]
+        pub const fn overflowing_mul_add2(
+            m1: $Single,
+            m2: $Single,
+            s1: i32,
+            m3: $Single,
+            m4: $Single,
+            s2: i32,
+            add: $Single,
+        ) -> ($Single, Ordering) {
+            // Both products are non-negative, so if the intermediate sum
+            // overflows, the final sum overflows too.
+            let (partial, overflow1) = overflowing_mul_add(m1, m2, add, s1);
+            let (ans, overflow2) = overflowing_mul_add(m3, m4, partial, s2);
+            if overflow1 || overflow2 {
+                (ans, Ordering::Greater)
+            } else {
+                (ans, Ordering::Equal)
+            }
+        }
+    };
+    ($Single:ident, Signed, $Unsigned:ty) => {
+        // The ordering of a non-zero product with respect to zero.
+        const fn prod_ordering(m1: $Single, m2: $Single) -> Ordering {
+            if (m1 < 0) != (m2 < 0) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }
+
+        const fn zero_ordering(val: $Single) -> Ordering {
+            if val < 0 {
+                Ordering::Less
+            } else if val > 0 {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }
+
+        // The ordering of (m1 × m2 << d) + m3 × m4 with respect to zero, where
+        // both products are non-zero and d >= 0.
+        const fn cancel_ordering(
+            m1: $Single,
+            m2: $Single,
+            d: i32,
+            m3: $Single,
+            m4: $Single,
+        ) -> Ordering {
+            const NBITS: i32 = <$Single>::BITS as i32;
+            // |m3 × m4| <= 2^(2 × NBITS - 2)
+            if d >= 2 * NBITS {
+                return prod_ordering(m1, m2);
+            }
+            match overflowing_mul_add2(m1, m2, -d, m3, m4, 0, 0) {
+                (val, Ordering::Equal) => zero_ordering(val),
+                (_, ord) => ord,
+            }
+        }
+
+        pub const fn overflowing_mul_add2(
+            m1: $Single,
+            m2: $Single,
+            s1: i32,
+            m3: $Single,
+            m4: $Single,
+            s2: i32,
+            add: $Single,
+        ) -> ($Single, Ordering) {
+            const NBITS: i32 = <$Single>::BITS as i32;
+
+            if m1 == 0 || m2 == 0 {
+                let (ans, overflow) = overflowing_mul_add(m3, m4, add, s2);
+                let ord = if overflow {
+                    prod_ordering(m3, m4)
+                } else {
+                    Ordering::Equal
+                };
+                return (ans, ord);
+            }
+            if m3 == 0 || m4 == 0 {
+                let (ans, overflow) = overflowing_mul_add(m1, m2, add, s1);
+                let ord = if overflow {
+                    prod_ordering(m1, m2)
+                } else {
+                    Ordering::Equal
+                };
+                return (ans, ord);
+            }
+
+            // The wrapped products are exact modulo 2^NBITS, and so is ans.
+            let (lo1, _) = overflowing_mul(m1, m2, s1);
+            let (lo2, _) = overflowing_mul(m3, m4, s2);
+            let (sum, carry1) = (add as $Unsigned).overflowing_add(lo1 as $Unsigned);
+            let (sum, carry2) = sum.overflowing_add(lo2 as $Unsigned);
+            let ans = sum as $Single;
+
+            // If both products are shifted left by at least NBITS, ans == add,
+            // and there is no overflow only if the two products cancel out.
+            if s1 <= -NBITS && s2 <= -NBITS {
+                let ord = if s1 <= s2 {
+                    cancel_ordering(m1, m2, s2 - s1, m3, m4)
+                } else {
+                    cancel_ordering(m3, m4, s1 - s2, m1, m2)
+                };
+                return (ans, ord);
+            }
+
+            // If one product is shifted left by at least 3 × NBITS, it is at
+            // least 2^(3 × NBITS) in magnitude, while the other product is
+            // shifted left by less than NBITS, so it is less than
+            // 2^(3 × NBITS - 3) in magnitude, and they cannot cancel out.
+            if s1 <= -3 * NBITS {
+                return (ans, prod_ordering(m1, m2));
+            }
+            if s2 <= -3 * NBITS {
+                return (ans, prod_ordering(m3, m4));
+            }
+
+            // The exact result is ans + hi × 2^NBITS, where hi is the sum of the
+            // products shifted right by NBITS more, add >> NBITS, and the
+            // carries. There is no overflow if hi is equal to ans >> NBITS,
+            // that is if rem == 0 in the following.
+            let sign = if ans < 0 { -1 } else { 0 };
+            let rem = (carry1 as $Single) + (carry2 as $Single) - ((add < 0) as $Single) - sign;
+            let s1 = s1.saturating_add(NBITS);
+            let s2 = s2.saturating_add(NBITS);
+            let (hi1, overflow1) = overflowing_mul(m1, m2, s1);
+            let (hi2, overflow2) = overflowing_mul(m3, m4, s2);
+            let ord = if overflow1 || overflow2 {
+                // Only products shifted left can overflow here, and the shifts
+                // are now at least NBITS larger, so the recursion is shallow.
+                match overflowing_mul_add2(m1, m2, s1, m3, m4, s2, rem) {
+                    (val, Ordering::Equal) => zero_ordering(val),
+                    (_, ord) => ord,
+                }
+            } else {
+                match hi1.overflowing_add(hi2) {
+                    // on overflow hi1 and hi2 have the same sign
+                    (_, true) => zero_ordering(hi1),
+                    (hi, false) => match hi.overflowing_add(rem) {
+                        (_, true) => zero_ordering(rem),
+                        (val, false) => zero_ordering(val),
+                    },
+                }
+            };
+            (ans, ord)
+        }
+    };
+}
+
+// Expanded inside the module of each integer type.
+//
+//   * Defines:
+//       - pub const fn overflowing_neg_mul_add
+//       - pub const fn overflowing_mul_sub
+//
+// overflowing_neg_mul_add computes add - (m1 × m2 >> frac_nbits), and
+// overflowing_mul_sub computes (m1 × m2 >> frac_nbits) - sub. In both cases the
+// exact result is rounded down like in overflowing_mul_add, which means that for
+// overflowing_neg_mul_add the shifted product is rounded up. They return the
+// wrapped result together with whether there was an overflow.
+macro_rules! mul_sub {
+    ($Single:ident, Unsigned) => {
+        #[inline]
+        pub const fn overflowing_neg_mul_add(
+            m1: $Single,
+            m2: $Single,
+            add: $Single,
+            frac_nbits: i32,
+        ) -> ($Single, bool) {
+            // The shifted product is exact if the trailing zeros of the
+            // factors cover the shift; otherwise it has to be rounded up.
+            let (floor, overflow1) = overflowing_mul(m1, m2, frac_nbits);
+            let inexact = frac_nbits > 0
+                && m1 != 0
+                && m2 != 0
+                && m1.trailing_zeros() + m2.trailing_zeros() < frac_nbits as u32;
+            let (ceil, overflow2) = floor.overflowing_add(inexact as $Single);
+            let (ans, overflow3) = add.overflowing_sub(ceil);
+            (ans, overflow1 || overflow2 || overflow3)
+        }
+
+        #[inline]
+        pub const fn overflowing_mul_sub(
+            m1: $Single,
+            m2: $Single,
+            sub: $Single,
+            frac_nbits: i32,
+        ) -> ($Single, bool) {
+            let (prod, overflow1) = overflowing_mul(m1, m2, frac_nbits);
+            let (ans, borrow) = prod.overflowing_sub(sub);
+            if !overflow1 {
+                return (ans, borrow);
+            }
+            // The shifted product is at least 2^NBITS, and prod is its value
+            // modulo 2^NBITS. The result fits only if the shifted product is
+            // less than 2^NBITS + sub, that is if it is less than 2^(NBITS + 1)
+            // and prod < sub.
+            let (_, overflow2) = overflowing_mul(m1, m2, frac_nbits.saturating_add(1));
+            (ans, !borrow || overflow2)
+        }
+    };
+    ($Single:ident, Signed) => {
+        #[inline]
+        pub const fn overflowing_neg_mul_add(
+            m1: $Single,
+            m2: $Single,
+            add: $Single,
+            frac_nbits: i32,
+        ) -> ($Single, bool) {
+            const NBITS: i32 = <$Single>::BITS as i32;
+
+            // add - m1 × m2 == add + (-m1) × m2 == add + m1 × (-m2)
+            if m1 != <$Single>::MIN {
+                overflowing_mul_add(-m1, m2, add, frac_nbits)
+            } else if m2 != <$Single>::MIN {
+                overflowing_mul_add(m1, -m2, add, frac_nbits)
+            } else {
+                // -(MIN × MIN) == MIN × 2^(NBITS - 1)
+                overflowing_mul_add(m1, 1, add, frac_nbits.saturating_sub(NBITS - 1))
+            }
+        }
+
+        #[inline]
+        pub const fn overflowing_mul_sub(
+            m1: $Single,
+            m2: $Single,
+            sub: $Single,
+            frac_nbits: i32,
+        ) -> ($Single, bool) {
+            if sub != <$Single>::MIN {
+                return overflowing_mul_add(m1, m2, -sub, frac_nbits);
+            }
+            // -MIN == MIN × -1 is exact as a product
+            match overflowing_mul_add2(m1, m2, frac_nbits, sub, -1, 0, 0) {
+                (ans, Ordering::Equal) => (ans, false),
+                (ans, _) => (ans, true),
+            }
+        }
+    };
+}
+
 macro_rules! mul_div_widen {
     ($Single:ident, $Double:ty, $Signedness:tt, $Unsigned:ty) => {
         pub mod $Single {
+            use core::cmp::Ordering;
+
+            mul_add2! { $Single, $Signedness, $Unsigned }
+            mul_sub! { $Single, $Signedness }
+
             #[inline]
             pub const fn overflowing_mul(
                 lhs: $Single,
@@ -698,7 +1119,7 @@ macro_rules! mul_div_widen {
                 //     0x80, and -0x81 and 0x80 are both still overflowing.
                 //   * for unsigned, the shifted product would be at least
                 //     0x100.
-                if frac_nbits <= -128 {
+                if frac_nbits <= -NBITS {
                     return (add, m1 != 0 && m2 != 0);
                 }
 
@@ -778,6 +1199,49 @@ macro_rules! mul_div_widen {
                 );
                 (quot, overflow)
             }
+
+            // 0 <= frac_nbits <= NBITS, rounds to the nearest with ties to even
+            #[inline]
+            pub const fn overflowing_div_round(
+                lhs: $Single,
+                rhs: $Single,
+                frac_nbits: u32,
+            ) -> ($Single, bool) {
+                const NBITS: u32 = <$Single>::BITS;
+                let lhs2 = (lhs as $Double) << frac_nbits;
+                let rhs2 = (rhs as $Double);
+                // As in overflowing_div, the remainder is zero in this case.
+                let (mut quot2, false) = lhs2.overflowing_div(rhs2) else {
+                    return (0, true);
+                };
+                let rem2 = lhs2 % rhs2;
+                let (rem_abs, rest) = if_signed_unsigned!(
+                    $Signedness,
+                    (
+                        rem2.unsigned_abs(),
+                        rhs2.unsigned_abs() - rem2.unsigned_abs()
+                    ),
+                    (rem2, rhs2 - rem2),
+                );
+                if rem_abs > rest || (rem_abs == rest && quot2 & 1 != 0) {
+                    if_signed_unsigned!(
+                        $Signedness,
+                        if (lhs2 < 0) != (rhs2 < 0) {
+                            quot2 -= 1;
+                        } else {
+                            quot2 += 1;
+                        },
+                        quot2 += 1,
+                    );
+                }
+                let quot = quot2 as $Single;
+                let overflow = if_signed_unsigned!(
+                    $Signedness,
+                    quot2 >> NBITS != if quot < 0 { -1 } else { 0 },
+                    quot2 >> NBITS != 0
+                );
+                (quot, overflow)
+            }
         }
     };
 }
@@ -793,8 +1257,12 @@ mul_div_widen! { i64, i128, Signed, u64 }
 
 pub mod u128 {
     use crate::int256::{self, U256};
+    use core::cmp::Ordering;
     use core::num::NonZeroU128;
 
+    mul_add2! { u128, Unsigned, u128 }
+    mul_sub! { u128, Unsigned }
+
     #[inline]
     pub const fn overflowing_mul(lhs: u128, rhs: u128, frac_nbits: i32) -> (u128, bool) {
         if frac_nbits <= 0 {
@@ -888,12 +1356,40 @@ pub mod u128 {
         let overflow = quot2.hi != 0;
         (quot, overflow)
     }
+
+    // 0 <= frac_nbits <= NBITS, rounds to the nearest with ties to even
+    #[inline]
+    pub const fn overflowing_div_round(lhs: u128, rhs: u128, frac_nbits: u32) -> (u128, bool) {
+        let Some(rhs) = NonZeroU128::new(rhs) else {
+            panic!("division by zero");
+        };
+        let lhs2 = if frac_nbits == 0 {
+            U256 { lo: lhs, hi: 0 }
+        } else if frac_nbits == 128 {
+            U256 { lo: 0, hi: lhs }
+        } else {
+            U256 {
+                lo: lhs << frac_nbits,
+                hi: lhs >> (128 - frac_nbits),
+            }
+        };
+        let (mut quot2, rem) = int256::div_rem_u256_u128(lhs2, rhs);
+        let rest = rhs.get() - rem;
+        if rem > rest || (rem == rest && quot2.lo & 1 != 0) {
+            quot2 = int256::wrapping_add_u256_u128(quot2, 1);
+        }
+        (quot2.lo, quot2.hi != 0)
+    }
 }
 
 pub mod i128 {
     use crate::int256::{self, I256};
+    use core::cmp::Ordering;
     use core::num::NonZeroI128;
 
+    mul_add2! { i128, Signed, u128 }
+    mul_sub! { i128, Signed }
+
     #[inline]
     pub const fn overflowing_mul(lhs: i128, rhs: i128, frac_nbits: i32) -> (i128, bool) {
         if frac_nbits <= 0 {
@@ -1006,6 +1502,40 @@ pub mod i128 {
         let overflow = quot2.hi != quot >> 127;
         (quot, overflow)
     }
+
+    // 0 <= frac_nbits <= NBITS, rounds to the nearest with ties to even
+    #[inline]
+    pub const fn overflowing_div_round(lhs: i128, rhs: i128, frac_nbits: u32) -> (i128, bool) {
+        let Some(rhs) = NonZeroI128::new(rhs) else {
+            panic!("division by zero");
+        };
+        let lhs2 = if frac_nbits == 0 {
+            I256 {
+                lo: lhs as u128,
+                hi: lhs >> 127,
+            }
+        } else if frac_nbits == 128 {
+            // As in overflowing_div_nz, the remainder is zero in this case.
+            if lhs == i128::MIN && rhs.get() == -1 {
+                return (0, true);
+            }
+            I256 { lo: 0, hi: lhs }
+        } else {
+            I256 {
+                lo: (lhs << frac_nbits) as u128,
+                hi: lhs >> (128 - frac_nbits),
+            }
+        };
+        let (mut quot2, rem) = int256::div_rem_i256_i128_no_overflow(lhs2, rhs);
+        let rem_abs = rem.unsigned_abs();
+        let rest = rhs.get().unsigned_abs() - rem_abs;
+        if rem_abs > rest || (rem_abs == rest && quot2.lo & 1 != 0) {
+            let away = if (lhs < 0) != (rhs.get() < 0) { -1 } else { 1 };
+            (quot2, _) = int256::overflowing_add_i256_i128(quot2, away);
+        }
+        let quot = quot2.lo as i128;
+        (quot, quot2.hi != quot >> 127)
+    }
 }
 
 pub const fn saturating_add_sub_i32(a: i32, b: i32, c: i32) -> i32 {
@@ -1024,47 +1554,408 @@ mod tests {
     use crate::*;
 
     #[test]
-    fn fixed_u16() {
-        const FRAC: i32 = 7;
-        let frac = FRAC;
-        let a = 12;
-        let b = 5;
-        for &(a, b) in &[(a, b), (b, a)] {
-            let af = FixedU16::<FRAC>::from_num(a);
-            let bf = FixedU16::<FRAC>::from_num(b);
-            assert_eq!((af + bf).to_bits(), (a << frac) + (b << frac));
-            if a > b {
-                assert_eq!((af - bf).to_bits(), (a << frac) - (b << frac));
+    fn bit_ops_with_inner() {
+        use crate::types::{I16F16, U8F8};
+
+        let x = I16F16::from_num(-3.25);
+        let int_mask = !0xFFFF;
+        assert_eq!(x & int_mask, -4);
+        assert_eq!(x & int_mask, x.floor());
+        assert_eq!(x & 0xFFFF, x.frac());
+        assert_eq!(x | 0xFFFF, x.floor() + I16F16::ONE - I16F16::DELTA);
+        assert_eq!(x ^ x.to_bits(), 0);
+
+        let mut y = U8F8::from_num(9.5);
+        y &= 0xFF00;
+        assert_eq!(y, 9);
+        y |= 0x0080;
+        assert_eq!(y, 9.5);
+        y ^= &0x0180;
+        assert_eq!(y, 8);
+    }
+
+    #[test]
+    fn const_bit_ops_with_inner() {
+        use crate::types::{I16F16, U8F8};
+
+        const X: I16F16 = I16F16::lit("-3.25");
+        const INT: I16F16 = X.const_bitand_bits(!0xFFFF);
+        const FRAC: I16F16 = X.const_bitand_bits(0xFFFF);
+        const ALL_FRAC: I16F16 = X.const_bitor_bits(0xFFFF);
+        const ZERO: I16F16 = X.const_bitxor_bits(X.to_bits());
+        assert_eq!(INT, X & !0xFFFF);
+        assert_eq!(INT, -4);
+        assert_eq!(FRAC, X.frac());
+        assert_eq!(ALL_FRAC, X | 0xFFFF);
+        assert_eq!(ZERO, 0);
+
+        const Y: U8F8 = U8F8::lit("9.5").const_bitand_bits(0xFF00);
+        const Z: U8F8 = Y.const_bitor_bits(0x0080).const_bitxor_bits(0x0180);
+        assert_eq!(Y, 9);
+        assert_eq!(Z, 8);
+    }
+
+    #[test]
+    fn mul_to_any_frac() {
+        // exact product bits rescaled to OUT_FRAC, rounding down
+        fn rescaled(prod: i64, shift: i32) -> i64 {
+            if shift >= 0 {
+                prod >> shift
+            } else {
+                prod << -shift
+            }
+        }
+        fn check_i8<const FRAC: i32, const RHS_FRAC: i32, const OUT_FRAC: i32>() {
+            for a in i8::MIN..=i8::MAX {
+                for b in i8::MIN..=i8::MAX {
+                    let bits = rescaled(i64::from(a) * i64::from(b), FRAC + RHS_FRAC - OUT_FRAC);
+                    let a = FixedI8::<FRAC>::from_bits(a);
+                    let b = FixedI8::<RHS_FRAC>::from_bits(b);
+                    let expected = i8::try_from(bits).ok().map(FixedI8::<OUT_FRAC>::from_bits);
+                    assert_eq!(a.checked_mul_to::<OUT_FRAC, RHS_FRAC>(b), expected);
+                    let (wrapped, overflow) = a.overflowing_mul_to::<OUT_FRAC, RHS_FRAC>(b);
+                    assert_eq!(overflow, expected.is_none());
+                    assert_eq!(wrapped, FixedI8::<OUT_FRAC>::from_bits(bits as i8));
+                    let saturated = bits.clamp(i64::from(i8::MIN), i64::from(i8::MAX));
+                    assert_eq!(
+                        a.saturating_mul_to::<OUT_FRAC, RHS_FRAC>(b),
+                        FixedI8::<OUT_FRAC>::from_bits(saturated as i8)
+                    );
+                }
+            }
+        }
+        fn check_u8<const FRAC: i32, const RHS_FRAC: i32, const OUT_FRAC: i32>() {
+            for a in u8::MIN..=u8::MAX {
+                for b in u8::MIN..=u8::MAX {
+                    let bits = rescaled(i64::from(a) * i64::from(b), FRAC + RHS_FRAC - OUT_FRAC);
+                    let a = FixedU8::<FRAC>::from_bits(a);
+                    let b = FixedU8::<RHS_FRAC>::from_bits(b);
+                    let expected = u8::try_from(bits).ok().map(FixedU8::<OUT_FRAC>::from_bits);
+                    assert_eq!(a.checked_mul_to::<OUT_FRAC, RHS_FRAC>(b), expected);
+                    let (wrapped, overflow) = a.overflowing_mul_to::<OUT_FRAC, RHS_FRAC>(b);
+                    assert_eq!(overflow, expected.is_none());
+                    assert_eq!(wrapped, FixedU8::<OUT_FRAC>::from_bits(bits as u8));
+                    let saturated = bits.min(i64::from(u8::MAX));
+                    assert_eq!(
+                        a.saturating_mul_to::<OUT_FRAC, RHS_FRAC>(b),
+                        FixedU8::<OUT_FRAC>::from_bits(saturated as u8)
+                    );
+                }
             }
-            assert_eq!((af * bf).to_bits(), (a << frac) * b);
-            assert_eq!((af / bf).to_bits(), (a << frac) / b);
-            assert_eq!((af % bf).to_bits(), (a << frac) % (b << frac));
-            assert_eq!((af & bf).to_bits(), (a << frac) & (b << frac));
-            assert_eq!((af | bf).to_bits(), (a << frac) | (b << frac));
-            assert_eq!((af ^ bf).to_bits(), (a << frac) ^ (b << frac));
-            assert_eq!((!af).to_bits(), !(a << frac));
-            assert_eq!((af << 4u8).to_bits(), (a << frac) << 4);
-            assert_eq!((af >> 4i128).to_bits(), (a << frac) >> 4);
-            assert_eq!((af * b).to_bits(), (a << frac) * b);
-            assert_eq!((b * af).to_bits(), (a << frac) * b);
-            assert_eq!((af / b).to_bits(), (a << frac) / b);
-            assert_eq!((af % b).to_bits(), (a << frac) % (b << frac));
         }
+
+        check_i8::<4, 2, 6>();
+        check_i8::<4, 2, 4>();
+        check_i8::<4, 2, 0>();
+        check_i8::<4, 4, 8>();
+        check_i8::<8, 8, 12>();
+        check_i8::<0, 0, -2>();
+        check_u8::<4, 2, 6>();
+        check_u8::<4, 2, 1>();
+        check_u8::<8, 8, 10>();
+        check_u8::<0, 0, -3>();
+
+        let a = FixedI32::<16>::from_num(-1.5);
+        let b = FixedI32::<8>::from_num(2.75);
+        let prod: FixedI32<24> = a.mul_to(b);
+        assert_eq!(prod, -4.125);
+        let prod: FixedI32<1> = a.mul_to(b);
+        assert_eq!(prod, -4.5);
+        assert_eq!(a.checked_mul_to::<29, 8>(b), None);
+        assert_eq!(a.saturating_mul_to::<29, 8>(b), FixedI32::<29>::MIN);
+        // MAX × MAX is just below 2^128, so it only fits without fractional bits
+        let big = FixedU128::<64>::MAX;
+        assert_eq!(
+            big.checked_mul_to::<0, 64>(big),
+            Some(FixedU128::<0>::from_bits(u128::MAX - 1))
+        );
+        assert_eq!(big.checked_mul_to::<1, 64>(big), None);
+        assert_eq!(
+            big.checked_mul_to::<-64, 64>(big),
+            Some(FixedU128::<-64>::from_bits(u64::MAX.into()))
+        );
     }
 
     #[test]
-    fn fixed_i16() {
-        const FRAC: i32 = 7;
-        let frac = FRAC;
-        let a = 12;
-        let b = 5;
-        for &(a, b) in &[
-            (a, b),
-            (a, -b),
-            (-a, b),
-            (-a, -b),
-            (b, a),
-            (b, -a),
+    fn mul_no_overflow() {
+        // the result must always match the checked product, which never fails
+        fn check_i8<const FRAC: i32, const RHS_FRAC: i32>()
+        where
+            If<{ 8 <= RHS_FRAC }>: True,
+        {
+            for a in i8::MIN..=i8::MAX {
+                for b in i8::MIN..=i8::MAX {
+                    let a = FixedI8::<FRAC>::from_bits(a);
+                    let b = FixedI8::<RHS_FRAC>::from_bits(b);
+                    let expected = a.checked_mul_to::<FRAC, RHS_FRAC>(b);
+                    assert_eq!(Some(a.mul_no_overflow(b)), expected);
+                }
+            }
+        }
+        fn check_u8<const FRAC: i32, const RHS_FRAC: i32>()
+        where
+            If<{ 8 <= RHS_FRAC }>: True,
+        {
+            for a in u8::MIN..=u8::MAX {
+                for b in u8::MIN..=u8::MAX {
+                    let a = FixedU8::<FRAC>::from_bits(a);
+                    let b = FixedU8::<RHS_FRAC>::from_bits(b);
+                    let expected = a.checked_mul_to::<FRAC, RHS_FRAC>(b);
+                    assert_eq!(Some(a.mul_no_overflow(b)), expected);
+                }
+            }
+        }
+
+        check_i8::<-3, 8>();
+        check_i8::<0, 8>();
+        check_i8::<4, 8>();
+        check_i8::<8, 8>();
+        check_i8::<4, 11>();
+        check_i8::<4, 20>();
+        check_u8::<0, 8>();
+        check_u8::<4, 8>();
+        check_u8::<8, 9>();
+        check_u8::<4, 20>();
+
+        let a = FixedI128::<0>::MIN;
+        let half = FixedI128::<128>::from_bits(i128::MIN);
+        assert_eq!(a.mul_no_overflow(half), FixedI128::<0>::from_bits(1 << 126));
+        let b = FixedU128::<64>::MAX;
+        assert_eq!(
+            b.mul_no_overflow(FixedU128::<128>::MAX),
+            b - FixedU128::DELTA
+        );
+    }
+
+    #[test]
+    fn add_frac() {
+        let a = FixedI32::<4>::from_num(1);
+        let b = FixedI32::<8>::from_num(0.5);
+        let sum: FixedI32<8> = a.add_frac(b);
+        assert_eq!(sum, 1.5);
+        let sum: FixedI32<8> = b.add_frac(a);
+        assert_eq!(sum, 1.5);
+        let same: FixedI32<4> = a.add_frac(a);
+        assert_eq!(same, 2);
+
+        // 2^23 with 4 fractional bits does not fit with 8 fractional bits
+        let big = FixedI32::<4>::from_num(1 << 23);
+        assert_eq!(big.checked_add_frac(b), None);
+        assert_eq!(
+            FixedI32::<4>::from_num(-(1 << 23)).checked_add_frac(b),
+            Some(FixedI32::<8>::from_num(-8_388_607.5))
+        );
+        assert_eq!(
+            FixedI32::<8>::MAX.checked_add_frac(FixedI32::<4>::DELTA),
+            None
+        );
+        assert_eq!(
+            FixedI32::<8>::MAX.overflowing_add_frac(FixedI32::<8>::DELTA),
+            (FixedI32::<8>::MIN, true)
+        );
+
+        // shifting by the whole width only keeps zero
+        let zero = FixedU8::<0>::ZERO;
+        let one = FixedU8::<0>::ONE;
+        let tiny = FixedU8::<8>::DELTA;
+        assert_eq!(zero.checked_add_frac(tiny), Some(tiny));
+        assert_eq!(one.checked_add_frac(tiny), None);
+        assert_eq!(tiny.overflowing_add_frac(one), (tiny, true));
+
+        // the difference between the numbers of fractional bits does not fit
+        // in i32
+        let lo = FixedI8::<{ i32::MIN }>::from_bits(1);
+        let hi = FixedI8::<{ i32::MAX }>::from_bits(3);
+        assert_eq!(lo.overflowing_add_frac(hi), (hi, true));
+        assert_eq!(hi.overflowing_add_frac(lo), (hi, true));
+        assert_eq!(lo.checked_add_frac(hi), None);
+        let lo_zero = FixedI8::<{ i32::MIN }>::ZERO;
+        assert_eq!(lo_zero.checked_add_frac(hi), Some(hi));
+        assert_eq!(hi.checked_add_frac(lo_zero), Some(hi));
+    }
+
+    #[test]
+    fn add_frac_trait() {
+        use crate::traits::AddFrac;
+        use crate::types::extra::max_frac;
+
+        fn sum<A: AddFrac<B>, B>(a: A, b: B) -> A::Output {
+            a.add_frac(b)
+        }
+        fn checked_sum<A: AddFrac<B>, B>(a: A, b: B) -> Option<A::Output> {
+            a.checked_add_frac(b)
+        }
+        fn overflowing_sum<A: AddFrac<B>, B>(a: A, b: B) -> (A::Output, bool) {
+            a.overflowing_add_frac(b)
+        }
+
+        let a = FixedI32::<4>::from_num(1);
+        let b = FixedI32::<8>::from_num(0.5);
+        let ab: <FixedI32<4> as AddFrac<FixedI32<8>>>::Output = sum(a, b);
+        let ba: FixedI32<{ max_frac(8, 4) }> = sum(b, a);
+        assert_eq!(ab, FixedI32::<8>::from_num(1.5));
+        assert_eq!(ba, ab);
+        assert_eq!(sum(a, a), FixedI32::<4>::from_num(2));
+
+        let big = FixedI32::<4>::from_num(1 << 23);
+        assert_eq!(checked_sum(big, b), None);
+        assert_eq!(checked_sum(b, big), None);
+        assert_eq!(
+            checked_sum(-big, b),
+            Some(FixedI32::<8>::from_num(-8_388_607.5))
+        );
+        assert_eq!(
+            overflowing_sum(FixedI32::<8>::MAX, a),
+            (FixedI32::<8>::MIN + FixedI32::<8>::from_bits(255), true)
+        );
+        assert_eq!(
+            overflowing_sum(FixedU8::<0>::ONE, FixedU8::<8>::DELTA),
+            (FixedU8::<8>::DELTA, true)
+        );
+    }
+
+    #[test]
+    fn mul_signed_int() {
+        for bits in u8::MIN..=u8::MAX {
+            let x = FixedU8::<3>::from_bits(bits);
+            for rhs in i8::MIN..=i8::MAX {
+                let exact = i32::from(bits) * i32::from(rhs);
+                let wrapped = FixedI8::<3>::from_bits(exact as i8);
+                let fits = i8::try_from(exact).is_ok();
+                assert_eq!(x.overflowing_mul_signed_int(rhs), (wrapped, !fits));
+                assert_eq!(x.wrapping_mul_signed_int(rhs), wrapped);
+                assert_eq!(x.checked_mul_signed_int(rhs), fits.then_some(wrapped));
+                let saturated = FixedI8::<3>::from_bits(exact.clamp(-128, 127) as i8);
+                assert_eq!(x.saturating_mul_signed_int(rhs), saturated);
+            }
+        }
+
+        let x = FixedU32::<16>::from_num(2.25);
+        assert_eq!(x.mul_signed_int(-4), FixedI32::<16>::from_num(-9));
+        assert_eq!(x.mul_signed_int(4), FixedI32::<16>::from_num(9));
+        assert_eq!(x.mul_signed_int(0), FixedI32::<16>::ZERO);
+        // exactly MIN fits, but one more does not
+        let half = FixedU32::<16>::from_bits(1 << 31);
+        assert_eq!(half.checked_mul_signed_int(-1), Some(FixedI32::<16>::MIN));
+        assert_eq!(half.checked_mul_signed_int(1), None);
+        assert_eq!(
+            (half + FixedU32::<16>::DELTA).checked_mul_signed_int(-1),
+            None
+        );
+        assert_eq!(
+            FixedU128::<64>::MAX.saturating_mul_signed_int(i128::MIN),
+            FixedI128::<64>::MIN
+        );
+        assert_eq!(
+            FixedU128::<64>::MAX.wrapping_mul_signed_int(-1),
+            FixedI128::<64>::DELTA
+        );
+    }
+
+    #[test]
+    fn mul_unsigned() {
+        fn check<const FRAC: i32>() {
+            for a in i8::MIN..=i8::MAX {
+                for b in u8::MIN..=u8::MAX {
+                    // exact product rounded down
+                    let exact = i64::from(a) * i64::from(b);
+                    let bits = if FRAC >= 0 {
+                        exact >> FRAC
+                    } else {
+                        exact << -FRAC
+                    };
+                    let x = FixedI8::<FRAC>::from_bits(a);
+                    let y = FixedU8::<FRAC>::from_bits(b);
+                    let wrapped = FixedI8::<FRAC>::from_bits(bits as i8);
+                    let fits = i8::try_from(bits).is_ok();
+                    assert_eq!(x.overflowing_mul_unsigned(y), (wrapped, !fits));
+                    assert_eq!(x.wrapping_mul_unsigned(y), wrapped);
+                    assert_eq!(x.checked_mul_unsigned(y), fits.then_some(wrapped));
+                    let saturated = FixedI8::<FRAC>::from_bits(bits.clamp(-128, 127) as i8);
+                    assert_eq!(x.saturating_mul_unsigned(y), saturated);
+                    if fits {
+                        assert_eq!(x.mul_unsigned(y), wrapped);
+                        assert_eq!(x * y, wrapped);
+                        assert_eq!(y * x, wrapped);
+                    }
+                }
+            }
+        }
+        check::<0>();
+        check::<4>();
+        check::<7>();
+        check::<8>();
+        check::<12>();
+        check::<-2>();
+
+        // negative self with an unsigned factor too large for the signed type
+        let x = FixedI32::<16>::from_num(-0.25);
+        let y = FixedU32::<16>::from_num(40000);
+        assert_eq!(x.mul_unsigned(y), -10000);
+        assert_eq!(
+            x.checked_mul_unsigned(y),
+            Some(FixedI32::<16>::from_num(-10000))
+        );
+        assert_eq!(FixedI32::<16>::checked_from_num(y), None);
+        // the product is rounded down
+        let x = FixedI64::<32>::from_bits(-1);
+        let y = FixedU64::<32>::from_num(0.5);
+        assert_eq!(x * y, -FixedI64::<32>::DELTA);
+        assert_eq!(y * x, -FixedI64::<32>::DELTA);
+        let mut z = FixedI16::<4>::from_num(-3);
+        z *= FixedU16::<4>::from_num(200);
+        assert_eq!(z, -600);
+        z *= &FixedU16::<4>::from_num(2);
+        assert_eq!(z, -1200);
+        assert_eq!(z.checked_mul_unsigned(FixedU16::<4>::from_num(2)), None);
+        assert_eq!(
+            z.saturating_mul_unsigned(FixedU16::<4>::MAX),
+            FixedI16::<4>::MIN
+        );
+    }
+
+    #[test]
+    fn fixed_u16() {
+        const FRAC: i32 = 7;
+        let frac = FRAC;
+        let a = 12;
+        let b = 5;
+        for &(a, b) in &[(a, b), (b, a)] {
+            let af = FixedU16::<FRAC>::from_num(a);
+            let bf = FixedU16::<FRAC>::from_num(b);
+            assert_eq!((af + bf).to_bits(), (a << frac) + (b << frac));
+            if a > b {
+                assert_eq!((af - bf).to_bits(), (a << frac) - (b << frac));
+            }
+            assert_eq!((af * bf).to_bits(), (a << frac) * b);
+            assert_eq!((af / bf).to_bits(), (a << frac) / b);
+            assert_eq!((af % bf).to_bits(), (a << frac) % (b << frac));
+            assert_eq!((af & bf).to_bits(), (a << frac) & (b << frac));
+            assert_eq!((af | bf).to_bits(), (a << frac) | (b << frac));
+            assert_eq!((af ^ bf).to_bits(), (a << frac) ^ (b << frac));
+            assert_eq!((!af).to_bits(), !(a << frac));
+            assert_eq!((af << 4u8).to_bits(), (a << frac) << 4);
+            assert_eq!((af >> 4i128).to_bits(), (a << frac) >> 4);
+            assert_eq!((af * b).to_bits(), (a << frac) * b);
+            assert_eq!((b * af).to_bits(), (a << frac) * b);
+            assert_eq!((af / b).to_bits(), (a << frac) / b);
+            assert_eq!((af % b).to_bits(), (a << frac) % (b << frac));
+        }
+    }
+
+    #[test]
+    fn fixed_i16() {
+        const FRAC: i32 = 7;
+        let frac = FRAC;
+        let a = 12;
+        let b = 5;
+        for &(a, b) in &[
+            (a, b),
+            (a, -b),
+            (-a, b),
+            (-a, -b),
+            (b, a),
+            (b, -a),
             (-b, a),
             (-b, -a),
         ] {
@@ -1217,6 +2108,157 @@ mod tests {
         }
     }
 
+    #[test]
+    fn div_rem_int() {
+        use crate::types::{I0F128, I4F4, I64F64, U0F8, U128F0, U4F4};
+        for bits in i8::MIN..=i8::MAX {
+            let x = I4F4::from_bits(bits);
+            for rhs in i8::MIN..=i8::MAX {
+                let checked = x.checked_div_rem_int(rhs);
+                if rhs == 0 || (bits == i8::MIN && rhs == -1) {
+                    assert_eq!(checked, None);
+                    continue;
+                }
+                let (quot, rem) = x.div_rem_int(rhs);
+                assert_eq!(checked, Some((quot, rem)));
+                assert_eq!(quot, x / rhs);
+                assert_eq!(quot.wrapping_mul_int(rhs) + rem, x);
+                assert!(rem == 0 || rem.is_negative() == x.is_negative());
+                assert!(rem.unsigned_abs().to_bits() < rhs.unsigned_abs());
+            }
+        }
+        for bits in u8::MIN..=u8::MAX {
+            let x = U4F4::from_bits(bits);
+            for rhs in u8::MIN..=u8::MAX {
+                let checked = x.checked_div_rem_int(rhs);
+                if rhs == 0 {
+                    assert_eq!(checked, None);
+                    continue;
+                }
+                let (quot, rem) = x.div_rem_int(rhs);
+                assert_eq!(checked, Some((quot, rem)));
+                assert_eq!(quot * rhs + rem, x);
+                assert!(rem.to_bits() < rhs);
+            }
+        }
+
+        assert_eq!(
+            U0F8::MAX.div_rem_int(2),
+            (U0F8::from_bits(127), U0F8::DELTA)
+        );
+        assert_eq!(
+            I64F64::from_num(-7).div_rem_int(3),
+            (
+                I64F64::from_bits(-(7 << 64) / 3),
+                I64F64::from_bits(-(7 << 64) % 3)
+            )
+        );
+        assert_eq!(I0F128::MIN.checked_div_rem_int(-1), None);
+        assert_eq!(
+            I0F128::MIN.div_rem_int(i128::MIN),
+            (I0F128::DELTA, I0F128::ZERO)
+        );
+        assert_eq!(
+            U128F0::MAX.div_rem_int(u128::MAX),
+            (U128F0::ONE, U128F0::ZERO)
+        );
+        assert_eq!(U128F0::MAX.checked_div_rem_int(0), None);
+    }
+
+    // Returns bits × 10^exp, rounding divisions to the nearest with ties to
+    // even, or None if bits × 10^exp does not fit in i128.
+    fn scale_pow10_exact(bits: i128, exp: i32) -> Option<i128> {
+        if exp >= 0 {
+            return 10i128.checked_pow(exp.unsigned_abs())?.checked_mul(bits);
+        }
+        let Some(div) = 10i128.checked_pow(exp.unsigned_abs()) else {
+            return Some(0);
+        };
+        let (quot, rem) = (bits.div_euclid(div), bits.rem_euclid(div));
+        if 2 * rem > div || (2 * rem == div && quot % 2 != 0) {
+            Some(quot + 1)
+        } else {
+            Some(quot)
+        }
+    }
+
+    #[test]
+    fn scale_pow10() {
+        use crate::types::{I0F128, I16F16, I4F4, U0F8, U128F0, U16F16, U4F4, U8F8};
+        for exp in -5..=5 {
+            for bits in i8::MIN..=i8::MAX {
+                let x = I4F4::from_bits(bits);
+                let exact = scale_pow10_exact(i128::from(bits), exp).unwrap();
+                let expected = i8::try_from(exact).ok().map(I4F4::from_bits);
+                assert_eq!(x.checked_scale_pow10(exp), expected);
+                assert_eq!(
+                    x.overflowing_scale_pow10(exp),
+                    (I4F4::from_bits(exact as i8), expected.is_none())
+                );
+                let saturated = expected.unwrap_or(if bits < 0 { I4F4::MIN } else { I4F4::MAX });
+                assert_eq!(x.saturating_scale_pow10(exp), saturated);
+            }
+            for bits in u8::MIN..=u8::MAX {
+                let x = U4F4::from_bits(bits);
+                let exact = scale_pow10_exact(i128::from(bits), exp).unwrap();
+                let expected = u8::try_from(exact).ok().map(U4F4::from_bits);
+                assert_eq!(x.checked_scale_pow10(exp), expected);
+                assert_eq!(
+                    x.overflowing_scale_pow10(exp),
+                    (U4F4::from_bits(exact as u8), expected.is_none())
+                );
+                assert_eq!(x.saturating_scale_pow10(exp), expected.unwrap_or(U4F4::MAX));
+            }
+        }
+
+        // multiplying by 10^2
+        assert_eq!(I16F16::from_num(1.25).scale_pow10(2), I16F16::from_num(125));
+        assert_eq!(
+            I16F16::from_num(-327.5).checked_scale_pow10(2),
+            Some(I16F16::from_num(-32750))
+        );
+        assert_eq!(I16F16::from_num(327.75).checked_scale_pow10(2), None);
+        assert_eq!(U16F16::from_num(700).saturating_scale_pow10(2), U16F16::MAX);
+        // dividing by 10^3
+        assert_eq!(
+            I16F16::from_num(1250).scale_pow10(-3),
+            I16F16::from_num(1.25)
+        );
+        assert_eq!(I16F16::from_num(-1).scale_pow10(-3), I16F16::from_bits(-66));
+        assert_eq!(
+            U16F16::from_num(3).checked_scale_pow10(-3),
+            Some(U16F16::from_bits(197))
+        );
+        // u16::MAX / 10^5 rounds up to 1 even though 10^5 does not fit
+        assert_eq!(U8F8::MAX.scale_pow10(-5), U8F8::DELTA);
+        assert_eq!(U0F8::MAX.scale_pow10(-2), U0F8::from_bits(3));
+        assert_eq!(U16F16::MAX.scale_pow10(-i32::MAX), U16F16::ZERO);
+
+        assert_eq!(U128F0::MAX.scale_pow10(-38), U128F0::from_num(3));
+        assert_eq!(U128F0::MAX.scale_pow10(-39), U128F0::ZERO);
+        assert_eq!(
+            U128F0::from_num(10u128.pow(37)).checked_scale_pow10(1),
+            Some(U128F0::from_num(10u128.pow(38)))
+        );
+        assert_eq!(
+            U128F0::from_num(10u128.pow(37)).checked_scale_pow10(2),
+            None
+        );
+        assert_eq!(
+            U128F0::ONE.overflowing_scale_pow10(i32::MAX),
+            (U128F0::ZERO, true)
+        );
+        assert_eq!(
+            I0F128::MIN.scale_pow10(-1),
+            I0F128::from_bits(i128::MIN / 10 - 1)
+        );
+        assert_eq!(I0F128::MIN.saturating_scale_pow10(1), I0F128::MIN);
+        assert_eq!(
+            I0F128::ZERO.checked_scale_pow10(i32::MAX),
+            Some(I0F128::ZERO)
+        );
+    }
+
     #[test]
     #[allow(clippy::modulo_one)]
     fn rem_int() {
@@ -1302,6 +2344,381 @@ mod tests {
         assert_eq!(i0(0.25).rem_euclid_int(1), i0(0.25));
     }
 
+    #[test]
+    fn div_overflow() {
+        use crate::types::{I16F16, I4F4, U4F4};
+
+        // compare with truncated division of the widened bits
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (x, y) = (I4F4::from_bits(a), I4F4::from_bits(b));
+                if b == 0 {
+                    assert_eq!(x.checked_div(y), None);
+                    continue;
+                }
+                let exact = (i32::from(a) << 4) / i32::from(b);
+                let fits = i8::try_from(exact).is_ok();
+                let wrapped = I4F4::from_bits(exact as i8);
+                assert_eq!(x.overflowing_div(y), (wrapped, !fits), "{a} {b}");
+                assert_eq!(x.wrapping_div(y), wrapped);
+                assert_eq!(x.checked_div(y), fits.then_some(wrapped));
+                let saturated = if fits {
+                    wrapped
+                } else if exact < 0 {
+                    I4F4::MIN
+                } else {
+                    I4F4::MAX
+                };
+                assert_eq!(x.saturating_div(y), saturated);
+                if fits {
+                    assert_eq!(x.unwrapped_div(y), wrapped);
+                    assert_eq!(x / y, wrapped);
+                }
+            }
+        }
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                let (x, y) = (U4F4::from_bits(a), U4F4::from_bits(b));
+                if b == 0 {
+                    assert_eq!(x.checked_div(y), None);
+                    continue;
+                }
+                let exact = (u32::from(a) << 4) / u32::from(b);
+                let fits = u8::try_from(exact).is_ok();
+                let wrapped = U4F4::from_bits(exact as u8);
+                assert_eq!(x.overflowing_div(y), (wrapped, !fits));
+                assert_eq!(x.checked_div(y), fits.then_some(wrapped));
+                assert_eq!(x.saturating_div(y), if fits { wrapped } else { U4F4::MAX });
+            }
+        }
+
+        let f = I16F16::from_num::<f64>;
+        assert_eq!(f(7.5).checked_div(f(2.0)), Some(f(3.75)));
+        assert_eq!(f(-7.5).checked_div(f(2.0)), Some(f(-3.75)));
+        assert_eq!(f(7.5).checked_div(I16F16::ZERO), None);
+        assert_eq!(I16F16::ZERO.checked_div(I16F16::ZERO), None);
+        // MIN / -DELTA and MAX / DELTA overflow
+        let neg_delta = -I16F16::DELTA;
+        assert_eq!(I16F16::MIN.checked_div(neg_delta), None);
+        assert_eq!(I16F16::MIN.saturating_div(neg_delta), I16F16::MAX);
+        assert_eq!(I16F16::MAX.saturating_div(neg_delta), I16F16::MIN);
+        assert_eq!(I16F16::MAX.checked_div(I16F16::DELTA), None);
+        assert_eq!(
+            I16F16::MIN.overflowing_div(-I16F16::ONE),
+            (I16F16::MIN, true)
+        );
+        assert_eq!(I16F16::MIN.checked_div(I16F16::ONE), Some(I16F16::MIN));
+    }
+
+    #[test]
+    fn div_euclid() {
+        use crate::types::{I16F16, I4F4, U4F4};
+
+        // compare with exact Euclidean division of the bits
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                if b == 0 {
+                    continue;
+                }
+                let (x, y) = (I4F4::from_bits(a), I4F4::from_bits(b));
+                let quot = i32::from(a).div_euclid(i32::from(b));
+                let exact = quot << 4;
+                let fits = i8::try_from(exact).is_ok();
+                let wrapped = I4F4::from_bits(exact as i8);
+                assert_eq!(x.overflowing_div_euclid(y), (wrapped, !fits), "{a} {b}");
+                assert_eq!(x.wrapping_div_euclid(y), wrapped);
+                assert_eq!(x.checked_div_euclid(y), fits.then_some(wrapped));
+                let saturated = if fits {
+                    wrapped
+                } else if exact < 0 {
+                    I4F4::MIN
+                } else {
+                    I4F4::MAX
+                };
+                assert_eq!(x.saturating_div_euclid(y), saturated);
+                if fits {
+                    // self == div_euclid(rhs) × rhs + rem_euclid(rhs)
+                    let rem = x.rem_euclid(y);
+                    assert!(rem >= 0 && rem.to_bits().unsigned_abs() < b.unsigned_abs());
+                    assert_eq!(quot * i32::from(b) + i32::from(rem.to_bits()), i32::from(a));
+                }
+            }
+        }
+        for a in u8::MIN..=u8::MAX {
+            for b in 1..=u8::MAX {
+                let (x, y) = (U4F4::from_bits(a), U4F4::from_bits(b));
+                let exact = u32::from(a / b) << 4;
+                let fits = u8::try_from(exact).is_ok();
+                let wrapped = U4F4::from_bits(exact as u8);
+                assert_eq!(x.overflowing_div_euclid(y), (wrapped, !fits));
+                assert_eq!(
+                    x.saturating_div_euclid(y),
+                    if fits { wrapped } else { U4F4::MAX }
+                );
+            }
+        }
+
+        // negative dividends round towards -∞ for positive divisors, unlike
+        // truncating division
+        let f = I16F16::from_num::<f64>;
+        assert_eq!(f(-7.5) / f(2.0), f(-3.75));
+        assert_eq!((f(-7.5) / f(2.0)).round_to_zero(), -3);
+        assert_eq!(f(-7.5).div_euclid(f(2.0)), -4);
+        assert_eq!(f(-7.5).rem_euclid(f(2.0)), 0.5);
+        assert_eq!(f(-7.5).div_euclid(f(-2.0)), 4);
+        assert_eq!(f(-7.5).rem_euclid(f(-2.0)), 0.5);
+        assert_eq!(f(-8.0).div_euclid(f(2.0)), -4);
+        assert_eq!(f(-0.25).div_euclid(f(2.0)), -1);
+        assert_eq!(f(-0.25).rem_euclid(f(2.0)), 1.75);
+        assert_eq!(f(7.5).div_euclid(f(-2.0)), -3);
+        assert_eq!(f(7.5).rem_euclid(f(-2.0)), 1.5);
+        // dividing by -DELTA
+        let neg_delta = -I16F16::DELTA;
+        assert_eq!(I16F16::MIN.checked_div_euclid(neg_delta), None);
+        assert_eq!(I16F16::MIN.saturating_div_euclid(neg_delta), I16F16::MAX);
+        assert_eq!(
+            I16F16::from_bits(-3).checked_div_euclid(neg_delta),
+            Some(I16F16::from_num(3))
+        );
+        assert_eq!(I16F16::from_bits(3).div_euclid(I16F16::DELTA), 3);
+    }
+
+    #[test]
+    fn div_round() {
+        use crate::types::{I4F4, I64F64, U4F4, U64F64};
+        use crate::{FixedI128, FixedI8, FixedU128, FixedU8};
+
+        // The quotients are small, so dividing as f64 gives the correctly
+        // rounded quotient, and ties are exactly representable.
+        let round = |n: i32, d: i32| (f64::from(n) / f64::from(d)).round_ties_even() as i32;
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                if b == 0 {
+                    continue;
+                }
+                let (a32, b32) = (i32::from(a), i32::from(b));
+                let (x, y) = (I4F4::from_bits(a), I4F4::from_bits(b));
+                let exact = round(a32 << 4, b32);
+                let fits = i8::try_from(exact).is_ok();
+                let wrapped = I4F4::from_bits(exact as i8);
+                assert_eq!(x.wrapping_div_round(y), wrapped, "{a} {b}");
+                assert_eq!(x.checked_div_round(y), fits.then_some(wrapped));
+                let saturated = if fits {
+                    wrapped
+                } else if exact < 0 {
+                    I4F4::MIN
+                } else {
+                    I4F4::MAX
+                };
+                assert_eq!(x.saturating_div_round(y), saturated);
+                if fits {
+                    assert_eq!(x.div_round(y), wrapped);
+                    // truncating division gives the same or a neighbouring value
+                    let trunc = i32::from(x.wrapping_div(y).to_bits());
+                    assert!((exact - trunc).abs() <= 1);
+                }
+
+                let (x, y) = (FixedI8::<0>::from_bits(a), FixedI8::<0>::from_bits(b));
+                let exact = round(a32, b32);
+                assert_eq!(x.wrapping_div_round(y).to_bits(), exact as i8);
+                let (x, y) = (FixedI8::<8>::from_bits(a), FixedI8::<8>::from_bits(b));
+                let exact = round(a32 << 8, b32);
+                assert_eq!(x.wrapping_div_round(y).to_bits(), exact as i8);
+                assert_eq!(
+                    x.checked_div_round(y).map(FixedI8::to_bits),
+                    i8::try_from(exact).ok()
+                );
+            }
+        }
+        for a in u8::MIN..=u8::MAX {
+            for b in 1..=u8::MAX {
+                let exact = round(i32::from(a) << 4, i32::from(b));
+                let fits = u8::try_from(exact).is_ok();
+                let (x, y) = (U4F4::from_bits(a), U4F4::from_bits(b));
+                assert_eq!(x.wrapping_div_round(y).to_bits(), exact as u8);
+                assert_eq!(
+                    x.saturating_div_round(y),
+                    if fits {
+                        U4F4::from_bits(exact as u8)
+                    } else {
+                        U4F4::MAX
+                    }
+                );
+                let (x, y) = (FixedU8::<8>::from_bits(a), FixedU8::<8>::from_bits(b));
+                let exact = round(i32::from(a) << 8, i32::from(b));
+                assert_eq!(
+                    x.checked_div_round(y).map(FixedU8::to_bits),
+                    u8::try_from(exact).ok()
+                );
+            }
+        }
+
+        // 2/3 rounds up, 1/3 rounds down, and 1.5 × DELTA and 2.5 × DELTA are
+        // ties that both round to 2 × DELTA
+        let (two, three) = (I64F64::from_num(2), I64F64::from_num(3));
+        assert_eq!(two / three, I64F64::from_bits(0xAAAA_AAAA_AAAA_AAAA));
+        assert_eq!(
+            two.div_round(three),
+            I64F64::from_bits(0xAAAA_AAAA_AAAA_AAAB)
+        );
+        assert_eq!(
+            I64F64::ONE.div_round(three),
+            I64F64::from_bits(0x5555_5555_5555_5555)
+        );
+        assert_eq!((-two).div_round(three), -two.div_round(three));
+        assert_eq!(two.div_round(-three), -two.div_round(three));
+        assert_eq!(I64F64::from_bits(3).div_round(two), I64F64::from_bits(2));
+        assert_eq!(I64F64::from_bits(5).div_round(two), I64F64::from_bits(2));
+        assert_eq!(I64F64::from_bits(7).div_round(two), I64F64::from_bits(4));
+        assert_eq!(I64F64::from_bits(-3).div_round(two), I64F64::from_bits(-2));
+        assert_eq!(I64F64::from_bits(-5).div_round(-two), I64F64::from_bits(2));
+        let (two, three) = (U64F64::from_num(2), U64F64::from_num(3));
+        assert_eq!(
+            two.div_round(three),
+            U64F64::from_bits(0xAAAA_AAAA_AAAA_AAAB)
+        );
+        assert_eq!(U64F64::from_bits(3).div_round(two), U64F64::from_bits(2));
+        assert_eq!(U64F64::from_bits(5).div_round(two), U64F64::from_bits(2));
+
+        // extreme numbers of fractional bits and overflow
+        let x = FixedU128::<0>::MAX;
+        assert_eq!(x.div_round(FixedU128::from_bits(2)), 1u128 << 127);
+        assert_eq!(x.checked_div_round(FixedU128::ZERO), None);
+        let x = FixedI128::<0>::MIN;
+        assert_eq!(x.checked_div_round(FixedI128::from_bits(-1)), None);
+        assert_eq!(x.wrapping_div_round(FixedI128::from_bits(-1)), x);
+        assert_eq!(
+            x.saturating_div_round(FixedI128::from_bits(-1)),
+            FixedI128::<0>::MAX
+        );
+        assert_eq!(x.div_round(FixedI128::from_bits(-2)), 1i128 << 126);
+        let x = FixedI128::<128>::from_bits(-3);
+        // 3 / (2^127 - 1) is slightly more than 6 × DELTA
+        let y = FixedI128::from_bits(i128::MIN + 1);
+        assert_eq!(x.div_round(y), FixedI128::<128>::from_bits(6));
+        assert_eq!(
+            FixedI128::<128>::MIN.checked_div_round(FixedI128::from_bits(-1)),
+            None
+        );
+        assert_eq!(
+            FixedI128::<128>::MIN.wrapping_div_round(FixedI128::from_bits(-1)),
+            0
+        );
+        let quarter = FixedU128::<128>::from_bits(1 << 126);
+        let half = FixedU128::<128>::from_bits(1 << 127);
+        assert_eq!(quarter.div_round(half), half);
+        assert_eq!(
+            FixedU128::<128>::from_bits(1).div_round(FixedU128::MAX),
+            FixedU128::<128>::from_bits(1)
+        );
+        assert_eq!(I64F64::MAX.checked_div_round(I64F64::ONE / 2), None);
+        assert_eq!(
+            I64F64::MIN.saturating_div_round(I64F64::ONE / 2),
+            I64F64::MIN
+        );
+    }
+
+    #[test]
+    fn is_multiple_of() {
+        use crate::types::{I16F16, I4F4, U4F4};
+
+        // compare with the remainder for all pairs
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (x, y) = (I4F4::from_bits(a), I4F4::from_bits(b));
+                let expected = if b == 0 {
+                    a == 0
+                } else {
+                    i16::from(a) % i16::from(b) == 0
+                };
+                assert_eq!(x.is_multiple_of(y), expected, "{a} {b}");
+            }
+        }
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                let (x, y) = (U4F4::from_bits(a), U4F4::from_bits(b));
+                assert_eq!(
+                    x.is_multiple_of(y),
+                    a.checked_rem(b).map_or(a == 0, |r| r == 0)
+                );
+            }
+        }
+
+        let f = I16F16::from_num::<f64>;
+        // multiples
+        assert!(f(7.5).is_multiple_of(f(2.5)));
+        assert!(f(-7.5).is_multiple_of(f(2.5)));
+        assert!(f(7.5).is_multiple_of(f(-0.5)));
+        assert!(f(0.75).is_multiple_of(f(0.25)));
+        assert!(I16F16::ZERO.is_multiple_of(f(3.0)));
+        assert!(I16F16::MIN.is_multiple_of(-I16F16::DELTA));
+        assert!(I16F16::MIN.is_multiple_of(I16F16::MIN));
+        // non-multiples
+        assert!(!f(7.5).is_multiple_of(f(2.0)));
+        assert!(!f(0.75).is_multiple_of(f(0.5)));
+        assert!(!f(2.5).is_multiple_of(f(7.5)));
+        assert!(!I16F16::MAX.is_multiple_of(I16F16::MIN));
+        // zero divisor
+        assert!(I16F16::ZERO.is_multiple_of(I16F16::ZERO));
+        assert!(!I16F16::DELTA.is_multiple_of(I16F16::ZERO));
+        assert!(!I16F16::MIN.is_multiple_of(I16F16::ZERO));
+    }
+
+    #[test]
+    fn rem_pow2() {
+        use crate::types::{I16F16, I4F4, U16F16, U4F4};
+
+        // the mask path of rem_euclid must match the plain integer remainder
+        for a in i8::MIN..=i8::MAX {
+            for b in [
+                1i8, 2, 4, 8, 16, 32, 64, -1, -2, -4, -8, -16, -32, -64, -128,
+            ] {
+                let expected = I4F4::from_bits(i16::from(a).rem_euclid(i16::from(b)) as i8);
+                let (x, y) = (I4F4::from_bits(a), I4F4::from_bits(b));
+                assert_eq!(x.rem_euclid(y), expected);
+                assert_eq!(x.checked_rem_euclid(y), Some(expected));
+            }
+        }
+        for a in u8::MIN..=u8::MAX {
+            for b in [1u8, 2, 4, 8, 16, 32, 64, 128] {
+                let expected = U4F4::from_bits(a % b);
+                let (x, y) = (U4F4::from_bits(a), U4F4::from_bits(b));
+                assert_eq!(x.rem_euclid(y), expected);
+                assert_eq!(x.checked_rem_euclid(y), Some(expected));
+            }
+        }
+
+        for bits in (-0x40_0000..0x40_0000).step_by(997) {
+            let x = I16F16::from_bits(bits);
+            for exp in 0..15 {
+                let div = I16F16::from_num(1 << exp);
+                assert_eq!(x.rem_pow2(exp), x.rem_euclid(div));
+            }
+            // 2^15 is not representable in I16F16
+            assert_eq!(x.rem_pow2(15).to_bits(), bits.rem_euclid(1 << 31));
+            let ux = U16F16::from_bits(bits as u32);
+            for exp in 0..16 {
+                let div = U16F16::from_num(1 << exp);
+                assert_eq!(ux.rem_pow2(exp), ux.rem_euclid(div));
+            }
+        }
+
+        assert_eq!(I16F16::from_num(-7.5).rem_pow2(1), I16F16::from_num(0.5));
+        assert_eq!(
+            I16F16::from_num(-7.5).rem_pow2(15),
+            I16F16::from_num(32760.5)
+        );
+        assert_eq!(I16F16::from_num(7.5).rem_pow2(16), I16F16::from_num(7.5));
+        assert_eq!(I16F16::MAX.rem_pow2(u32::MAX), I16F16::MAX);
+        assert_eq!(U16F16::MAX.rem_pow2(16), U16F16::MAX);
+        assert_eq!(U16F16::MAX.rem_pow2(3), U16F16::from_bits(0x7_FFFF));
+        assert_eq!(U4F4::MAX.rem_pow2(0), U4F4::from_num(0.9375));
+        // with negative FRAC, DELTA is larger than one
+        type Coarse = crate::FixedI16<{ -2 }>;
+        assert_eq!(Coarse::from_num(-12).rem_pow2(1), Coarse::ZERO);
+        assert_eq!(Coarse::from_num(-12).rem_pow2(4), Coarse::from_num(4));
+    }
+
     #[test]
     fn div_rem_nonzerou() {
         use crate::types::{U0F32, U16F16, U1F31, U31F1, U32F0};
@@ -1431,6 +2848,488 @@ mod tests {
         check_mul_add_no_int! { U0F8 U0F16 U0F32 U0F64 U0F128 }
     }
 
+    #[test]
+    fn clamped_mul_add() {
+        use crate::types::{I16F16, U8F8};
+
+        let (lo, hi) = (I16F16::from_num(-10), I16F16::from_num(10));
+        let gain = I16F16::from_num(4);
+        // in range
+        let x = I16F16::from_num(1.5);
+        assert_eq!(x.clamped_mul_add(gain, I16F16::ONE, lo, hi), 7);
+        // above and below the range
+        assert_eq!(
+            I16F16::from_num(3).clamped_mul_add(gain, I16F16::ONE, lo, hi),
+            hi
+        );
+        assert_eq!(
+            I16F16::from_num(-3).clamped_mul_add(gain, I16F16::ONE, lo, hi),
+            lo
+        );
+        // exactly on the bounds
+        assert_eq!(
+            I16F16::from_num(2.25).clamped_mul_add(gain, I16F16::ONE, lo, hi),
+            hi
+        );
+        // the intermediate product overflows in both directions
+        let max = I16F16::MAX;
+        assert_eq!(max.clamped_mul_add(gain, I16F16::ZERO, lo, hi), hi);
+        assert_eq!(max.clamped_mul_add(-gain, I16F16::ZERO, lo, hi), lo);
+        // -MAX + MAX × 1.5 overflows in the product but not in the result
+        let wide = (I16F16::MIN, I16F16::MAX);
+        assert_eq!(
+            max.clamped_mul_add(I16F16::from_num(1.5), -max, wide.0, wide.1),
+            max / 2
+        );
+        // the range can be a single point
+        assert_eq!(x.clamped_mul_add(gain, I16F16::ONE, hi, hi), hi);
+
+        // multiplier with a different number of fractional bits
+        let mul = FixedI32::<20>::from_num(0.5);
+        assert_eq!(x.clamped_mul_add(mul, I16F16::ZERO, lo, hi), 0.75);
+
+        let (lo, hi) = (U8F8::from_num(1), U8F8::from_num(200));
+        assert_eq!(U8F8::MAX.clamped_mul_add(U8F8::MAX, U8F8::MAX, lo, hi), hi);
+        assert_eq!(
+            U8F8::ZERO.clamped_mul_add(U8F8::MAX, U8F8::ZERO, lo, hi),
+            lo
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lo > hi")]
+    fn clamped_mul_add_bad_range() {
+        use crate::types::I16F16;
+        let _ = I16F16::ONE.clamped_mul_add(I16F16::ONE, I16F16::ONE, I16F16::ONE, -I16F16::ONE);
+    }
+
+    // self - a × b computed exactly and rounded down
+    fn sub_prod_exact(sf: i64, a: i64, b: i64, s: i32) -> i64 {
+        if s >= 0 {
+            (sf * (1 << s) - a * b).div_euclid(1 << s)
+        } else {
+            sf - a * b * (1 << -s)
+        }
+    }
+
+    macro_rules! check_sub_prod_exhaustive {
+        ($Fixed:ident, $Inner:ident, $vals:expr; $(($A:literal, $B:literal, $F:literal))*) => { $(
+            for sf in $vals {
+                let x = $Fixed::<$F>::from_bits(sf);
+                for a in $Inner::MIN..=$Inner::MAX {
+                    for b in $Inner::MIN..=$Inner::MAX {
+                        let exact = sub_prod_exact(sf.into(), a.into(), b.into(), $A + $B - $F);
+                        let expected = (
+                            $Fixed::<$F>::from_bits(exact as $Inner),
+                            $Inner::try_from(exact).is_err(),
+                        );
+                        let (fa, fb) = ($Fixed::<$A>::from_bits(a), $Fixed::<$B>::from_bits(b));
+                        assert_eq!(x.overflowing_sub_prod(fa, fb), expected);
+                    }
+                }
+            }
+        )* };
+    }
+
+    macro_rules! check_sub_prod {
+        ($($F:ty)*) => { $(
+            let min = <$F>::MIN;
+            let max = <$F>::MAX;
+            let delta = <$F>::DELTA;
+            let zero = <$F>::ZERO;
+            let one = <$F>::ONE;
+            let three = one * 3;
+            let hmax = max / 2;
+            assert_eq!(max.overflowing_sub_prod(one, zero), (max, false));
+            assert_eq!(min.overflowing_sub_prod(one, delta), (max, true));
+            assert_eq!(max.overflowing_sub_prod(one, delta), (max - delta, false));
+            assert_eq!(max.overflowing_sub_prod(one, max), (zero, false));
+            assert_eq!(
+                zero.overflowing_sub_prod(three, max),
+                (zero.wrapping_sub(max.wrapping_mul_int(3)), true)
+            );
+            assert_eq!(hmax.overflowing_sub_prod(hmax, one), (zero, false));
+            assert_eq!(max.saturating_sub_prod(three, max), min);
+            assert_eq!(min.checked_sub_prod(one, delta), None);
+            assert_eq!(max.wrapping_sub_prod(three, max), max.wrapping_sub(max.wrapping_mul_int(3)));
+        )* };
+    }
+
+    #[test]
+    fn sub_prod() {
+        use crate::types::*;
+        check_sub_prod! { I4F4 I8F8 I16F16 I32F32 I64F64 }
+        check_sub_prod! { I8F0 I16F0 I32F0 I64F0 I128F0 }
+        check_sub_prod! { U4F4 U8F8 U16F16 U32F32 U64F64 }
+        check_sub_prod! { U8F0 U16F0 U32F0 U64F0 U128F0 }
+
+        let ivals = [i8::MIN, -100, -1, 0, 1, 37, i8::MAX];
+        check_sub_prod_exhaustive! {
+            FixedI8, i8, ivals;
+            (4, 4, 4) (0, 0, 0) (8, 8, 8) (7, 7, 0) (-2, 3, 4) (3, 2, 9)
+        }
+        let uvals = [0, 1, 37, 128, 200, u8::MAX];
+        check_sub_prod_exhaustive! {
+            FixedU8, u8, uvals;
+            (4, 4, 4) (0, 0, 0) (8, 8, 8) (8, 8, 0) (-2, 3, 4) (3, 2, 9)
+        }
+
+        // signed: the product overflows on its own, but the result does not
+        let (min, max) = (I16F16::MIN, I16F16::MAX);
+        assert_eq!(min.sub_prod(min, I16F16::from_num(1.5)), -(min / 2));
+        assert_eq!(
+            max.checked_sub_prod(max, I16F16::from_num(1.5)),
+            Some(-(max / 2) - I16F16::DELTA)
+        );
+        assert_eq!(min.saturating_sub_prod(min, I16F16::ONE), I16F16::ZERO);
+        assert_eq!(
+            I16F16::ZERO.saturating_sub_prod(max, I16F16::from_num(2)),
+            min
+        );
+        assert_eq!(
+            I16F16::ZERO.saturating_sub_prod(min, I16F16::from_num(2)),
+            max
+        );
+        assert_eq!(
+            I16F16::ZERO.saturating_sub_prod(max, I16F16::from_num(-2)),
+            max
+        );
+
+        // MIN × MIN cannot be negated by negating a factor
+        let half = FixedI128::<128>::MIN;
+        assert_eq!(I8F120::ONE.sub_prod(half, half), 0.75);
+        assert_eq!(
+            I0F128::MIN.overflowing_sub_prod(half, half),
+            (I0F128::from_num(0.25), true)
+        );
+        let half = FixedI64::<64>::MIN;
+        assert_eq!(I8F56::ONE.sub_prod(half, half), 0.75);
+
+        // the product is rounded up, so that the result is rounded down
+        let half = U0F128::from_num(0.5);
+        assert_eq!(
+            U0F128::MAX.sub_prod(U0F128::DELTA, half),
+            U0F128::MAX - U0F128::DELTA
+        );
+        assert_eq!(
+            U0F128::ZERO.overflowing_sub_prod(U0F128::DELTA, half),
+            (U0F128::MAX, true)
+        );
+        assert_eq!(U0F128::MAX.sub_prod(half, half), U0F128::MAX - half / 2);
+        assert_eq!(
+            I0F128::ZERO.sub_prod(I0F128::DELTA, I0F128::DELTA),
+            -I0F128::DELTA
+        );
+        assert_eq!(
+            I64F64::ZERO.sub_prod(-I64F64::DELTA, I64F64::DELTA),
+            I64F64::ZERO
+        );
+
+        // parameters with different numbers of fractional bits
+        let a = FixedI32::<-4>::from_bits(1);
+        let b = FixedI32::<20>::from_num(0.25);
+        assert_eq!(I16F16::from_num(5).sub_prod(a, b), 1);
+        assert_eq!(
+            I16F16::from_num(5).unwrapped_sub_prod(a, b),
+            I16F16::from_num(5).add_prod(-a, b)
+        );
+    }
+
+    // a × b - sub computed exactly and rounded down
+    fn mul_sub_exact(a: i64, b: i64, sub: i64, s: i32) -> i64 {
+        if s >= 0 {
+            (a * b - sub * (1 << s)).div_euclid(1 << s)
+        } else {
+            a * b * (1 << -s) - sub
+        }
+    }
+
+    macro_rules! check_mul_sub_exhaustive {
+        ($Fixed:ident, $Inner:ident, $vals:expr; $(($F:literal, $M:literal))*) => { $(
+            for sub in $vals {
+                let fsub = $Fixed::<$F>::from_bits(sub);
+                for a in $Inner::MIN..=$Inner::MAX {
+                    for b in $Inner::MIN..=$Inner::MAX {
+                        let exact = mul_sub_exact(a.into(), b.into(), sub.into(), $M);
+                        let expected = (
+                            $Fixed::<$F>::from_bits(exact as $Inner),
+                            $Inner::try_from(exact).is_err(),
+                        );
+                        let (fa, fb) = ($Fixed::<$F>::from_bits(a), $Fixed::<$M>::from_bits(b));
+                        assert_eq!(fa.overflowing_mul_sub(fb, fsub), expected);
+                        assert_eq!(
+                            fa.overflowing_neg_mul_add(fb, fsub),
+                            fsub.overflowing_sub_prod(fa, fb)
+                        );
+                    }
+                }
+            }
+        )* };
+    }
+
+    macro_rules! check_mul_sub {
+        ($($F:ty)*) => { $(
+            let min = <$F>::MIN;
+            let max = <$F>::MAX;
+            let delta = <$F>::DELTA;
+            let zero = <$F>::ZERO;
+            let one = <$F>::ONE;
+            let two = one * 2;
+            let three = one * 3;
+            assert_eq!(max.overflowing_mul_sub(one, zero), (max, false));
+            assert_eq!(max.overflowing_mul_sub(one, max), (zero, false));
+            assert_eq!(max.overflowing_mul_sub(two, max), (max, false));
+            assert_eq!(
+                max.overflowing_mul_sub(three, max),
+                (max.wrapping_mul_int(2), true)
+            );
+            assert_eq!(min.overflowing_mul_sub(one, delta), (max, true));
+            assert_eq!(max.saturating_mul_sub(three, max), max);
+            assert_eq!(min.saturating_mul_sub(one, delta), min);
+            assert_eq!(max.checked_mul_sub(two, max), Some(max));
+            assert_eq!(max.checked_mul_sub(three, max), None);
+            assert_eq!(max.wrapping_mul_sub(three, max), max.wrapping_mul_int(2));
+            assert_eq!(max.unwrapped_mul_sub(two, max), max);
+            assert_eq!(max.mul_sub(one, delta), max - delta);
+
+            assert_eq!(one.overflowing_neg_mul_add(max, max), (zero, false));
+            assert_eq!(three.overflowing_neg_mul_add(max, min), min.overflowing_sub_prod(three, max));
+            assert_eq!(three.saturating_neg_mul_add(max, zero), min);
+            assert_eq!(delta.checked_neg_mul_add(one, min), None);
+            assert_eq!(delta.wrapping_neg_mul_add(one, min), max);
+            assert_eq!(one.unwrapped_neg_mul_add(one, max), max - one);
+            assert_eq!(one.neg_mul_add(delta, max), max - delta);
+        )* };
+    }
+
+    #[test]
+    fn mul_sub() {
+        use crate::types::*;
+        check_mul_sub! { I4F4 I8F8 I16F16 I32F32 I64F64 }
+        check_mul_sub! { I8F0 I16F0 I32F0 I64F0 I128F0 }
+        check_mul_sub! { U4F4 U8F8 U16F16 U32F32 U64F64 }
+        check_mul_sub! { U8F0 U16F0 U32F0 U64F0 U128F0 }
+
+        let ivals = [i8::MIN, -100, -1, 0, 1, 37, i8::MAX];
+        check_mul_sub_exhaustive! {
+            FixedI8, i8, ivals;
+            (4, 4) (0, 0) (8, 8) (4, 7) (4, -2) (2, 9)
+        }
+        let uvals = [0, 1, 37, 128, 200, u8::MAX];
+        check_mul_sub_exhaustive! {
+            FixedU8, u8, uvals;
+            (4, 4) (0, 0) (8, 8) (4, 8) (4, -2) (2, 9)
+        }
+
+        // signed: subtracting MIN cannot be done by adding its negation
+        let (min, max) = (I16F16::MIN, I16F16::MAX);
+        assert_eq!(
+            I16F16::from_num(-1).overflowing_mul_sub(max, min),
+            (I16F16::DELTA, false)
+        );
+        assert_eq!(
+            min.overflowing_mul_sub(I16F16::ONE, min),
+            (I16F16::ZERO, false)
+        );
+        assert_eq!(
+            I16F16::ONE.overflowing_mul_sub(I16F16::ONE, min),
+            (min + I16F16::ONE, true)
+        );
+        assert_eq!(I16F16::ONE.saturating_mul_sub(I16F16::ONE, min), max);
+        assert_eq!(max.saturating_mul_sub(I16F16::from_num(-2), max), min);
+        assert_eq!(min.saturating_mul_sub(I16F16::from_num(-2), min), max);
+        let (min, max) = (I64F64::MIN, I64F64::MAX);
+        assert_eq!(
+            I64F64::from_num(-1).checked_mul_sub(max, min),
+            Some(I64F64::DELTA)
+        );
+        assert_eq!(I64F64::ONE.checked_mul_sub(I64F64::ONE, min), None);
+
+        // unsigned: the product overflows, but the subtraction brings it back
+        let (min, max) = (U16F16::MIN, U16F16::MAX);
+        assert_eq!(
+            max.saturating_mul_sub(U16F16::from_num(1.5), max / 2 + U16F16::ONE),
+            max - U16F16::ONE
+        );
+        assert_eq!(max.saturating_mul_sub(U16F16::from_num(3), max), max);
+        assert_eq!(U16F16::ONE.saturating_mul_sub(U16F16::ONE, max), min);
+        let (min, max) = (U64F64::MIN, U64F64::MAX);
+        assert_eq!(max.checked_mul_sub(U64F64::from_num(2), max), Some(max));
+        assert_eq!(max.checked_mul_sub(U64F64::from_num(2), min), None);
+        assert_eq!(
+            U0F128::MAX.overflowing_mul_sub(U0F128::MAX, U0F128::MAX),
+            (
+                U0F128::MAX
+                    .wrapping_mul(U0F128::MAX)
+                    .wrapping_sub(U0F128::MAX),
+                true
+            )
+        );
+
+        // parameters with different numbers of fractional bits
+        let a = FixedI32::<-4>::from_bits(1);
+        assert_eq!(I16F16::from_num(5).mul_sub(a, I16F16::ONE), 79);
+        assert_eq!(
+            I16F16::from_num(0.25).neg_mul_add(a, I16F16::from_num(5)),
+            1
+        );
+    }
+
+    #[test]
+    fn add_prod2() {
+        use crate::types::{I16F16, I4F4, I64F64, U8F8};
+
+        let (max, three) = (I16F16::MAX, I16F16::from_num(3));
+        // -MAX + MAX × 3 overflows, but adding -MAX × 1 brings it back
+        assert_eq!((-max).add_prod2(max, three, -max, I16F16::ONE), max);
+        assert_eq!((-max).checked_add_prod(max, three), None);
+        assert_eq!(
+            (-max).checked_add_prod2(max, three, -max, I16F16::ONE),
+            Some(max)
+        );
+        assert_eq!(
+            (-max).overflowing_add_prod2(max, three, -max, I16F16::ONE),
+            (max, false)
+        );
+
+        // MAX × MAX overflows on its own; each product is rounded down, so
+        // 1008.0625 - 1008.0625 becomes 1008 - 1008.0625
+        let max = I4F4::MAX;
+        assert_eq!(
+            I4F4::ZERO.checked_add_prod2(max, max, -max, max),
+            Some(-I4F4::DELTA)
+        );
+        assert_eq!(
+            max.overflowing_add_prod2(max, I4F4::from_num(3), I4F4::ZERO, I4F4::ZERO),
+            (max.wrapping_mul_int(4), true)
+        );
+
+        // MAX × 4 + MIN × 4 = -DELTA × 4
+        let (max, min, four) = (I64F64::MAX, I64F64::MIN, I64F64::from_num(4));
+        assert_eq!(
+            I64F64::ZERO.checked_add_prod2(max, four, min, four),
+            Some(-I64F64::DELTA * 4)
+        );
+        assert_eq!(I64F64::ZERO.checked_add_prod2(max, four, max, four), None);
+        assert_eq!(I64F64::ZERO.saturating_add_prod2(max, four, max, four), max);
+        assert_eq!(I64F64::ZERO.saturating_add_prod2(min, four, min, four), min);
+        assert_eq!(
+            I64F64::ZERO.saturating_add_prod2(max, -four, max, four),
+            I64F64::ZERO
+        );
+
+        // parameters with different numbers of fractional bits
+        let a = FixedI32::<-4>::from_bits(1);
+        let b = FixedI32::<20>::from_num(0.25);
+        let c = FixedI32::<0>::from_num(-3);
+        let d = I16F16::from_num(1.5);
+        assert_eq!(I16F16::ONE.add_prod2(a, b, c, d), 0.5);
+        assert_eq!(
+            I16F16::ONE.wrapping_add_prod2(a, b, c, d),
+            I16F16::ONE.add_prod(a, b).add_prod(c, d)
+        );
+
+        // products shifted left by at least the number of bits
+        let big = FixedI8::<-8>::from_bits(1);
+        assert_eq!(
+            FixedI8::<0>::ZERO.overflowing_add_prod(big, big),
+            (FixedI8::ZERO, true)
+        );
+        assert_eq!(
+            FixedI8::<0>::ONE.checked_add_prod2(big, big, -big, big),
+            Some(FixedI8::ONE)
+        );
+
+        let (one, max) = (U8F8::ONE, U8F8::MAX);
+        assert_eq!(
+            one.checked_add_prod2(U8F8::from_num(2), one, U8F8::from_num(0.5), one),
+            Some(U8F8::from_num(3.5))
+        );
+        assert_eq!(one.checked_add_prod2(max, one, U8F8::ZERO, one), None);
+        assert_eq!(U8F8::ZERO.saturating_add_prod2(max, max, one, one), max);
+        assert_eq!(
+            one.unwrapped_add_prod2(max, U8F8::ZERO, U8F8::DELTA, U8F8::from_num(0.5)),
+            one
+        );
+    }
+
+    #[test]
+    fn checked_mul_acc_slice() {
+        use crate::types::{I16F16, I8F8, U8F8};
+
+        let a = [
+            I16F16::from_num(1.5),
+            I16F16::from_num(-2),
+            I16F16::from_num(0.25),
+        ];
+        let b = [
+            I16F16::from_num(4),
+            I16F16::from_num(0.5),
+            I16F16::from_num(-8),
+        ];
+        let mut acc = I16F16::from_num(10);
+        assert_eq!(acc.checked_mul_acc_slice(&a, &b), Some(()));
+        // 10 + 6 - 1 - 2
+        assert_eq!(acc, 13);
+        assert_eq!(acc.checked_mul_acc_slice::<16, 16>(&[], &[]), Some(()));
+        assert_eq!(acc, 13);
+
+        // same as accumulating one pair at a time
+        let mut one_at_a_time = I16F16::from_num(10);
+        for (&x, &y) in a.iter().zip(&b) {
+            one_at_a_time.mul_acc(x, y);
+        }
+        assert_eq!(one_at_a_time, 13);
+
+        // slices with different numbers of fractional bits
+        let a = [FixedI32::<-2>::from_num(8), FixedI32::<-2>::from_num(-4)];
+        let b = [
+            FixedI32::<30>::from_num(0.5),
+            FixedI32::<30>::from_num(0.25),
+        ];
+        let mut acc = I16F16::ZERO;
+        assert_eq!(acc.checked_mul_acc_slice(&a, &b), Some(()));
+        assert_eq!(acc, 3);
+
+        // overflow in the middle leaves self unchanged
+        let a = [
+            I8F8::from_num(100),
+            I8F8::from_num(100),
+            I8F8::from_num(-100),
+        ];
+        let ones = [I8F8::ONE; 3];
+        let mut acc = I8F8::from_num(1.5);
+        assert_eq!(acc.checked_mul_acc_slice(&a, &ones), None);
+        assert_eq!(acc, 1.5);
+        // overflow of a single product
+        let mut acc = I8F8::ZERO;
+        assert_eq!(
+            acc.checked_mul_acc_slice(&[I8F8::from_num(16)], &[I8F8::from_num(8)]),
+            None
+        );
+        assert_eq!(acc, 0);
+        let mut acc = U8F8::ONE;
+        assert_eq!(
+            acc.checked_mul_acc_slice(&[U8F8::MAX, U8F8::ONE], &[U8F8::ONE, U8F8::ONE]),
+            None
+        );
+        assert_eq!(acc, 1);
+        assert_eq!(
+            acc.checked_mul_acc_slice(&[U8F8::MAX - U8F8::ONE], &[U8F8::ONE]),
+            Some(())
+        );
+        assert_eq!(acc, U8F8::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "slices have different lengths")]
+    fn checked_mul_acc_slice_lengths() {
+        use crate::types::I16F16;
+
+        let mut acc = I16F16::ZERO;
+        let _ = acc.checked_mul_acc_slice(&[I16F16::ONE; 2], &[I16F16::ONE; 3]);
+    }
+
     #[test]
     fn overflowing_mul_add_large_frac_nbits() {
         let nbits_2 = 128;
@@ -1693,6 +3592,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn overflowing_mul_add_narrow_neg_frac_nbits() {
+        use crate::FixedI8;
+
+        // For narrow types, a product shifted left by at least the number of
+        // bits always overflows unless it is zero.
+        for nbits in [-8, -10, -15, -100, i32::MIN] {
+            assert_eq!(arith::u8::overflowing_mul_add(1, 1, 5, nbits), (5, true));
+            assert_eq!(arith::u8::overflowing_mul_add(0, 7, 5, nbits), (5, false));
+            assert_eq!(arith::i8::overflowing_mul_add(-1, 1, 5, nbits), (5, true));
+            assert_eq!(arith::i8::overflowing_mul_add(3, 0, -5, nbits), (-5, false));
+            assert_eq!(
+                arith::u16::overflowing_mul_add(1, 1, 5, nbits.saturating_mul(2)),
+                (5, true)
+            );
+            assert_eq!(
+                arith::i32::overflowing_mul_add(1, 1, 5, nbits.saturating_mul(4)),
+                (5, true)
+            );
+        }
+
+        // 1024 × 1024 does not fit in FixedI8<0>
+        let (a, b) = (FixedI8::<-10>::from_bits(1), FixedI8::<-10>::from_bits(1));
+        assert_eq!(
+            FixedI8::<0>::ZERO.overflowing_add_prod(a, b),
+            (FixedI8::ZERO, true)
+        );
+        assert_eq!(FixedI8::<0>::ONE.checked_add_prod(a, b), None);
+        let zero = FixedI8::<-10>::ZERO;
+        assert_eq!(
+            FixedI8::<0>::ONE.checked_add_prod(a, zero),
+            Some(FixedI8::ONE)
+        );
+    }
+
     #[test]
     fn issue_26() {
         use crate::{FixedI128, FixedU128};
@@ -1805,4 +3739,77 @@ mod tests {
             (I0F128::from_bits(-2), true)
         );
     }
+
+    #[test]
+    fn overflowing_mul_add2_exhaustive() {
+        use core::cmp::Ordering;
+
+        fn exact(m1: i16, m2: i16, s: i32) -> i128 {
+            let prod = i128::from(m1) * i128::from(m2);
+            if s >= 0 {
+                prod >> s
+            } else {
+                prod << -s
+            }
+        }
+        fn ordering(sum: i128, min: i128, max: i128) -> Ordering {
+            if sum < min {
+                Ordering::Less
+            } else if sum > max {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }
+
+        let shifts = [-40, -24, -16, -9, -8, -1, 0, 1, 7, 8, 15, 16, 24];
+        let ivals = [-128i8, -127, -64, -1, 0, 1, 63, 64, 127];
+        let uvals = [0u8, 1, 2, 127, 128, 255];
+        for &s1 in &shifts {
+            for &s2 in &shifts {
+                for m1 in ivals {
+                    for m2 in ivals {
+                        for m3 in ivals {
+                            for m4 in ivals {
+                                for add in [i8::MIN, -1, 0, 1, i8::MAX] {
+                                    let (m1, m2, m3, m4) =
+                                        (m1.into(), m2.into(), m3.into(), m4.into());
+                                    let sum =
+                                        i128::from(add) + exact(m1, m2, s1) + exact(m3, m4, s2);
+                                    assert_eq!(
+                                        arith::i8::overflowing_mul_add2(
+                                            m1 as i8, m2 as i8, s1, m3 as i8, m4 as i8, s2, add
+                                        ),
+                                        (sum as i8, ordering(sum, -128, 127)),
+                                        "{add} + {m1} × {m2} >> {s1} + {m3} × {m4} >> {s2}"
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+                for m1 in uvals {
+                    for m2 in uvals {
+                        for m3 in uvals {
+                            for m4 in uvals {
+                                for add in [0, 1, u8::MAX] {
+                                    let (m1, m2, m3, m4) =
+                                        (m1.into(), m2.into(), m3.into(), m4.into());
+                                    let sum =
+                                        i128::from(add) + exact(m1, m2, s1) + exact(m3, m4, s2);
+                                    assert_eq!(
+                                        arith::u8::overflowing_mul_add2(
+                                            m1 as u8, m2 as u8, s1, m3 as u8, m4 as u8, s2, add
+                                        ),
+                                        (sum as u8, ordering(sum, 0, 255)),
+                                        "{add} + {m1} × {m2} >> {s1} + {m3} × {m4} >> {s2}"
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
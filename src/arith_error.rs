@@ -0,0 +1,107 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/**
+An error which can be returned when an arithmetic operation on fixed-point
+numbers overflows.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, ArithError};
+let vals = [I16F16::MAX, I16F16::ONE];
+let err: ArithError = I16F16::try_sum(vals).unwrap_err();
+assert_eq!(err.to_string(), "overflow");
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArithError {
+    pub(crate) _priv: (),
+}
+
+impl ArithError {
+    #[inline]
+    pub(crate) const fn message(self) -> &'static str {
+        "overflow"
+    }
+}
+
+impl Display for ArithError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.message(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ArithError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I16F16, I8F8, U0F8};
+    use crate::ArithError;
+
+    fn mean(vals: &[I16F16]) -> Result<I16F16, ArithError> {
+        let sum = I16F16::try_sum(vals.iter().copied())?;
+        Ok(sum / I16F16::from_num(vals.len()))
+    }
+
+    #[test]
+    fn try_sum_fits() {
+        let vals = [
+            I8F8::from_num(100),
+            I8F8::from_num(27.5),
+            I8F8::from_num(-50.25),
+        ];
+        assert_eq!(I8F8::try_sum(vals), Ok(I8F8::from_num(77.25)));
+        assert_eq!(I8F8::try_sum([]), Ok(I8F8::ZERO));
+        assert_eq!(
+            U0F8::try_sum([U0F8::from_num(0.5); 1]),
+            Ok(U0F8::from_num(0.5))
+        );
+        // intermediate sums must fit, even if the total would
+        assert_eq!(I8F8::try_sum([I8F8::MAX, I8F8::MIN]), Ok(-I8F8::DELTA));
+        assert_eq!(
+            mean(&[I16F16::from_num(1.5), I16F16::from_num(2.5)]),
+            Ok(I16F16::from_num(2))
+        );
+    }
+
+    #[test]
+    fn try_sum_overflow() {
+        let err = ArithError { _priv: () };
+        assert_eq!(I8F8::try_sum([I8F8::MAX, I8F8::DELTA]), Err(err));
+        assert_eq!(I8F8::try_sum([I8F8::MIN, -I8F8::DELTA]), Err(err));
+        assert_eq!(U0F8::try_sum([U0F8::from_num(0.5); 2]), Err(err));
+        assert_eq!(mean(&[I16F16::MAX, I16F16::MAX]), Err(err));
+
+        // the error is returned at the first overflow, without consuming the rest
+        let mut vals = [I8F8::MAX, I8F8::ONE, I8F8::ONE, I8F8::ONE].into_iter();
+        assert_eq!(I8F8::try_sum(vals.by_ref()), Err(err));
+        assert_eq!(vals.len(), 2);
+        // later values cannot bring an overflowed sum back into range
+        assert_eq!(I8F8::try_sum([I8F8::MAX, I8F8::ONE, -I8F8::ONE]), Err(err));
+    }
+}
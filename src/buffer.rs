@@ -0,0 +1,104 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/**
+An error which can be returned when writing fixed-point numbers to a byte
+buffer that is too small.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, BufferTooSmall};
+let vals = [I16F16::ONE, I16F16::ZERO];
+let mut buf = [0u8; 7];
+let err: BufferTooSmall = I16F16::write_be_slice(&vals, &mut buf).unwrap_err();
+assert_eq!(err.to_string(), "buffer too small");
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    pub(crate) _priv: (),
+}
+
+impl BufferTooSmall {
+    #[inline]
+    pub(crate) const fn message(self) -> &'static str {
+        "buffer too small"
+    }
+}
+
+impl Display for BufferTooSmall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.message(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BufferTooSmall {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F8, I16F16, U64F64};
+
+    #[test]
+    fn exact_size() {
+        let vals = [I16F16::from_bits(0x0102_0304), I16F16::from_bits(-2)];
+        let mut buf = [0u8; 8];
+        assert_eq!(I16F16::write_be_slice(&vals, &mut buf), Ok(8));
+        assert_eq!(buf, [0x01, 0x02, 0x03, 0x04, 0xFF, 0xFF, 0xFF, 0xFE]);
+        assert_eq!(I16F16::write_le_slice(&vals, &mut buf), Ok(8));
+        assert_eq!(buf, [0x04, 0x03, 0x02, 0x01, 0xFE, 0xFF, 0xFF, 0xFF]);
+
+        let vals = [I0F8::from_bits(-1), I0F8::from_bits(5), I0F8::ZERO];
+        let mut buf = [0u8; 3];
+        assert_eq!(I0F8::write_be_slice(&vals, &mut buf), Ok(3));
+        assert_eq!(buf, [0xFF, 0x05, 0x00]);
+    }
+
+    #[test]
+    fn larger_buffer() {
+        let vals = [U64F64::from_bits(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10)];
+        let mut buf = [0xAAu8; 20];
+        assert_eq!(U64F64::write_le_slice(&vals, &mut buf), Ok(16));
+        for (i, &b) in buf[..16].iter().enumerate() {
+            assert_eq!(usize::from(b), 16 - i);
+        }
+        // bytes past the written length are left untouched
+        assert_eq!(buf[16..], [0xAA; 4]);
+        assert_eq!(U64F64::write_be_slice(&[], &mut buf), Ok(0));
+    }
+
+    #[test]
+    fn too_small() {
+        let vals = [I16F16::ONE, I16F16::ONE];
+        let mut buf = [0u8; 7];
+        assert!(I16F16::write_be_slice(&vals, &mut buf).is_err());
+        assert!(I16F16::write_le_slice(&vals, &mut buf).is_err());
+        // nothing is written on error
+        assert_eq!(buf, [0; 7]);
+        assert!(U64F64::write_be_slice(&[U64F64::ZERO], &mut []).is_err());
+    }
+}
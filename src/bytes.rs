@@ -13,7 +13,14 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-// Kept trimmed: no underscores at beginning or end of slice
+// Digits can be separated by underscores, or by group separators when parsing
+// grouped strings, so any byte that is not an ASCII digit or letter is treated
+// as a separator.
+const fn is_sep(byte: u8) -> bool {
+    !byte.is_ascii_alphanumeric()
+}
+
+// Kept trimmed: no separators at beginning or end of slice
 #[derive(Clone, Copy, Debug)]
 pub struct DigitsUnds<'a> {
     bytes: &'a [u8],
@@ -31,7 +38,7 @@ impl<'a> DigitsUnds<'a> {
         while let Some((&byte, rem)) = rem_bytes.split_first() {
             rem_bytes = rem;
 
-            if byte == b'_' {
+            if is_sep(byte) {
                 trailing_unds += 1;
             } else {
                 if digits == 0 {
@@ -66,7 +73,7 @@ impl<'a> DigitsUnds<'a> {
         while let Some((&byte, rem)) = rem_bytes.split_first() {
             rem_bytes = rem;
 
-            if byte == b'_' {
+            if is_sep(byte) {
                 unds += 1;
             } else {
                 remaining_digits -= 1;
@@ -83,8 +90,11 @@ impl<'a> DigitsUnds<'a> {
             digits: mid,
         };
 
-        // skip over underscores between first part and last part
-        while let Some((&b'_', rem)) = rem_bytes.split_first() {
+        // skip over separators between first part and last part
+        while let Some((&byte, rem)) = rem_bytes.split_first() {
+            if !is_sep(byte) {
+                break;
+            }
             rem_bytes = rem;
         }
         (
@@ -102,11 +112,14 @@ impl<'a> DigitsUnds<'a> {
             return None;
         };
 
-        // first byte is never underscore
-        debug_assert!(first != b'_');
+        // first byte is never a separator
+        debug_assert!(!is_sep(first));
 
-        // skip over underscores between first digit and last part
-        while let Some((&b'_', rem)) = rem_bytes.split_first() {
+        // skip over separators between first digit and last part
+        while let Some((&byte, rem)) = rem_bytes.split_first() {
+            if !is_sep(byte) {
+                break;
+            }
             rem_bytes = rem;
         }
         Some((
@@ -124,11 +137,14 @@ impl<'a> DigitsUnds<'a> {
             return None;
         };
 
-        // last byte is never underscore
-        debug_assert!(last != b'_');
+        // last byte is never a separator
+        debug_assert!(!is_sep(last));
 
-        // skip over underscores between first part and last digit
-        while let Some((&b'_', rem)) = rem_bytes.split_last() {
+        // skip over separators between first part and last digit
+        while let Some((&byte, rem)) = rem_bytes.split_last() {
+            if !is_sep(byte) {
+                break;
+            }
             rem_bytes = rem;
         }
         Some((
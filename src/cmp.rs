@@ -394,6 +394,67 @@ mod tests {
     use core::cmp::Ordering;
     use half::{bf16 as half_bf16, f16 as half_f16};
 
+    #[test]
+    fn min_max() {
+        use crate::types::{I16F16, U16F16};
+        let (a, b) = (I16F16::from_num(-3.5), I16F16::from_num(-0.25));
+        assert_eq!(a.min(b), a);
+        assert_eq!(b.min(a), a);
+        assert_eq!(a.max(b), b);
+        assert_eq!(b.max(a), b);
+        assert_eq!(I16F16::MIN.max(I16F16::MAX), I16F16::MAX);
+        assert_eq!(I16F16::MIN.min(-I16F16::DELTA), I16F16::MIN);
+        // equal operands return self, which has the same value as other
+        assert_eq!(a.min(a), a);
+        assert_eq!(a.max(a), a);
+        for x in [I16F16::MIN, a, b, I16F16::ZERO, I16F16::MAX] {
+            for y in [I16F16::MIN, a, b, I16F16::ZERO, I16F16::MAX] {
+                assert_eq!(x.min(y), Ord::min(x, y));
+                assert_eq!(x.max(y), Ord::max(x, y));
+            }
+        }
+
+        let (a, b) = (U16F16::from_num(1.25), U16F16::MAX);
+        assert_eq!(a.min(b), a);
+        assert_eq!(a.max(b), b);
+        assert_eq!(U16F16::ZERO.max(U16F16::ZERO), U16F16::ZERO);
+
+        const LIMIT: I16F16 = I16F16::ZERO.max(I16F16::DELTA).min(I16F16::ONE);
+        assert_eq!(LIMIT, I16F16::DELTA);
+    }
+
+    #[test]
+    fn clamp() {
+        use crate::types::{I0F32, I16F16, U16F16};
+        let (min, max) = (I16F16::from_num(-2.5), I16F16::from_num(3));
+        assert_eq!(I16F16::MIN.clamp(min, max), min);
+        assert_eq!(I16F16::from_num(-2.75).clamp(min, max), min);
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(I16F16::from_num(1.25).clamp(min, max), 1.25);
+        assert_eq!(max.clamp(min, max), max);
+        assert_eq!(I16F16::MAX.clamp(min, max), max);
+        for x in [I16F16::MIN, min, I16F16::ZERO, max, I16F16::MAX] {
+            assert_eq!(x.clamp(min, max), Ord::clamp(x, min, max));
+            // min == max always returns that value
+            assert_eq!(x.clamp(max, max), max);
+        }
+
+        let (min, max) = (U16F16::from_num(1), U16F16::from_num(1.5));
+        assert_eq!(U16F16::ZERO.clamp(min, max), min);
+        assert_eq!(U16F16::from_num(1.25).clamp(min, max), 1.25);
+        assert_eq!(U16F16::MAX.clamp(min, max), max);
+
+        const CLAMPED: I0F32 = I0F32::MIN.clamp(I0F32::ZERO, I0F32::MAX);
+        assert_eq!(CLAMPED, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "min > max")]
+    fn clamp_bad_range() {
+        let _ =
+            crate::types::I16F16::ZERO.clamp(crate::types::I16F16::ONE, crate::types::I16F16::ZERO);
+    }
+
     #[test]
     fn cmp_signed() {
         use core::cmp::Ordering::*;
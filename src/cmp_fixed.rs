@@ -510,4 +510,29 @@ mod tests {
         let b: u64 = 1000;
         assert!(I80F48::from(a) > b);
     }
+
+    #[test]
+    fn eq_different_frac() {
+        use crate::{FixedI32, FixedU16};
+        let a = FixedI32::<4>::from_num(1.5);
+        let b = FixedI32::<8>::from_num(1.5);
+        assert_eq!(a, b);
+        assert_eq!(b, a);
+        assert_ne!(a, b + FixedI32::<8>::DELTA);
+        assert_ne!(b + FixedI32::<8>::DELTA, a);
+        assert_eq!(-a, -b);
+        assert_ne!(-a, -b - FixedI32::<8>::DELTA);
+
+        // negative and out-of-range numbers of fractional bits
+        assert_eq!(FixedI32::<-4>::from_num(-32), FixedI32::<0>::from_num(-32));
+        assert_ne!(FixedI32::<-4>::from_num(-32), FixedI32::<0>::from_num(-31));
+        assert_eq!(FixedI32::<40>::ZERO, FixedI32::<-40>::ZERO);
+        assert_ne!(FixedI32::<40>::DELTA, FixedI32::<-40>::ZERO);
+        assert_eq!(
+            FixedU16::<16>::from_num(0.25),
+            FixedU16::<2>::from_num(0.25)
+        );
+        assert_ne!(FixedU16::<16>::from_num(0.125), FixedU16::<2>::ZERO);
+        assert_ne!(FixedU16::<16>::MAX, FixedU16::<0>::ZERO);
+    }
 }
@@ -647,4 +647,62 @@ mod tests {
         compare_parse(GAMMA, "0.5772156649015328606065120900824024310421");
         compare_parse(CATALAN, "0.9159655941772190150546035149323841107741");
     }
+
+    // The associated constants are rounded down, so they are within one ULP of
+    // the exact value. The f64 constants themselves can be off by half an f64
+    // ULP, so that is allowed for as well.
+    fn check_assoc(val: f64, exact: f64, delta: f64) {
+        let f64_ulp = exact * f64::EPSILON;
+        let diff = exact - val;
+        assert!(
+            -f64_ulp <= diff && diff < delta + f64_ulp,
+            "{val} vs {exact}"
+        );
+    }
+
+    macro_rules! check_assoc_consts {
+        ($Fix:ty) => {{
+            // evaluate in const context
+            const PI: $Fix = <$Fix>::PI;
+            const TAU: $Fix = <$Fix>::TAU;
+            const FRAC_PI_2: $Fix = <$Fix>::FRAC_PI_2;
+            const E: $Fix = <$Fix>::E;
+            const SQRT_2: $Fix = <$Fix>::SQRT_2;
+            const FRAC_1_SQRT_2: $Fix = <$Fix>::FRAC_1_SQRT_2;
+            const LN_2: $Fix = <$Fix>::LN_2;
+            const LN_10: $Fix = <$Fix>::LN_10;
+            const LOG2_E: $Fix = <$Fix>::LOG2_E;
+            const LOG2_10: $Fix = <$Fix>::LOG2_10;
+            const LOG10_2: $Fix = <$Fix>::LOG10_2;
+            let delta = <$Fix>::DELTA.to_num::<f64>();
+            let pairs = [
+                (PI, f64::consts::PI),
+                (TAU, f64::consts::TAU),
+                (FRAC_PI_2, f64::consts::FRAC_PI_2),
+                (E, f64::consts::E),
+                (SQRT_2, f64::consts::SQRT_2),
+                (FRAC_1_SQRT_2, f64::consts::FRAC_1_SQRT_2),
+                (LN_2, f64::consts::LN_2),
+                (LN_10, f64::consts::LN_10),
+                (LOG2_E, f64::consts::LOG2_E),
+                (LOG2_10, f64::consts::LOG2_10),
+                (LOG10_2, f64::consts::LOG10_2),
+            ];
+            for (val, exact) in pairs {
+                check_assoc(val.to_num::<f64>(), exact, delta);
+            }
+        }};
+    }
+
+    #[test]
+    fn assoc_consts_f64() {
+        use crate::types::{I16F16, I4F124, I4F28, I8F56, U16F16, U3F29, U3F61};
+        check_assoc_consts!(I16F16);
+        check_assoc_consts!(U16F16);
+        check_assoc_consts!(I4F28);
+        check_assoc_consts!(U3F29);
+        check_assoc_consts!(I8F56);
+        check_assoc_consts!(U3F61);
+        check_assoc_consts!(I4F124);
+    }
 }
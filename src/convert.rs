@@ -15,6 +15,7 @@
 
 use crate::{
     traits::{FromFixed, LosslessTryFrom, LossyFrom, ToFixed},
+    try_from::{TryFromErrorKind, TryFromFixedError},
     types::extra::{If, True},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
@@ -44,6 +45,35 @@ macro_rules! i_f_equivalent {
                 src.to_bits()
             }
         }
+
+        impl<const FRAC: i32> TryFrom<$Fixed<FRAC>> for $Int
+        where
+            If<{ FRAC != 0 }>: True,
+        {
+            type Error = TryFromFixedError;
+
+            /// Converts a fixed-point number to an integer.
+            ///
+            /// This conversion succeeds only if the fixed-point number is a
+            /// whole number that fits in the integer. For zero fractional
+            /// bits, the infallible [`From`] conversion is used instead.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the number has a fractional part or if the
+            /// value does not fit.
+            #[inline]
+            fn try_from(src: $Fixed<FRAC>) -> Result<$Int, TryFromFixedError> {
+                if !src.frac().is_zero() {
+                    return Err(TryFromFixedError {
+                        kind: TryFromErrorKind::HasFraction,
+                    });
+                }
+                src.checked_to_num().ok_or(TryFromFixedError {
+                    kind: TryFromErrorKind::Overflow,
+                })
+            }
+        }
     };
 }
 
@@ -1373,6 +1403,352 @@ mod tests {
         assert_eq!(U1F127::from(true), 1);
     }
 
+    #[test]
+    fn from_wrapping_int() {
+        use core::num::Wrapping;
+
+        // 40_000 << 16 does not fit in 32 bits
+        let wrapped = I16F16::from_bits(40_000 << 16);
+        assert_eq!(I16F16::from_num(Wrapping(40_000i32)), wrapped);
+        assert_eq!(I16F16::wrapping_from_num(40_000i32), wrapped);
+        assert_eq!(I16F16::checked_from_num(Wrapping(40_000i32)), None);
+        assert_eq!(
+            I16F16::saturating_from_num(Wrapping(40_000i32)),
+            I16F16::MAX
+        );
+        assert_eq!(
+            I16F16::overflowing_from_num(Wrapping(40_000i32)),
+            (wrapped, true)
+        );
+        assert_eq!(I16F16::from_num(Wrapping(-3i32)), -3);
+
+        assert_eq!(U4F4::from_num(Wrapping(17u8)), 1);
+        assert_eq!(U4F4::from_num(Wrapping(255u128)), 15);
+        assert_eq!(I4F4::from_num(Wrapping(8i64)), -8);
+        assert_eq!(I64F64::from_num(Wrapping(i64::MIN)), i64::MIN);
+    }
+
+    // the borrows are what is being tested
+    #[allow(clippy::needless_borrows_for_generic_args)]
+    #[test]
+    fn from_ref() {
+        let x = 2.5f64;
+        assert_eq!(I16F16::from_num(&x), 2.5);
+        assert_eq!(I16F16::checked_from_num(&f64::NAN), None);
+        assert_eq!(U4F4::saturating_from_num(&-3i32), U4F4::ZERO);
+        assert_eq!(U4F4::wrapping_from_num(&17u8), 1);
+        assert_eq!(
+            I4F4::overflowing_from_num(&8i64),
+            (I4F4::from_num(-8), true)
+        );
+        assert_eq!(I4F4::from_num(&I16F16::from_num(-1.25)), -1.25);
+        assert_eq!(I16F16::from_num(&true), 1);
+        assert_eq!(I16F16::from_num(&core::num::Wrapping(-3i8)), -3);
+        assert_eq!(I16F16::from_num(&half::f16::from_f32(0.5)), 0.5);
+
+        fn sum<'a, T: 'a>(values: impl IntoIterator<Item = &'a T>) -> I16F16
+        where
+            &'a T: crate::traits::ToFixed,
+        {
+            values.into_iter().map(I16F16::from_num).sum()
+        }
+        assert_eq!(sum(&[1.5f32, 2.25, -0.5]), 3.25);
+        assert_eq!(sum(&[1u8, 2, 3]), 6);
+    }
+
+    #[test]
+    fn saturating_from_non_finite() {
+        use half::{bf16 as half_bf16, f16 as half_f16};
+
+        assert_eq!(I16F16::saturating_from_num(f32::NAN), I16F16::ZERO);
+        assert_eq!(I16F16::saturating_from_num(-f64::NAN), I16F16::ZERO);
+        assert_eq!(U4F4::saturating_from_num(f16::NAN), U4F4::ZERO);
+        assert_eq!(I4F4::saturating_from_num(f128::NAN), I4F4::ZERO);
+        assert_eq!(I4F4::saturating_from_num(half_f16::NAN), I4F4::ZERO);
+        assert_eq!(U4F4::saturating_from_num(half_bf16::NAN), U4F4::ZERO);
+
+        assert_eq!(I16F16::saturating_from_num(f32::INFINITY), I16F16::MAX);
+        assert_eq!(I16F16::saturating_from_num(f64::NEG_INFINITY), I16F16::MIN);
+        assert_eq!(U4F4::saturating_from_num(f64::INFINITY), U4F4::MAX);
+        assert_eq!(U4F4::saturating_from_num(f32::NEG_INFINITY), U4F4::MIN);
+        assert_eq!(I4F4::saturating_from_num(half_bf16::INFINITY), I4F4::MAX);
+        assert_eq!(I4F4::saturating_from_num(half_f16::NEG_INFINITY), I4F4::MIN);
+    }
+
+    #[test]
+    fn from_num_or() {
+        let default = I16F16::from_num(-7);
+        assert_eq!(I16F16::from_num_or(1.5f64, default), 1.5);
+        assert_eq!(I16F16::from_num_or(f64::NAN, default), default);
+        assert_eq!(I16F16::from_num_or(f32::INFINITY, default), default);
+        assert_eq!(I16F16::from_num_or(f32::NEG_INFINITY, default), default);
+        assert_eq!(I16F16::from_num_or(1e10f64, default), default);
+        assert_eq!(I16F16::from_num_or(40_000, default), default);
+        assert_eq!(I16F16::from_num_or(-3, default), -3);
+
+        let default = U4F4::from_num(2.5);
+        assert_eq!(U4F4::from_num_or(f32::NAN, default), default);
+        assert_eq!(U4F4::from_num_or(-1.0f32, default), default);
+        assert_eq!(U4F4::from_num_or(I16F16::from_num(3.25), default), 3.25);
+        assert_eq!(U4F4::from_num_or(I16F16::from_num(16), default), default);
+    }
+
+    #[test]
+    fn from_num_opt() {
+        assert_eq!(I16F16::from_num_opt(Some(2.5)), Some(I16F16::from_num(2.5)));
+        assert_eq!(I16F16::from_num_opt(None::<f64>), None);
+        assert_eq!(U4F4::from_num_opt(Some(3u8)), Some(U4F4::from_num(3)));
+        assert_eq!(
+            U4F4::from_num_opt(Some(I16F16::from_num(3.25))),
+            Some(U4F4::from_num(3.25))
+        );
+        let vals = [Some(1.5f32), None, Some(-2.0)];
+        let fixed = vals.map(I16F16::from_num_opt);
+        assert_eq!(
+            fixed,
+            [
+                Some(I16F16::from_num(1.5)),
+                None,
+                Some(I16F16::from_num(-2))
+            ]
+        );
+    }
+
+    #[test]
+    fn from_num_nonzero() {
+        let tiny = I16F16::DELTA.to_num::<f64>() / 4.0;
+        assert_eq!(I16F16::from_num(tiny), I16F16::ZERO);
+        assert_eq!(I16F16::from_num_nonzero(tiny), I16F16::DELTA);
+        assert_eq!(I16F16::from_num_nonzero(-tiny), -I16F16::DELTA);
+        assert_eq!(I16F16::from_num_nonzero(f64::MIN_POSITIVE), I16F16::DELTA);
+        assert_eq!(I16F16::from_num_nonzero(5e-324), I16F16::DELTA);
+        assert_eq!(I16F16::from_num_nonzero(0.0), I16F16::ZERO);
+        assert_eq!(I16F16::from_num_nonzero(-0.0), I16F16::ZERO);
+        // half of DELTA is a tie that from_num rounds to even, that is to zero
+        let half = I16F16::DELTA.to_num::<f64>() / 2.0;
+        assert_eq!(I16F16::from_num(half), I16F16::ZERO);
+        assert_eq!(I16F16::from_num_nonzero(half), I16F16::DELTA);
+        // just above half of DELTA already rounds to DELTA
+        assert_eq!(I16F16::from_num(half * 1.5), I16F16::DELTA);
+        assert_eq!(I16F16::from_num_nonzero(half * 1.5), I16F16::DELTA);
+        assert_eq!(I16F16::from_num_nonzero(2.75), I16F16::from_num(2.75));
+        assert_eq!(I16F16::from_num_nonzero(-2.75), I16F16::from_num(-2.75));
+
+        let tiny = U4F4::DELTA.to_num::<f64>() / 4.0;
+        assert_eq!(U4F4::from_num(tiny), U4F4::ZERO);
+        assert_eq!(U4F4::from_num_nonzero(tiny), U4F4::DELTA);
+        assert_eq!(U4F4::from_num_nonzero(-tiny), U4F4::ZERO);
+        assert_eq!(U4F4::from_num_nonzero(0.0), U4F4::ZERO);
+        assert_eq!(U4F4::from_num_nonzero(3.5), U4F4::from_num(3.5));
+
+        // the result can be used as a divisor
+        let quarter = I16F16::ONE / 4;
+        assert_eq!(quarter.checked_div(I16F16::from_num(1e-9)), None);
+        assert_eq!(
+            quarter.checked_div(I16F16::from_num_nonzero(1e-9)),
+            Some(I16F16::from_num(1 << 14))
+        );
+    }
+
+    #[test]
+    fn fits_in() {
+        // coarser targets with more integer bits
+        assert!(I16F16::MAX.fits_in::<I32F0>());
+        assert!(I16F16::from_num(-0.75).fits_in::<I32F0>());
+        assert!(I16F16::from_num(127.9).fits_in::<I8F8>());
+        assert!(!I16F16::from_num(128).fits_in::<I8F8>());
+        assert!(I16F16::from_num(-128).fits_in::<I8F8>());
+        assert!(!I16F16::from_num(-128.25).fits_in::<I8F8>());
+
+        // same inner type, fewer integer bits
+        assert!(I16F16::from_num(3.5).fits_in::<I4F28>());
+        assert!(!I16F16::from_num(8).fits_in::<I4F28>());
+        assert!(I16F16::from_num(-8).fits_in::<I4F28>());
+        assert!(!I16F16::from_num(-8.5).fits_in::<I4F28>());
+        assert!(U16F16::from_num(0.5).fits_in::<U0F32>());
+        assert!(!U16F16::ONE.fits_in::<U0F32>());
+
+        // signedness changes
+        assert!(!I16F16::from_num(-0.5).fits_in::<U16F16>());
+        assert!(!U16F16::MAX.fits_in::<I16F16>());
+        assert!(U16F16::MAX.fits_in::<I32F32>());
+
+        // integers and floats
+        assert!(I16F16::from_num(255.5).fits_in::<u8>());
+        assert!(!I16F16::from_num(256).fits_in::<u8>());
+        assert!(!I16F16::from_num(-0.5).fits_in::<u8>());
+        assert!(I16F16::MAX.fits_in::<f32>());
+
+        for bits in i8::MIN..=i8::MAX {
+            let val = I4F4::from_bits(bits);
+            assert_eq!(val.fits_in::<I2F6>(), I2F6::checked_from_num(val).is_some());
+        }
+    }
+
+    #[test]
+    fn roundtrips_through() {
+        // lossless conversions
+        assert!(I16F16::from_num(-3.25).roundtrips_through::<I32F32>());
+        assert!(I16F16::MIN.roundtrips_through::<I32F32>());
+        assert!(I16F16::from_num(127.75).roundtrips_through::<I8F8>());
+        assert!(U16F16::MAX.roundtrips_through::<I32F32>());
+        assert!(I16F16::from_num(-5).roundtrips_through::<i8>());
+        assert!(I16F16::MAX.roundtrips_through::<f64>());
+        assert!(U4F4::MAX.roundtrips_through::<f32>());
+
+        // lossy conversions: fractional bits discarded
+        assert!(!I16F16::DELTA.roundtrips_through::<I8F8>());
+        assert!(!I16F16::from_num(-0.5).roundtrips_through::<I32F0>());
+        assert!(!I16F16::from_num(2.5).roundtrips_through::<u8>());
+        assert!(!I32F32::MAX.roundtrips_through::<f32>());
+
+        // lossy conversions: overflow
+        assert!(!I16F16::from_num(128).roundtrips_through::<I8F8>());
+        assert!(!I16F16::from_num(-1).roundtrips_through::<U16F16>());
+        assert!(!I16F16::from_num(300).roundtrips_through::<u8>());
+
+        for bits in i8::MIN..=i8::MAX {
+            let val = I4F4::from_bits(bits);
+            let expected = I2F6::checked_from_num(val).is_some();
+            assert_eq!(val.roundtrips_through::<I2F6>(), expected);
+            assert_eq!(val.roundtrips_through::<I6F2>(), bits % 4 == 0);
+        }
+    }
+
+    #[test]
+    fn to_f64_scaled() {
+        use crate::{FixedI32, FixedU128};
+        use std::format;
+
+        // bits × 2^−32 × 10^exp is exactly (bits × 5^32) × 10^(exp − 32), and
+        // parsing that decimal string is correctly rounded
+        let pow5_32 = 5i128.pow(32);
+        for bits in [
+            1,
+            -1,
+            3,
+            0x1999_9999,
+            -0x1999_9999,
+            0x7654_3210,
+            i32::MAX,
+            i32::MIN,
+            0x1234,
+            -0x5_5555,
+        ] {
+            let val = I32F32::from_bits(i64::from(bits) << 8);
+            let dec = i128::from(bits) * 256 * pow5_32;
+            for exp in (-60..=60).chain([-330, -300, -100, 100, 250, 290, 320]) {
+                let expected = format!("{dec}e{}", exp - 32).parse::<f64>().unwrap();
+                assert_eq!(val.to_f64_scaled(exp), expected, "{val} e{exp}");
+            }
+        }
+
+        // whole numbers
+        assert_eq!(I32F32::from_num(-7).to_f64_scaled(3), -7000.0);
+        assert_eq!(U16F16::from_num(1250).to_f64_scaled(-3), 1.25);
+        assert_eq!(I16F16::from_num(5).to_f64_scaled(-1), 0.5);
+        assert_eq!(I16F16::ZERO.to_f64_scaled(10), 0.0);
+        assert_eq!(I16F16::ZERO.to_f64_scaled(-10), 0.0);
+        // 0.1 is not exact in binary, but the result is correctly rounded
+        assert_eq!(I16F16::ONE.to_f64_scaled(-1), 0.1);
+        assert_eq!(U8F8::from_num(3).to_f64_scaled(-1), 0.3);
+
+        // extreme values and numbers of fractional bits
+        assert_eq!(
+            U128F0::MAX.to_f64_scaled(-38),
+            format!("{}e-38", u128::MAX).parse::<f64>().unwrap()
+        );
+        assert_eq!(
+            I128F0::MIN.to_f64_scaled(5),
+            format!("{}e5", i128::MIN).parse::<f64>().unwrap()
+        );
+        assert_eq!(FixedU128::<128>::DELTA.to_f64_scaled(0), 2f64.powi(-128));
+        assert_eq!(
+            FixedI32::<-100>::from_bits(3).to_f64_scaled(2),
+            300.0 * 2f64.powi(100)
+        );
+        assert_eq!(
+            FixedI32::<1000>::from_bits(1).to_f64_scaled(301),
+            1e301 * 2f64.powi(-1000)
+        );
+
+        // overflow and underflow
+        assert_eq!(I16F16::ONE.to_f64_scaled(309), f64::INFINITY);
+        assert_eq!(I16F16::NEG_ONE.to_f64_scaled(400), f64::NEG_INFINITY);
+        assert_eq!(I16F16::MAX.to_f64_scaled(i32::MAX), f64::INFINITY);
+        assert_eq!(I16F16::ONE.to_f64_scaled(-324), 0.0);
+        assert_eq!(I16F16::ONE.to_f64_scaled(i32::MIN), 0.0);
+        assert!(I16F16::NEG_ONE.to_f64_scaled(-400).is_sign_negative());
+        assert_eq!(I16F16::ONE.to_f64_scaled(-323), 1e-323);
+    }
+
+    #[test]
+    fn try_into_inner() {
+        use crate::{FixedI8, FixedU8};
+
+        // whole numbers
+        assert_eq!(i32::try_from(I16F16::from_num(-5)), Ok(-5));
+        assert_eq!(u32::try_from(U16F16::from_num(7)), Ok(7));
+        assert_eq!(i8::try_from(I4F4::MIN), Ok(-8));
+        assert_eq!(u8::try_from(U0F8::ZERO), Ok(0));
+        assert_eq!(i8::try_from(FixedI8::<-2>::from_num(-128)), Ok(-128));
+
+        // fractional parts
+        let err = i32::try_from(I16F16::from_num(2.5)).unwrap_err();
+        assert_eq!(err.message(), "number has a fractional part");
+        assert!(i32::try_from(I16F16::from_num(-0.5)).is_err());
+        assert!(u32::try_from(U16F16::DELTA).is_err());
+        assert!(u8::try_from(U0F8::MAX).is_err());
+
+        // out of range
+        let err = i8::try_from(FixedI8::<-2>::from_bits(32)).unwrap_err();
+        assert_eq!(err.message(), "overflow");
+        assert!(i8::try_from(FixedI8::<-2>::from_bits(-33)).is_err());
+        assert_eq!(u8::try_from(FixedU8::<-1>::from_bits(127)), Ok(254));
+        assert!(u8::try_from(FixedU8::<-1>::from_bits(128)).is_err());
+    }
+
+    #[test]
+    fn const_checked_from_fixed() {
+        use crate::{FixedI32, FixedU8};
+
+        fn check_i32<const SRC: i32, const DST: i32>(src: FixedI32<SRC>) {
+            assert_eq!(
+                FixedI32::<DST>::const_checked_from_fixed(src),
+                FixedI32::<DST>::checked_from_num(src)
+            );
+        }
+        fn check_u8<const SRC: i32, const DST: i32>(src: FixedU8<SRC>) {
+            assert_eq!(
+                FixedU8::<DST>::const_checked_from_fixed(src),
+                FixedU8::<DST>::checked_from_num(src)
+            );
+        }
+
+        for bits in [0, 1, -1, 0x7F, -0x80, 0x0123_4567, i32::MAX, i32::MIN] {
+            check_i32::<8, 16>(FixedI32::from_bits(bits));
+            check_i32::<16, 8>(FixedI32::from_bits(bits));
+            check_i32::<8, 8>(FixedI32::from_bits(bits));
+            check_i32::<0, 32>(FixedI32::from_bits(bits));
+            check_i32::<32, 0>(FixedI32::from_bits(bits));
+            check_i32::<-4, 36>(FixedI32::from_bits(bits));
+            check_i32::<36, -4>(FixedI32::from_bits(bits));
+        }
+        for bits in [0, 1, 0x0F, 0x10, 0x80, u8::MAX] {
+            check_u8::<0, 4>(FixedU8::from_bits(bits));
+            check_u8::<4, 0>(FixedU8::from_bits(bits));
+            check_u8::<0, 8>(FixedU8::from_bits(bits));
+            check_u8::<8, 0>(FixedU8::from_bits(bits));
+        }
+
+        const A: Option<FixedI32<16>> =
+            FixedI32::<16>::const_checked_from_fixed(FixedI32::<8>::from_bits(-0x180));
+        assert_eq!(A, Some(FixedI32::from_num(-1.5)));
+        const B: Option<FixedI32<16>> =
+            FixedI32::<16>::const_checked_from_fixed(FixedI32::<8>::MAX);
+        assert_eq!(B, None);
+    }
+
     #[test]
     fn to_size() {
         let min_i24 = I24F8::MIN;
@@ -15,7 +15,9 @@
 
 use crate::{
     debug_hex::{self, IsDebugHex},
+    grouped::Grouped,
     int_helper,
+    trimmed::Trimmed,
     types::extra::{If, True},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
@@ -149,7 +151,13 @@ impl Buffer {
         if has_exp {
             self.encode_exp(format == Format::UpExp);
         }
-        self.pad_and_print(is_neg, format.prefix(), fmt)
+        self.pad_and_print(
+            is_neg,
+            format.prefix(),
+            format == Format::GroupedDec,
+            format == Format::TrimmedDec,
+            fmt,
+        )
     }
 
     // rounds, and then trims trailing zeros from frac.
@@ -225,7 +233,14 @@ impl Buffer {
         }
     }
 
-    fn pad_and_print(&self, is_neg: bool, maybe_prefix: &str, fmt: &mut Formatter) -> FmtResult {
+    fn pad_and_print(
+        &self,
+        is_neg: bool,
+        maybe_prefix: &str,
+        grouped: bool,
+        trimmed: bool,
+        fmt: &mut Formatter,
+    ) -> FmtResult {
         use core::fmt::Write;
 
         let sign = if is_neg {
@@ -262,13 +277,27 @@ impl Buffer {
         } else {
             2
         };
-        let end_zeros = fmt.precision().map_or(0, |x| x - self.frac_digits);
+        // trimmed numbers have no trailing zeros, except for a single zero
+        // when the alternate flag requires at least one fractional digit
+        let end_zeros = if trimmed {
+            usize::from(fmt.alternate() && self.frac_digits == 0)
+        } else {
+            fmt.precision().map_or(0, |x| x - self.frac_digits)
+        };
         let has_frac = self.frac_digits > 0 || end_zeros > 0;
 
         let digits_width = 1 + self.int_digits + self.frac_digits - abs_begin;
+        let int_bytes = &self.digits[abs_begin..1 + self.int_digits];
+        // one separator before every group of three integer digits except the first
+        let separators = if grouped {
+            (int_bytes.len() - 1) / 3
+        } else {
+            0
+        };
         let req_width = sign.len()
             + prefix.len()
             + digits_width
+            + separators
             + usize::from(has_frac)
             + end_zeros
             + self.exp_len;
@@ -295,8 +324,16 @@ impl Buffer {
         for _ in 0..pad_zeros {
             fmt.write_char('0')?;
         }
-        let int_bytes = &self.digits[abs_begin..1 + self.int_digits];
-        fmt.write_str(str::from_utf8(int_bytes).unwrap())?;
+        if separators == 0 {
+            fmt.write_str(str::from_utf8(int_bytes).unwrap())?;
+        } else {
+            let first = int_bytes.len() - separators * 3;
+            fmt.write_str(str::from_utf8(&int_bytes[..first]).unwrap())?;
+            for group in int_bytes[first..].chunks(3) {
+                fmt.write_char(',')?;
+                fmt.write_str(str::from_utf8(group).unwrap())?;
+            }
+        }
         if has_frac {
             fmt.write_char('.')?;
             let frac_bytes =
@@ -369,6 +406,8 @@ enum Format {
     LowHex,
     UpHex,
     Dec,
+    GroupedDec,
+    TrimmedDec,
     LowExp,
     UpExp,
 }
@@ -389,7 +428,11 @@ impl Format {
             Format::Bin => 1,
             Format::Oct => 3,
             Format::LowHex | Format::UpHex => 4,
-            Format::Dec | Format::LowExp | Format::UpExp => 4,
+            Format::Dec
+            | Format::GroupedDec
+            | Format::TrimmedDec
+            | Format::LowExp
+            | Format::UpExp => 4,
         }
     }
     fn max_digit(self) -> u8 {
@@ -397,7 +440,11 @@ impl Format {
             Format::Bin => 1,
             Format::Oct => 7,
             Format::LowHex | Format::UpHex => 15,
-            Format::Dec | Format::LowExp | Format::UpExp => 9,
+            Format::Dec
+            | Format::GroupedDec
+            | Format::TrimmedDec
+            | Format::LowExp
+            | Format::UpExp => 9,
         }
     }
     fn prefix(self) -> &'static str {
@@ -405,7 +452,11 @@ impl Format {
             Format::Bin => "0b",
             Format::Oct => "0o",
             Format::LowHex | Format::UpHex => "0x",
-            Format::Dec | Format::LowExp | Format::UpExp => "",
+            Format::Dec
+            | Format::GroupedDec
+            | Format::TrimmedDec
+            | Format::LowExp
+            | Format::UpExp => "",
         }
     }
 }
@@ -531,7 +582,9 @@ where
             *b = Mul10::mul10_assign(&mut frac);
 
             // Check if very close to zero, to avoid things like 0.19999999 and 0.20000001.
-            if auto_prec && frac < Self::from(10) || Self::wrapping_neg(frac) < Self::from(10) {
+            // This is only done for auto precision, as with a specified
+            // precision we want exact digits.
+            if auto_prec && (frac < Self::from(10) || Self::wrapping_neg(frac) < Self::from(10)) {
                 buf.frac_digits = i + 1;
                 break;
             }
@@ -629,7 +682,7 @@ fn fmt<U: FmtHelper>(
         Format::Bin | Format::Oct | Format::LowHex | Format::UpHex => {
             fmt_radix2((neg, int, frac), format, fmt)
         }
-        Format::Dec | Format::LowExp | Format::UpExp => {
+        Format::Dec | Format::GroupedDec | Format::TrimmedDec | Format::LowExp | Format::UpExp => {
             fmt_dec((neg, int, frac), frac_nbits, format, fmt)
         }
     }
@@ -651,7 +704,7 @@ fn fmt_dec<U: FmtHelper>(
     let int_used_nbits = FmtHelper::int_used_nbits(int);
     let frac_used_nbits = FmtHelper::frac_used_nbits(frac);
     let int_max_len = ceil_log10_2_times(int_used_nbits);
-    let frac_max_len = if fmt.precision().is_some() {
+    let frac_max_len = if fmt.precision().is_some() || format == Format::TrimmedDec {
         // for specified precision, we want exact fractions till the very end
         frac_used_nbits
     } else {
@@ -665,7 +718,11 @@ fn fmt_dec<U: FmtHelper>(
     let frac_format = DecFracFormat {
         int_sig_digits,
         has_exp,
-        precision: fmt.precision(),
+        // trimmed numbers are exact unless a precision is specified
+        precision: match (fmt.precision(), format) {
+            (None, Format::TrimmedDec) => Some(frac_used_nbits as usize),
+            (precision, _) => precision,
+        },
     };
     let mut truncation = FmtHelper::write_frac_dec(frac, frac_nbits, frac_format, &mut buf);
     if has_exp {
@@ -708,6 +765,36 @@ macro_rules! impl_fmt {
             }
         }
 
+        impl<const FRAC: i32> Display for Grouped<$Fixed<FRAC>>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= $nbits) }>: True,
+        {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                let neg_abs = int_helper::$Inner::neg_abs(self.0.to_bits());
+                fmt(
+                    neg_abs,
+                    $Fixed::<FRAC>::FRAC_BITS as u32,
+                    Format::GroupedDec,
+                    f,
+                )
+            }
+        }
+
+        impl<const FRAC: i32> Display for Trimmed<$Fixed<FRAC>>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= $nbits) }>: True,
+        {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                let neg_abs = int_helper::$Inner::neg_abs(self.0.to_bits());
+                fmt(
+                    neg_abs,
+                    $Fixed::<FRAC>::FRAC_BITS as u32,
+                    Format::TrimmedDec,
+                    f,
+                )
+            }
+        }
+
         impl<const FRAC: i32> Debug for $Fixed<FRAC> {
             fn fmt(&self, f: &mut Formatter) -> FmtResult {
                 if FRAC < 0 || FRAC > $nbits {
@@ -942,6 +1029,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn radix_point() {
+        let x = I4F4::from_num(1.5);
+        assert_eq!(format!("{x:b}"), "1.1");
+        assert_eq!(format!("{x:.4b}"), "1.1000");
+        assert_eq!(format!("{x:#.4b}"), "0b1.1000");
+        assert_eq!(format!("{:#.4b}", -x), "-0b1.1000");
+        assert_eq!(format!("{x:o}"), "1.4");
+        assert_eq!(format!("{x:#.2o}"), "0o1.40");
+        assert_eq!(format!("{x:#x}"), "0x1.8");
+        let y = U4F12::from_bits(0xABCD);
+        assert_eq!(format!("{y:x}"), "a.bcd");
+        assert_eq!(format!("{y:#X}"), "0xA.BCD");
+        assert_eq!(format!("{y:.1X}"), "A.C");
+        let z = U4F4::from_num(3);
+        assert_eq!(format!("{z:b}"), "11");
+        assert_eq!(format!("{z:#.4b}"), "0b11.0000");
+    }
+
     #[test]
     fn debug_hex() {
         let v = I16F16::MAX;
@@ -1043,6 +1149,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn close_to_round_decimal_with_precision() {
+        // 11/256 = 0.04296875 is close to 0.043, which is fine for the auto
+        // precision, but with a specified precision all digits are exact
+        let fix = U8F8::from_bits(11);
+        assert_eq!(format!("{fix}"), "0.043");
+        assert_eq!(format!("{fix:.8}"), "0.04296875");
+        assert_eq!(format!("{fix:.10}"), "0.0429687500");
+        assert_eq!(format!("{fix:.5}"), "0.04297");
+        for bits in 0..=u8::MAX {
+            let fix = U0F8::from_bits(bits);
+            let float = f32::from(bits) / 256.;
+            assert_eq!(format!("{fix:.8}"), format!("{float:.8}"));
+        }
+    }
+
     #[test]
     fn check_ceil_log10_2_times() {
         for i in 0..112_816 {
@@ -0,0 +1,312 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::int256::{self, U256};
+
+// 2^(2^−(i + 1)) for 0 ≤ i < 32, with 127 fractional bits
+pub const EXP2_TABLE: [u128; 32] = [
+    0xb504_f333_f9de_6484_597d_89b3_754a_be9f,
+    0x9837_f051_8db8_a96f_46ad_2318_2e42_f6f6,
+    0x8b95_c1e3_ea8b_d6e6_fbe4_6287_58a5_3c90,
+    0x85aa_c367_cc48_7b14_c5c9_5b8c_2154_c1b2,
+    0x82cd_8698_ac2b_a1d7_3e2a_475b_4652_0bff,
+    0x8164_d1f3_bc03_0773_7be5_6527_bd14_def5,
+    0x80b1_ed4f_d999_ab6c_2533_5719_b6e6_fd20,
+    0x8058_d7d2_d5e5_f6b0_94d5_89f6_08ee_4aa2,
+    0x802c_6436_d0e0_4f50_ff8c_e94a_6797_b3ce,
+    0x8016_302f_1746_7628_3690_dfe4_4d11_d008,
+    0x800b_179c_8202_8fd0_945e_54e2_ae18_f2f0,
+    0x8005_8baf_7fee_3b5d_1c71_8b38_e549_cb93,
+    0x8002_c5d0_0fdc_fcb6_b656_6a58_c048_be1f,
+    0x8001_62e6_1bed_4a48_e84c_2e1a_4634_73da,
+    0x8000_b172_92f7_02a3_aa22_beac_ca94_9013,
+    0x8000_58b9_2abb_ae02_030c_5fa5_256f_41fe,
+    0x8000_2c5c_8dad_e4d7_1776_c0f4_dbea_67d6,
+    0x8000_162e_44ea_f636_526b_e456_600b_dbe5,
+    0x8000_0b17_21fa_7c18_8307_016c_1cd4_e8b7,
+    0x8000_058b_90de_7e4c_ecfc_4875_0348_8bb2,
+    0x8000_02c5_c867_8f36_cbfc_e50a_6de6_0b14,
+    0x8000_0162_e431_db9f_80b2_347b_5d62_e516,
+    0x8000_00b1_7218_72d0_c7b0_8cf1_e011_4153,
+    0x8000_0058_b90c_1aa8_a5c3_736c_b77e_8e00,
+    0x8000_002c_5c86_05a4_635f_2efc_2362_d978,
+    0x8000_0016_2e43_00e6_35cf_4a10_9e39_39bd,
+    0x8000_000b_1721_7ff8_1bef_9c55_1590_cf83,
+    0x8000_0005_8b90_bfdd_4e39_cd52_c0cf_a27d,
+    0x8000_0002_c5c8_5fe6_f72d_669e_0e76_e412,
+    0x8000_0001_62e4_2ff1_8f9a_d351_86d0_df28,
+    0x8000_0000_b172_17f8_4cce_71aa_0dcf_ffe8,
+    0x8000_0000_58b9_0bfc_07a7_7ad5_6ed2_2aaa,
+];
+
+// ln 2 with 128 fractional bits
+pub const LN_2: u128 = 0xb172_17f7_d1cf_79ab_c9e3_b398_03f2_f6af;
+
+// log2(e) with 255 fractional bits, rounded down
+pub const LOG2_E: U256 = U256 {
+    lo: 0xeb57_7aa8_dd69_5a58_8b25_166c_d1a1_3247,
+    hi: 0xb8aa_3b29_5c17_f0bb_be87_fed0_691d_3e88,
+};
+
+// If the integer part of |x| is at least this, 2^x and e^x are either zero
+// or overflow for all widths and numbers of fractional bits.
+const INT_LIMIT: u128 = 256;
+
+// Multiplies two numbers with 127 fractional bits, rounding to the nearest,
+// and saturating if the product is not < 2.
+pub const fn mul(a: u128, b: u128) -> u128 {
+    let prod = int256::wide_mul_u128(a, b);
+    if prod.hi >> 127 != 0 {
+        return u128::MAX;
+    }
+    let shifted = (prod.hi << 1) | (prod.lo >> 127);
+    shifted.saturating_add((prod.lo >> 126) & 1)
+}
+
+// Returns 2^r with 127 fractional bits, where r has 128 fractional bits.
+const fn exp2_frac(r: u128) -> u128 {
+    let mut acc = 1 << 127;
+    let mut i = 0;
+    while i < EXP2_TABLE.len() {
+        if (r >> (127 - i)) & 1 != 0 {
+            acc = mul(acc, EXP2_TABLE[i]);
+        }
+        i += 1;
+    }
+    // The remaining t < 2^−32, so 2^t = e^u, where u = t ln 2, can be
+    // computed using 1 + u + u^2/2 + u^3/6 with an error < 2^−130.
+    let t = r & (u128::MAX >> EXP2_TABLE.len());
+    let u = int256::wide_mul_u128(t, LN_2).hi >> 1;
+    let u2 = mul(u, u);
+    let u3 = mul(u2, u);
+    let poly = (1 << 127) + u + (u2 >> 1) + u3 / 6;
+    mul(acc, poly)
+}
+
+// Returns 2^±(int + frac / 2^128) with frac_nbits fractional bits, rounded to
+// the nearest, where frac_nbits ≤ 128 and int ≤ INT_LIMIT.
+//
+// Returns the wrapped bits, and whether the result fits in a u128.
+const fn exp2_parts(neg: bool, int: u128, frac: u128, frac_nbits: u32) -> (u128, bool) {
+    let int = int as i32;
+    let (int, frac) = if !neg {
+        (int, frac)
+    } else if frac == 0 {
+        (-int, 0)
+    } else {
+        (-int - 1, frac.wrapping_neg())
+    };
+    let mantissa = exp2_frac(frac);
+    // result = mantissa × 2^shift
+    let shift = int + frac_nbits as i32 - 127;
+    if shift > 0 {
+        // mantissa ≥ 2^127, so this always overflows
+        let wrapped = if shift < 128 { mantissa << shift } else { 0 };
+        (wrapped, false)
+    } else if shift == 0 {
+        (mantissa, true)
+    } else if shift < -128 {
+        (0, true)
+    } else if shift == -128 {
+        // 2^127 ≤ mantissa < 2^128, so the result rounds to 1
+        (1, true)
+    } else {
+        let shift = -shift as u32;
+        let rounded = (mantissa >> shift) + ((mantissa >> (shift - 1)) & 1);
+        (rounded, true)
+    }
+}
+
+// Returns 2^±(abs / 2^frac_nbits), where frac_nbits ≤ 128.
+const fn exp2(neg: bool, abs: u128, frac_nbits: u32) -> (u128, bool) {
+    let (int, frac) = if frac_nbits == 0 {
+        (abs, 0)
+    } else if frac_nbits < 128 {
+        (abs >> frac_nbits, abs << (128 - frac_nbits))
+    } else {
+        (0, abs)
+    };
+    if int >= INT_LIMIT {
+        return exp2_parts(neg, INT_LIMIT, 0, frac_nbits);
+    }
+    exp2_parts(neg, int, frac, frac_nbits)
+}
+
+// Returns the 128 bits of the 384-bit number words starting at bit start,
+// where start < 384.
+const fn extract(words: [u128; 3], start: u32) -> u128 {
+    let index = (start / 128) as usize;
+    let shift = start % 128;
+    let lo = words[index] >> shift;
+    if shift == 0 || index == 2 {
+        lo
+    } else {
+        lo | (words[index + 1] << (128 - shift))
+    }
+}
+
+// Returns e^±(abs / 2^frac_nbits), where frac_nbits ≤ 128.
+const fn exp(neg: bool, abs: u128, frac_nbits: u32) -> (u128, bool) {
+    let int = if frac_nbits < 128 {
+        abs >> frac_nbits
+    } else {
+        0
+    };
+    if int >= INT_LIMIT {
+        return exp2_parts(neg, INT_LIMIT, 0, frac_nbits);
+    }
+    // e^x = 2^(x log2(e)), where x log2(e) = abs × LOG2_E / 2^(frac_nbits +
+    // 255). Since abs < 2^(frac_nbits + 8), the integer part is < 2^9.
+    let lo = int256::wide_mul_u128(abs, LOG2_E.lo);
+    let hi = int256::wide_mul_u128(abs, LOG2_E.hi);
+    let (mid, carry) = lo.hi.overflowing_add(hi.lo);
+    let words = [lo.lo, mid, hi.hi + carry as u128];
+    let int = extract(words, frac_nbits + 255);
+    let frac = extract(words, frac_nbits + 127);
+    exp2_parts(neg, int, frac, frac_nbits)
+}
+
+pub mod exp2 {
+    macro_rules! impl_exp2 {
+        ($Inner:ident, |$val:ident| $neg_abs:expr) => {
+            // Returns 2^(val / 2^frac_nbits), where frac_nbits ≤ 128, and a
+            // bool indicating overflow.
+            pub const fn $Inner($val: $Inner, frac_nbits: u32) -> ($Inner, bool) {
+                let (neg, abs) = $neg_abs;
+                let (bits, fits) = super::exp2(neg, abs as u128, frac_nbits);
+                (bits as $Inner, !fits || bits > $Inner::MAX as u128)
+            }
+        };
+    }
+
+    impl_exp2! { i8, |val| (val < 0, val.unsigned_abs()) }
+    impl_exp2! { i16, |val| (val < 0, val.unsigned_abs()) }
+    impl_exp2! { i32, |val| (val < 0, val.unsigned_abs()) }
+    impl_exp2! { i64, |val| (val < 0, val.unsigned_abs()) }
+    impl_exp2! { i128, |val| (val < 0, val.unsigned_abs()) }
+    impl_exp2! { u8, |val| (false, val) }
+    impl_exp2! { u16, |val| (false, val) }
+    impl_exp2! { u32, |val| (false, val) }
+    impl_exp2! { u64, |val| (false, val) }
+    impl_exp2! { u128, |val| (false, val) }
+}
+
+macro_rules! impl_exp {
+    ($Inner:ident, |$val:ident| $neg_abs:expr) => {
+        // Returns e^(val / 2^frac_nbits), where frac_nbits ≤ 128, and a bool
+        // indicating overflow.
+        pub const fn $Inner($val: $Inner, frac_nbits: u32) -> ($Inner, bool) {
+            let (neg, abs) = $neg_abs;
+            let (bits, fits) = exp(neg, abs as u128, frac_nbits);
+            (bits as $Inner, !fits || bits > $Inner::MAX as u128)
+        }
+    };
+}
+
+impl_exp! { i8, |val| (val < 0, val.unsigned_abs()) }
+impl_exp! { i16, |val| (val < 0, val.unsigned_abs()) }
+impl_exp! { i32, |val| (val < 0, val.unsigned_abs()) }
+impl_exp! { i64, |val| (val < 0, val.unsigned_abs()) }
+impl_exp! { i128, |val| (val < 0, val.unsigned_abs()) }
+impl_exp! { u8, |val| (false, val) }
+impl_exp! { u16, |val| (false, val) }
+impl_exp! { u32, |val| (false, val) }
+impl_exp! { u64, |val| (false, val) }
+impl_exp! { u128, |val| (false, val) }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F8, I16F16, I32F32, I64F64, I8F8, U0F8, U16F16, U1F7, U2F126};
+    use crate::{FixedI128, FixedU128};
+
+    // Checks exp and exp2 against f64 for a grid of inputs. The error must be
+    // less than one DELTA plus the error of the f64 functions themselves, and
+    // overflow must only be reported when the result is out of range.
+    macro_rules! check_grid {
+        ($Fix:ty, $lo:expr, $hi:expr, $step:expr) => {
+            let delta = <$Fix>::DELTA.to_num::<f64>();
+            let max = <$Fix>::MAX.to_num::<f64>();
+            let mut x: f64 = $lo;
+            while x <= $hi {
+                let fix = <$Fix>::from_num(x);
+                let f = fix.to_num::<f64>();
+                for (name, val, expected) in [
+                    ("exp", fix.checked_exp(), f.exp()),
+                    ("exp2", fix.checked_exp2(), f.exp2()),
+                ] {
+                    let tol = delta + expected * 4.0 * f64::EPSILON;
+                    match val {
+                        Some(val) => {
+                            let err = (val.to_num::<f64>() - expected).abs();
+                            assert!(err < tol, "{name}({fix}) = {val}, error {err}");
+                        }
+                        None => assert!(expected > max - tol, "{name}({fix}) overflowed"),
+                    }
+                }
+                x += $step;
+            }
+        };
+    }
+
+    #[test]
+    fn grid() {
+        check_grid!(I8F8, -128.0, 127.0, 0.0123);
+        check_grid!(I16F16, -30.0, 16.0, 0.000_77);
+        check_grid!(U16F16, 0.0, 17.0, 0.000_77);
+        check_grid!(I32F32, -30.0, 32.0, 0.000_37);
+        check_grid!(I32F32, -1.0e6, 1.0e6, 37.7);
+        check_grid!(I64F64, -50.0, 64.0, 0.001_3);
+        check_grid!(U2F126, 0.0, 2.0, 0.000_13);
+    }
+
+    #[test]
+    fn exact() {
+        for i in -32..31 {
+            let fix = I32F32::from_num(i);
+            assert_eq!(fix.exp2(), I32F32::from_bits(1 << (32 + i)));
+        }
+        assert_eq!(I32F32::from_num(-34).exp2(), I32F32::ZERO);
+        assert_eq!(I16F16::ZERO.exp(), I16F16::ONE);
+        assert_eq!(U16F16::ZERO.exp(), U16F16::ONE);
+        assert_eq!(U1F7::ZERO.exp2(), U1F7::ONE);
+        assert_eq!(FixedI128::<126>::ZERO.exp(), FixedI128::<126>::ONE);
+        assert_eq!(FixedU128::<127>::ZERO.exp2(), FixedU128::<127>::ONE);
+        assert_eq!(I64F64::from_num(-64).exp2(), I64F64::DELTA);
+        assert_eq!(I64F64::from_num(63).checked_exp2(), None);
+        assert_eq!(I64F64::from_num(62).exp2(), I64F64::from_bits(1 << 126));
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(I16F16::from_num(14.99).checked_exp2().is_some());
+        assert_eq!(I16F16::from_num(15).checked_exp2(), None);
+        assert_eq!(U16F16::from_num(16).checked_exp2(), None);
+        assert_eq!(I16F16::from_num(15).saturating_exp2(), I16F16::MAX);
+        assert_eq!(I16F16::from_num(16).wrapping_exp2(), I16F16::ZERO);
+        assert_eq!(I16F16::MIN.saturating_exp(), I16F16::ZERO);
+        assert_eq!(I16F16::MAX.checked_exp(), None);
+
+        // 1 cannot be represented without integer bits
+        assert_eq!(I0F8::ZERO.checked_exp(), None);
+        assert_eq!(U0F8::ZERO.checked_exp2(), None);
+        assert_eq!(I0F8::MIN.checked_exp2(), None);
+        assert_eq!(
+            U1F7::from_num(0.5).checked_exp2(),
+            Some(U1F7::from_bits(181))
+        );
+        assert_eq!(U0F8::ZERO.wrapping_exp(), U0F8::ZERO);
+        assert_eq!(U0F8::ZERO.saturating_exp(), U0F8::MAX);
+    }
+}
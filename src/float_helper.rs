@@ -13,6 +13,9 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+use crate::int256::{self, U256};
+use core::num::NonZeroU128;
+
 macro_rules! make_helper {
     ($Float:ident($Bits:ident, $IBits:ident) $(; use $path:path)?) => {
         #[allow(non_snake_case)]
@@ -230,3 +233,97 @@ make_helper! { f16(u16, i16) }
 make_helper! { f32(u32, i32) }
 make_helper! { f64(u64, i64) }
 make_helper! { f128(u128, i128) }
+
+// Returns (−1)^neg × abs × 2^−frac_bits × 10^dec_exp as an f64, rounded to the
+// nearest, with ties rounded to even.
+//
+// The scaling is done in steps of 10^±k with k ≤ 55, since 5^55 is the largest
+// power of five that fits in u128. After each step, the exact result is cut to
+// 128 significant bits, and any discarded bits are collected in a sticky bit.
+// This means that when |dec_exp| ≤ 55 the result is rounded only once; for
+// larger exponents the intermediate truncations can very rarely cause double
+// rounding.
+pub fn f64_from_neg_abs_dec_exp(
+    neg: bool,
+    abs: u128,
+    frac_bits: i32,
+    dec_exp: i32,
+) -> core::primitive::f64 {
+    const MAX_POW5_EXP: u32 = 55;
+
+    if abs == 0 {
+        return 0.0;
+    }
+    let lz = abs.leading_zeros();
+    let mut abs = abs << lz;
+    let mut frac_bits = i64::from(frac_bits) + i64::from(lz);
+    let mut rem_exp = dec_exp;
+    // whether any nonzero bits have been discarded
+    let mut inexact = false;
+    while rem_exp != 0 {
+        // abs is normalized, so the value is in [2^bin_exp, 2^(bin_exp + 1))
+        let bin_exp = 127 - frac_bits;
+        if rem_exp > 0 && bin_exp > 1023 {
+            return if neg {
+                core::primitive::f64::NEG_INFINITY
+            } else {
+                core::primitive::f64::INFINITY
+            };
+        }
+        if rem_exp < 0 && bin_exp < -1076 {
+            return if neg { -0.0 } else { 0.0 };
+        }
+
+        let k = rem_exp.unsigned_abs().min(MAX_POW5_EXP);
+        let mut pow5 = 1u128;
+        let mut i = 0;
+        while i < k {
+            pow5 *= 5;
+            i += 1;
+        }
+        let wide = if rem_exp > 0 {
+            // × 10^k = × 5^k × 2^k
+            frac_bits -= i64::from(k);
+            rem_exp -= k as i32;
+            int256::wide_mul_u128(abs, pow5)
+        } else {
+            // × 10^−k = × 2^128 ÷ 5^k × 2^−(128 + k)
+            frac_bits += 128 + i64::from(k);
+            rem_exp += k as i32;
+            let Some(divisor) = NonZeroU128::new(pow5) else {
+                unreachable!();
+            };
+            let dividend = U256 { lo: 0, hi: abs };
+            let (quot, rem) = int256::div_rem_u256_u128(dividend, divisor);
+            inexact = inexact || rem != 0;
+            quot
+        };
+        // keep the 128 most significant bits
+        let hi_lz = wide.hi.leading_zeros();
+        abs = if hi_lz == 128 {
+            let lo_lz = wide.lo.leading_zeros();
+            frac_bits += i64::from(lo_lz);
+            wide.lo << lo_lz
+        } else if hi_lz == 0 {
+            inexact = inexact || wide.lo != 0;
+            frac_bits -= 128;
+            wide.hi
+        } else {
+            inexact = inexact || wide.lo << hi_lz != 0;
+            frac_bits -= i64::from(128 - hi_lz);
+            (wide.hi << hi_lz) | (wide.lo >> (128 - hi_lz))
+        };
+        if inexact {
+            abs |= 1;
+        }
+    }
+
+    // narrow to 64 bits, keeping a sticky bit
+    let mut narrow = (abs >> 64) as u64;
+    if abs as u64 != 0 {
+        narrow |= 1;
+    }
+    frac_bits -= 64;
+    let frac_bits = frac_bits.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32;
+    f64::from_neg_abs(neg, narrow, frac_bits)
+}
@@ -92,6 +92,11 @@ use std::error::Error;
 //       - pub const fn saturating_from_str_radix
 //       - pub const fn wrapping_from_str_radix
 //       - pub const fn overflowing_from_str_radix
+//       - pub const fn from_percent_str
+//       - pub const fn from_grouped_str
+//       - pub const fn from_str_with_separators
+//       - pub const fn saturating_from_str_with_separators
+//       - pub const fn overflowing_from_str_with_separators
 macro_rules! all {
     ($Single:ident) => {
         use crate::from_str::{ParseErrorKind, ParseFixedError, Sep};
@@ -154,11 +159,130 @@ macro_rules! all {
             frac_nbits: u32,
         ) -> Result<($Single, bool), ParseFixedError> {
             let bytes = s.as_bytes();
-            match from_str(bytes, radix, Sep::Error, frac_nbits) {
+            match from_str(bytes, radix, Sep::Error, frac_nbits, 0) {
                 Ok(val) => Ok(val),
                 Err(kind) => Err(ParseFixedError { kind }),
             }
         }
+
+        #[inline]
+        pub const fn from_percent_str(
+            s: &str,
+            frac_nbits: u32,
+        ) -> Result<$Single, ParseFixedError> {
+            let bytes = match s.as_bytes().split_last() {
+                Some((b'%', bytes)) => bytes,
+                _ => {
+                    return Err(ParseFixedError {
+                        kind: ParseErrorKind::NoPercentSign,
+                    })
+                }
+            };
+            // dividing by 100 is exact when done on the decimal exponent
+            match from_str(bytes, 10, Sep::Error, frac_nbits, -2) {
+                Ok((val, false)) => Ok(val),
+                Ok((_, true)) => Err(ParseFixedError {
+                    kind: ParseErrorKind::Overflow,
+                }),
+                Err(kind) => Err(ParseFixedError { kind }),
+            }
+        }
+
+        pub const fn from_grouped_str(
+            s: &str,
+            group: char,
+            decimal: char,
+            frac_nbits: u32,
+        ) -> Result<$Single, ParseFixedError> {
+            assert!(
+                group != decimal,
+                "group and decimal separators are the same"
+            );
+            assert!(
+                crate::from_str::is_valid_separator(group)
+                    && crate::from_str::is_valid_separator(decimal),
+                "invalid separator"
+            );
+            let (group_buf, group_len) = crate::from_str::encode_utf8(group);
+            let (decimal_buf, decimal_len) = crate::from_str::encode_utf8(decimal);
+            let group_bytes = group_buf.split_at(group_len).0;
+            let decimal_bytes = decimal_buf.split_at(decimal_len).0;
+            match crate::from_str::parse_grouped(s.as_bytes(), group_bytes, decimal_bytes) {
+                Ok(parse) => match from_parse(parse, 10, frac_nbits) {
+                    (val, false) => Ok(val),
+                    (_, true) => Err(ParseFixedError {
+                        kind: ParseErrorKind::Overflow,
+                    }),
+                },
+                Err(kind) => Err(ParseFixedError { kind }),
+            }
+        }
+
+        #[inline]
+        pub const fn from_str_with_separators(
+            s: &str,
+            decimal: char,
+            exp: char,
+            frac_nbits: u32,
+        ) -> Result<$Single, ParseFixedError> {
+            match overflowing_from_str_with_separators(s, decimal, exp, frac_nbits) {
+                Ok((val, false)) => Ok(val),
+                Ok((_, true)) => Err(ParseFixedError {
+                    kind: ParseErrorKind::Overflow,
+                }),
+                Err(e) => Err(e),
+            }
+        }
+
+        #[inline]
+        pub const fn saturating_from_str_with_separators(
+            s: &str,
+            decimal: char,
+            exp: char,
+            frac_nbits: u32,
+        ) -> Result<$Single, ParseFixedError> {
+            match overflowing_from_str_with_separators(s, decimal, exp, frac_nbits) {
+                Ok((val, false)) => Ok(val),
+                Ok((_, true)) => {
+                    let bytes = s.as_bytes();
+                    let starts_with_minus = match bytes.first() {
+                        Some(s) => *s == b'-',
+                        None => false,
+                    };
+                    if starts_with_minus {
+                        Ok($Single::MIN)
+                    } else {
+                        Ok($Single::MAX)
+                    }
+                }
+                Err(e) => Err(e),
+            }
+        }
+
+        pub const fn overflowing_from_str_with_separators(
+            s: &str,
+            decimal: char,
+            exp: char,
+            frac_nbits: u32,
+        ) -> Result<($Single, bool), ParseFixedError> {
+            assert!(
+                decimal != exp,
+                "decimal and exponent separators are the same"
+            );
+            assert!(
+                crate::from_str::is_valid_separator(decimal)
+                    && crate::from_str::is_valid_exp_separator(exp),
+                "invalid separator"
+            );
+            let (decimal_buf, decimal_len) = crate::from_str::encode_utf8(decimal);
+            let (exp_buf, exp_len) = crate::from_str::encode_utf8(exp);
+            let decimal_bytes = decimal_buf.split_at(decimal_len).0;
+            let exp_bytes = exp_buf.split_at(exp_len).0;
+            match crate::from_str::parse_separated(s.as_bytes(), decimal_bytes, exp_bytes) {
+                Ok(parse) => Ok(from_parse(parse, 10, frac_nbits)),
+                Err(kind) => Err(ParseFixedError { kind }),
+            }
+        }
     };
 }
 
@@ -168,6 +292,7 @@ macro_rules! all {
 //   * Expands `all` macro.
 //   * Defines:
 //       - pub const fn from_str
+//       - pub(super) const fn from_parse
 //       - pub const fn lit
 macro_rules! signed {
     ($Single:ident, $Uns:ident) => {
@@ -179,23 +304,31 @@ macro_rules! signed {
                 radix: u32,
                 sep: Sep,
                 frac_nbits: u32,
+                exp_offset: i32,
             ) -> Result<($Single, bool), ParseErrorKind> {
-                let (neg, abs, mut overflow) = match crate::from_str::$Uns::get_int_frac(
-                    bytes,
+                match crate::from_str::parse_bounds(bytes, radix, sep, exp_offset) {
+                    Ok(parse) => Ok(from_parse(parse, radix, frac_nbits)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            pub(super) const fn from_parse(
+                parse: crate::from_str::Parse,
+                radix: u32,
+                frac_nbits: u32,
+            ) -> ($Single, bool) {
+                let (neg, abs, mut overflow) = crate::from_str::$Uns::get_int_frac(
+                    parse,
                     radix,
-                    sep,
                     $Single::BITS - frac_nbits,
                     frac_nbits,
-                ) {
-                    Ok((neg, abs, overflow)) => (neg, abs, overflow),
-                    Err(e) => return Err(e),
-                };
+                );
                 let bound = if !neg { $Single::MAX } else { $Single::MIN };
                 if abs > bound.unsigned_abs() {
                     overflow = true;
                 }
                 let abs = if neg { abs.wrapping_neg() } else { abs } as $Single;
-                Ok((abs, overflow))
+                (abs, overflow)
             }
 
             pub const fn lit(s: &str, frac_nbits: u32) -> Result<$Single, ParseFixedError> {
@@ -239,6 +372,7 @@ signed! { i128, u128 }
 //   * Expands `all` macro.
 //   * Defines:
 //       - pub(super) const fn from_str
+//       - pub(super) const fn from_parse
 //       - pub const fn lit
 //       - pub(super) const fn lit_no_sign
 //       - pub(super) const fn get_int_frac
@@ -267,17 +401,22 @@ macro_rules! unsigned {
             radix: u32,
             sep: Sep,
             frac_nbits: u32,
+            exp_offset: i32,
         ) -> Result<($Uns, bool), ParseErrorKind> {
+            match parse_bounds(bytes, radix, sep, exp_offset) {
+                Ok(parse) => Ok(from_parse(parse, radix, frac_nbits)),
+                Err(e) => Err(e),
+            }
+        }
+
+        pub(super) const fn from_parse(parse: Parse, radix: u32, frac_nbits: u32) -> ($Uns, bool) {
             let (neg, abs, mut overflow) =
-                match get_int_frac(bytes, radix, sep, $Uns::BITS - frac_nbits, frac_nbits) {
-                    Ok((neg, abs, overflow)) => (neg, abs, overflow),
-                    Err(e) => return Err(e),
-                };
+                get_int_frac(parse, radix, $Uns::BITS - frac_nbits, frac_nbits);
             if neg && abs > 0 {
                 overflow = true;
             }
             let abs = if neg { abs.wrapping_neg() } else { abs };
-            Ok((abs, overflow))
+            (abs, overflow)
         }
 
         #[inline]
@@ -314,7 +453,7 @@ macro_rules! unsigned {
             if let Some((b'-' | b'+', _)) = bytes.split_first() {
                 return Err(ParseErrorKind::MisplacedSign);
             }
-            match from_str(bytes, radix, Sep::Skip, frac_nbits) {
+            match from_str(bytes, radix, Sep::Skip, frac_nbits, 0) {
                 Ok((val, false)) => Ok(val),
                 Ok((_, true)) => Err(ParseErrorKind::Overflow),
                 Err(e) => Err(e),
@@ -322,21 +461,17 @@ macro_rules! unsigned {
         }
 
         pub(super) const fn get_int_frac(
-            bytes: &[u8],
+            parse: Parse,
             radix: u32,
-            sep: Sep,
             int_nbits: u32,
             frac_nbits: u32,
-        ) -> Result<(bool, $Uns, bool), ParseErrorKind> {
+        ) -> (bool, $Uns, bool) {
             let Parse {
                 neg,
                 int,
                 frac,
                 bit_exp,
-            } = match parse_bounds(bytes, radix, sep) {
-                Ok(o) => o,
-                Err(e) => return Err(e),
-            };
+            } = parse;
             let (int_val, mut overflow) = get_int(int, radix, int_nbits, bit_exp);
             let (frac_val, frac_overflow) = match get_frac(frac, radix, frac_nbits, bit_exp) {
                 Some(val) => (val, false),
@@ -360,7 +495,7 @@ macro_rules! unsigned {
                 }
                 val = new_val;
             }
-            Ok((neg, val, overflow))
+            (neg, val, overflow)
         }
 
         pub(super) const fn get_int(
@@ -1026,6 +1161,7 @@ enum ParseErrorKind {
     InvalidDigit,
     MisplacedSign,
     MisplacedUnderscore,
+    MisplacedGroupSeparator,
     NoDigits,
     TooManyPoints,
     Overflow,
@@ -1033,6 +1169,7 @@ enum ParseErrorKind {
     ExpNoDigits,
     TooManyExp,
     ExpOverflow,
+    NoPercentSign,
 }
 
 impl ParseFixedError {
@@ -1044,6 +1181,7 @@ impl ParseFixedError {
             InvalidDigit => "invalid literal: invalid digit found in string",
             MisplacedSign => "invalid literal: misplaced sign found in string",
             MisplacedUnderscore => "invalid literal: misplaced underscore found in string",
+            MisplacedGroupSeparator => "invalid literal: misplaced group separator found in string",
             NoDigits => "invalid literal: string has no digits",
             TooManyPoints => "invalid literal: more than one point found in string",
             Overflow => "invalid literal: overflow",
@@ -1051,6 +1189,7 @@ impl ParseFixedError {
             ExpNoDigits => "invalid literal: exponent has no digits",
             TooManyExp => "invalid literal: more than one exponent found",
             ExpOverflow => "invalid literal: exponent overflow",
+            NoPercentSign => "invalid literal: string does not end with a percent sign",
         }
     }
 
@@ -1061,6 +1200,7 @@ impl ParseFixedError {
             InvalidDigit => "invalid digit found in string",
             MisplacedSign => "misplaced sign found in string",
             MisplacedUnderscore => "misplaced underscore found in string",
+            MisplacedGroupSeparator => "misplaced group separator found in string",
             NoDigits => "string has no digits",
             TooManyPoints => "more than one point found in string",
             Overflow => "overflow",
@@ -1068,6 +1208,7 @@ impl ParseFixedError {
             ExpNoDigits => "exponent has no digits",
             TooManyExp => "more than one exponent found",
             ExpOverflow => "exponent overflow",
+            NoPercentSign => "string does not end with a percent sign",
         }
     }
 }
@@ -1087,7 +1228,14 @@ impl Error for ParseFixedError {
 
 // Zeros at start of int and at end of frac are trimmed.
 // Leading underscores for either int or frac are never accepted, even for Sep::Skip.
-const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, ParseErrorKind> {
+// The decimal exponent exp_offset is added to the parsed exponent; it must be
+// zero unless radix is 10.
+const fn parse_bounds(
+    bytes: &[u8],
+    radix: u32,
+    sep: Sep,
+    exp_offset: i32,
+) -> Result<Parse<'_>, ParseErrorKind> {
     let mut sign: Option<bool> = None;
     let mut int_start: Option<usize> = None;
     let mut point: Option<usize> = None;
@@ -1248,6 +1396,12 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
         }
         _ => DigitsUnds::EMPTY,
     };
+    exp = match exp.checked_add(exp_offset) {
+        Some(s) => s,
+        None => {
+            return Err(ParseErrorKind::ExpOverflow);
+        }
+    };
     let bit_exp = if exp_is_bit {
         match radix {
             2 => 0,
@@ -1278,6 +1432,266 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
     })
 }
 
+// Separators cannot be digits, letters or signs.
+const fn is_valid_separator(c: char) -> bool {
+    !c.is_ascii_alphanumeric() && c != '+' && c != '-'
+}
+
+// The exponent separator can be a letter, but not a digit or a sign.
+const fn is_valid_exp_separator(c: char) -> bool {
+    !c.is_ascii_digit() && c != '+' && c != '-'
+}
+
+// Like char::encode_utf8, which is not const in the minimum supported version.
+const fn encode_utf8(c: char) -> ([u8; 4], usize) {
+    let code = c as u32;
+    if code < 0x80 {
+        ([code as u8, 0, 0, 0], 1)
+    } else if code < 0x800 {
+        (
+            [0xC0 | (code >> 6) as u8, 0x80 | (code & 0x3F) as u8, 0, 0],
+            2,
+        )
+    } else if code < 0x1_0000 {
+        (
+            [
+                0xE0 | (code >> 12) as u8,
+                0x80 | ((code >> 6) & 0x3F) as u8,
+                0x80 | (code & 0x3F) as u8,
+                0,
+            ],
+            3,
+        )
+    } else {
+        (
+            [
+                0xF0 | (code >> 18) as u8,
+                0x80 | ((code >> 12) & 0x3F) as u8,
+                0x80 | ((code >> 6) & 0x3F) as u8,
+                0x80 | (code & 0x3F) as u8,
+            ],
+            4,
+        )
+    }
+}
+
+// Returns the rest of bytes if it starts with prefix.
+const fn strip_prefix<'a>(bytes: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if bytes.len() < prefix.len() {
+        return None;
+    }
+    let (start, rest) = bytes.split_at(prefix.len());
+    let mut i = 0;
+    while i < prefix.len() {
+        if start[i] != prefix[i] {
+            return None;
+        }
+        i += 1;
+    }
+    Some(rest)
+}
+
+// Parses a decimal number with an optional sign, where the integer part can be
+// split into groups of three digits by the group separator, and where the
+// decimal separator is used instead of the point. The first group can have one
+// to three digits, and the fractional part cannot contain group separators.
+//
+// Any byte that is not an ASCII alphanumeric byte is skipped inside DigitsUnds,
+// so group separators are left in the integer part.
+const fn parse_grouped<'a>(
+    bytes: &'a [u8],
+    group: &[u8],
+    decimal: &[u8],
+) -> Result<Parse<'a>, ParseErrorKind> {
+    let (neg, mut rem_bytes) = match bytes.split_first() {
+        Some((b'-', rem)) => (true, rem),
+        Some((b'+', rem)) => (false, rem),
+        _ => (false, bytes),
+    };
+    let int_bytes = rem_bytes;
+    let mut int_len = 0;
+    let mut int_digits = 0;
+    let mut group_digits = 0;
+    let mut has_group = false;
+    let mut has_point = false;
+    while let Some((&byte, rem)) = rem_bytes.split_first() {
+        if byte.is_ascii_digit() {
+            int_digits += 1;
+            group_digits += 1;
+            if has_group && group_digits > 3 {
+                return Err(ParseErrorKind::MisplacedGroupSeparator);
+            }
+            int_len += 1;
+            rem_bytes = rem;
+        } else if let Some(rem) = strip_prefix(rem_bytes, group) {
+            if group_digits == 0 || group_digits > 3 || (has_group && group_digits != 3) {
+                return Err(ParseErrorKind::MisplacedGroupSeparator);
+            }
+            has_group = true;
+            group_digits = 0;
+            int_len += group.len();
+            rem_bytes = rem;
+        } else if let Some(rem) = strip_prefix(rem_bytes, decimal) {
+            has_point = true;
+            rem_bytes = rem;
+            break;
+        } else if byte == b'-' || byte == b'+' {
+            return Err(ParseErrorKind::MisplacedSign);
+        } else {
+            return Err(ParseErrorKind::InvalidDigit);
+        }
+    }
+    if has_group && group_digits != 3 {
+        return Err(ParseErrorKind::MisplacedGroupSeparator);
+    }
+    let frac_bytes = rem_bytes;
+    let mut frac_digits = 0;
+    while let Some((&byte, rem)) = rem_bytes.split_first() {
+        if byte.is_ascii_digit() {
+            frac_digits += 1;
+            rem_bytes = rem;
+        } else if strip_prefix(rem_bytes, group).is_some() {
+            return Err(ParseErrorKind::MisplacedGroupSeparator);
+        } else if strip_prefix(rem_bytes, decimal).is_some() {
+            return Err(ParseErrorKind::TooManyPoints);
+        } else if byte == b'-' || byte == b'+' {
+            return Err(ParseErrorKind::MisplacedSign);
+        } else {
+            return Err(ParseErrorKind::InvalidDigit);
+        }
+    }
+    if int_digits == 0 && frac_digits == 0 {
+        return Err(ParseErrorKind::NoDigits);
+    }
+    let int = DigitsUnds::new(int_bytes.split_at(int_len).0);
+    let frac = if has_point {
+        DigitsUnds::new(frac_bytes)
+    } else {
+        DigitsUnds::EMPTY
+    };
+    let Some((int, frac)) = DigitsExp::new_int_frac(int, frac, 0) else {
+        return Err(ParseErrorKind::ExpOverflow);
+    };
+    Ok(Parse {
+        neg,
+        int,
+        frac,
+        bit_exp: None,
+    })
+}
+
+// Parses a decimal number with an optional sign and an optional exponent, where
+// the decimal separator is used instead of the point, and the exponent
+// separator is used instead of “e”. The exponent can have its own optional
+// sign. Underscores are not accepted.
+const fn parse_separated<'a>(
+    bytes: &'a [u8],
+    decimal: &[u8],
+    exp_sep: &[u8],
+) -> Result<Parse<'a>, ParseErrorKind> {
+    let (neg, mut rem_bytes) = match bytes.split_first() {
+        Some((b'-', rem)) => (true, rem),
+        Some((b'+', rem)) => (false, rem),
+        _ => (false, bytes),
+    };
+    let int_bytes = rem_bytes;
+    let mut int_len = 0;
+    let mut has_point = false;
+    let mut has_exp = false;
+    while let Some((&byte, rem)) = rem_bytes.split_first() {
+        if byte.is_ascii_digit() {
+            int_len += 1;
+            rem_bytes = rem;
+        } else if let Some(rem) = strip_prefix(rem_bytes, decimal) {
+            has_point = true;
+            rem_bytes = rem;
+            break;
+        } else if let Some(rem) = strip_prefix(rem_bytes, exp_sep) {
+            has_exp = true;
+            rem_bytes = rem;
+            break;
+        } else if byte == b'-' || byte == b'+' {
+            return Err(ParseErrorKind::MisplacedSign);
+        } else {
+            return Err(ParseErrorKind::InvalidDigit);
+        }
+    }
+    let frac_bytes = rem_bytes;
+    let mut frac_len = 0;
+    if has_point {
+        while let Some((&byte, rem)) = rem_bytes.split_first() {
+            if byte.is_ascii_digit() {
+                frac_len += 1;
+                rem_bytes = rem;
+            } else if let Some(rem) = strip_prefix(rem_bytes, exp_sep) {
+                has_exp = true;
+                rem_bytes = rem;
+                break;
+            } else if strip_prefix(rem_bytes, decimal).is_some() {
+                return Err(ParseErrorKind::TooManyPoints);
+            } else if byte == b'-' || byte == b'+' {
+                return Err(ParseErrorKind::MisplacedSign);
+            } else {
+                return Err(ParseErrorKind::InvalidDigit);
+            }
+        }
+    }
+    if int_len == 0 && frac_len == 0 {
+        return Err(ParseErrorKind::NoDigits);
+    }
+    let mut exp: i32 = 0;
+    if has_exp {
+        let exp_neg = match rem_bytes.split_first() {
+            Some((b'-', rem)) => {
+                rem_bytes = rem;
+                true
+            }
+            Some((b'+', rem)) => {
+                rem_bytes = rem;
+                false
+            }
+            _ => false,
+        };
+        let mut has_exp_digit = false;
+        while let Some((&byte, rem)) = rem_bytes.split_first() {
+            if byte.is_ascii_digit() {
+                exp = match exp.checked_mul(10) {
+                    Some(s) => s,
+                    None => return Err(ParseErrorKind::ExpOverflow),
+                };
+                let digit = (byte - b'0') as i32;
+                let add = if exp_neg { -digit } else { digit };
+                exp = match exp.checked_add(add) {
+                    Some(s) => s,
+                    None => return Err(ParseErrorKind::ExpOverflow),
+                };
+                has_exp_digit = true;
+                rem_bytes = rem;
+            } else if strip_prefix(rem_bytes, exp_sep).is_some() {
+                return Err(ParseErrorKind::TooManyExp);
+            } else if byte == b'-' || byte == b'+' {
+                return Err(ParseErrorKind::MisplacedSign);
+            } else {
+                return Err(ParseErrorKind::ExpInvalidDigit);
+            }
+        }
+        if !has_exp_digit {
+            return Err(ParseErrorKind::ExpNoDigits);
+        }
+    }
+    let int = DigitsUnds::new(int_bytes.split_at(int_len).0);
+    let frac = DigitsUnds::new(frac_bytes.split_at(frac_len).0);
+    let Some((int, frac)) = DigitsExp::new_int_frac(int, frac, exp) else {
+        return Err(ParseErrorKind::ExpOverflow);
+    };
+    Ok(Parse {
+        neg,
+        int,
+        frac,
+        bit_exp: None,
+    })
+}
+
 const fn frac_is_half(digits: DigitsExp, radix: u32) -> bool {
     // since zeros are trimmed, when the value is one half there has to be
     // exatly one digit, and rest has to be empty
@@ -1548,7 +1962,7 @@ mod tests {
             int,
             frac,
             bit_exp,
-        } = parse_bounds(bytes, radix, sep).unwrap();
+        } = parse_bounds(bytes, radix, sep, 0).unwrap();
         assert_eq!(neg, check.0);
         assert!(digits_eq_bytes(int, check.1.as_bytes()));
         assert!(digits_eq_bytes(frac, check.2.as_bytes()));
@@ -1567,7 +1981,7 @@ mod tests {
     #[track_caller]
     fn check_parse_bounds_err(bytes: &str, radix: u32, sep: Sep, check: ParseErrorKind) {
         let bytes = bytes.as_bytes();
-        let kind = parse_bounds(bytes, radix, sep).unwrap_err();
+        let kind = parse_bounds(bytes, radix, sep, 0).unwrap_err();
         assert_eq!(kind, check);
     }
 
@@ -2773,6 +3187,56 @@ mod tests {
         assert_ok!(U4F4, "0.5625", 10, 0x09, false);
     }
 
+    #[test]
+    fn huge_exponents() {
+        let overflow = ParseFixedError {
+            kind: ParseErrorKind::Overflow,
+        };
+        let exp_overflow = ParseFixedError {
+            kind: ParseErrorKind::ExpOverflow,
+        };
+
+        // huge positive exponents overflow unless the value is zero
+        for s in [
+            "1e1000",
+            "1e2000000000",
+            "0.0001e2147483647",
+            "-1E2147483647",
+        ] {
+            assert_eq!(I16F16::from_str(s), Err(overflow));
+            assert_eq!(U128F0::from_str(s), Err(overflow));
+            assert_eq!(I0F128::from_str(s), Err(overflow));
+        }
+        assert_eq!(I16F16::saturating_from_str("1e1000"), Ok(I16F16::MAX));
+        assert_eq!(I16F16::saturating_from_str("-1e1000"), Ok(I16F16::MIN));
+        assert_eq!(U16F16::saturating_from_str("1e2147483647"), Ok(U16F16::MAX));
+        // all the digits that are not discarded are zero when wrapping
+        assert_eq!(I16F16::wrapping_from_str("1e1000"), Ok(I16F16::ZERO));
+        assert_eq!(I16F16::from_str("0e2147483647"), Ok(I16F16::ZERO));
+        assert_eq!(I16F16::from_str("0.000e1000"), Ok(I16F16::ZERO));
+
+        // huge negative exponents round to zero
+        for s in ["1e-1000", "9.99e-2000000000", "-1e-2147483648", "5e-40"] {
+            assert_eq!(I16F16::from_str(s), Ok(I16F16::ZERO));
+            assert_eq!(I64F64::from_str(s), Ok(I64F64::ZERO));
+            assert_eq!(U0F128::from_str(s), Ok(U0F128::ZERO));
+        }
+        // a huge exponent can still be cancelled by a huge number of digits
+        let mut long = String::from("0.");
+        long.extend(core::iter::repeat('0').take(9999));
+        long.push_str("15e10000");
+        assert_eq!(I16F16::from_str(&long), Ok(I16F16::from_num(1.5)));
+
+        // exponents that do not fit in i32 are errors
+        assert_eq!(I16F16::from_str("1e2147483648"), Err(exp_overflow));
+        assert_eq!(I16F16::from_str("1e-2147483649"), Err(exp_overflow));
+        assert_eq!(I16F16::from_str("0e99999999999"), Err(exp_overflow));
+        assert_eq!(
+            I16F16::saturating_from_str("1e99999999999"),
+            Err(exp_overflow)
+        );
+    }
+
     #[test]
     fn frac4() {
         for u in 0..=255u8 {
@@ -2783,6 +3247,355 @@ mod tests {
         }
     }
 
+    #[test]
+    fn percent() {
+        let err = |kind| ParseFixedError { kind };
+        assert_eq!(I16F16::from_percent_str("50%"), Ok(I16F16::from_num(0.5)));
+        assert_eq!(U8F8::from_percent_str("12.5%"), Ok(U8F8::from_num(0.125)));
+        assert_eq!(
+            I16F16::from_percent_str("-250%"),
+            Ok(I16F16::from_num(-2.5))
+        );
+        assert_eq!(I16F16::from_percent_str("1e2%"), Ok(I16F16::ONE));
+        // 33.3% is 21823.488 / 65536, rounded down
+        assert_eq!(
+            I16F16::from_percent_str("33.3%"),
+            Ok(I16F16::from_bits(21823))
+        );
+        // 0.1953125% is exactly half of U0F8::DELTA, rounded to even 0
+        assert_eq!(U0F8::from_percent_str("0.1953125%"), Ok(U0F8::ZERO));
+        // 0.5859375% is 1.5 times U0F8::DELTA, rounded to even 2
+        assert_eq!(U0F8::from_percent_str("0.5859375%"), Ok(U0F8::from_bits(2)));
+        // 100% does not fit in U0F8, but 99.6% rounds to U0F8::MAX
+        assert_eq!(U0F8::from_percent_str("99.6%"), Ok(U0F8::MAX));
+        assert_eq!(
+            U0F8::from_percent_str("100%").unwrap_err(),
+            err(ParseErrorKind::Overflow)
+        );
+
+        assert_eq!(
+            I16F16::from_percent_str("50").unwrap_err(),
+            err(ParseErrorKind::NoPercentSign)
+        );
+        assert_eq!(
+            I16F16::from_percent_str("").unwrap_err(),
+            err(ParseErrorKind::NoPercentSign)
+        );
+        assert_eq!(
+            I16F16::from_percent_str("%").unwrap_err(),
+            err(ParseErrorKind::NoDigits)
+        );
+        assert_eq!(
+            I16F16::from_percent_str("50%%").unwrap_err(),
+            err(ParseErrorKind::InvalidDigit)
+        );
+        assert_eq!(
+            I16F16::from_percent_str("5%0").unwrap_err(),
+            err(ParseErrorKind::NoPercentSign)
+        );
+        assert_eq!(
+            I16F16::from_percent_str("1.2.5%").unwrap_err(),
+            err(ParseErrorKind::TooManyPoints)
+        );
+        assert_eq!(
+            U8F8::from_percent_str("-50%").unwrap_err(),
+            err(ParseErrorKind::Overflow)
+        );
+    }
+
+    #[test]
+    fn grouped() {
+        let err = |kind| ParseFixedError { kind };
+        // comma-grouped
+        assert_eq!(
+            I32F32::from_grouped_str("1,234,567.25", ',', '.'),
+            Ok(I32F32::from_num(1_234_567.25))
+        );
+        assert_eq!(
+            I32F32::from_grouped_str("-12,345", ',', '.'),
+            Ok(I32F32::from_num(-12_345))
+        );
+        assert_eq!(
+            U16F16::from_grouped_str("+999.5", ',', '.'),
+            Ok(U16F16::from_num(999.5))
+        );
+        assert_eq!(
+            U16F16::from_grouped_str("1234.5", ',', '.'),
+            Ok(U16F16::from_num(1234.5))
+        );
+        assert_eq!(
+            U16F16::from_grouped_str(".5", ',', '.'),
+            Ok(U16F16::from_num(0.5))
+        );
+        // space-grouped, including a multi-byte narrow no-break space
+        assert_eq!(
+            I32F32::from_grouped_str("1 234 567,125", ' ', ','),
+            Ok(I32F32::from_num(1_234_567.125))
+        );
+        assert_eq!(
+            I32F32::from_grouped_str("-7\u{202f}654\u{202f}321,5", '\u{202f}', ','),
+            Ok(I32F32::from_num(-7_654_321.5))
+        );
+        assert_eq!(U0F8::from_grouped_str("0,001", '.', ','), Ok(U0F8::ZERO));
+        // rounding is to the nearest, with ties rounded to even
+        assert_eq!(
+            U8F0::from_grouped_str("2.5", ',', '.'),
+            Ok(U8F0::from_num(2))
+        );
+        assert_eq!(
+            U8F0::from_grouped_str("3.5", ',', '.'),
+            Ok(U8F0::from_num(4))
+        );
+        assert_eq!(
+            I16F0::from_grouped_str("1,000.5", ',', '.'),
+            Ok(I16F0::from_num(1000))
+        );
+
+        // misplaced separators
+        for s in [
+            ",123",
+            "1,23",
+            "1,2345",
+            "1234,567",
+            "1,,234",
+            "1,234,",
+            "12,34,567",
+            "1,234.5,6",
+            "-,123",
+            "1,.5",
+        ] {
+            assert_eq!(
+                I32F32::from_grouped_str(s, ',', '.').unwrap_err(),
+                err(ParseErrorKind::MisplacedGroupSeparator),
+                "{s}"
+            );
+        }
+        assert_eq!(
+            I32F32::from_grouped_str("1 23,5", ' ', ',').unwrap_err(),
+            err(ParseErrorKind::MisplacedGroupSeparator)
+        );
+        // other errors
+        assert_eq!(
+            I32F32::from_grouped_str("1,234.5.6", ',', '.').unwrap_err(),
+            err(ParseErrorKind::TooManyPoints)
+        );
+        assert_eq!(
+            I32F32::from_grouped_str("1_234.5", ',', '.').unwrap_err(),
+            err(ParseErrorKind::InvalidDigit)
+        );
+        assert_eq!(
+            I32F32::from_grouped_str("1.5", ' ', ',').unwrap_err(),
+            err(ParseErrorKind::InvalidDigit)
+        );
+        assert_eq!(
+            I32F32::from_grouped_str("1-2", ',', '.').unwrap_err(),
+            err(ParseErrorKind::MisplacedSign)
+        );
+        assert_eq!(
+            I32F32::from_grouped_str("-.", ',', '.').unwrap_err(),
+            err(ParseErrorKind::NoDigits)
+        );
+        assert_eq!(
+            I32F32::from_grouped_str("", ',', '.').unwrap_err(),
+            err(ParseErrorKind::NoDigits)
+        );
+        assert_eq!(
+            I8F8::from_grouped_str("1,000", ',', '.').unwrap_err(),
+            err(ParseErrorKind::Overflow)
+        );
+        assert_eq!(
+            U8F8::from_grouped_str("-1", ',', '.').unwrap_err(),
+            err(ParseErrorKind::Overflow)
+        );
+    }
+
+    #[test]
+    fn encode_utf8() {
+        for c in [
+            'a',
+            '\u{a0}',
+            '\u{7ff}',
+            '\u{800}',
+            '\u{202f}',
+            '\u{ffff}',
+            '\u{1_0000}',
+            char::MAX,
+        ] {
+            let mut buf = [0; 4];
+            let expected = c.encode_utf8(&mut buf).as_bytes();
+            let (encoded, len) = from_str::encode_utf8(c);
+            assert_eq!(&encoded[..len], expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "group and decimal separators are the same")]
+    fn grouped_same_separators() {
+        let _ = I32F32::from_grouped_str("1,234", ',', ',');
+    }
+
+    #[test]
+    fn with_separators() {
+        let err = |kind| ParseFixedError { kind };
+        // comma as decimal separator
+        assert_eq!(
+            I32F32::from_str_with_separators("1,5", ',', 'e'),
+            Ok(I32F32::from_num(1.5))
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("-1,5", ',', 'e'),
+            Ok(I32F32::from_num(-1.5))
+        );
+        assert_eq!(
+            U16F16::from_str_with_separators("+0,25", ',', 'e'),
+            Ok(U16F16::from_num(0.25))
+        );
+        assert_eq!(
+            U16F16::from_str_with_separators(",5", ',', 'e'),
+            Ok(U16F16::from_num(0.5))
+        );
+        assert_eq!(
+            U16F16::from_str_with_separators("3,", ',', 'e'),
+            Ok(U16F16::from_num(3))
+        );
+        assert_eq!(
+            U16F16::from_str_with_separators("0012,500", ',', 'e'),
+            Ok(U16F16::from_num(12.5))
+        );
+        // exponents
+        assert_eq!(
+            I32F32::from_str_with_separators("1,25e2", ',', 'e'),
+            Ok(I32F32::from_num(125))
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("125e-3", ',', 'e'),
+            Ok(I32F32::from_num(0.125))
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("-12,5E+1", ',', 'E'),
+            Ok(I32F32::from_num(-125))
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1,5×3", ',', '×'),
+            Ok(I32F32::from_num(1500))
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1'5", '\'', 'e'),
+            Ok(I32F32::from_num(1.5))
+        );
+        // the same results as from_str when using the default separators
+        for s in ["1.75", "-0.00625e3", "12e-1", ".5", "7."] {
+            assert_eq!(
+                I16F16::from_str_with_separators(s, '.', 'e'),
+                I16F16::from_str(s),
+                "{s}"
+            );
+        }
+        // rounding
+        assert_eq!(
+            U8F0::from_str_with_separators("2,5", ',', 'e'),
+            Ok(U8F0::from_num(2))
+        );
+        assert_eq!(
+            U8F0::from_str_with_separators("3,5", ',', 'e'),
+            Ok(U8F0::from_num(4))
+        );
+        // saturating
+        assert_eq!(
+            I8F8::saturating_from_str_with_separators("1000,5", ',', 'e'),
+            Ok(I8F8::MAX)
+        );
+        assert_eq!(
+            I8F8::saturating_from_str_with_separators("-1e5", ',', 'e'),
+            Ok(I8F8::MIN)
+        );
+        assert_eq!(
+            U8F8::saturating_from_str_with_separators("-0,5", ',', 'e'),
+            Ok(U8F8::ZERO)
+        );
+        assert_eq!(
+            U8F8::saturating_from_str_with_separators("1,5", ',', 'e'),
+            Ok(U8F8::from_num(1.5))
+        );
+        assert_eq!(
+            U8F8::saturating_from_str_with_separators("1.5", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::InvalidDigit)
+        );
+        // errors
+        assert_eq!(
+            I32F32::from_str_with_separators("1.5", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::InvalidDigit)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1,5,6", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::TooManyPoints)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1_000", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::InvalidDigit)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1-2", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::MisplacedSign)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("-,", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::NoDigits)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("e5", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::NoDigits)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1E5", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::InvalidDigit)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1e", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::ExpNoDigits)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1e-", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::ExpNoDigits)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1e1,5", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::ExpInvalidDigit)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1e1e1", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::TooManyExp)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1e1-1", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::MisplacedSign)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1e99999999999", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::ExpOverflow)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1e1000", ',', 'e').unwrap_err(),
+            err(ParseErrorKind::Overflow)
+        );
+        assert_eq!(
+            I32F32::from_str_with_separators("1e-1000", ',', 'e'),
+            Ok(I32F32::ZERO)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "decimal and exponent separators are the same")]
+    fn with_separators_same() {
+        let _ = I32F32::from_str_with_separators("1,5", ',', ',');
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid separator")]
+    fn with_separators_invalid() {
+        let _ = I32F32::from_str_with_separators("1", '0', 'e');
+    }
+
     #[test]
     fn frac17() {
         for u in 0..(1 << 17) {
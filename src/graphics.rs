@@ -0,0 +1,296 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    types::extra::{If, True},
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+
+// The transfer functions are computed on u64 values with 62 fractional bits,
+// so that 1 is representable and intermediate products fit in u128.
+const FRAC_BITS: u32 = 62;
+const ONE: u64 = 1 << FRAC_BITS;
+
+// ln 2 with 64 fractional bits
+const LN_2: u128 = 0xB172_17F7_D1CF_79AC;
+
+// Returns −log₂ x with 64 fractional bits, for 0 < x ≤ 1.
+//
+// x is normalized to m × 2^−e with 1 ≤ m < 2, then the fractional bits of
+// log₂ m are found one at a time by repeated squaring.
+fn neg_log2(x: u64) -> u128 {
+    debug_assert!(0 < x && x <= ONE);
+    let e = x.leading_zeros() - (u64::BITS - 1 - FRAC_BITS);
+    let mut m = x << e;
+    let mut frac = 0u64;
+    for i in 1..=64 {
+        // m < 2, so m² < 4 fits in u64 with 62 fractional bits
+        m = ((u128::from(m) * u128::from(m)) >> FRAC_BITS) as u64;
+        if m >= 2 << FRAC_BITS {
+            m >>= 1;
+            frac |= 1 << (64 - i);
+        }
+    }
+    (u128::from(e) << 64) - u128::from(frac)
+}
+
+// Returns 2^−y with 62 fractional bits, for y ≥ 0 with 64 fractional bits.
+//
+// 2^−y = 2^(1 − f) × 2^−(i + 1) where i and f are the integer and fractional
+// parts of y, and 2^(1 − f) = e^((1 − f) ln 2) is summed as a Taylor series.
+fn exp2_neg(y: u128) -> u64 {
+    let shift = (y >> 64) + 1 + 2;
+    if shift >= 128 {
+        return 0;
+    }
+    let one_minus_frac = (1 << 64) - (y & u128::from(u64::MAX));
+    // t ≤ ln 2 < 1 with 64 fractional bits
+    let t = (one_minus_frac * LN_2) >> 64;
+    let mut sum: u128 = 1 << 64;
+    let mut term: u128 = 1 << 64;
+    let mut n = 1;
+    while term != 0 {
+        term = ((term * t) >> 64) / n;
+        sum += term;
+        n += 1;
+    }
+    // 1 < sum ≤ 2 with 64 fractional bits
+    (sum >> shift) as u64
+}
+
+// Returns x^(num/den) for 0 ≤ x ≤ 1.
+fn pow_ratio(x: u64, num: u128, den: u128) -> u64 {
+    if x == 0 {
+        return 0;
+    }
+    exp2_neg(neg_log2(x) * num / den)
+}
+
+fn srgb_to_linear(c: u64) -> u64 {
+    let c = u128::from(c);
+    let one = u128::from(ONE);
+    // c ≤ 0.04045
+    if c * 20000 <= 809 * one {
+        // c / 12.92
+        return (c * 25 / 323) as u64;
+    }
+    // ((c + 0.055) / 1.055)^2.4
+    let base = (c * 200 + 11 * one) / 211;
+    pow_ratio(base as u64, 12, 5)
+}
+
+fn linear_to_srgb(l: u64) -> u64 {
+    let one = u128::from(ONE);
+    // l ≤ 0.003_130_8
+    if u128::from(l) * 10_000_000 <= 31308 * one {
+        // 12.92 l
+        return (u128::from(l) * 323 / 25) as u64;
+    }
+    // 1.055 l^(1/2.4) − 0.055
+    let pow = u128::from(pow_ratio(l, 5, 12));
+    ((pow * 211).saturating_sub(11 * one) / 200) as u64
+}
+
+macro_rules! graphics {
+    ($Fixed:ident, $nbits:literal) => {
+        impl<const FRAC: i32> $Fixed<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= $nbits) }>: True,
+        {
+            // Converts self to 62 fractional bits, clamped to 0 ≤ x ≤ 1.
+            fn to_unit(self) -> u64 {
+                FixedU64::<62>::saturating_from_num(self).to_bits().min(ONE)
+            }
+
+            // Converts from 62 fractional bits, rounding to the nearest and
+            // saturating.
+            fn from_unit(unit: u64) -> Self {
+                let half = if FRAC < 62 { 1 << (61 - FRAC) } else { 0 };
+                Self::saturating_from_num(FixedU64::<62>::from_bits(unit + half))
+            }
+
+            /// Converts an sRGB-encoded color component to linear light.
+            ///
+            /// `self` is clamped to 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1 and
+            /// the sRGB transfer function is inverted:
+            /// <i>x</i>/12.92 for <i>x</i>&nbsp;≤&nbsp;0.040&nbsp;45,
+            /// otherwise
+            /// ((<i>x</i>&nbsp;+&nbsp;0.055)/1.055)<sup>2.4</sup>.
+            ///
+            /// The power is computed through a binary logarithm and
+            /// exponential with 62 fractional bits, with an error below
+            /// 2<sup>&minus;58</sup> before the result is rounded to the
+            /// nearest. So for numbers with up to 58 fractional bits, the
+            /// result is within one [`DELTA`] of the exact value, and it is
+            /// almost always correctly rounded. If 1 is not representable, the
+            /// result saturates to [`MAX`].
+            ///
+            /// This requires the `graphics` [optional feature].
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// #![feature(generic_const_exprs)]
+            /// # #![allow(incomplete_features)]
+            ///
+            #[doc = concat!("use fixed::", stringify!($Fixed), ";")]
+            #[doc = concat!("type Fix = ", stringify!($Fixed), "<6>;")]
+            /// assert_eq!(Fix::ZERO.srgb_to_linear(), 0);
+            /// assert_eq!(Fix::ONE.srgb_to_linear(), 1);
+            /// // 0.5 in sRGB is about 0.214 in linear light
+            /// assert_eq!(Fix::from_num(0.5).srgb_to_linear(), Fix::from_num(0.21875));
+            /// ```
+            ///
+            /// [`DELTA`]: Self::DELTA
+            /// [`MAX`]: Self::MAX
+            /// [optional feature]: crate#optional-features
+            #[inline]
+            #[must_use]
+            pub fn srgb_to_linear(self) -> Self {
+                Self::from_unit(srgb_to_linear(self.to_unit()))
+            }
+
+            /// Converts a linear-light color component to sRGB encoding.
+            ///
+            /// `self` is clamped to 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1 and
+            /// the sRGB transfer function is applied:
+            /// 12.92<i>x</i> for <i>x</i>&nbsp;≤&nbsp;0.003&nbsp;130&nbsp;8,
+            /// otherwise
+            /// 1.055<i>x</i><sup>1/2.4</sup>&nbsp;&minus;&nbsp;0.055.
+            ///
+            /// The power is computed through a binary logarithm and
+            /// exponential with 62 fractional bits, with an error below
+            /// 2<sup>&minus;58</sup> before the result is rounded to the
+            /// nearest. So for numbers with up to 58 fractional bits, the
+            /// result is within one [`DELTA`] of the exact value, and it is
+            /// almost always correctly rounded. If 1 is not representable, the
+            /// result saturates to [`MAX`].
+            ///
+            /// This requires the `graphics` [optional feature].
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// #![feature(generic_const_exprs)]
+            /// # #![allow(incomplete_features)]
+            ///
+            #[doc = concat!("use fixed::", stringify!($Fixed), ";")]
+            #[doc = concat!("type Fix = ", stringify!($Fixed), "<6>;")]
+            /// assert_eq!(Fix::ZERO.linear_to_srgb(), 0);
+            /// assert_eq!(Fix::ONE.linear_to_srgb(), 1);
+            /// // 0.5 in linear light is about 0.735 in sRGB
+            /// assert_eq!(Fix::from_num(0.5).linear_to_srgb(), Fix::from_num(0.734375));
+            /// ```
+            ///
+            /// [`DELTA`]: Self::DELTA
+            /// [`MAX`]: Self::MAX
+            /// [optional feature]: crate#optional-features
+            #[inline]
+            #[must_use]
+            pub fn linear_to_srgb(self) -> Self {
+                Self::from_unit(linear_to_srgb(self.to_unit()))
+            }
+        }
+    };
+}
+
+graphics! { FixedI8, 8 }
+graphics! { FixedI16, 16 }
+graphics! { FixedI32, 32 }
+graphics! { FixedI64, 64 }
+graphics! { FixedI128, 128 }
+graphics! { FixedU8, 8 }
+graphics! { FixedU16, 16 }
+graphics! { FixedU32, 32 }
+graphics! { FixedU64, 64 }
+graphics! { FixedU128, 128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I16F16, I32F32, I8F8, U0F16, U0F64, U1F15};
+
+    fn ref_to_linear(c: f64) -> f64 {
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    fn ref_to_srgb(l: f64) -> f64 {
+        if l <= 0.003_130_8 {
+            l * 12.92
+        } else {
+            1.055 * l.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    #[test]
+    fn reference_values() {
+        let close = |a: I32F32, b: f64| (a.to_num::<f64>() - b).abs() < 1e-9;
+        let half = I32F32::from_num(0.5);
+        assert!(close(half.srgb_to_linear(), 0.214_041_140_482_232_5));
+        assert!(close(half.linear_to_srgb(), 0.735_356_983_052_449_1));
+        let grey = I32F32::from_num(0.18);
+        assert!(close(grey.linear_to_srgb(), 0.461_356_129_500_441_5));
+        let c = I32F32::from_num(128) / 255;
+        assert!(close(c.srgb_to_linear(), 0.215_860_500_965_604));
+        // linear segments
+        let c = I32F32::from_num(0.04);
+        assert!(close(c.srgb_to_linear(), 0.04 / 12.92));
+        let l = I32F32::from_num(0.003);
+        assert!(close(l.linear_to_srgb(), 0.003 * 12.92));
+    }
+
+    #[test]
+    fn exhaustive_u1f15() {
+        for bits in 0..=1u16 << 15 {
+            let x = U1F15::from_bits(bits);
+            let xf = x.to_num::<f64>();
+            for (got, exact) in [
+                (x.srgb_to_linear(), ref_to_linear(xf)),
+                (x.linear_to_srgb(), ref_to_srgb(xf)),
+            ] {
+                let scaled = exact * 32768.0;
+                // skip values too close to a tie for f64 to decide
+                if (scaled - scaled.floor() - 0.5).abs() > 1e-6 {
+                    assert_eq!(got.to_bits(), scaled.round() as u16, "x = {x}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for k in 0..=255 {
+            let c = I32F32::from_num(k) / 255;
+            let back = c.srgb_to_linear().linear_to_srgb();
+            // the rounding error of the linear value is scaled by up to 12.92
+            assert!(back.dist(c) <= I32F32::DELTA * 8, "k = {k}");
+        }
+    }
+
+    #[test]
+    fn clamping() {
+        assert_eq!(I16F16::from_num(-0.5).srgb_to_linear(), 0);
+        assert_eq!(I16F16::from_num(-0.5).linear_to_srgb(), 0);
+        assert_eq!(I16F16::from_num(3).srgb_to_linear(), 1);
+        assert_eq!(I8F8::MAX.linear_to_srgb(), 1);
+        // the result rounds to 1, which is not representable, so it saturates
+        assert_eq!(U0F16::MAX.linear_to_srgb(), U0F16::MAX);
+        assert_eq!(U0F64::ZERO.srgb_to_linear(), U0F64::ZERO);
+    }
+}
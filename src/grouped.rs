@@ -0,0 +1,108 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+/// Displays a fixed-point number with thousands separators.
+///
+/// The [`Display`] implementation of this wrapper formats the fixed-point
+/// number like the [`Display`] implementation of the fixed-point number itself,
+/// but inserts a comma between every group of three digits in the integer part.
+/// The fractional part is not grouped.
+///
+/// All the formatting options such as precision, width, fill, alignment and
+/// sign are supported. The thousands separators count towards the width.
+///
+/// The underlying value can be retrieved through the `.0` index.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::{types::I32F32, Grouped};
+/// let val = I32F32::from_num(1_234_567.5);
+/// assert_eq!(format!("{}", Grouped(val)), "1,234,567.5");
+/// assert_eq!(format!("{:.2}", Grouped(-val)), "-1,234,567.50");
+/// assert_eq!(format!("{:>14.1}", Grouped(val)), "   1,234,567.5");
+/// assert_eq!(format!("{}", Grouped(I32F32::from_num(999))), "999");
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Grouped<F>(pub F);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::{I0F32, I16F16, I64F64, U128F0, U8F8},
+        Grouped,
+    };
+    use std::format;
+
+    #[test]
+    fn magnitudes() {
+        assert_eq!(format!("{}", Grouped(I64F64::ZERO)), "0");
+        assert_eq!(format!("{}", Grouped(I64F64::from_num(12))), "12");
+        assert_eq!(format!("{}", Grouped(I64F64::from_num(123))), "123");
+        assert_eq!(format!("{}", Grouped(I64F64::from_num(1234))), "1,234");
+        assert_eq!(format!("{}", Grouped(I64F64::from_num(12345))), "12,345");
+        assert_eq!(format!("{}", Grouped(I64F64::from_num(123_456))), "123,456");
+        assert_eq!(
+            format!("{}", Grouped(I64F64::from_num(1_234_567.25))),
+            "1,234,567.25"
+        );
+        assert_eq!(
+            format!("{}", Grouped(I64F64::MAX.int())),
+            "9,223,372,036,854,775,807"
+        );
+        assert_eq!(
+            format!("{}", Grouped(U128F0::MAX)),
+            "340,282,366,920,938,463,463,374,607,431,768,211,455"
+        );
+        assert_eq!(format!("{}", Grouped(U8F8::MAX)), "255.996");
+        assert_eq!(format!("{}", Grouped(I0F32::from_num(-0.25))), "-0.25");
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(format!("{}", Grouped(I16F16::from_num(-1))), "-1");
+        assert_eq!(format!("{}", Grouped(I16F16::from_num(-1000))), "-1,000");
+        assert_eq!(format!("{}", Grouped(I16F16::MIN)), "-32,768");
+        assert_eq!(
+            format!("{}", Grouped(I64F64::from_num(-1_234_567.5))),
+            "-1,234,567.5"
+        );
+        assert_eq!(
+            format!("{}", Grouped(I64F64::MIN)),
+            "-9,223,372,036,854,775,808"
+        );
+    }
+
+    #[test]
+    fn options() {
+        let val = I16F16::from_num(12345.5);
+        assert_eq!(format!("{:.0}", Grouped(val)), "12,346");
+        assert_eq!(format!("{:.3}", Grouped(val)), "12,345.500");
+        assert_eq!(format!("{:+}", Grouped(val)), "+12,345.5");
+        assert_eq!(format!("{:10}", Grouped(val)), "  12,345.5");
+        assert_eq!(format!("{:<10}|", Grouped(-val)), "-12,345.5 |");
+        assert_eq!(format!("{:*^12}", Grouped(val)), "**12,345.5**");
+        // rounding can add a digit, and hence a separator
+        assert_eq!(format!("{:.0}", Grouped(I16F16::from_num(999.5))), "1,000");
+        // the plain Display is not affected
+        assert_eq!(format!("{val}"), "12345.5");
+    }
+}
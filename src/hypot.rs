@@ -99,7 +99,12 @@ pub const fn u128(a: u128, b: u128) -> (u128, bool) {
     let aa = int256::wide_mul_u128(a, a);
     let bb = int256::wide_mul_u128(b, b);
     let (sum, overflow) = int256::overflowing_add_u256(aa, bb);
+    sqrt_u256(sum, overflow)
+}
 
+// Returns the square root of sum + (overflow << 256), rounded down and wrapped,
+// and whether it overflows. Requires sum.hi != 0 if overflow is false.
+const fn sqrt_u256(sum: U256, overflow: bool) -> (u128, bool) {
     let mut x = sum;
     let mut y;
     let mut bit;
@@ -158,10 +163,80 @@ pub const fn u128(a: u128, b: u128) -> (u128, bool) {
     (y.lo, overflow)
 }
 
+// The squares are accumulated in the double-width type. If their sum does not
+// fit, its square root does not fit in the single-width type either.
+pub mod norm {
+    use crate::int256::{self, U256};
+    use crate::int_helper;
+
+    macro_rules! impl_norm {
+        ($Single:ident, $Double:ident) => {
+            pub fn $Single<I: Iterator<Item = $Single>>(vals: I) -> Option<$Single> {
+                let mut sum: $Double = 0;
+                for a in vals {
+                    sum = sum.checked_add((a as $Double) * (a as $Double))?;
+                }
+                Some(int_helper::$Double::isqrt(sum) as $Single)
+            }
+        };
+    }
+
+    impl_norm! { u8, u16 }
+    impl_norm! { u16, u32 }
+    impl_norm! { u32, u64 }
+    impl_norm! { u64, u128 }
+
+    pub fn u128<I: Iterator<Item = u128>>(vals: I) -> Option<u128> {
+        let mut sum = U256 { lo: 0, hi: 0 };
+        for a in vals {
+            let (next, overflow) = int256::overflowing_add_u256(sum, int256::wide_mul_u128(a, a));
+            if overflow {
+                return None;
+            }
+            sum = next;
+        }
+        if sum.hi == 0 {
+            return Some(int_helper::u128::isqrt(sum.lo));
+        }
+        Some(super::sqrt_u256(sum, false).0)
+    }
+}
+
+// Alpha max plus beta min approximation with alpha = 123/128 and beta = 51/128,
+// which are close to the coefficients that minimize the largest error.
+//
+// The result is (123 × max + 51 × min) / 128 rounded down, computed without a
+// wider type by splitting max = 128 × max_q + max_r and likewise for min.
+pub mod fast {
+    macro_rules! impl_fast_hypot {
+        ($Single:ident) => {
+            pub const fn $Single(a: $Single, b: $Single) -> ($Single, bool) {
+                let (max, min) = if a >= b { (a, b) } else { (b, a) };
+                let (max_q, max_r) = (max >> 7, max & 0x7F);
+                let (min_q, min_r) = (min >> 7, min & 0x7F);
+                // 123 × max_q and 51 × min_q cannot overflow as max_q and min_q
+                // are < 2^(BITS - 7)
+                let (sum, overflow1) = (123 * max_q).overflowing_add(51 * min_q);
+                // the remainder term is < 174, but is computed as u32 so that
+                // the intermediate products do not overflow for u8
+                let rem = (123 * max_r as u32 + 51 * min_r as u32) >> 7;
+                let (sum, overflow2) = sum.overflowing_add(rem as $Single);
+                (sum, overflow1 || overflow2)
+            }
+        };
+    }
+
+    impl_fast_hypot! { u8 }
+    impl_fast_hypot! { u16 }
+    impl_fast_hypot! { u32 }
+    impl_fast_hypot! { u64 }
+    impl_fast_hypot! { u128 }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::hypot;
-    use crate::types::{U1F127, U1F15, U1F31, U1F63, U1F7};
+    use crate::types::{I16F16, U1F127, U1F15, U1F31, U1F63, U1F7, U8F8};
 
     #[test]
     fn check_max() {
@@ -223,6 +298,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn norm() {
+        // 3² + 4² + 12² = 13²
+        let v = [3, 4, 12].map(I16F16::from_num);
+        assert_eq!(I16F16::norm(&v), 13);
+        let v = [3, -4, -12].map(I16F16::from_num);
+        assert_eq!(I16F16::checked_norm(&v), Some(I16F16::from_num(13)));
+        let v = [3, 4, 12].map(U8F8::from_num);
+        assert_eq!(U8F8::norm(&v), 13);
+        assert_eq!(U8F8::norm(&[]), 0);
+        // same as hypot for two elements
+        let (a, b) = (I16F16::from_num(1.5), I16F16::from_num(-2.25));
+        assert_eq!(I16F16::norm(&[a, b]), a.hypot(b));
+
+        // a longer vector, with 1² + 2² + … + 24² = 4900 = 70²
+        let v: [I16F16; 24] = core::array::from_fn(|i| I16F16::from_num(i + 1));
+        assert_eq!(I16F16::norm(&v), 70);
+        // the squares are summed exactly, so the error is < DELTA, while
+        // chaining hypot rounds down at each step
+        let v: [I16F16; 20] = core::array::from_fn(|i| I16F16::from_num(i) / 7);
+        let exact = v
+            .iter()
+            .map(|x| x.to_num::<f64>().powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let norm = I16F16::norm(&v);
+        assert!(
+            norm <= exact && exact < norm + I16F16::DELTA,
+            "{norm} {exact}"
+        );
+        let chained = v.iter().fold(I16F16::ZERO, |acc, &x| acc.hypot(x));
+        assert!(chained <= norm);
+
+        // overflow
+        let v = [I16F16::MAX, I16F16::ONE];
+        assert_eq!(I16F16::checked_norm(&v), None);
+        assert_eq!(I16F16::saturating_norm(&v), I16F16::MAX);
+        assert_eq!(I16F16::checked_norm(&[I16F16::MIN]), None);
+        assert_eq!(U8F8::checked_norm(&[U8F8::MAX]), Some(U8F8::MAX));
+        assert_eq!(U8F8::checked_norm(&[U8F8::MAX; 2]), None);
+        // small values only use the low half of the 256-bit accumulator
+        let v = [U1F127::DELTA * 3, U1F127::DELTA * 4];
+        assert_eq!(U1F127::norm(&v), U1F127::DELTA * 5);
+        // 16 squares of 2^125 sum to 2^254, which fits in the accumulator
+        assert_eq!(
+            U1F127::checked_norm(&[U1F127::ONE / 4; 16]),
+            Some(U1F127::ONE)
+        );
+        assert_eq!(U1F127::checked_norm(&[U1F127::ONE; 4]), None);
+        let v = [U1F127::MAX, U1F127::ZERO];
+        assert_eq!(U1F127::checked_norm(&v), Some(U1F127::MAX));
+    }
+
+    #[test]
+    fn fast_error_bound() {
+        // Before rounding down, the approximation is within
+        // [0.9609375, 1.0402660] × exact.
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let exact = f64::from(a).hypot(f64::from(b));
+                let approx = match hypot::fast::u8(a, b) {
+                    (approx, false) => f64::from(approx),
+                    (_, true) => {
+                        assert!(exact * 1.040_266 >= 256.0, "{a} {b}");
+                        continue;
+                    }
+                };
+                assert!(approx <= exact * 1.040_266, "{a} {b}");
+                assert!(approx > exact * 0.960_937_4 - 1.0, "{a} {b}");
+            }
+        }
+
+        for (a, b) in [
+            (u128::MAX / 2, 0),
+            (u128::MAX / 3, u128::MAX / 4),
+            (1 << 100, 1 << 99),
+        ] {
+            let exact = (a as f64).hypot(b as f64);
+            let (approx, overflow) = hypot::fast::u128(a, b);
+            assert!(!overflow);
+            let approx = approx as f64;
+            assert!(approx <= exact * 1.040_266);
+            assert!(approx >= exact * 0.960_937_4);
+        }
+        // 123/128 × MAX fits, but adding 51/128 × MAX/8 does not
+        assert!(!hypot::fast::u128(u128::MAX, 0).1);
+        assert!(hypot::fast::u128(u128::MAX, u128::MAX / 8).1);
+    }
+
+    #[test]
+    fn fast_hypot_fixed() {
+        let delta = I16F16::DELTA;
+        for a in -20..=20 {
+            for b in -20..=20 {
+                let a = I16F16::from_num(a) / 4;
+                let b = I16F16::from_num(b) / 4;
+                let exact = a.hypot(b);
+                let approx = a.fast_hypot(b);
+                assert!(approx <= exact * I16F16::from_num(1.0403) + delta);
+                assert!(approx >= exact * I16F16::from_num(0.9609) - delta);
+                assert_eq!(approx, b.fast_hypot(a));
+                assert_eq!(approx, (-a).fast_hypot(b));
+            }
+        }
+        assert_eq!(U8F8::from_num(3).fast_hypot(U8F8::from_num(4)), 5.039_062_5);
+        assert_eq!(U8F8::from_num(200).fast_hypot(U8F8::ZERO), 192.1875);
+    }
+
     #[test]
     fn check_sqrt_2() {
         assert_eq!(hypot::u8(1 << 7, 1 << 7), (U1F7::SQRT_2.to_bits(), false));
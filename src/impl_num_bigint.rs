@@ -0,0 +1,150 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    try_from::{TryFromErrorKind, TryFromFixedError},
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+use num_bigint::{BigInt, BigUint};
+
+const OVERFLOW: TryFromFixedError = TryFromFixedError {
+    kind: TryFromErrorKind::Overflow,
+};
+
+macro_rules! impl_big {
+    ($Fixed:ident($Inner:ident), $Big:ident) => {
+        impl<const FRAC: i32> TryFrom<&$Big> for $Fixed<FRAC> {
+            type Error = TryFromFixedError;
+
+            /// Converts a big integer to a fixed-point number.
+            ///
+            /// Like [`from_num`][Self::from_num] for primitive integers, any
+            /// low bits that do not fit when `FRAC` is negative are discarded,
+            /// which rounds down.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the value does not fit.
+            fn try_from(src: &$Big) -> Result<$Fixed<FRAC>, TryFromFixedError> {
+                let shifted = if FRAC < 0 {
+                    src >> FRAC.unsigned_abs()
+                } else if FRAC < $Inner::BITS as i32 {
+                    src << FRAC
+                } else if src.bits() == 0 {
+                    // zero is the only value that fits when all bits are fractional
+                    return Ok(Self::ZERO);
+                } else {
+                    // avoid allocating a huge shifted value that can never fit
+                    return Err(OVERFLOW);
+                };
+                match $Inner::try_from(shifted) {
+                    Ok(bits) => Ok(Self::from_bits(bits)),
+                    Err(_) => Err(OVERFLOW),
+                }
+            }
+        }
+
+        impl<const FRAC: i32> TryFrom<$Big> for $Fixed<FRAC> {
+            type Error = TryFromFixedError;
+
+            /// Converts a big integer to a fixed-point number.
+            ///
+            /// This is the same as converting from a reference.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the value does not fit.
+            #[inline]
+            fn try_from(src: $Big) -> Result<$Fixed<FRAC>, TryFromFixedError> {
+                Self::try_from(&src)
+            }
+        }
+    };
+    ($Fixed:ident($Inner:ident)) => {
+        impl_big! { $Fixed($Inner), BigInt }
+        impl_big! { $Fixed($Inner), BigUint }
+    };
+}
+
+impl_big! { FixedI8(i8) }
+impl_big! { FixedI16(i16) }
+impl_big! { FixedI32(i32) }
+impl_big! { FixedI64(i64) }
+impl_big! { FixedI128(i128) }
+impl_big! { FixedU8(u8) }
+impl_big! { FixedU16(u16) }
+impl_big! { FixedU32(u32) }
+impl_big! { FixedU64(u64) }
+impl_big! { FixedU128(u128) }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F32, I16F16, I64F64, I8F8, U0F32, U16F16, U64F64};
+    use crate::{FixedI128, FixedI8, FixedU128};
+    use num_bigint::{BigInt, BigUint};
+
+    #[test]
+    fn fits() {
+        let big = BigInt::from(-1234);
+        assert_eq!(I16F16::try_from(&big), Ok(I16F16::from_num(-1234)));
+        assert!(U16F16::try_from(&big).is_err());
+        let big = BigUint::from(1234u32);
+        assert_eq!(I16F16::try_from(&big), Ok(I16F16::from_num(1234)));
+        assert_eq!(U16F16::try_from(big), Ok(U16F16::from_num(1234)));
+
+        let big = BigInt::from(i64::MIN);
+        assert_eq!(I64F64::try_from(&big), Ok(I64F64::MIN));
+        let big = BigUint::from(u64::MAX);
+        assert_eq!(U64F64::try_from(&big), Ok(U64F64::MAX.int()));
+
+        // zero fits even when there are no integer bits
+        assert_eq!(I0F32::try_from(BigInt::from(0)), Ok(I0F32::ZERO));
+        assert_eq!(U0F32::try_from(BigUint::from(0u32)), Ok(U0F32::ZERO));
+
+        // negative FRAC allows values wider than 128 bits; low bits round down
+        let big = (BigInt::from(i128::MAX) << 10) + 1000;
+        assert_eq!(FixedI128::<-10>::try_from(&big), Ok(FixedI128::<-10>::MAX));
+        assert_eq!(FixedI128::<-10>::try_from(-&big), Ok(FixedI128::<-10>::MIN));
+        let big = BigUint::from(u128::MAX) << 3;
+        assert_eq!(FixedU128::<-3>::try_from(&big), Ok(FixedU128::<-3>::MAX));
+        assert_eq!(
+            FixedI8::<-2>::try_from(BigInt::from(-1)),
+            Ok(FixedI8::<-2>::from_num(-4))
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(I8F8::try_from(BigInt::from(128)).is_err());
+        assert!(I8F8::try_from(BigInt::from(-129)).is_err());
+        assert_eq!(I8F8::try_from(BigInt::from(-128)), Ok(I8F8::MIN));
+        assert!(U16F16::try_from(BigUint::from(65536u32)).is_err());
+
+        let big = BigInt::from(i64::MAX) + 1;
+        assert!(I64F64::try_from(&big).is_err());
+        let big = BigUint::from(u128::MAX) << 64;
+        assert!(U64F64::try_from(&big).is_err());
+        assert!(FixedU128::<-3>::try_from((BigUint::from(u128::MAX) << 3) + 8u32).is_err());
+
+        assert!(I0F32::try_from(BigInt::from(-1)).is_err());
+        assert!(U0F32::try_from(BigUint::from(1u32)).is_err());
+        assert!(FixedI8::<1000>::try_from(BigInt::from(1)).is_err());
+        assert_eq!(
+            FixedI8::<1000>::try_from(BigInt::from(0)),
+            Ok(FixedI8::<1000>::ZERO)
+        );
+    }
+}
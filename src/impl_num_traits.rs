@@ -14,6 +14,7 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::consts;
+use crate::powi;
 use crate::types::extra::{If, True};
 use crate::{
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
@@ -21,9 +22,10 @@ use crate::{
 };
 use core::fmt::{Display, Formatter, Result as FmtResult};
 use num_traits::bounds::Bounded;
-use num_traits::cast::{FromPrimitive, ToPrimitive};
+use num_traits::cast::{FromPrimitive, NumCast, ToPrimitive};
 use num_traits::float::FloatConst;
 use num_traits::identities::{ConstOne, ConstZero, One, Zero};
+use num_traits::int::PrimInt;
 use num_traits::ops::bytes::{FromBytes, ToBytes};
 use num_traits::ops::checked::{
     CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl, CheckedShr, CheckedSub,
@@ -31,7 +33,7 @@ use num_traits::ops::checked::{
 use num_traits::ops::inv::Inv;
 use num_traits::ops::mul_add::{MulAdd, MulAddAssign};
 use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
-use num_traits::ops::saturating::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use num_traits::ops::saturating::{Saturating, SaturatingAdd, SaturatingMul, SaturatingSub};
 use num_traits::ops::wrapping::{
     WrappingAdd, WrappingMul, WrappingNeg, WrappingShl, WrappingShr, WrappingSub,
 };
@@ -73,7 +75,7 @@ impl Error for RadixParseFixedError {
 }
 
 macro_rules! impl_traits {
-    ($Fixed:ident, $nbits:expr, $one_max_frac:expr, $Signedness:tt) => {
+    ($Fixed:ident($Inner:ident, $nbits:expr, $one_max_frac:expr), $Signedness:tt) => {
         impl<const FRAC: i32> Bounded for $Fixed<FRAC> {
             #[inline]
             fn min_value() -> Self {
@@ -139,6 +141,96 @@ macro_rules! impl_traits {
             }
         }
 
+        impl<const FRAC: i32> PrimInt for $Fixed<FRAC>
+        where
+            If<{ (0 <= FRAC) & (FRAC <= $nbits) }>: True,
+            If<{ FRAC <= $one_max_frac }>: True,
+        {
+            #[inline]
+            fn count_ones(self) -> u32 {
+                self.count_ones()
+            }
+            #[inline]
+            fn count_zeros(self) -> u32 {
+                self.count_zeros()
+            }
+            #[inline]
+            fn leading_ones(self) -> u32 {
+                self.leading_ones()
+            }
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                self.leading_zeros()
+            }
+            #[inline]
+            fn trailing_ones(self) -> u32 {
+                self.trailing_ones()
+            }
+            #[inline]
+            fn trailing_zeros(self) -> u32 {
+                self.trailing_zeros()
+            }
+            #[inline]
+            fn rotate_left(self, n: u32) -> Self {
+                self.rotate_left(n)
+            }
+            #[inline]
+            fn rotate_right(self, n: u32) -> Self {
+                self.rotate_right(n)
+            }
+            #[inline]
+            fn signed_shl(self, n: u32) -> Self {
+                Self::from_bits(PrimInt::signed_shl(self.to_bits(), n))
+            }
+            #[inline]
+            fn signed_shr(self, n: u32) -> Self {
+                Self::from_bits(PrimInt::signed_shr(self.to_bits(), n))
+            }
+            #[inline]
+            fn unsigned_shl(self, n: u32) -> Self {
+                Self::from_bits(PrimInt::unsigned_shl(self.to_bits(), n))
+            }
+            #[inline]
+            fn unsigned_shr(self, n: u32) -> Self {
+                Self::from_bits(PrimInt::unsigned_shr(self.to_bits(), n))
+            }
+            #[inline]
+            fn swap_bytes(self) -> Self {
+                self.swap_bytes()
+            }
+            #[inline]
+            fn reverse_bits(self) -> Self {
+                self.reverse_bits()
+            }
+            #[inline]
+            fn from_be(x: Self) -> Self {
+                Self::from_be(x)
+            }
+            #[inline]
+            fn from_le(x: Self) -> Self {
+                Self::from_le(x)
+            }
+            #[inline]
+            fn to_be(self) -> Self {
+                self.to_be()
+            }
+            #[inline]
+            fn to_le(self) -> Self {
+                self.to_le()
+            }
+            // This is the fixed-point power like powi, not a power of the bits.
+            #[inline]
+            #[track_caller]
+            fn pow(self, exp: u32) -> Self {
+                let (bits, overflow) = match powi::$Inner(self.to_bits(), FRAC as u32, exp.into()) {
+                    Some(s) => s,
+                    None => unreachable!(),
+                };
+                debug_assert!(!overflow, "overflow");
+                Self::from_bits(bits)
+            }
+        }
+
         impl<const FRAC: i32> Inv for $Fixed<FRAC>
         where
             If<{ (0 <= FRAC) & (FRAC <= $nbits) }>: True,
@@ -274,6 +366,17 @@ macro_rules! impl_traits {
             }
         }
 
+        impl<const FRAC: i32> Saturating for $Fixed<FRAC> {
+            #[inline]
+            fn saturating_add(self, v: Self) -> Self {
+                self.saturating_add(v)
+            }
+            #[inline]
+            fn saturating_sub(self, v: Self) -> Self {
+                self.saturating_sub(v)
+            }
+        }
+
         impl<const FRAC: i32> WrappingAdd for $Fixed<FRAC> {
             #[inline]
             fn wrapping_add(&self, v: &Self) -> Self {
@@ -549,6 +652,26 @@ macro_rules! impl_traits {
             }
         }
 
+        impl<const FRAC: i32> NumCast for $Fixed<FRAC> {
+            #[inline]
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                // Integers are converted exactly, even if they are too large
+                // for f64 to represent exactly; other values go through f64,
+                // rounding to the nearest.
+                let float = n.to_f64();
+                if let Some(i) = n.to_i128() {
+                    if float.map_or(true, |f| f == i as f64) {
+                        return Self::checked_from_num(i);
+                    }
+                } else if let Some(u) = n.to_u128() {
+                    if float.map_or(true, |f| f == u as f64) {
+                        return Self::checked_from_num(u);
+                    }
+                }
+                Self::checked_from_num(float?)
+            }
+        }
+
         impl<const FRAC: i32> ToBytes for $Fixed<FRAC> {
             type Bytes = [u8; $nbits / 8];
 
@@ -583,13 +706,354 @@ macro_rules! impl_traits {
     };
 }
 
-impl_traits! { FixedI8, 8, 6, Signed }
-impl_traits! { FixedI16, 16, 14, Signed }
-impl_traits! { FixedI32, 32, 30, Signed }
-impl_traits! { FixedI64, 64, 62, Signed }
-impl_traits! { FixedI128, 128, 126, Signed }
-impl_traits! { FixedU8, 8, 7, Unsigned }
-impl_traits! { FixedU16, 16, 15, Unsigned }
-impl_traits! { FixedU32, 32, 31, Unsigned }
-impl_traits! { FixedU64, 64, 63, Unsigned }
-impl_traits! { FixedU128, 128, 127, Unsigned }
+impl_traits! { FixedI8(i8, 8, 6), Signed }
+impl_traits! { FixedI16(i16, 16, 14), Signed }
+impl_traits! { FixedI32(i32, 32, 30), Signed }
+impl_traits! { FixedI64(i64, 64, 62), Signed }
+impl_traits! { FixedI128(i128, 128, 126), Signed }
+impl_traits! { FixedU8(u8, 8, 7), Unsigned }
+impl_traits! { FixedU16(u16, 16, 15), Unsigned }
+impl_traits! { FixedU32(u32, 32, 31), Unsigned }
+impl_traits! { FixedU64(u64, 64, 63), Unsigned }
+impl_traits! { FixedU128(u128, 128, 127), Unsigned }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I16F16, I64F64, I8F8, U0F8, U8F8};
+    use num_traits::bounds::Bounded;
+    use num_traits::cast::{NumCast, ToPrimitive};
+    use num_traits::identities::{One, Zero};
+    use num_traits::int::PrimInt;
+    use num_traits::ops::checked::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub,
+    };
+    use num_traits::ops::saturating::{SaturatingAdd, SaturatingMul, SaturatingSub};
+    use num_traits::ops::wrapping::{
+        WrappingAdd, WrappingMul, WrappingNeg, WrappingShl, WrappingShr, WrappingSub,
+    };
+    use num_traits::sign::{Signed, Unsigned};
+    use num_traits::Num;
+
+    fn cast<T: ToPrimitive, U: NumCast>(n: T) -> Option<U> {
+        U::from(n)
+    }
+
+    #[test]
+    fn num_cast() {
+        assert_eq!(<I16F16 as NumCast>::from(2.5), Some(I16F16::from_num(2.5)));
+        assert_eq!(<I16F16 as NumCast>::from(-3i8), Some(I16F16::from_num(-3)));
+        assert_eq!(<U8F8 as NumCast>::from(-1i32), None);
+        assert_eq!(<U8F8 as NumCast>::from(256u32), None);
+        assert_eq!(<U8F8 as NumCast>::from(f64::NAN), None);
+        // rounds to the nearest like from_num
+        assert_eq!(
+            <U0F8 as NumCast>::from(0.25 + 1.0 / 1024.0),
+            Some(U0F8::from_num(0.25))
+        );
+
+        // integers too large for f64 are still exact
+        let big = (1i64 << 60) + 1;
+        assert_eq!(<I64F64 as NumCast>::from(big), Some(I64F64::from_num(big)));
+        let huge = u128::MAX >> 64;
+        let fix = <crate::types::U128F0 as NumCast>::from(huge).unwrap();
+        assert_eq!(fix.to_bits(), huge);
+
+        // fixed to fixed through the generic interface
+        let half = I16F16::from_num(-0.5);
+        assert_eq!(cast::<I16F16, I64F64>(half), Some(I64F64::from_num(-0.5)));
+        assert_eq!(cast::<I16F16, U8F8>(half), None);
+        assert_eq!(cast::<I16F16, f64>(half), Some(-0.5));
+        assert_eq!(cast::<U8F8, i32>(U8F8::from_num(7.75)), Some(7));
+    }
+
+    // A generic algorithm written only against num-traits.
+    fn clamped_sum<T: Num + Bounded + PartialOrd + Copy>(vals: &[T]) -> T {
+        let mut acc = T::zero();
+        for &v in vals {
+            acc = if v > T::zero() && acc > T::max_value() - v {
+                T::max_value()
+            } else if v < T::zero() && acc < T::min_value() - v {
+                T::min_value()
+            } else {
+                acc + v
+            };
+        }
+        acc
+    }
+
+    #[test]
+    fn num_bounded() {
+        let vals = [
+            I8F8::from_num(100),
+            I8F8::from_num(50),
+            I8F8::from_num(-3.5),
+        ];
+        assert_eq!(clamped_sum(&vals), I8F8::MAX - I8F8::from_num(3.5));
+        let vals = [I8F8::from_num(1.25), I8F8::from_num(-0.5)];
+        assert_eq!(clamped_sum(&vals), 0.75);
+        assert_eq!(clamped_sum::<U8F8>(&[]), U8F8::ZERO);
+
+        assert_eq!(<I8F8 as Bounded>::min_value(), I8F8::MIN);
+        assert_eq!(<U8F8 as Bounded>::max_value(), U8F8::MAX);
+        assert!(<I8F8 as Zero>::zero().is_zero());
+        assert!(<I8F8 as One>::one().is_one());
+        assert_eq!(<U0F8 as Zero>::zero(), U0F8::ZERO);
+        assert_eq!(
+            <I8F8 as Num>::from_str_radix("-1.8", 16),
+            Ok(I8F8::from_num(-1.5))
+        );
+        assert_eq!(
+            <I8F8 as Num>::from_str_radix("11.1", 2),
+            Ok(I8F8::from_num(3.5))
+        );
+        assert!(<I8F8 as Num>::from_str_radix("1", 3).is_err());
+    }
+
+    fn generic_abs<T: Signed>(vals: &[T]) -> T {
+        let mut acc = T::zero();
+        for v in vals {
+            acc = acc + v.abs();
+        }
+        acc
+    }
+
+    fn generic_distance<T: Num + PartialOrd + Copy>(a: T, b: T) -> T {
+        if a < b {
+            b - a
+        } else {
+            a - b
+        }
+    }
+
+    fn unsigned_distance<T: Unsigned + PartialOrd + Copy>(a: T, b: T) -> T {
+        generic_distance(a, b)
+    }
+
+    #[test]
+    fn signed_unsigned() {
+        let vals = [
+            I8F8::from_num(-1.5),
+            I8F8::from_num(2.25),
+            I8F8::from_num(-0.25),
+        ];
+        assert_eq!(generic_abs(&vals), 4);
+        assert_eq!(generic_abs(&[-3.5f64, 1.0]), 4.5);
+
+        let neg = I8F8::from_num(-1.5);
+        assert_eq!(Signed::abs(&neg), 1.5);
+        assert_eq!(Signed::signum(&neg), -1);
+        assert_eq!(Signed::signum(&I8F8::ZERO), 0);
+        assert!(Signed::is_negative(&neg));
+        assert!(!Signed::is_positive(&neg));
+        assert!(Signed::is_positive(&-neg));
+        assert_eq!(Signed::abs_sub(&neg, &I8F8::ONE), 0);
+        assert_eq!(Signed::abs_sub(&I8F8::ONE, &neg), 2.5);
+
+        let (a, b) = (U8F8::from_num(1.5), U8F8::from_num(4));
+        assert_eq!(unsigned_distance(a, b), 2.5);
+        assert_eq!(unsigned_distance(b, a), 2.5);
+        assert_eq!(unsigned_distance(7u32, 2u32), 5);
+    }
+
+    // Evaluates a × b + c / d - e % f, or None if any step fails.
+    fn checked_eval<T>(a: T, b: T, c: T, d: T, e: T, f: T) -> Option<T>
+    where
+        T: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem,
+    {
+        let prod = a.checked_mul(&b)?;
+        let quot = c.checked_div(&d)?;
+        let rem = e.checked_rem(&f)?;
+        prod.checked_add(&quot)?.checked_sub(&rem)
+    }
+
+    fn checked_negate_pair<T: CheckedNeg>(a: T, b: T) -> Option<(T, T)> {
+        Some((a.checked_neg()?, b.checked_neg()?))
+    }
+
+    #[test]
+    fn checked_ops() {
+        let f = I16F16::from_num;
+        // 1.5 × 2 + 3 / 4 - 5.5 % 2 = 3 + 0.75 - 1.5
+        assert_eq!(
+            checked_eval(f(1.5), f(2.0), f(3.0), f(4.0), f(5.5), f(2.0)),
+            Some(f(2.25))
+        );
+        assert_eq!(checked_eval(3i32, 4, 9, 3, 7, 4), Some(12));
+        // each operation can fail on its own
+        let (one, zero) = (I16F16::ONE, I16F16::ZERO);
+        assert_eq!(checked_eval(I16F16::MAX, f(2.0), one, one, one, one), None);
+        assert_eq!(checked_eval(one, one, one, zero, one, one), None);
+        assert_eq!(checked_eval(one, one, one, one, one, zero), None);
+        assert_eq!(
+            checked_eval(I16F16::MAX, one, I16F16::MAX, I16F16::MAX, zero, one),
+            None
+        );
+        assert_eq!(
+            checked_eval(I16F16::MIN, one, zero, one, I16F16::MAX - one, I16F16::MAX),
+            None
+        );
+
+        assert_eq!(CheckedAdd::checked_add(&U8F8::MAX, &U8F8::DELTA), None);
+        assert_eq!(CheckedSub::checked_sub(&U8F8::ZERO, &U8F8::DELTA), None);
+        assert_eq!(
+            CheckedMul::checked_mul(&U8F8::MAX, &U8F8::from_num(0.5)),
+            Some(U8F8::MAX / 2)
+        );
+        assert_eq!(
+            CheckedDiv::checked_div(&U8F8::ONE, &U8F8::from_num(0.25)),
+            Some(U8F8::from_num(4))
+        );
+        assert_eq!(
+            CheckedRem::checked_rem(&U8F8::from_num(7.5), &U8F8::from_num(2)),
+            Some(U8F8::from_num(1.5))
+        );
+
+        assert_eq!(
+            checked_negate_pair(I8F8::from_num(1.5), I8F8::MAX),
+            Some((I8F8::from_num(-1.5), -I8F8::MAX))
+        );
+        assert_eq!(checked_negate_pair(I8F8::ONE, I8F8::MIN), None);
+        assert_eq!(
+            checked_negate_pair(U8F8::ZERO, U8F8::ZERO),
+            Some((U8F8::ZERO, U8F8::ZERO))
+        );
+        assert_eq!(checked_negate_pair(U8F8::ZERO, U8F8::DELTA), None);
+        assert_eq!(checked_negate_pair(-5i32, 7), Some((5, -7)));
+    }
+
+    // Evaluates a × b + c - d with saturation at each step.
+    fn saturating_eval<T>(a: T, b: T, c: T, d: T) -> T
+    where
+        T: SaturatingAdd + SaturatingSub + SaturatingMul,
+    {
+        a.saturating_mul(&b).saturating_add(&c).saturating_sub(&d)
+    }
+
+    // Evaluates -(a × b + c - d) with wrapping at each step.
+    fn wrapping_eval<T>(a: T, b: T, c: T, d: T) -> T
+    where
+        T: WrappingAdd + WrappingSub + WrappingMul + WrappingNeg,
+    {
+        a.wrapping_mul(&b)
+            .wrapping_add(&c)
+            .wrapping_sub(&d)
+            .wrapping_neg()
+    }
+
+    fn wrapping_shifts<T: WrappingShl + WrappingShr>(a: T, shl: u32, shr: u32) -> (T, T) {
+        (a.wrapping_shl(shl), a.wrapping_shr(shr))
+    }
+
+    #[test]
+    fn saturating_wrapping_ops() {
+        let f = I8F8::from_num;
+        // 1.5 × 2 + 3 - 0.5
+        assert_eq!(saturating_eval(f(1.5), f(2.0), f(3.0), f(0.5)), 5.5);
+        assert_eq!(
+            saturating_eval(f(100.0), f(2.0), f(-1.0), f(0.0)),
+            I8F8::MAX - I8F8::ONE
+        );
+        assert_eq!(
+            saturating_eval(f(100.0), f(-2.0), f(1.0), f(1.0)),
+            I8F8::MIN
+        );
+        assert_eq!(
+            saturating_eval(f(-1.0), f(1.0), I8F8::MIN, I8F8::MAX),
+            I8F8::MIN
+        );
+        let u = U8F8::from_num;
+        assert_eq!(saturating_eval(u(1.5), u(2.0), u(3.0), u(7.0)), 0);
+        assert_eq!(
+            saturating_eval(u(200.0), u(2.0), u(0.0), u(1.0)),
+            U8F8::MAX - U8F8::ONE
+        );
+        assert_eq!(saturating_eval(250u8, 2, 0, 1), 254);
+
+        assert_eq!(wrapping_eval(f(1.5), f(2.0), f(3.0), f(0.5)), -5.5);
+        // 100 × 2 wraps to -56, then -56 + 1 - 0 = -55, negated
+        assert_eq!(wrapping_eval(f(100.0), f(2.0), f(1.0), f(0.0)), 55);
+        assert_eq!(
+            wrapping_eval(I8F8::MIN, I8F8::ONE, I8F8::ZERO, I8F8::ZERO),
+            I8F8::MIN
+        );
+        // unsigned negation wraps around too
+        assert_eq!(
+            wrapping_eval(u(1.0), u(1.0), u(0.0), u(0.0)),
+            U8F8::from_bits(0xFF00)
+        );
+        assert_eq!(wrapping_eval(u(1.0), u(1.0), u(0.0), u(2.0)), u(1.0));
+        assert_eq!(wrapping_eval(100i8, 2, 1, 0), 55);
+
+        // the shift amount wraps modulo the number of bits
+        assert_eq!(wrapping_shifts(f(1.5), 1, 2), (f(3.0), f(0.375)));
+        assert_eq!(wrapping_shifts(f(1.5), 17, 18), (f(3.0), f(0.375)));
+        assert_eq!(wrapping_shifts(f(-1.5), 0, 1), (f(-1.5), f(-0.75)));
+        assert_eq!(
+            wrapping_shifts(U8F8::MAX, 8, 8),
+            (U8F8::from_bits(0xFF00), U8F8::from_bits(0xFF))
+        );
+        assert_eq!(wrapping_shifts(3u8, 9, 9), (6, 1));
+    }
+
+    // Returns the number of set bits and the position of the highest set bit.
+    fn bit_summary<T: PrimInt>(n: T) -> (u32, Option<u32>) {
+        let nbits = n.count_ones() + n.count_zeros();
+        let high = (!n.is_zero()).then(|| nbits - 1 - n.leading_zeros());
+        (n.count_ones(), high)
+    }
+
+    fn generic_cube<T: PrimInt>(n: T) -> T {
+        n.pow(3)
+    }
+
+    #[test]
+    fn prim_int() {
+        assert_eq!(bit_summary(U8F8::from_num(1.5)), (2, Some(8)));
+        assert_eq!(bit_summary(I8F8::from_num(-1)), (8, Some(15)));
+        assert_eq!(bit_summary(U8F8::ZERO), (0, None));
+        assert_eq!(bit_summary(0x0180u16), (2, Some(8)));
+
+        let x = U8F8::from_bits(0x0F01);
+        assert_eq!(PrimInt::leading_zeros(x), 4);
+        assert_eq!(PrimInt::trailing_zeros(x), 0);
+        assert_eq!(PrimInt::leading_ones(!x), 4);
+        assert_eq!(PrimInt::trailing_ones(x), 1);
+        assert_eq!(PrimInt::rotate_left(x, 4), U8F8::from_bits(0xF010));
+        assert_eq!(PrimInt::rotate_right(x, 4), U8F8::from_bits(0x10F0));
+        assert_eq!(PrimInt::swap_bytes(x), U8F8::from_bits(0x010F));
+        assert_eq!(PrimInt::reverse_bits(x), U8F8::from_bits(0x80F0));
+        assert_eq!(<U8F8 as PrimInt>::from_be(PrimInt::to_be(x)), x);
+        assert_eq!(<U8F8 as PrimInt>::from_le(PrimInt::to_le(x)), x);
+
+        // signed and unsigned shifts regardless of the signedness of the type
+        let neg = I8F8::from_num(-2);
+        assert_eq!(PrimInt::signed_shr(neg, 2), I8F8::from_num(-0.5));
+        assert_eq!(PrimInt::unsigned_shr(neg, 2), I8F8::from_bits(0x3F80));
+        assert_eq!(PrimInt::signed_shl(neg, 1), I8F8::from_num(-4));
+        let high = U8F8::from_bits(0x8000);
+        assert_eq!(PrimInt::signed_shr(high, 4), U8F8::from_bits(0xF800));
+        assert_eq!(PrimInt::unsigned_shr(high, 4), U8F8::from_bits(0x0800));
+        assert_eq!(PrimInt::unsigned_shl(high, 1), U8F8::ZERO);
+
+        // pow is the fixed-point power, not the power of the bits
+        assert_eq!(generic_cube(I8F8::from_num(1.5)), 3.375);
+        assert_eq!(generic_cube(I8F8::from_num(-2)), -8);
+        assert_eq!(generic_cube(U8F8::from_num(0.5)), 0.125);
+        assert_eq!(generic_cube(3u32), 27);
+        assert_eq!(PrimInt::pow(I16F16::from_num(0.5), 0), 1);
+        assert_eq!(PrimInt::pow(I16F16::ZERO, 0), 1);
+        assert_eq!(PrimInt::pow(I16F16::ONE, u32::MAX), 1);
+        assert_eq!(PrimInt::pow(-I16F16::ONE, u32::MAX), -1);
+        assert_eq!(PrimInt::pow(I16F16::from_num(0.5), u32::MAX), 0);
+    }
+
+    #[test]
+    fn to_f64_through_trait() {
+        fn to_f64<T: ToPrimitive>(n: &T) -> Option<f64> {
+            n.to_f64()
+        }
+        assert_eq!(to_f64(&I16F16::from_num(2.5)), Some(2.5));
+        assert_eq!(to_f64(&U0F8::MAX), Some(255.0 / 256.0));
+        assert_eq!(to_f64(&I64F64::MIN), Some(-(2f64.powi(63))));
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedRange, FixedU128, FixedU16, FixedU32,
+    FixedU64, FixedU8, Unwrapped, Wrapping,
+};
+use core::ops::RangeInclusive;
+use proptest::{
+    arbitrary::Arbitrary,
+    strategy::{Map, NewTree, Strategy},
+    test_runner::TestRunner,
+};
+
+macro_rules! impl_trait {
+    ($Fixed:ident, $Inner:ident) => {
+        impl<const FRAC: i32> Arbitrary for $Fixed<FRAC> {
+            type Parameters = ();
+            type Strategy = Map<<$Inner as Arbitrary>::Strategy, fn($Inner) -> Self>;
+
+            #[inline]
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                <$Inner as Arbitrary>::arbitrary().prop_map(Self::from_bits)
+            }
+        }
+
+        impl<const FRAC: i32> Arbitrary for Wrapping<$Fixed<FRAC>> {
+            type Parameters = ();
+            type Strategy = Map<<$Inner as Arbitrary>::Strategy, fn($Inner) -> Self>;
+
+            #[inline]
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                <$Inner as Arbitrary>::arbitrary().prop_map(Self::from_bits)
+            }
+        }
+
+        impl<const FRAC: i32> Arbitrary for Unwrapped<$Fixed<FRAC>> {
+            type Parameters = ();
+            type Strategy = Map<<$Inner as Arbitrary>::Strategy, fn($Inner) -> Self>;
+
+            #[inline]
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                <$Inner as Arbitrary>::arbitrary().prop_map(Self::from_bits)
+            }
+        }
+
+        // Values are generated uniformly over the bits in lo..=hi, and shrink
+        // towards lo.
+        impl<const FRAC: i32> Strategy for FixedRange<$Fixed<FRAC>> {
+            type Tree = <Map<RangeInclusive<$Inner>, fn($Inner) -> $Fixed<FRAC>> as Strategy>::Tree;
+            type Value = $Fixed<FRAC>;
+
+            #[inline]
+            fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+                let bits = self.lo().to_bits()..=self.hi().to_bits();
+                bits.prop_map($Fixed::from_bits as fn($Inner) -> $Fixed<FRAC>)
+                    .new_tree(runner)
+            }
+        }
+    };
+}
+
+impl_trait! { FixedI8, i8 }
+impl_trait! { FixedI16, i16 }
+impl_trait! { FixedI32, i32 }
+impl_trait! { FixedI64, i64 }
+impl_trait! { FixedI128, i128 }
+impl_trait! { FixedU8, u8 }
+impl_trait! { FixedU16, u16 }
+impl_trait! { FixedU32, u32 }
+impl_trait! { FixedU64, u64 }
+impl_trait! { FixedU128, u128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I16F16, I4F4, U0F128, U8F8};
+    use crate::{FixedRange, Wrapping};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn bits_round_trip(a: I16F16, b: U0F128, w: Wrapping<I4F4>) {
+            prop_assert_eq!(I16F16::from_bits(a.to_bits()), a);
+            prop_assert_eq!(U0F128::from_bits(b.to_bits()), b);
+            prop_assert_eq!(Wrapping::<I4F4>::from_bits(w.to_bits()), w);
+        }
+
+        #[test]
+        fn range_strategy(
+            a in FixedRange::new(I16F16::from_num(-1.5), I16F16::from_num(2.25)),
+            b in FixedRange::new(U8F8::MAX, U8F8::MAX),
+        ) {
+            prop_assert!((-1.5..=2.25).contains(&a));
+            prop_assert_eq!(b, U8F8::MAX);
+        }
+    }
+}
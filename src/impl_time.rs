@@ -0,0 +1,279 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    try_from::{TryFromErrorKind, TryFromFixedError},
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+use core::time::Duration as StdDuration;
+use time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+// Returns nanos / 1e9 in units of 2^-frac_nbits seconds, rounded down, or
+// None if it does not fit in u128. The quotient is built from exact partial
+// divisions, so the value is only rounded once.
+fn frac_bits(nanos: u32, frac_nbits: u32) -> Option<u128> {
+    if nanos == 0 {
+        return Some(0);
+    }
+    let (mut bits, mut rem, mut left) = (0u128, u128::from(nanos), frac_nbits);
+    while left > 0 {
+        // rem < 1e9 < 2^30, so rem << 98 cannot overflow
+        let step = left.min(98);
+        if bits.leading_zeros() < step {
+            return None;
+        }
+        let wide = rem << step;
+        // wide / 1e9 < 2^step since rem < 1e9
+        bits = (bits << step) | (wide / NANOS_PER_SEC);
+        rem = wide % NANOS_PER_SEC;
+        left -= step;
+    }
+    Some(bits)
+}
+
+// Returns the magnitude of a duration in units of 2^-frac_nbits seconds, rounded
+// down, or None if it does not fit in u128.
+fn magnitude_bits(abs: StdDuration, frac_nbits: i32) -> Option<u128> {
+    let secs = u128::from(abs.as_secs());
+    if frac_nbits <= 0 {
+        // the fractional part of a second cannot affect the rounded value
+        return Some(secs.checked_shr(frac_nbits.unsigned_abs()).unwrap_or(0));
+    }
+    let frac = frac_bits(abs.subsec_nanos(), frac_nbits.unsigned_abs())?;
+    if secs == 0 {
+        return Some(frac);
+    }
+    if secs.leading_zeros() < frac_nbits.unsigned_abs() {
+        return None;
+    }
+    // frac < 2^frac_nbits, so it only occupies the bits cleared by the shift
+    Some((secs << frac_nbits) | frac)
+}
+
+macro_rules! impl_duration {
+    ($Fixed:ident, $Inner:ident) => {
+        impl<const FRAC: i32> TryFrom<Duration> for $Fixed<FRAC> {
+            type Error = TryFromFixedError;
+
+            /// Converts a [`Duration`] to a fixed-point number of seconds.
+            ///
+            /// The value is rounded towards zero. Negative durations can only
+            /// be converted to signed fixed-point numbers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the value does not fit.
+            fn try_from(src: Duration) -> Result<$Fixed<FRAC>, TryFromFixedError> {
+                let bits = magnitude_bits(src.unsigned_abs(), FRAC).and_then(|mag| {
+                    if src.is_negative() {
+                        let neg = 0i128.checked_sub_unsigned(mag)?;
+                        $Inner::try_from(neg).ok()
+                    } else {
+                        $Inner::try_from(mag).ok()
+                    }
+                });
+                bits.map(Self::from_bits).ok_or(TryFromFixedError {
+                    kind: TryFromErrorKind::Overflow,
+                })
+            }
+        }
+    };
+}
+
+impl_duration! { FixedI8, i8 }
+impl_duration! { FixedI16, i16 }
+impl_duration! { FixedI32, i32 }
+impl_duration! { FixedI64, i64 }
+impl_duration! { FixedI128, i128 }
+impl_duration! { FixedU8, u8 }
+impl_duration! { FixedU16, u16 }
+impl_duration! { FixedU32, u32 }
+impl_duration! { FixedU64, u64 }
+impl_duration! { FixedU128, u128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F128, I0F32, I16F16, I32F32, I64F64, U0F128, U0F32, U16F16};
+    use crate::{FixedI128, FixedI8, FixedU128, FixedU8};
+    use time::Duration;
+
+    #[test]
+    fn positive() {
+        let d = Duration::new(3, 500_000_000);
+        assert_eq!(I16F16::try_from(d), Ok(I16F16::from_num(3.5)));
+        assert_eq!(U16F16::try_from(d), Ok(U16F16::from_num(3.5)));
+        // 0.1 s is rounded down
+        let d = Duration::milliseconds(100);
+        assert_eq!(I32F32::try_from(d), Ok(I32F32::from_bits(0x1999_9999)));
+        assert_eq!(
+            U0F128::try_from(d),
+            Ok(U0F128::from_bits(0x1999_9999_9999_9999_9999_9999_9999_9999))
+        );
+        assert_eq!(I16F16::try_from(Duration::ZERO), Ok(I16F16::ZERO));
+    }
+
+    #[test]
+    fn negative() {
+        let d = Duration::new(-3, -500_000_000);
+        assert_eq!(I16F16::try_from(d), Ok(I16F16::from_num(-3.5)));
+        // -0.1 s is rounded towards zero
+        let d = Duration::milliseconds(-100);
+        assert_eq!(I32F32::try_from(d), Ok(I32F32::from_bits(-0x1999_9999)));
+        assert_eq!(
+            I0F128::try_from(d),
+            Ok(I0F128::from_bits(
+                -0x1999_9999_9999_9999_9999_9999_9999_9999
+            ))
+        );
+        assert!(U16F16::try_from(d).is_err());
+        assert!(U16F16::try_from(Duration::seconds(-1)).is_err());
+        assert_eq!(I16F16::try_from(Duration::seconds(-32768)), Ok(I16F16::MIN));
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(I16F16::try_from(Duration::seconds(32768)).is_err());
+        assert!(I16F16::try_from(Duration::seconds(-32769)).is_err());
+        // rounding towards zero can bring the value back in range
+        assert_eq!(
+            I16F16::try_from(Duration::new(-32768, -15_258)),
+            Ok(I16F16::MIN)
+        );
+        assert!(I16F16::try_from(Duration::new(-32768, -15_259)).is_err());
+        assert_eq!(
+            I64F64::try_from(Duration::MAX).map(I64F64::int),
+            Ok(I64F64::from_num(i64::MAX))
+        );
+        assert_eq!(
+            I64F64::try_from(Duration::seconds(i64::MIN)),
+            Ok(I64F64::MIN)
+        );
+        assert!(I64F64::try_from(Duration::MIN).is_err());
+        assert!(I32F32::try_from(Duration::MAX).is_err());
+    }
+
+    #[test]
+    fn no_int_bits() {
+        assert_eq!(I0F32::try_from(Duration::ZERO), Ok(I0F32::ZERO));
+        assert_eq!(U0F32::try_from(Duration::ZERO), Ok(U0F32::ZERO));
+        assert_eq!(I0F128::try_from(Duration::ZERO), Ok(I0F128::ZERO));
+        let d = Duration::milliseconds(250);
+        assert_eq!(I0F32::try_from(d), Ok(I0F32::from_num(0.25)));
+        assert_eq!(U0F32::try_from(d), Ok(U0F32::from_num(0.25)));
+        assert!(I0F32::try_from(Duration::milliseconds(500)).is_err());
+        assert_eq!(
+            U0F32::try_from(Duration::milliseconds(500)),
+            Ok(U0F32::from_num(0.5))
+        );
+        assert!(U0F32::try_from(Duration::SECOND).is_err());
+        // -0.5 s is exactly MIN
+        let d = Duration::milliseconds(-500);
+        assert_eq!(I0F32::try_from(d), Ok(I0F32::MIN));
+        assert_eq!(I0F128::try_from(d), Ok(I0F128::MIN));
+        assert!(I0F128::try_from(Duration::new(0, -500_000_001)).is_err());
+        assert!(U0F32::try_from(d).is_err());
+        assert!(U0F32::try_from(Duration::new(0, -1)).is_err());
+        // -1 ns is rounded towards zero to 0
+        assert_eq!(
+            FixedU8::<8>::try_from(Duration::new(0, -1)),
+            Ok(FixedU8::ZERO)
+        );
+    }
+
+    #[test]
+    fn frac_out_of_range() {
+        // 7 s = 0b111 s is rounded towards zero to 4 s
+        let d = Duration::new(7, 999_999_999);
+        assert_eq!(FixedI8::<-2>::try_from(d), Ok(FixedI8::<-2>::from_num(4)));
+        assert_eq!(FixedI8::<-2>::try_from(-d), Ok(FixedI8::<-2>::from_num(-4)));
+        assert_eq!(FixedU8::<-100>::try_from(Duration::MAX), Ok(FixedU8::ZERO));
+        // 2^-10 s is about 976_562.5 ns
+        let d = Duration::nanoseconds(976_563);
+        assert_eq!(FixedU8::<10>::try_from(d), Ok(FixedU8::<10>::from_bits(1)));
+        assert_eq!(
+            FixedI8::<10>::try_from(-d),
+            Ok(FixedI8::<10>::from_bits(-1))
+        );
+        assert!(FixedU8::<10>::try_from(Duration::milliseconds(250)).is_err());
+        assert_eq!(
+            FixedU8::<200>::try_from(Duration::ZERO),
+            Ok(FixedU8::<200>::ZERO)
+        );
+        assert!(FixedU8::<200>::try_from(Duration::nanoseconds(1)).is_err());
+    }
+
+    // Computes nanos / 1e9 in units of 2^-frac_nbits seconds one bit at a
+    // time, rounded down, or None if it does not fit in u128.
+    fn exact_frac_bits(nanos: u32, frac_nbits: u32) -> Option<u128> {
+        let (mut bits, mut rem) = (0u128, u128::from(nanos));
+        for _ in 0..frac_nbits {
+            rem <<= 1;
+            let bit = u128::from(rem >= 1_000_000_000);
+            rem -= bit * 1_000_000_000;
+            bits = bits.checked_mul(2)? | bit;
+        }
+        Some(bits)
+    }
+
+    fn check_u128<const FRAC: i32>(nanos: u32) {
+        let d = Duration::nanoseconds(i64::from(nanos));
+        assert_eq!(
+            FixedU128::<FRAC>::try_from(d).ok(),
+            exact_frac_bits(nanos, FRAC.unsigned_abs()).map(FixedU128::from_bits)
+        );
+    }
+
+    #[test]
+    fn frac_above_128() {
+        let nanos = [
+            1,
+            3,
+            7_450_580,
+            100_000_000,
+            123_456_789,
+            124_999_999,
+            125_000_000,
+            249_999_999,
+            250_000_000,
+        ];
+        for n in nanos {
+            let d = Duration::nanoseconds(i64::from(n));
+            let exact = exact_frac_bits(n, 130);
+            assert_eq!(
+                FixedU128::<130>::try_from(d).ok(),
+                exact.map(FixedU128::from_bits)
+            );
+            let exact_i = exact.and_then(|b| i128::try_from(b).ok());
+            assert_eq!(
+                FixedI128::<130>::try_from(d).ok(),
+                exact_i.map(FixedI128::from_bits)
+            );
+            assert_eq!(
+                FixedI128::<130>::try_from(-d).ok(),
+                exact
+                    .and_then(|b| 0i128.checked_sub_unsigned(b))
+                    .map(FixedI128::from_bits)
+            );
+            check_u128::<129>(n);
+            check_u128::<150>(n);
+            check_u128::<157>(n);
+            check_u128::<158>(n);
+            check_u128::<300>(n);
+        }
+    }
+}
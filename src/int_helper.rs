@@ -24,9 +24,31 @@ macro_rules! make_helper {
 
         pub mod $u {
             #[inline]
-            pub fn neg_abs(val: $u) -> (bool, $u) {
+            pub const fn neg_abs(val: $u) -> (bool, $u) {
                 (false, val)
             }
+
+            // Returns the square root of val, rounded down.
+            #[inline]
+            pub const fn isqrt(val: $u) -> $u {
+                if val < 2 {
+                    return val;
+                }
+                // start with the largest power of four ≤ val
+                let mut bit: $u = 1 << ((<$u>::BITS - 1 - val.leading_zeros()) & !1);
+                let mut rem = val;
+                let mut root: $u = 0;
+                while bit != 0 {
+                    if rem >= root + bit {
+                        rem -= root + bit;
+                        root = (root >> 1) + bit;
+                    } else {
+                        root >>= 1;
+                    }
+                    bit >>= 2;
+                }
+                root
+            }
         }
     };
 }
@@ -228,6 +228,66 @@ pub const fn u128(v: u128, start: u128, end: u128, frac_bits: u32) -> (u128, boo
     }
 }
 
+// Brings v, start and end to the smaller of v_frac and range_frac fractional
+// bits. The shift is always to the right, so it rounds towards −∞ and cannot
+// overflow; since inv_lerp only depends on ratios, the common scale does not
+// matter.
+pub mod align {
+    macro_rules! make_align {
+        ($i:ident, $u:ident) => {
+            #[inline]
+            pub const fn $i(
+                v: $i,
+                v_frac: i32,
+                start: $i,
+                end: $i,
+                range_frac: i32,
+            ) -> ($i, $i, $i) {
+                const fn shr(val: $i, shift: u32) -> $i {
+                    match val.checked_shr(shift) {
+                        Some(shifted) => shifted,
+                        None => val >> ($i::BITS - 1),
+                    }
+                }
+                let shift = v_frac.abs_diff(range_frac);
+                if v_frac > range_frac {
+                    (shr(v, shift), start, end)
+                } else {
+                    (v, shr(start, shift), shr(end, shift))
+                }
+            }
+
+            #[inline]
+            pub const fn $u(
+                v: $u,
+                v_frac: i32,
+                start: $u,
+                end: $u,
+                range_frac: i32,
+            ) -> ($u, $u, $u) {
+                const fn shr(val: $u, shift: u32) -> $u {
+                    match val.checked_shr(shift) {
+                        Some(shifted) => shifted,
+                        None => 0,
+                    }
+                }
+                let shift = v_frac.abs_diff(range_frac);
+                if v_frac > range_frac {
+                    (shr(v, shift), start, end)
+                } else {
+                    (v, shr(start, shift), shr(end, shift))
+                }
+            }
+        };
+    }
+
+    make_align! { i8, u8 }
+    make_align! { i16, u16 }
+    make_align! { i32, u32 }
+    make_align! { i64, u64 }
+    make_align! { i128, u128 }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::inv_lerp;
@@ -257,6 +317,97 @@ mod tests {
         assert_eq!(inv_lerp::u8(200, 0, 2, 2), (200 - 56, true));
     }
 
+    #[test]
+    fn align() {
+        // self has more fractional bits
+        assert_eq!(inv_lerp::align::i8(-3, 2, 5, 6, 1), (-2, 5, 6));
+        assert_eq!(inv_lerp::align::u8(7, 3, 5, 6, 1), (1, 5, 6));
+        // start and end have more fractional bits
+        assert_eq!(inv_lerp::align::i8(5, 1, -3, 3, 2), (5, -2, 1));
+        assert_eq!(inv_lerp::align::u8(5, 0, 3, 9, 2), (5, 0, 2));
+        // shifts by the whole width or more
+        assert_eq!(inv_lerp::align::i8(-3, 8, 5, 6, 0), (-1, 5, 6));
+        assert_eq!(inv_lerp::align::i8(3, -100, -5, 6, 100), (3, -1, 0));
+        assert_eq!(inv_lerp::align::u8(255, 8, 5, 6, 0), (0, 5, 6));
+        assert_eq!(
+            inv_lerp::align::i128(-1, i32::MIN, i128::MIN, i128::MAX, i32::MAX),
+            (-1, -1, 0)
+        );
+    }
+
+    #[test]
+    fn mixed_frac() {
+        use crate::types::{I16F16, I24F8, I32F0, I8F24, U16F16, U24F8};
+
+        // range with fewer fractional bits than self
+        let start = I24F8::from_num(-2);
+        let end = I24F8::from_num(6);
+        let v = I16F16::from_num(1);
+        assert_eq!(v.inv_lerp_mixed::<8, 16>(start, end), 0.375);
+        assert_eq!(
+            v.inv_lerp_mixed::<8, 16>(start, end),
+            v.inv_lerp::<16>(I16F16::from_num(start), I16F16::from_num(end))
+        );
+        // self is rounded down to 8 fractional bits
+        let v = I16F16::from_num(1) + I16F16::DELTA;
+        assert_eq!(v.inv_lerp_mixed::<8, 16>(start, end), 0.375);
+        let v = I16F16::from_num(1) - I16F16::DELTA;
+        assert_eq!(
+            v.inv_lerp_mixed::<8, 16>(start, end),
+            I16F16::from_num(0.375) - I16F16::from_num(1) / 2048
+        );
+
+        // range with more fractional bits than self
+        let start = I8F24::from_num(-2.25);
+        let end = I8F24::from_num(5.75);
+        let v = I16F16::from_num(1.75);
+        assert_eq!(v.inv_lerp_mixed::<24, 16>(start, end), 0.5);
+        // start and end are rounded down to 16 fractional bits
+        let fine_start = start + I8F24::DELTA;
+        let fine_end = end + I8F24::DELTA;
+        assert_eq!(v.inv_lerp_mixed::<24, 16>(fine_start, fine_end), 0.5);
+        assert_eq!(
+            v.checked_inv_lerp_mixed::<24, 16>(start, start + I8F24::DELTA),
+            None
+        );
+        assert_eq!(
+            v.checked_inv_lerp_mixed::<24, 0>(start, start - I8F24::DELTA),
+            Some(I32F0::from_num(-4 * 65536))
+        );
+
+        // the rest of the family agrees with inv_lerp after conversion
+        let start = U24F8::from_num(10.5);
+        let end = U24F8::from_num(2.5);
+        for v in [0.0, 2.5, 6.5, 10.5, 20.0, 30000.0] {
+            let v = U16F16::from_num(v);
+            let (conv_start, conv_end) = (U16F16::from_num(start), U16F16::from_num(end));
+            let expected = v.overflowing_inv_lerp::<16>(conv_start, conv_end);
+            assert_eq!(v.overflowing_inv_lerp_mixed::<8, 16>(start, end), expected);
+            assert_eq!(v.wrapping_inv_lerp_mixed::<8, 16>(start, end), expected.0);
+            assert_eq!(
+                v.checked_inv_lerp_mixed::<8, 16>(start, end),
+                v.checked_inv_lerp::<16>(conv_start, conv_end)
+            );
+            assert_eq!(
+                v.saturating_inv_lerp_mixed::<8, 16>(start, end),
+                v.saturating_inv_lerp::<16>(conv_start, conv_end)
+            );
+            if !expected.1 {
+                assert_eq!(v.unwrapped_inv_lerp_mixed::<8, 16>(start, end), expected.0);
+            }
+        }
+        let v = I16F16::MIN;
+        let (start, end) = (I24F8::from_num(1), I24F8::from_num(2));
+        assert_eq!(
+            v.saturating_inv_lerp_mixed::<8, 16>(start, end),
+            I16F16::MIN
+        );
+        assert_eq!(
+            v.saturating_inv_lerp_mixed::<8, 16>(end, start),
+            I16F16::MAX
+        );
+    }
+
     #[test]
     fn inv_lerp_i128() {
         assert_eq!(inv_lerp::i128(-128, -128, 127, 0), (0, false));
@@ -154,6 +154,8 @@ pub const fn u128(r: u128, start: u128, end: u128, frac_bits: u32) -> (u128, boo
 #[cfg(test)]
 mod tests {
     use crate::lerp;
+    use crate::types::{I16F16, I28F4, U16F16, U28F4};
+    use crate::{FixedI8, FixedU8};
 
     #[test]
     fn lerp_i8() {
@@ -221,4 +223,127 @@ mod tests {
             (u128::MAX.wrapping_mul(2), true)
         );
     }
+
+    #[test]
+    fn lerp_coarse_endpoints() {
+        let (start, end) = (I28F4::from_num(-3), I28F4::from_num(5));
+        let r = I16F16::from_num(0.75);
+        assert_eq!(r.lerp(start, end), 3);
+        assert_eq!(r.checked_lerp(start, end), Some(I28F4::from_num(3)));
+        assert_eq!(r.saturating_lerp(start, end), 3);
+        assert_eq!(r.wrapping_lerp(start, end), 3);
+        assert_eq!(r.unwrapped_lerp(start, end), 3);
+        assert_eq!(r.overflowing_lerp(start, end), (I28F4::from_num(3), false));
+
+        // ±1/3 × 8 = ±2.666… is rounded down to a multiple of 1/16
+        let r = I16F16::from_bits(0x5555);
+        assert_eq!(
+            r.lerp(start, end),
+            I28F4::from_num(-3 + 2) + I28F4::from_bits(10)
+        );
+        assert_eq!(
+            r.lerp(end, start),
+            I28F4::from_num(5 - 2) - I28F4::from_bits(11)
+        );
+
+        let r = I16F16::from_num(2);
+        let (lo, hi) = (I28F4::ZERO, I28F4::MAX);
+        assert_eq!(r.checked_lerp(lo, hi), None);
+        assert_eq!(r.saturating_lerp(lo, hi), I28F4::MAX);
+        assert_eq!((-r).saturating_lerp(lo, hi), I28F4::MIN);
+        assert_eq!(r.wrapping_lerp(lo, hi), I28F4::from_bits(-2));
+        assert_eq!(r.overflowing_lerp(lo, hi), (I28F4::from_bits(-2), true));
+
+        let r = U16F16::from_num(1.5);
+        let (lo, hi) = (U28F4::ZERO, U28F4::MAX);
+        assert_eq!(r.checked_lerp(lo, hi), None);
+        assert_eq!(r.saturating_lerp(lo, hi), U28F4::MAX);
+        assert_eq!(r.saturating_lerp(hi, lo), U28F4::ZERO);
+        assert_eq!(r.lerp(U28F4::from_num(1), U28F4::from_num(3)), 4);
+    }
+
+    #[test]
+    fn lerp_endpoints_and_extrapolation() {
+        let (start, end) = (I16F16::from_num(-7.25), I16F16::from_num(12.5));
+        assert_eq!(I16F16::ZERO.lerp(start, end), start);
+        assert_eq!(I16F16::ONE.lerp(start, end), end);
+        assert_eq!(I16F16::ONE.lerp(end, start), start);
+
+        // outside [0, 1] the result continues on the same line
+        let step = end - start;
+        for k in -4..=4 {
+            let t = I16F16::from_num(k);
+            assert_eq!(t.lerp(start, end), start + step * k);
+            let t = I16F16::from_num(k) + I16F16::from_num(0.5);
+            let half = step / 2;
+            assert_eq!(t.lerp(start, end), start + step * k + half);
+        }
+
+        let (start, end) = (U16F16::from_num(3), U16F16::from_num(11));
+        assert_eq!(U16F16::ZERO.lerp(start, end), start);
+        assert_eq!(U16F16::ONE.lerp(start, end), end);
+        assert_eq!(U16F16::from_num(2.5).lerp(start, end), 23);
+    }
+
+    #[test]
+    fn lerp_clamped() {
+        let (start, end) = (I16F16::from_num(-7.25), I16F16::from_num(12.5));
+        for t in [-1000.0, -1.5, -0.0625, 0.0] {
+            let t = I16F16::from_num(t);
+            assert_eq!(t.lerp_clamped(start, end), start);
+            assert_eq!(t.lerp_clamped(end, start), end);
+        }
+        for t in [1.0, 1.0625, 2.5, 30000.0] {
+            let t = I16F16::from_num(t);
+            assert_eq!(t.lerp_clamped(start, end), end);
+            assert_eq!(t.lerp_clamped(end, start), start);
+        }
+        for t in [0.0625, 0.25, 0.5, 0.875, 0.9375] {
+            let t = I16F16::from_num(t);
+            assert_eq!(t.lerp_clamped(start, end), t.lerp(start, end));
+            assert_eq!(t.lerp_clamped(end, start), t.lerp(end, start));
+        }
+        // lerp would overflow, but the clamped result is within the range
+        let (lo, hi) = (I16F16::MIN, I16F16::MAX);
+        assert_eq!(I16F16::from_num(-2).lerp_clamped(lo, hi), lo);
+        assert_eq!(I16F16::from_num(2).lerp_clamped(lo, hi), hi);
+        assert!(I16F16::from_num(2).checked_lerp(lo, hi).is_none());
+
+        let (start, end) = (U28F4::from_num(3), U28F4::from_num(11));
+        assert_eq!(U28F4::from_num(0.5).lerp_clamped(start, end), 7);
+        assert_eq!(U28F4::from_num(4).lerp_clamped(start, end), end);
+        assert_eq!(U28F4::from_num(4).lerp_clamped(end, start), start);
+
+        // 1 cannot be represented, so the factor is clamped to MAX
+        let (start, end) = (FixedI8::<0>::ZERO, FixedI8::<0>::from_num(100));
+        let max = FixedI8::<7>::MAX;
+        assert_eq!(max.lerp_clamped(start, end), 99);
+        assert_eq!(FixedI8::<7>::MIN.lerp_clamped(start, end), start);
+        let (start, end) = (FixedU8::<0>::ZERO, FixedU8::<0>::from_num(200));
+        assert_eq!(FixedU8::<8>::MAX.lerp_clamped(start, end), 199);
+        assert_eq!(FixedU8::<8>::MAX.lerp_clamped(end, start), 0);
+    }
+
+    #[test]
+    fn lerp_inv_lerp_round_trip() {
+        // With a range that is a power of two, every t with few enough
+        // fractional bits round-trips exactly.
+        let (start, end) = (I16F16::from_num(-3), I16F16::from_num(5));
+        for bits in -0x3_0000..=0x3_0000 {
+            let t = I16F16::from_bits(bits << 3);
+            let x = t.lerp(start, end);
+            assert_eq!(x.inv_lerp::<16>(start, end), t);
+            assert_eq!(x.inv_lerp::<16>(end, start), I16F16::ONE - t);
+        }
+
+        // Going the other way, inv_lerp rounds down, so lerp can only undo it
+        // to within the rounding error multiplied by the range.
+        let (start, end) = (U16F16::from_num(10), U16F16::from_num(13));
+        for bits in (10 << 16..=13 << 16).step_by(7) {
+            let x = U16F16::from_bits(bits);
+            let t = x.inv_lerp::<16>(start, end);
+            let back = t.lerp(start, end);
+            assert!(back <= x && x - back < U16F16::from_bits(4), "{x} {back}");
+        }
+    }
 }
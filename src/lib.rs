@@ -69,10 +69,14 @@ are enough fractional bits.
 
 This crate does *not* provide general analytic functions.
 
-  * No algebraic functions are provided, for example no `pow`.
-  * No trigonometric functions are provided, for example no `sin` or `cos`.
-  * No other transcendental functions are provided, for example no `log` or
-    `exp`.
+  * Of the algebraic functions, only the integer power `powi`, the square
+    root `sqrt`, the reciprocal square root `rsqrt` and `hypot` are provided,
+    for example there is no `cbrt` or `powf`.
+  * Only the basic trigonometric functions `sin`, `cos`, `sin_cos`, `atan`
+    and `atan2` are provided, for example there is no `tan` or `asin`.
+  * Of the other transcendental functions, only the exponentials `exp` and
+    `exp2` and the logarithms `ln`, `log2`, `log10` and `log` are provided,
+    for example there is no `sinh`.
 
 These functions are not provided because different implementations can have
 different trade-offs, for example trading some correctness for speed.
@@ -106,7 +110,10 @@ The conversions supported cover the following cases.
   * Fixed-point numbers can be converted to strings using [`Display`],
     [`Binary`], [`Octal`], [`LowerHex`], [`UpperHex`], [`LowerExp`] and
     [`UpperExp`]. The output is rounded to the nearest, with ties rounded to
-    even.
+    even. Binary, octal and hexadecimal output includes the radix point rather
+    than showing the raw bits; a precision keeps trailing fractional zeros and
+    the alternate flag `#` adds a prefix, so that 1.5 formatted as an
+    [`I4F4`] with `{:#.4b}` gives `0b1.1000`.
   * All fixed-point numbers are plain old data, so [`bytemuck`] bit casting
     conversions can be used.
 
@@ -219,18 +226,35 @@ The *fixed* crate has these optional feature:
     [*arbitrary* crate].
  2. `borsh`, disabled by default. This implements serialization and
     deserialization using the [*borsh* crate].
- 3. `serde`, disabled by default. This provides serialization support for the
-    fixed-point types. This feature requires the [*serde* crate].
- 4. `std`, disabled by default. This is for features that are not possible under
+ 3. `geometry`, disabled by default. This provides the small matrix types
+    [`Mat2`] and [`Mat3`] for two-dimensional and three-dimensional
+    transformations.
+ 4. `graphics`, disabled by default. This provides the
+    [`srgb_to_linear`] and [`linear_to_srgb`] methods to convert color
+    components between sRGB encoding and linear light.
+ 5. `num-bigint`, disabled by default. This implements [`TryFrom`] conversions
+    from the `BigInt` and `BigUint` types of the [*num-bigint* crate].
+ 6. `proptest`, disabled by default. This implements the `Arbitrary` trait of
+    the [*proptest* crate] for fixed-point numbers, generating values uniformly
+    over their bits, and implements its `Strategy` trait for [`FixedRange`] to
+    generate values within an interval.
+ 7. `serde`, disabled by default. This provides serialization support for the
+    fixed-point types, including the [`SerdeBits`] wrapper which always
+    serializes as the raw underlying integer. This feature requires the
+    [*serde* crate].
+ 8. `std`, disabled by default. This is for features that are not possible under
     `no_std`: currently the implementation of the [`Error`] trait for
-    [`ParseFixedError`].
- 5. `serde-str`, disabled by default. Fixed-point numbers are serialized as
-    strings showing the value when using human-readable formats. This feature
-    requires the `serde` and the `std` optional features. With this feature,
-    serialization is only supported for fixed-point numbers where the number of
-    fractional bits is from zero to the total number of bits. **Warning:**
-    numbers serialized when this feature is enabled cannot be deserialized when
-    this feature is disabled, and vice versa.
+    [`ParseFixedError`], [`TryFromFixedError`], [`BufferTooSmall`] and
+    [`ArithError`].
+ 9. `time`, disabled by default. This implements [`TryFrom`] conversions from
+    the `Duration` type of the [*time* crate] to fixed-point numbers of seconds.
+ 10. `serde-str`, disabled by default. Fixed-point numbers are serialized as
+     strings showing the value when using human-readable formats. This feature
+     requires the `serde` and the `std` optional features. With this feature,
+     serialization is only supported for fixed-point numbers where the number of
+     fractional bits is from zero to the total number of bits. **Warning:**
+     numbers serialized when this feature is enabled cannot be deserialized when
+     this feature is disabled, and vice versa.
 
 To enable features, you can add the dependency like this to [*Cargo.toml*]:
 
@@ -334,8 +358,11 @@ shall be dual licensed as above, without any additional terms or conditions.
 [*cordic* crate]: https://crates.io/crates/cordic
 [*fixed* crate]: https://crates.io/crates/fixed
 [*half* crate]: https://crates.io/crates/half
+[*num-bigint* crate]: https://crates.io/crates/num-bigint
 [*num-traits* crate]: https://crates.io/crates/num-traits
+[*proptest* crate]: https://crates.io/crates/proptest
 [*serde* crate]: https://crates.io/crates/serde
+[*time* crate]: https://crates.io/crates/time
 [CORDIC]: https://en.wikipedia.org/wiki/CORDIC
 [LICENSE-APACHE]: https://www.apache.org/licenses/LICENSE-2.0
 [LICENSE-MIT]: https://opensource.org/licenses/MIT
@@ -352,7 +379,10 @@ shall be dual licensed as above, without any additional terms or conditions.
 [`LossyInto`]: traits::LossyInto
 [`LowerExp`]: core::fmt::LowerExp
 [`LowerHex`]: core::fmt::LowerHex
+[`Mat2`]: https://docs.rs/fixed/2.0.0-alpha.29.0/fixed/struct.Mat2.html
+[`Mat3`]: https://docs.rs/fixed/2.0.0-alpha.29.0/fixed/struct.Mat3.html
 [`Octal`]: core::fmt::Octal
+[`SerdeBits`]: https://docs.rs/fixed/2.0.0-alpha.29.0/fixed/struct.SerdeBits.html
 [`U20F12`]: types::U20F12
 [`UpperExp`]: core::fmt::UpperExp
 [`UpperHex`]: core::fmt::UpperHex
@@ -361,7 +391,9 @@ shall be dual licensed as above, without any additional terms or conditions.
 [`from_str_binary`]: FixedI32::from_str_binary
 [`from_str_hex`]: FixedI32::from_str_hex
 [`from_str_octal`]: FixedI32::from_str_octal
+[`linear_to_srgb`]: https://docs.rs/fixed/2.0.0-alpha.29.0/fixed/struct.FixedU16.html#method.linear_to_srgb
 [`lit`]: FixedI32::lit
+[`srgb_to_linear`]: https://docs.rs/fixed/2.0.0-alpha.29.0/fixed/struct.FixedU16.html#method.srgb_to_linear
 [`to_num`]: FixedI32::to_num
 */
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -410,6 +442,7 @@ PC9zdmc+Cg==
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![feature(generic_const_exprs)]
 #![feature(f16, f128)]
+#![feature(step_trait)]
 #![allow(incomplete_features)]
 
 #[cfg(all(not(feature = "std"), test))]
@@ -419,8 +452,10 @@ extern crate std;
 mod macros;
 
 mod arith;
+mod arith_error;
 #[cfg(feature = "borsh")]
 mod borshize;
+mod buffer;
 mod bytes;
 mod cast;
 mod cmp;
@@ -429,43 +464,71 @@ pub mod consts;
 mod convert;
 mod debug_hex;
 mod display;
+mod exp;
 mod fixed_from_bits;
 mod float_helper;
 mod from_str;
+#[cfg(feature = "graphics")]
+mod graphics;
+mod grouped;
 mod helpers;
 mod hypot;
 #[cfg(feature = "arbitrary")]
 mod impl_arbitrary;
 mod impl_bytemuck;
+#[cfg(feature = "num-bigint")]
+mod impl_num_bigint;
 #[cfg(feature = "num-traits")]
 mod impl_num_traits;
+#[cfg(feature = "proptest")]
+mod impl_proptest;
+#[cfg(feature = "time")]
+mod impl_time;
 mod int256;
 mod int_helper;
 mod inv_lerp;
 mod lerp;
+mod ln;
 mod log;
 mod log10;
+#[cfg(feature = "geometry")]
+mod matrix;
+mod mul_div;
+mod powi;
 mod prim_traits;
+mod range;
+mod round;
 mod saturating;
 #[cfg(feature = "serde")]
 mod serdeize;
 mod sqrt;
+mod step;
 pub mod traits;
 mod traits_bits;
+mod trig;
+mod trimmed;
+mod try_from;
 pub mod types;
 mod unwrapped;
 mod wrapping;
 
 #[cfg(feature = "num-traits")]
 pub use crate::impl_num_traits::RadixParseFixedError;
+#[cfg(feature = "geometry")]
+pub use crate::matrix::{Mat2, Mat3};
+#[cfg(feature = "serde")]
+pub use crate::serdeize::SerdeBits;
 pub use crate::{
-    from_str::ParseFixedError, saturating::Saturating, unwrapped::Unwrapped, wrapping::Wrapping,
+    arith_error::ArithError, buffer::BufferTooSmall, from_str::ParseFixedError, grouped::Grouped,
+    range::FixedRange, round::Round, saturating::Saturating, trimmed::Trimmed,
+    try_from::TryFromFixedError, unwrapped::Unwrapped, wrapping::Wrapping,
 };
 use crate::{
     log::Base,
-    traits::{FromFixed, ToFixed},
-    types::extra::{If, True},
+    traits::{Fixed, FromFixed, ToFixed},
+    types::extra::{max_frac, If, True},
 };
+use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
 use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
 
@@ -584,6 +647,41 @@ range ",
 [`", stringify!($Inner), "`]; it is `#[repr(transparent)]` with
 [`", stringify!($Inner), "`] as the only non-zero-sized field.
 
+The arithmetic operators `*` and `/` accept an [`", stringify!($Inner), "`]
+operand as an integer value, so that multiplying by `2` doubles the number. The
+bitwise operators `&`, `|` and `^` instead accept an
+[`", stringify!($Inner), "`] operand as a raw bit pattern, so that the
+fractional bits can be masked without constructing a fixed-point mask:
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+let x = ", stringify!($Self), "::<4>::from_num(1.75);
+assert_eq!(x & !0xF, 1);
+assert_eq!(x & 0xF, 0.75);
+assert_eq!(x * 2, 3.5);
+```
+
+Ranges of fixed-point numbers can be iterated over in either direction, with
+successive values differing by <i>Δ</i>:
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let start = Fix::ONE;
+let end = start + Fix::DELTA * 3;
+let mut sweep = start..end;
+assert_eq!(sweep.next(), Some(start));
+assert_eq!(sweep.next_back(), Some(end - Fix::DELTA));
+let reversed = [end - Fix::DELTA, start + Fix::DELTA, start];
+assert!((start..end).rev().eq(reversed));
+```
+
 # Examples
 
 ```rust
@@ -654,6 +752,7 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
             {Self, Inner} = {$Self, $Inner},
             Signedness = $Signedness,
             {nm4, nm1, n} = {$nm4, $nm1, $n},
+            nc2 = $nc2,
             {USelf, UInner} = {$USelf, $UInner},
             NonZeroUInner = $NonZeroUInner,
         }
@@ -940,7 +1039,9 @@ fn _compile_fail_tests() {}
 
 #[cfg(test)]
 mod tests {
-    use crate::types::{I0F32, I16F16, I1F31, U0F32, U16F16};
+    use crate::types::{
+        I0F32, I16F16, I1F31, I32F32, I4F4, I8F8, U0F32, U16F16, U32F32, U4F4, U64F64, U8F8,
+    };
 
     #[test]
     fn rounding_signed() {
@@ -1278,6 +1379,153 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rounding_ties_away() {
+        let half = I16F16::from_num(0.5);
+        assert_eq!(half.round(), 1);
+        assert_eq!((-half).round(), -1);
+        assert_eq!(I16F16::from_num(1.5).round(), 2);
+        assert_eq!(I16F16::from_num(-1.5).round(), -2);
+        assert_eq!(I16F16::from_num(2.5).round(), 3);
+        assert_eq!(I16F16::from_num(2.5).round_ties_even(), 2);
+        assert_eq!(U16F16::from_num(0.5).round(), 1);
+        assert_eq!(U16F16::from_num(1.5).round(), 2);
+
+        // the ceiling of MAX does not fit
+        assert_eq!(I16F16::MAX.checked_round(), None);
+        assert_eq!(I16F16::MAX.saturating_round(), I16F16::MAX);
+        assert_eq!(I16F16::MIN.checked_round(), Some(I16F16::MIN));
+        assert_eq!(U16F16::MAX.checked_round(), None);
+        assert_eq!(U16F16::MAX.saturating_round(), U16F16::MAX);
+        let below_tie = I16F16::MAX - I16F16::from_num(0.5);
+        assert_eq!(below_tie.checked_round(), Some(I16F16::MAX.floor()));
+    }
+
+    #[test]
+    fn rounding_ties_even() {
+        for (tie, even) in [
+            (0.5, 0),
+            (1.5, 2),
+            (2.5, 2),
+            (3.5, 4),
+            (-0.5, 0),
+            (-1.5, -2),
+        ] {
+            let tie = I16F16::from_num(tie);
+            assert_eq!(tie.round_ties_even(), even, "{tie}");
+            assert_eq!(tie.checked_round_ties_even(), Some(I16F16::from_num(even)));
+            assert_eq!(tie.saturating_round_ties_even(), even);
+            assert_eq!(tie.wrapping_round_ties_even(), even);
+            assert_eq!(
+                tie.overflowing_round_ties_even(),
+                (I16F16::from_num(even), false)
+            );
+        }
+        assert_eq!(U16F16::from_num(0.5).round_ties_even(), 0);
+        assert_eq!(U16F16::from_num(2.5).round_ties_even(), 2);
+        assert_eq!(I16F16::from_num(-2.5).round_ties_even(), -2);
+        // not ties
+        assert_eq!(I16F16::from_num(2.5625).round_ties_even(), 3);
+        assert_eq!(I16F16::from_num(-0.4375).round_ties_even(), 0);
+
+        // 32767.5 rounds to 32768, which does not fit, but 32766.5 fits
+        let max_tie = I16F16::from_num(32767.5);
+        assert_eq!(max_tie.checked_round_ties_even(), None);
+        assert_eq!(max_tie.saturating_round_ties_even(), I16F16::MAX);
+        assert_eq!(max_tie.wrapping_round_ties_even(), I16F16::MIN);
+        assert_eq!(max_tie.overflowing_round_ties_even(), (I16F16::MIN, true));
+        let tie = I16F16::from_num(32766.5);
+        assert_eq!(tie.checked_round_ties_even(), Some(I16F16::from_num(32766)));
+        assert_eq!(I16F16::MIN.checked_round_ties_even(), Some(I16F16::MIN));
+    }
+
+    #[test]
+    fn snap_to() {
+        use crate::{FixedI8, FixedU8};
+
+        // nearest multiple of step, ties away from zero
+        fn nearest(bits: i32, step: i32) -> i32 {
+            let half = step / 2;
+            if bits < 0 {
+                -((-bits + half) / step * step)
+            } else {
+                (bits + half) / step * step
+            }
+        }
+        fn check<const C: i32>() {
+            let step = 1 << (4 - C).max(0);
+            for b in i8::MIN..=i8::MAX {
+                let expected = nearest(i32::from(b), step);
+                if let Ok(expected) = i8::try_from(expected) {
+                    let x = FixedI8::<4>::from_bits(b);
+                    assert_eq!(x.snap_to::<C>(), FixedI8::<4>::from_bits(expected));
+                }
+            }
+            for b in u8::MIN..=u8::MAX {
+                let expected = nearest(i32::from(b), step);
+                if let Ok(expected) = u8::try_from(expected) {
+                    let x = FixedU8::<4>::from_bits(b);
+                    assert_eq!(x.snap_to::<C>(), FixedU8::<4>::from_bits(expected));
+                }
+            }
+        }
+        check::<6>();
+        check::<4>();
+        check::<3>();
+        check::<1>();
+        check::<0>();
+        check::<-2>();
+        check::<-3>();
+        check::<-4>();
+        check::<-10>();
+
+        let x = I16F16::from_num(-3.140_625);
+        assert_eq!(x.snap_to::<3>(), -3.125);
+        assert_eq!(x.snap_to::<0>(), -3);
+        assert_eq!(x.snap_to::<-2>(), -4);
+        assert_eq!(I16F16::MIN.snap_to::<-15>(), I16F16::MIN);
+        assert_eq!(I16F16::from_num(16_383.5).snap_to::<-15>(), 0);
+        assert_eq!(U0F32::from_num(0.7).snap_to::<1>(), 0.5);
+        assert_eq!(U0F32::from_num(0.25).snap_to::<1>(), 0.5);
+        assert_eq!(U0F32::from_num(0.25).snap_to::<-1>(), 0);
+    }
+
+    #[test]
+    fn rounding_overflow_policies() {
+        let max = I16F16::MAX;
+        assert_eq!(max.checked_ceil(), None);
+        assert_eq!(max.saturating_ceil(), I16F16::MAX);
+        assert_eq!(max.wrapping_ceil(), I16F16::MIN);
+        assert_eq!(max.checked_floor(), Some(I16F16::from_num(i16::MAX)));
+        assert_eq!(max.checked_round(), None);
+        assert_eq!(max.saturating_round(), I16F16::MAX);
+        assert_eq!(max.checked_round_ties_even(), None);
+        assert_eq!(max.round_to_zero(), I16F16::from_num(i16::MAX));
+
+        // 32767.5 is a tie, and both rounding away from zero and rounding to
+        // even give 32768, which overflows
+        let tie = I16F16::from_num(i16::MAX) + I16F16::from_num(0.5);
+        assert_eq!(tie.checked_round(), None);
+        assert_eq!(tie.wrapping_round(), I16F16::MIN);
+        assert_eq!(tie.checked_round_ties_even(), None);
+        assert_eq!(tie.saturating_round_ties_even(), I16F16::MAX);
+        let below_tie = tie - I16F16::DELTA;
+        assert_eq!(below_tie.checked_round(), Some(I16F16::from_num(i16::MAX)));
+
+        let min = I16F16::MIN;
+        assert_eq!(min.checked_floor(), Some(min));
+        assert_eq!(min.checked_ceil(), Some(min));
+        assert_eq!(min.round_to_zero(), min);
+
+        let max = U16F16::MAX;
+        assert_eq!(max.checked_ceil(), None);
+        assert_eq!(max.saturating_ceil(), U16F16::MAX);
+        assert_eq!(max.wrapping_ceil(), U16F16::ZERO);
+        assert_eq!(max.checked_round(), None);
+        assert_eq!(max.checked_floor(), Some(U16F16::from_num(u16::MAX)));
+        assert_eq!(max.round_to_zero(), U16F16::from_num(u16::MAX));
+    }
+
     #[test]
     fn rounding_unsigned() {
         // 0
@@ -1489,6 +1737,820 @@ mod tests {
         assert_eq!(I1F31::NEG_ONE.overflowing_recip(), (I1F31::NEG_ONE, false));
     }
 
+    #[test]
+    fn recip_special_values() {
+        use crate::types::{I24F8, U24F8};
+
+        // powers of two are exact
+        for i in -8..=8 {
+            let x = I16F16::from_num(2f64.powi(i));
+            let expected = I16F16::from_num(2f64.powi(-i));
+            assert_eq!(x.recip(), expected);
+            assert_eq!((-x).recip(), -expected);
+            assert_eq!(
+                U16F16::from_num(2f64.powi(i)).recip(),
+                expected.to_num::<U16F16>()
+            );
+        }
+        assert_eq!(U0F32::from_num(0.5).checked_recip(), None);
+        assert_eq!(I16F16::from_num(2f64.powi(-15)).checked_recip(), None);
+        assert_eq!(
+            I16F16::from_num(-(2f64.powi(-15))).checked_recip(),
+            Some(I16F16::MIN)
+        );
+
+        // 1/DELTA = 2^FRAC
+        assert_eq!(I24F8::DELTA.recip(), I24F8::from_num(256));
+        assert_eq!(U24F8::DELTA.recip(), U24F8::from_num(256));
+        assert_eq!((-I24F8::DELTA).recip(), I24F8::from_num(-256));
+        assert_eq!(I16F16::DELTA.checked_recip(), None);
+        assert_eq!(I16F16::DELTA.saturating_recip(), I16F16::MAX);
+        assert_eq!((-I16F16::DELTA).saturating_recip(), I16F16::MIN);
+        assert_eq!(U16F16::DELTA.overflowing_recip(), (U16F16::ZERO, true));
+        assert_eq!(U16F16::DELTA.wrapping_recip(), U16F16::ZERO);
+
+        // 1/MAX is just above 2^-15 for I16F16 and 2^-16 for U16F16, and is
+        // rounded towards zero
+        assert_eq!(I16F16::MAX.recip(), I16F16::from_bits(2));
+        assert_eq!(I16F16::MIN.recip(), I16F16::from_bits(-2));
+        assert_eq!(U16F16::MAX.recip(), U16F16::DELTA);
+        assert_eq!(I24F8::MAX.recip(), I24F8::ZERO);
+        assert_eq!(U32F32::MAX.recip(), U32F32::DELTA);
+
+        assert_eq!(I16F16::ZERO.checked_recip(), None);
+    }
+
+    #[test]
+    fn ulps_between() {
+        let x = I16F16::from_num(-0.5);
+        assert_eq!(x.ulps_between(x), 0);
+        assert_eq!(x.ulps_between(x + I16F16::DELTA), 1);
+        assert_eq!((x - I16F16::DELTA).ulps_between(x), 1);
+        assert_eq!(x.ulps_between(-x), 1 << 16);
+        assert_eq!(I16F16::MIN.ulps_between(I16F16::MAX), u32::MAX);
+        assert_eq!(I16F16::MAX.ulps_between(I16F16::MIN), u32::MAX);
+
+        let y = U0F32::from_num(0.25);
+        assert_eq!(y.ulps_between(y), 0);
+        assert_eq!(y.ulps_between(y + U0F32::DELTA), 1);
+        assert_eq!(U0F32::ZERO.ulps_between(U0F32::MAX), u32::MAX);
+        assert_eq!(
+            <I16F16 as crate::traits::Fixed>::ulps_between(I16F16::ONE, I16F16::NEG_ONE),
+            2 << 16
+        );
+    }
+
+    #[test]
+    fn next_up_down() {
+        assert_eq!(I16F16::MAX.next_up(), I16F16::MAX);
+        assert_eq!(I16F16::MIN.next_down(), I16F16::MIN);
+        assert_eq!(U0F32::MAX.next_up(), U0F32::MAX);
+        assert_eq!(U0F32::ZERO.next_down(), U0F32::ZERO);
+        assert_eq!(I16F16::ZERO.next_down(), -I16F16::DELTA);
+        assert_eq!(I16F16::NEG_ONE.next_up(), I16F16::NEG_ONE + I16F16::DELTA);
+
+        for x in [
+            I16F16::MIN.next_up(),
+            I16F16::from_num(-2.5),
+            I16F16::ZERO,
+            I16F16::DELTA,
+            I16F16::from_num(1000.125),
+            I16F16::MAX.next_down(),
+        ] {
+            assert_eq!(x.next_up().next_down(), x);
+            assert_eq!(x.next_down().next_up(), x);
+            assert_eq!(x.ulps_between(x.next_up()), 1);
+            assert!(x.next_down() < x && x < x.next_up());
+        }
+        let mut y = U0F32::from_num(0.5);
+        for _ in 0..3 {
+            y = y.next_up();
+        }
+        assert_eq!(y, U0F32::from_num(0.5) + U0F32::DELTA * 3);
+    }
+
+    #[test]
+    fn inc_dec() {
+        for x in [
+            I16F16::MIN.inc(),
+            I16F16::from_num(-2.5),
+            I16F16::ZERO,
+            I16F16::from_num(1000.125),
+            I16F16::MAX.dec(),
+        ] {
+            assert_eq!(x.inc(), x + I16F16::DELTA);
+            assert_eq!(x.dec(), x - I16F16::DELTA);
+            assert_eq!(x.checked_inc(), Some(x.inc()));
+            assert_eq!(x.checked_dec(), Some(x.dec()));
+            assert_eq!(x.saturating_inc(), x.inc());
+            assert_eq!(x.saturating_dec(), x.dec());
+        }
+
+        assert_eq!(I16F16::MAX.checked_inc(), None);
+        assert_eq!(I16F16::MAX.saturating_inc(), I16F16::MAX);
+        assert_eq!(I16F16::MAX.inc(), I16F16::MIN);
+        assert_eq!(I16F16::MIN.checked_dec(), None);
+        assert_eq!(I16F16::MIN.saturating_dec(), I16F16::MIN);
+        assert_eq!(I16F16::MIN.dec(), I16F16::MAX);
+        assert_eq!(U0F32::MAX.checked_inc(), None);
+        assert_eq!(U0F32::MAX.saturating_inc(), U0F32::MAX);
+        assert_eq!(U0F32::MAX.inc(), U0F32::ZERO);
+        assert_eq!(U0F32::ZERO.checked_dec(), None);
+        assert_eq!(U0F32::ZERO.dec(), U0F32::MAX);
+
+        // walk a grid one step at a time
+        let mut y = U0F32::from_num(0.5);
+        for _ in 0..3 {
+            y = y.inc();
+        }
+        assert_eq!(y, U0F32::from_num(0.5) + U0F32::DELTA * 3);
+    }
+
+    #[test]
+    fn logical_shr() {
+        // -1.5 is 1111_1110.1000_0000 in I8F8
+        let neg = I8F8::from_num(-1.5);
+        assert_eq!(neg >> 1u32, I8F8::from_num(-0.75));
+        assert_eq!(neg.logical_shr(1).to_bits(), 0x7F40);
+        assert_eq!(neg >> 8u32, -I8F8::DELTA * 2);
+        assert_eq!(neg.logical_shr(8), I8F8::from_bits(0xFE));
+        assert_eq!(neg.logical_shr(15), I8F8::DELTA);
+        assert_eq!(neg.logical_shr(0), neg);
+        assert_eq!(I8F8::MIN.logical_shr(15), I8F8::DELTA);
+        assert_eq!(I8F8::MIN >> 15u32, -I8F8::DELTA);
+
+        // for non-negative and unsigned numbers, both shifts are the same
+        let pos = I8F8::from_num(1.5);
+        assert_eq!(pos.logical_shr(3), pos >> 3u32);
+        assert_eq!(U8F8::MAX.logical_shr(5), U8F8::MAX >> 5u32);
+        assert_eq!(
+            <I16F16 as crate::traits::Fixed>::logical_shr(-I16F16::DELTA, 31),
+            I16F16::DELTA
+        );
+    }
+
+    #[test]
+    fn positive_diff() {
+        let (a, b) = (I16F16::from_num(5.5), I16F16::from_num(-1.25));
+        assert_eq!(a.positive_diff(b), 6.75);
+        assert_eq!(b.positive_diff(a), 0);
+        assert_eq!(a.positive_diff(a), 0);
+        assert_eq!(a.checked_positive_diff(b), Some(I16F16::from_num(6.75)));
+        assert_eq!(b.checked_positive_diff(a), Some(I16F16::ZERO));
+
+        // the difference overflows only when it is positive
+        let (max, min) = (I16F16::MAX, I16F16::MIN);
+        assert_eq!(max.checked_positive_diff(min), None);
+        assert_eq!(min.checked_positive_diff(max), Some(I16F16::ZERO));
+        assert_eq!(max.saturating_positive_diff(min), I16F16::MAX);
+        assert_eq!(max.wrapping_positive_diff(min), -I16F16::DELTA);
+        assert_eq!(max.overflowing_positive_diff(min), (-I16F16::DELTA, true));
+        assert_eq!(min.unwrapped_positive_diff(max), I16F16::ZERO);
+        assert_eq!(
+            <I16F16 as crate::traits::Fixed>::saturating_positive_diff(max, -I16F16::DELTA),
+            I16F16::MAX
+        );
+
+        // unsigned numbers never overflow
+        let (a, b) = (U16F16::MAX, U16F16::ZERO);
+        assert_eq!(a.positive_diff(b), U16F16::MAX);
+        assert_eq!(b.positive_diff(a), U16F16::ZERO);
+        assert_eq!(a.overflowing_positive_diff(b), (U16F16::MAX, false));
+        assert_eq!(b.wrapping_positive_diff(a), U16F16::ZERO);
+    }
+
+    #[test]
+    fn signum_int() {
+        assert_eq!(I16F16::from_num(-3.5).signum_int(), -1);
+        assert_eq!(I16F16::ZERO.signum_int(), 0);
+        assert_eq!(I16F16::from_num(3.5).signum_int(), 1);
+        assert_eq!(I16F16::MIN.signum_int(), -1);
+        assert_eq!(U16F16::ZERO.signum_int(), 0);
+        assert_eq!(U16F16::DELTA.signum_int(), 1);
+        assert_eq!(U16F16::MAX.signum_int(), 1);
+
+        // signum would overflow for these, since neither 1 nor -1 fit
+        assert_eq!(I0F32::DELTA.signum_int(), 1);
+        assert_eq!(I0F32::MIN.signum_int(), -1);
+        assert_eq!(I1F31::MAX.signum_int(), 1);
+        assert_eq!(U0F32::MAX.signum_int(), 1);
+        assert_eq!(
+            <I0F32 as crate::traits::Fixed>::signum_int(-I0F32::DELTA),
+            -1
+        );
+    }
+
+    #[test]
+    fn copysign() {
+        let pos = I16F16::from_num(3.25);
+        let neg = I16F16::from_num(-0.75);
+        // negative sign onto a positive value
+        assert_eq!(pos.copysign(neg), -pos);
+        assert_eq!(pos.copysign(I16F16::MIN), -pos);
+        // positive sign onto a negative value
+        assert_eq!(neg.copysign(pos), -neg);
+        assert_eq!(neg.copysign(I16F16::DELTA), -neg);
+        // same sign or zero sign keeps a non-negative value
+        assert_eq!(pos.copysign(pos), pos);
+        assert_eq!(neg.copysign(neg), neg);
+        assert_eq!(neg.copysign(I16F16::ZERO), -neg);
+        assert_eq!(I16F16::ZERO.copysign(neg), I16F16::ZERO);
+        assert_eq!(I16F16::MAX.copysign(neg), -I16F16::MAX);
+
+        // MIN only overflows when it must become non-negative
+        assert_eq!(I16F16::MIN.copysign(neg), I16F16::MIN);
+        assert_eq!(I16F16::MIN.checked_copysign(neg), Some(I16F16::MIN));
+        assert_eq!(I16F16::MIN.checked_copysign(pos), None);
+        assert_eq!(I16F16::MIN.checked_copysign(I16F16::ZERO), None);
+        assert_eq!((-I16F16::MAX).checked_copysign(pos), Some(I16F16::MAX));
+        assert_eq!(pos.checked_copysign(neg), Some(-pos));
+
+        // works for every number of fractional bits
+        assert_eq!(I0F32::DELTA.copysign(I0F32::MIN), -I0F32::DELTA);
+        assert_eq!(I0F32::MIN.checked_copysign(I0F32::DELTA), None);
+        assert_eq!(
+            <I16F16 as crate::traits::FixedSigned>::copysign(pos, neg),
+            -pos
+        );
+    }
+
+    #[test]
+    fn sign() {
+        use crate::{FixedI32, FixedU32};
+        use core::cmp::Ordering;
+
+        assert_eq!(I16F16::MIN.sign(), Ordering::Less);
+        assert_eq!(I16F16::from_num(-3.5).sign(), Ordering::Less);
+        assert_eq!(I16F16::ZERO.sign(), Ordering::Equal);
+        assert_eq!(I16F16::DELTA.sign(), Ordering::Greater);
+        assert_eq!(I16F16::MAX.sign(), Ordering::Greater);
+        assert_eq!(U16F16::ZERO.sign(), Ordering::Equal);
+        assert_eq!(U16F16::MAX.sign(), Ordering::Greater);
+
+        // zero and one integer bits, where signum would overflow
+        assert_eq!(I0F32::MIN.sign(), Ordering::Less);
+        assert_eq!(I0F32::ZERO.sign(), Ordering::Equal);
+        assert_eq!(I0F32::MAX.sign(), Ordering::Greater);
+        assert_eq!(I1F31::MAX.sign(), Ordering::Greater);
+        assert_eq!(U0F32::DELTA.sign(), Ordering::Greater);
+        // negative FRAC and more fractional bits than total bits
+        assert_eq!(FixedI32::<-8>::MIN.sign(), Ordering::Less);
+        assert_eq!(FixedI32::<40>::from_bits(-1).sign(), Ordering::Less);
+        assert_eq!(FixedU32::<40>::DELTA.sign(), Ordering::Greater);
+
+        for x in [I16F16::MIN, -I16F16::DELTA, I16F16::ZERO, I16F16::MAX] {
+            assert_eq!(x.sign(), x.cmp(&I16F16::ZERO));
+            assert_eq!(x.sign() as i32, x.signum_int());
+        }
+        assert_eq!(
+            <I0F32 as crate::traits::Fixed>::sign(-I0F32::DELTA),
+            Ordering::Less
+        );
+    }
+
+    // Returns the bits of the largest representable root, where the root has
+    // bits m << FRAC for FRAC ≥ 0 and bits m for FRAC < 0, using brute force.
+    fn int_sqrt_bits(bits: u32, frac: i32) -> u32 {
+        let shift = frac.unsigned_abs();
+        let mut m = 0u32;
+        while u64::from(m + 1).pow(2) << shift <= u64::from(bits) {
+            m += 1;
+        }
+        if frac >= 0 {
+            m << shift
+        } else {
+            m
+        }
+    }
+
+    macro_rules! check_int_sqrt {
+        ($($FRAC:literal),*) => {$(
+            for bits in u8::MIN..=u8::MAX {
+                let expected = FixedU8::<$FRAC>::from_bits(int_sqrt_bits(u32::from(bits), $FRAC) as u8);
+                assert_eq!(FixedU8::<$FRAC>::from_bits(bits).int_sqrt(), expected);
+            }
+            for bits in i8::MIN..=i8::MAX {
+                let x = FixedI8::<$FRAC>::from_bits(bits);
+                if bits < 0 {
+                    assert_eq!(x.checked_int_sqrt(), None);
+                    continue;
+                }
+                let expected = FixedI8::<$FRAC>::from_bits(int_sqrt_bits(bits as u32, $FRAC) as i8);
+                assert_eq!(x.checked_int_sqrt(), Some(expected));
+            }
+        )*};
+    }
+
+    #[test]
+    fn int_sqrt() {
+        use crate::{types::U128F0, FixedI8, FixedU128, FixedU8};
+        check_int_sqrt!(-9, -8, -7, -3, -1, 0, 1, 4, 7, 8, 9);
+
+        assert_eq!(I16F16::from_num(99.9).int_sqrt(), 9);
+        assert_eq!(I16F16::from_num(100).int_sqrt(), 10);
+        assert_eq!(U16F16::MAX.int_sqrt(), 255);
+        assert_eq!(I0F32::MAX.int_sqrt(), 0);
+        assert_eq!(U128F0::MAX.int_sqrt(), U128F0::from_num(u64::MAX));
+        assert_eq!(
+            U128F0::from_num(u128::from(u64::MAX).pow(2)).int_sqrt(),
+            U128F0::from_num(u64::MAX)
+        );
+        assert_eq!(
+            U128F0::from_num(u128::from(u64::MAX).pow(2) - 1).int_sqrt(),
+            U128F0::from_num(u64::MAX - 1)
+        );
+        assert_eq!(
+            FixedU128::<-64>::MAX.int_sqrt(),
+            FixedU128::<-64>::from_bits(u128::from(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn is_normalized() {
+        use crate::{
+            types::{I0F8, I1F7, I8F0, U0F8, U1F7},
+            FixedI8, FixedU8,
+        };
+
+        // in range
+        assert!(I16F16::ZERO.is_normalized());
+        assert!(I16F16::from_num(0.5).is_normalized());
+        assert!((I16F16::ONE - I16F16::DELTA).is_normalized());
+        assert!(I16F16::NEG_ONE.is_normalized());
+        assert!(U16F16::ZERO.is_normalized());
+        assert!((U16F16::ONE - U16F16::DELTA).is_normalized());
+        assert!(I1F7::MIN.is_normalized());
+        assert!(I1F7::MAX.is_normalized());
+        assert!(I0F8::MIN.is_normalized());
+        assert!(U0F8::MAX.is_normalized());
+        assert!(FixedI8::<10>::MIN.is_normalized());
+        assert!(FixedU8::<10>::MAX.is_normalized());
+        assert!(I8F0::ZERO.is_normalized());
+        assert!(I8F0::NEG_ONE.is_normalized());
+        assert!(FixedI8::<-2>::ZERO.is_normalized());
+
+        // out of range
+        assert!(!I16F16::ONE.is_normalized());
+        assert!(!(I16F16::NEG_ONE - I16F16::DELTA).is_normalized());
+        assert!(!I16F16::MIN.is_normalized());
+        assert!(!I16F16::MAX.is_normalized());
+        assert!(!U16F16::ONE.is_normalized());
+        assert!(!U16F16::MAX.is_normalized());
+        assert!(!U1F7::ONE.is_normalized());
+        assert!(!I8F0::ONE.is_normalized());
+        assert!(!FixedI8::<-2>::from_bits(-1).is_normalized());
+        assert!(!FixedU8::<-2>::from_bits(1).is_normalized());
+    }
+
+    #[test]
+    fn block_leading_zeros() {
+        use crate::types::{I0F8, U8F8};
+
+        // the element with the most significant bits determines the result
+        let buf = [
+            I16F16::from_num(0.125),
+            I16F16::from_num(-3.5),
+            I16F16::from_num(100.25),
+            I16F16::from_num(-0.001),
+            I16F16::ZERO,
+        ];
+        // 100.25 needs 7 integer bits + 1 sign bit
+        assert_eq!(I16F16::block_leading_zeros(&buf), 32 - 8 - 16);
+        let shift = I16F16::block_leading_zeros(&buf);
+        for val in buf {
+            assert_eq!((val << shift) >> shift, val);
+        }
+        // shifting by one more overflows for 100.25
+        assert_ne!((buf[2] << (shift + 1)) >> (shift + 1), buf[2]);
+
+        // negative powers of two need one bit less than positive ones
+        assert_eq!(I16F16::block_leading_zeros(&[I16F16::from_num(-128)]), 8);
+        assert_eq!(I16F16::block_leading_zeros(&[I16F16::from_num(128)]), 7);
+        assert_eq!(I16F16::block_leading_zeros(&[I16F16::MIN, I16F16::MAX]), 0);
+        assert_eq!(I16F16::block_leading_zeros(&[I16F16::NEG_ONE]), 15);
+        assert_eq!(I16F16::block_leading_zeros(&[-I16F16::DELTA]), 31);
+        assert_eq!(I16F16::block_leading_zeros(&[]), 31);
+        assert_eq!(I0F8::block_leading_zeros(&[I0F8::ZERO]), 7);
+
+        let buf = [U8F8::from_num(0.5), U8F8::from_num(3), U8F8::DELTA];
+        assert_eq!(U8F8::block_leading_zeros(&buf), 6);
+        assert_eq!(U8F8::block_leading_zeros(&[U8F8::MAX]), 0);
+        assert_eq!(U8F8::block_leading_zeros(&[U8F8::ZERO]), 16);
+        assert_eq!(U8F8::block_leading_zeros(&[]), 16);
+    }
+
+    #[test]
+    fn to_bits_be_le() {
+        use crate::types::U44F20;
+
+        let f = I16F16::from_bits(0x1234_5678);
+        assert_eq!(f.to_bits_be().to_ne_bytes(), [0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(f.to_bits_le().to_ne_bytes(), [0x78, 0x56, 0x34, 0x12]);
+        assert_eq!(f.to_bits_be(), f.to_be().to_bits());
+        assert_eq!(f.to_bits_le(), f.to_le().to_bits());
+        assert_eq!(i32::from_be(f.to_bits_be()), f.to_bits());
+        assert_eq!(i32::from_le(f.to_bits_le()), f.to_bits());
+        assert_eq!(I16F16::from_be(I16F16::from_bits(f.to_bits_be())), f);
+
+        let f = U44F20::from_bits(0x0102_0304_0506_0708);
+        assert_eq!(
+            f.to_bits_be().to_ne_bytes(),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+        );
+        assert_eq!(
+            f.to_bits_le().to_ne_bytes(),
+            [0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01]
+        );
+        if cfg!(target_endian = "little") {
+            assert_eq!(f.to_bits_le(), f.to_bits());
+            assert_eq!(f.to_bits_be(), 0x0807_0605_0403_0201);
+        } else {
+            assert_eq!(f.to_bits_be(), f.to_bits());
+            assert_eq!(f.to_bits_le(), 0x0807_0605_0403_0201);
+        }
+    }
+
+    #[test]
+    fn to_canonical_bytes() {
+        use crate::types::{I120F8, I4F4, I8F8, U4F4};
+        use crate::{FixedI16, FixedI8};
+
+        let mut expected = [0; 16];
+        expected[15] = 0x12;
+        assert_eq!(I4F4::from_bits(0x12).to_canonical_bytes(), expected);
+        assert_eq!(U4F4::from_bits(0x12).to_canonical_bytes(), expected);
+        expected[14] = 0x12;
+        expected[15] = 0x34;
+        assert_eq!(I8F8::from_bits(0x1234).to_canonical_bytes(), expected);
+        expected[12..].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(
+            I16F16::from_bits(0x1234_5678).to_canonical_bytes(),
+            expected
+        );
+        let bits = 0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10;
+        assert_eq!(
+            I120F8::from_bits(bits).to_canonical_bytes(),
+            bits.to_be_bytes()
+        );
+
+        // the width, the number of fractional bits and the signedness are
+        // not encoded, so numbers of different types can collide
+        assert_eq!(
+            FixedI8::<0>::from_num(-1).to_canonical_bytes(),
+            FixedI16::<0>::from_num(255).to_canonical_bytes()
+        );
+        assert_eq!(
+            I4F4::from_num(1.0 / 16.0).to_canonical_bytes(),
+            I8F8::from_num(1.0 / 256.0).to_canonical_bytes()
+        );
+
+        // negative numbers are zero-padded, so they differ between widths
+        let bytes = [
+            I4F4::NEG_ONE.to_canonical_bytes(),
+            I8F8::NEG_ONE.to_canonical_bytes(),
+            I16F16::NEG_ONE.to_canonical_bytes(),
+            I120F8::NEG_ONE.to_canonical_bytes(),
+        ];
+        assert_eq!(bytes[0][..15], [0; 15]);
+        assert_eq!(bytes[0][15], 0xF0);
+        assert_eq!(bytes[1][..14], [0; 14]);
+        assert_eq!(bytes[1][14..], [0xFF, 0x00]);
+        assert_eq!(bytes[2][..12], [0; 12]);
+        assert_eq!(bytes[2][12..], [0xFF, 0xFF, 0x00, 0x00]);
+        assert_eq!(bytes[3][..15], [0xFF; 15]);
+        for i in 0..bytes.len() {
+            for j in i + 1..bytes.len() {
+                assert_ne!(bytes[i], bytes[j]);
+            }
+        }
+
+        // distinct values of a type give distinct bytes
+        for a in i8::MIN..=i8::MAX {
+            let canonical = I4F4::from_bits(a).to_canonical_bytes();
+            assert_eq!(canonical[..15], [0; 15]);
+            assert_eq!(canonical[15], a as u8);
+        }
+    }
+
+    #[test]
+    fn shift_block_left() {
+        use crate::types::{I0F8, U8F8};
+
+        let orig = [
+            I16F16::from_num(0.125),
+            I16F16::from_num(-3.5),
+            I16F16::from_num(100.25),
+            I16F16::ZERO,
+            I16F16::from_num(-128),
+        ];
+        let shift = I16F16::block_leading_zeros(&orig);
+        let mut buf = orig;
+        I16F16::shift_block_left(&mut buf, shift);
+        for (val, orig) in buf.iter().zip(orig) {
+            assert_eq!(*val, orig << shift);
+        }
+        assert_eq!(I16F16::block_leading_zeros(&buf), 0);
+
+        // shifting by one more saturates 100.25 and −128
+        let mut buf = orig;
+        I16F16::shift_block_left(&mut buf, shift + 1);
+        assert_eq!(buf[0], orig[0] << (shift + 1));
+        assert_eq!(buf[1], orig[1] << (shift + 1));
+        assert_eq!(buf[2], I16F16::MAX);
+        assert_eq!(buf[3], I16F16::ZERO);
+        assert_eq!(buf[4], I16F16::MIN);
+
+        // large shifts saturate all but zero
+        let mut buf = orig;
+        I16F16::shift_block_left(&mut buf, 100);
+        assert_eq!(
+            buf,
+            [
+                I16F16::MAX,
+                I16F16::MIN,
+                I16F16::MAX,
+                I16F16::ZERO,
+                I16F16::MIN
+            ]
+        );
+
+        let mut buf = [I0F8::from_bits(-1), I0F8::from_bits(1)];
+        I0F8::shift_block_left(&mut buf, 6);
+        assert_eq!(buf, [I0F8::from_bits(-1 << 6), I0F8::from_bits(1 << 6)]);
+        I0F8::shift_block_left(&mut buf, 0);
+        assert_eq!(buf, [I0F8::from_bits(-1 << 6), I0F8::from_bits(1 << 6)]);
+        // −1 << 7 is exactly MIN, but 1 << 7 overflows
+        I0F8::shift_block_left(&mut buf, 1);
+        assert_eq!(buf, [I0F8::MIN, I0F8::MAX]);
+
+        let mut buf = [U8F8::from_num(0.5), U8F8::from_num(3), U8F8::DELTA];
+        U8F8::shift_block_left(&mut buf, 6);
+        assert_eq!(
+            buf,
+            [
+                U8F8::from_num(32),
+                U8F8::from_num(192),
+                U8F8::from_bits(1 << 6)
+            ]
+        );
+        U8F8::shift_block_left(&mut buf, 1);
+        assert_eq!(
+            buf,
+            [U8F8::from_num(64), U8F8::MAX, U8F8::from_bits(1 << 7)]
+        );
+        let mut empty: [U8F8; 0] = [];
+        U8F8::shift_block_left(&mut empty, 3);
+    }
+
+    #[test]
+    fn from_bit_array() {
+        // 1010.1100 = 10.75 for U4F4, and -5.25 for I4F4
+        let bits = [true, false, true, false, true, true, false, false];
+        assert_eq!(U4F4::from_bit_array(bits), 10.75);
+        assert_eq!(I4F4::from_bit_array(bits), -5.25);
+        assert_eq!(U4F4::from_bit_array([false; 8]), U4F4::ZERO);
+        assert_eq!(I4F4::from_bit_array([true; 8]), -I4F4::DELTA);
+
+        // register with the sign bit and the lowest bit set
+        let mut bits = [false; 32];
+        bits[0] = true;
+        bits[31] = true;
+        assert_eq!(I16F16::from_bit_array(bits), I16F16::MIN + I16F16::DELTA);
+        assert_eq!(U16F16::from_bit_array(bits), U16F16::from_bits(0x8000_0001));
+
+        // round trip through the binary representation
+        let x = I32F32::from_num(-1234.5678);
+        let mut bits = [false; 64];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = x.to_bits() & (1 << (63 - i)) != 0;
+        }
+        assert_eq!(I32F32::from_bit_array(bits), x);
+    }
+
+    #[test]
+    fn from_bits_masked() {
+        // 4-bit reserved header in the top bits of a packed 16-bit word
+        let reserved = 0xF000u16;
+        let clean = 0x0ABCu16;
+        assert_eq!(
+            U8F8::from_bits_masked(clean, reserved),
+            Some(U8F8::from_bits(clean))
+        );
+        assert_eq!(U8F8::from_bits_masked(clean | 0x1000, reserved), None);
+        assert_eq!(U8F8::from_bits_masked(0xFFFF, reserved), None);
+        assert_eq!(U8F8::from_bits_masked(0xFFFF, 0), Some(U8F8::MAX));
+
+        // for signed numbers the sign bit can be reserved
+        let reserved = i32::MIN;
+        assert_eq!(
+            I16F16::from_bits_masked(0x7FFF_FFFF, reserved),
+            Some(I16F16::MAX)
+        );
+        assert_eq!(I16F16::from_bits_masked(-1, reserved), None);
+        assert_eq!(I16F16::from_bits_masked(0, -1), Some(I16F16::ZERO));
+        assert_eq!(
+            <I16F16 as crate::traits::Fixed>::from_bits_masked(1, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn wide_mul_add_dot_product() {
+        // exact dot product, compared with the sum of exact products
+        let xs = [0.5, -1.25, 3.0625, -7.9375, 0.0625, 7.9375, -8.0];
+        let ys = [7.5, 7.9375, -0.0625, -8.0, 0.0625, 7.9375, 0.5];
+        let mut acc = I8F8::ZERO;
+        let mut expected = 0.0f64;
+        for (&x, &y) in xs.iter().zip(&ys) {
+            let (a, b) = (I4F4::from_num(x), I4F4::from_num(y));
+            acc = a.wide_mul_add(b, acc);
+            expected += x * y;
+        }
+        assert_eq!(acc, expected);
+        assert_eq!(acc.to_bits(), (expected * 256.0) as i16);
+
+        // many small products that would be lost if rounded to I16F16
+        let tiny = I16F16::DELTA;
+        let mut acc = I32F32::ZERO;
+        for _ in 0..1000 {
+            acc = tiny.wide_mul_add(tiny, acc);
+        }
+        assert_eq!(acc.to_bits(), 1000);
+        assert_eq!(I16F16::from_num(acc), I16F16::ZERO);
+
+        // unsigned, accumulating at the top of the range
+        let a = U8F8::MAX;
+        let acc = a.wide_mul_add(a, U16F16::ZERO);
+        assert_eq!(acc, a.wide_mul(a));
+        assert_eq!(
+            U8F8::ONE.wide_mul_add(U8F8::DELTA, acc).to_bits(),
+            acc.to_bits() + 0x100
+        );
+        assert_eq!(
+            U32F32::MAX.wide_mul_add(U32F32::ONE, U64F64::ZERO),
+            U64F64::from_num(U32F32::MAX)
+        );
+    }
+
+    #[test]
+    fn wide_mul_int() {
+        // the same-width product overflows, but the wide product fits
+        let x = I16F16::from_num(1000.5);
+        assert_eq!(x.checked_mul_int(100), None);
+        assert_eq!(x.wide_mul_int(100), 100_050);
+        assert_eq!(x.wide_mul_int(-100), -100_050);
+        assert_eq!(
+            I16F16::MIN.wide_mul_int(i32::MIN).to_bits(),
+            i64::from(i32::MIN) * i64::from(i32::MIN)
+        );
+        assert_eq!(
+            I16F16::MAX.wide_mul_int(i32::MIN).to_bits(),
+            i64::from(i32::MAX) * i64::from(i32::MIN)
+        );
+        let y = U8F8::MAX;
+        assert_eq!(y.checked_mul_int(2), None);
+        assert_eq!(y.wide_mul_int(u16::MAX).to_bits(), 0xFFFF * 0xFFFF);
+        assert_eq!(
+            U32F32::MAX.wide_mul_int(u64::MAX).to_bits(),
+            u128::from(u64::MAX) * u128::from(u64::MAX)
+        );
+        // the wide product is exact, so it agrees with the overflowing one in
+        // the low bits
+        let (wrapped, overflow) = x.overflowing_mul_int(100);
+        assert!(overflow);
+        assert_eq!(wrapped.to_bits(), x.wide_mul_int(100).to_bits() as i32);
+    }
+
+    #[test]
+    fn const_widen_narrow_inner() {
+        use crate::{FixedI16, FixedI32, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64};
+
+        // a constant table that mixes widths
+        const SMALL: [FixedI16<8>; 3] = [
+            FixedI16::<8>::MIN,
+            FixedI16::<8>::from_bits(-0x180),
+            FixedI16::<8>::MAX,
+        ];
+        const WIDE: [FixedI32<8>; 3] = [
+            SMALL[0].const_widen_inner(),
+            SMALL[1].const_widen_inner(),
+            SMALL[2].const_widen_inner(),
+        ];
+        assert_eq!(WIDE[0], -128);
+        assert_eq!(WIDE[1], -1.5);
+        assert_eq!(WIDE[2], FixedI32::<8>::from_bits(0x7FFF));
+        const BACK: [Option<FixedI16<8>>; 3] = [
+            WIDE[0].const_narrow_inner(),
+            WIDE[1].const_narrow_inner(),
+            WIDE[2].const_narrow_inner(),
+        ];
+        assert_eq!(BACK, SMALL.map(Some));
+
+        // narrowing overflows outside the narrow range
+        const TOO_LOW: Option<FixedI16<8>> = FixedI32::<8>::from_bits(-0x8001).const_narrow_inner();
+        const TOO_HIGH: Option<FixedI16<8>> = FixedI32::<8>::from_bits(0x8000).const_narrow_inner();
+        assert_eq!(TOO_LOW, None);
+        assert_eq!(TOO_HIGH, None);
+        assert_eq!(
+            FixedI16::<8>::MAX.const_widen_inner().const_narrow_inner(),
+            Some(FixedI16::MAX)
+        );
+
+        // unsigned numbers are zero-extended
+        const U: FixedU64<8> = FixedU32::<8>::MAX.const_widen_inner();
+        assert_eq!(U.to_bits(), u64::from(u32::MAX));
+        assert_eq!(U.const_narrow_inner(), Some(FixedU32::<8>::MAX));
+        assert_eq!((U + FixedU64::DELTA).const_narrow_inner(), None);
+        assert_eq!(
+            FixedU16::<16>::MAX.const_widen_inner().const_narrow_inner(),
+            Some(FixedU16::<16>::MAX)
+        );
+        assert_eq!(
+            FixedU128::<0>::from_bits(u128::from(u64::MAX)).const_narrow_inner(),
+            Some(FixedU64::<0>::MAX)
+        );
+        assert_eq!(FixedU128::<0>::MAX.const_narrow_inner(), None);
+        // FRAC does not need to be in the usual range
+        assert_eq!(
+            FixedI8::<-2>::from_bits(-3).const_widen_inner(),
+            FixedI16::<-2>::from_bits(-3)
+        );
+        assert_eq!(
+            FixedI16::<20>::from_bits(-200).const_narrow_inner(),
+            None::<FixedI8<20>>
+        );
+    }
+
+    #[test]
+    fn square() {
+        use crate::types::{I1F7, I4F4, U0F8};
+        assert_eq!(I16F16::from_num(-1.5).square(), 2.25);
+        assert_eq!(
+            I16F16::from_num(181).checked_square(),
+            Some(I16F16::from_num(32761))
+        );
+        assert_eq!(I16F16::from_num(182).checked_square(), None);
+        assert_eq!(I16F16::MIN.saturating_square(), I16F16::MAX);
+        assert_eq!(
+            I16F16::MIN.overflowing_square(),
+            I16F16::MIN.overflowing_mul(I16F16::MIN)
+        );
+        assert_eq!(
+            I4F4::MIN.wrapping_square(),
+            I4F4::MIN.wrapping_mul(I4F4::MIN)
+        );
+        // -1 × -1 overflows for I1F7, which cannot represent 1
+        assert_eq!(I1F7::MIN.overflowing_square(), (I1F7::MIN, true));
+        assert_eq!(U0F8::MAX.square(), U0F8::from_bits(0xFE));
+        for bits in i8::MIN..=i8::MAX {
+            let x = I4F4::from_bits(bits);
+            assert_eq!(x.overflowing_square(), x.overflowing_mul(x));
+            assert_eq!(x.checked_square(), x.checked_mul(x));
+            assert_eq!(x.saturating_square(), x.saturating_mul(x));
+            assert_eq!(x.wide_square(), x.wide_mul(x));
+        }
+    }
+
+    #[test]
+    fn wide_square() {
+        use crate::types::{I0F8, I4F4, U32F32, U8F8};
+        for x in [I16F16::MIN, I16F16::MAX, I16F16::DELTA, -I16F16::DELTA] {
+            assert_eq!(x.wide_square(), x.wide_mul(x));
+        }
+        for x in [U8F8::MIN, U8F8::MAX] {
+            assert_eq!(x.wide_square(), x.wide_mul(x));
+        }
+        for x in [U32F32::MIN, U32F32::MAX] {
+            assert_eq!(x.wide_square(), x.wide_mul(x));
+        }
+        assert_eq!(I16F16::MIN.wide_square().to_bits(), 1 << 62);
+        assert_eq!(I0F8::MIN.wide_square(), 0.25);
+        assert_eq!(I4F4::MIN.wide_square(), 64);
+        // sums of squares fit even when each square overflows the narrow type
+        let (a, b) = (I16F16::from_num(30000), I16F16::from_num(-20000));
+        assert_eq!(a.checked_square(), None);
+        let sum = a.wide_square() + b.wide_square();
+        assert_eq!(sum, 1_300_000_000);
+    }
+
+    #[test]
+    fn wide_neg() {
+        use crate::types::{I0F64, I8F0, U0F64};
+        assert_eq!(I16F16::from_num(-2.5).wide_neg(), 2.5);
+        assert_eq!(I16F16::from_num(2.5).wide_neg(), -2.5);
+        assert_eq!(I16F16::ZERO.wide_neg(), 0);
+        // -MIN overflows, but the wide negation fits
+        assert_eq!(I16F16::MIN.checked_neg(), None);
+        assert_eq!(I16F16::MIN.wide_neg().to_bits(), -i64::from(i32::MIN));
+        assert_eq!(I16F16::MIN.wide_neg(), 32768);
+        assert_eq!(I8F0::MIN.wide_neg(), 128);
+        assert_eq!(I0F64::MIN.wide_neg().to_bits(), -i128::from(i64::MIN));
+        // unsigned numbers negate into the wider signed type
+        assert_eq!(U8F8::MAX.wide_neg().to_bits(), -i32::from(u16::MAX));
+        assert_eq!(U8F8::from_num(1.25).wide_neg(), -1.25);
+        assert_eq!(U0F64::MAX.wide_neg().to_bits(), -i128::from(u64::MAX));
+    }
+
     #[test]
     fn wide_mul_mixed() {
         // +7FFF.FFFF * 7FFF.FFFF = +3FFF_FFFE.0000_0001
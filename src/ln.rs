@@ -0,0 +1,401 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::exp::{self, EXP2_TABLE, LN_2, LOG2_E};
+use crate::int256::{self, U256};
+use core::num::NonZeroU128;
+
+// log10(2) with 128 fractional bits, rounded to the nearest
+const LOG10_2: u128 = 0x4d10_4d42_7de7_fbcc_47c4_acd6_05be_48bc;
+
+// Returns log2(m / 2^127) with 128 fractional bits, where m ≥ 2^127.
+const fn log2_mantissa(m: u128) -> u128 {
+    // Find the product acc of the table entries 2^(2^−(i + 1)) that gives the
+    // top 32 bits of the logarithm.
+    let mut acc = 1 << 127;
+    let mut log: u128 = 0;
+    let mut i = 0;
+    while i < EXP2_TABLE.len() {
+        let next = exp::mul(acc, EXP2_TABLE[i]);
+        if next <= m {
+            acc = next;
+            log |= 1 << (127 - i);
+        }
+        i += 1;
+    }
+    // Now m / acc = 1 + r, where r < 2^−32, so ln(1 + r) can be computed
+    // using r − r^2/2 + r^3/3 with an error < 2^−130.
+    // SAFETY: acc ≥ 2^127, so it is not zero.
+    let acc = unsafe { NonZeroU128::new_unchecked(acc) };
+    let num = U256 {
+        lo: 0,
+        hi: m - acc.get(),
+    };
+    let r = int256::div_rem_u256_u128(num, acc).0.lo;
+    let r2 = int256::wide_mul_u128(r, r).hi;
+    let r3 = int256::wide_mul_u128(r2, r).hi;
+    let ln = r - r2 / 2 + r3 / 3;
+    // log2(1 + r) = ln(1 + r) × log2(e), where LOG2_E.hi has 127 fractional bits
+    let prod = int256::wide_mul_u128(ln, LOG2_E.hi);
+    log.saturating_add((prod.hi << 1) | (prod.lo >> 127))
+}
+
+// Returns log2(bits / 2^frac_nbits) as a sign and an absolute value with 128
+// fractional bits, where bits > 0 and frac_nbits ≤ 128.
+const fn log2(bits: u128, frac_nbits: u32) -> (bool, U256) {
+    let lz = bits.leading_zeros();
+    let frac = log2_mantissa(bits << lz);
+    let int = 127 - lz as i32 - frac_nbits as i32;
+    if int >= 0 {
+        (
+            false,
+            U256 {
+                lo: frac,
+                hi: int as u128,
+            },
+        )
+    } else if frac == 0 {
+        (
+            true,
+            U256 {
+                lo: 0,
+                hi: int.unsigned_abs() as u128,
+            },
+        )
+    } else {
+        (
+            true,
+            U256 {
+                lo: frac.wrapping_neg(),
+                hi: (int.unsigned_abs() - 1) as u128,
+            },
+        )
+    }
+}
+
+// Multiplies abs by factor, both with 128 fractional bits, where factor < 1.
+const fn scale(abs: U256, factor: u128) -> U256 {
+    let int = int256::wide_mul_u128(abs.hi, factor);
+    let frac = int256::wide_mul_u128(abs.lo, factor);
+    int256::wrapping_add_u256_u128(int, frac.hi + (frac.lo >> 127))
+}
+
+const fn leading_zeros(a: U256) -> u32 {
+    if a.hi != 0 {
+        a.hi.leading_zeros()
+    } else {
+        128 + a.lo.leading_zeros()
+    }
+}
+
+// Returns the top 128 bits of a, where lz is the number of leading zeros of a.
+const fn top(a: U256, lz: u32) -> u128 {
+    if lz == 0 {
+        a.hi
+    } else if lz < 128 {
+        (a.hi << lz) | (a.lo >> (128 - lz))
+    } else {
+        a.lo << (lz - 128)
+    }
+}
+
+// Returns a × 2^shift rounded to the nearest, with ties rounded up, and
+// whether the result fits in a u128.
+const fn shift_round(a: U256, shift: i32) -> (u128, bool) {
+    if shift >= 0 {
+        let shift = shift as u32;
+        let fits = leading_zeros(a) >= 128 + shift;
+        let wrapped = if shift < 128 { a.lo << shift } else { 0 };
+        return (wrapped, fits);
+    }
+    let shift = shift.unsigned_abs();
+    if shift > 256 {
+        return (0, true);
+    }
+    let (shifted, fits) = if shift >= 256 {
+        (0, true)
+    } else if shift >= 128 {
+        (a.hi >> (shift - 128), true)
+    } else {
+        (
+            (a.lo >> shift) | (a.hi << (128 - shift)),
+            a.hi >> shift == 0,
+        )
+    };
+    let round_bit = shift - 1;
+    let round = if round_bit >= 128 {
+        (a.hi >> (round_bit - 128)) & 1
+    } else {
+        (a.lo >> round_bit) & 1
+    };
+    let (rounded, carry) = shifted.overflowing_add(round);
+    (rounded, fits && !carry)
+}
+
+// Returns the wrapped value of ±abs and whether it overflows for $Inner, where
+// rounded is the result of shift_round.
+macro_rules! finish {
+    ($Inner:ident, $neg:expr, $rounded:expr) => {{
+        let neg = $neg;
+        let (abs, fits): (u128, bool) = $rounded;
+        let max = if !neg {
+            $Inner::MAX as u128
+        } else if $Inner::MIN == 0 {
+            0
+        } else {
+            $Inner::MAX as u128 + 1
+        };
+        let wrapped = if neg { abs.wrapping_neg() } else { abs };
+        (wrapped as $Inner, !fits || abs > max)
+    }};
+}
+
+macro_rules! impl_ln {
+    ($Inner:ident) => {
+        // Returns ln(val / 2^frac_nbits), where val > 0 and frac_nbits ≤ 128,
+        // and a bool indicating overflow.
+        pub const fn $Inner(val: $Inner, frac_nbits: u32) -> ($Inner, bool) {
+            let (neg, abs) = log2(val as u128, frac_nbits);
+            let abs = scale(abs, LN_2);
+            finish!($Inner, neg, shift_round(abs, frac_nbits as i32 - 128))
+        }
+    };
+}
+
+impl_ln! { i8 }
+impl_ln! { i16 }
+impl_ln! { i32 }
+impl_ln! { i64 }
+impl_ln! { i128 }
+impl_ln! { u8 }
+impl_ln! { u16 }
+impl_ln! { u32 }
+impl_ln! { u64 }
+impl_ln! { u128 }
+
+pub mod log2 {
+    use crate::ln::shift_round;
+
+    macro_rules! impl_log2 {
+        ($Inner:ident) => {
+            // Returns log2(val / 2^frac_nbits), where val > 0 and frac_nbits ≤
+            // 128, and a bool indicating overflow.
+            pub const fn $Inner(val: $Inner, frac_nbits: u32) -> ($Inner, bool) {
+                let (neg, abs) = super::log2(val as u128, frac_nbits);
+                finish!($Inner, neg, shift_round(abs, frac_nbits as i32 - 128))
+            }
+        };
+    }
+
+    impl_log2! { i8 }
+    impl_log2! { i16 }
+    impl_log2! { i32 }
+    impl_log2! { i64 }
+    impl_log2! { i128 }
+    impl_log2! { u8 }
+    impl_log2! { u16 }
+    impl_log2! { u32 }
+    impl_log2! { u64 }
+    impl_log2! { u128 }
+}
+
+pub mod log10 {
+    use crate::ln::{scale, shift_round, LOG10_2};
+
+    macro_rules! impl_log10 {
+        ($Inner:ident) => {
+            // Returns log10(val / 2^frac_nbits), where val > 0 and frac_nbits
+            // ≤ 128, and a bool indicating overflow.
+            pub const fn $Inner(val: $Inner, frac_nbits: u32) -> ($Inner, bool) {
+                let (neg, abs) = super::log2(val as u128, frac_nbits);
+                let abs = scale(abs, LOG10_2);
+                finish!($Inner, neg, shift_round(abs, frac_nbits as i32 - 128))
+            }
+        };
+    }
+
+    impl_log10! { i8 }
+    impl_log10! { i16 }
+    impl_log10! { i32 }
+    impl_log10! { i64 }
+    impl_log10! { i128 }
+    impl_log10! { u8 }
+    impl_log10! { u16 }
+    impl_log10! { u32 }
+    impl_log10! { u64 }
+    impl_log10! { u128 }
+}
+
+// Returns log2(val) / log2(base) as a sign, a quotient with 128 fractional bits
+// and the power of two by which to multiply it, where val > 0 and base > 0, or
+// None if the base is one.
+const fn log_base(val: u128, base: u128, frac_nbits: u32) -> Option<(bool, U256, i32)> {
+    let (neg, num) = log2(val, frac_nbits);
+    let (base_neg, den) = log2(base, frac_nbits);
+    let num_lz = leading_zeros(num);
+    let den_lz = leading_zeros(den);
+    if den_lz == 256 {
+        return None;
+    }
+    if num_lz == 256 {
+        return Some((false, U256 { lo: 0, hi: 0 }, 0));
+    }
+    let num = U256 {
+        lo: 0,
+        hi: top(num, num_lz),
+    };
+    // SAFETY: den is not zero, so its top bits are not zero.
+    let den = unsafe { NonZeroU128::new_unchecked(top(den, den_lz)) };
+    // num / den ≥ 1/2, so the quotient with 128 fractional bits has at least
+    // 127 significant bits
+    let quot = int256::div_rem_u256_u128(num, den).0;
+    Some((neg != base_neg, quot, den_lz as i32 - num_lz as i32))
+}
+
+pub mod log {
+    use crate::ln::shift_round;
+
+    macro_rules! impl_log {
+        ($Inner:ident) => {
+            // Returns the logarithm of val / 2^frac_nbits to base base /
+            // 2^frac_nbits, where val > 0, base > 0 and frac_nbits ≤ 128, and a
+            // bool indicating overflow, or None if the base is one.
+            pub const fn $Inner(
+                val: $Inner,
+                base: $Inner,
+                frac_nbits: u32,
+            ) -> Option<($Inner, bool)> {
+                match super::log_base(val as u128, base as u128, frac_nbits) {
+                    Some((neg, quot, shift)) => {
+                        let shift = shift + frac_nbits as i32 - 128;
+                        Some(finish!($Inner, neg, shift_round(quot, shift)))
+                    }
+                    None => None,
+                }
+            }
+        };
+    }
+
+    impl_log! { i8 }
+    impl_log! { i16 }
+    impl_log! { i32 }
+    impl_log! { i64 }
+    impl_log! { i128 }
+    impl_log! { u8 }
+    impl_log! { u16 }
+    impl_log! { u32 }
+    impl_log! { u64 }
+    impl_log! { u128 }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F8, I16F16, I32F32, I4F124, I64F64, I8F8, U0F8, U16F16, U32F32};
+    use crate::{FixedI128, FixedU128};
+
+    // Checks ln, log2, log10 and log against f64 for inputs spanning many
+    // octaves. The error must be less than one DELTA plus the error of the
+    // conversion to f64 and of the f64 functions themselves, and overflow must only be reported when the
+    // result is out of range.
+    macro_rules! check_octaves {
+        ($Fix:ty, $step:expr) => {
+            let delta = <$Fix>::DELTA.to_num::<f64>();
+            let min = <$Fix>::MIN.to_num::<f64>();
+            let max = <$Fix>::MAX.to_num::<f64>();
+            let base = <$Fix>::from_num(3);
+            let mut fix = <$Fix>::DELTA;
+            while let Some(next) = fix.checked_add(<$Fix>::from_bits(fix.to_bits() / $step + 1)) {
+                let f = fix.to_num::<f64>();
+                for (name, val, expected) in [
+                    ("ln", fix.checked_ln(), f.ln()),
+                    ("log2", fix.checked_log2(), f.log2()),
+                    ("log10", fix.checked_log10(), f.log10()),
+                    ("log", fix.checked_log(base), f.ln() / 3f64.ln()),
+                ] {
+                    // f may have been rounded, which adds an absolute error
+                    // to the logarithm even when the result is small
+                    let tol = delta + (expected.abs() + 1.0) * 4.0 * f64::EPSILON;
+                    match val {
+                        Some(val) => {
+                            let err = (val.to_num::<f64>() - expected).abs();
+                            assert!(err < tol, "{name}({fix}) = {val}, error {err}");
+                        }
+                        None => assert!(
+                            expected < min + tol || expected > max - tol,
+                            "{name}({fix}) overflowed"
+                        ),
+                    }
+                }
+                fix = next;
+            }
+        };
+    }
+
+    #[test]
+    fn octaves() {
+        check_octaves!(I8F8, 50);
+        check_octaves!(I16F16, 500);
+        check_octaves!(U16F16, 500);
+        check_octaves!(I32F32, 1000);
+        check_octaves!(U32F32, 1000);
+        check_octaves!(I64F64, 1000);
+        check_octaves!(I4F124, 1000);
+    }
+
+    #[test]
+    fn exact() {
+        for i in -32..31 {
+            let fix = I32F32::from_bits(1 << (32 + i));
+            assert_eq!(fix.log2(), I32F32::from_num(i));
+            assert_eq!(fix.log(I32F32::from_num(2)), I32F32::from_num(i));
+        }
+        assert_eq!(I16F16::ONE.ln(), I16F16::ZERO);
+        assert_eq!(U16F16::ONE.log10(), U16F16::ZERO);
+        assert_eq!(I16F16::from_num(1000).log10(), I16F16::from_num(3));
+        assert_eq!(
+            I16F16::from_num(0.125).log(I16F16::from_num(0.5)),
+            I16F16::from_num(3)
+        );
+        assert_eq!(
+            FixedU128::<127>::ONE.checked_log2(),
+            Some(FixedU128::<127>::ZERO)
+        );
+        assert_eq!(FixedI128::<0>::MAX.log2(), FixedI128::<0>::from_num(127));
+        assert_eq!(FixedI128::<128>::DELTA.checked_log2(), None);
+        assert_eq!(FixedI128::<120>::DELTA.log2(), -120);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(I16F16::ZERO.checked_ln(), None);
+        assert_eq!(I16F16::NEG_ONE.checked_log2(), None);
+        assert_eq!(U16F16::ZERO.checked_log10(), None);
+        assert_eq!(I16F16::ONE.checked_log(I16F16::ONE), None);
+        assert_eq!(I16F16::ONE.checked_log(I16F16::ZERO), None);
+        assert_eq!(I16F16::ONE.checked_log(I16F16::NEG_ONE), None);
+        assert_eq!(I16F16::ZERO.checked_log(I16F16::from_num(2)), None);
+
+        // negative results cannot be represented by unsigned numbers
+        assert_eq!(U16F16::from_num(0.5).checked_ln(), None);
+        assert_eq!(U0F8::MAX.checked_log2(), None);
+        // log2(0.25) = −2 needs integer bits
+        assert_eq!(I0F8::from_num(0.25).checked_log2(), None);
+        assert_eq!(
+            I0F8::from_num(0.25).checked_log(I0F8::DELTA),
+            Some(I0F8::from_num(0.25))
+        );
+        assert_eq!(I0F8::from_num(0.25).checked_log10(), None);
+    }
+}
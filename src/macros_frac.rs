@@ -18,6 +18,7 @@ macro_rules! fixed_frac {
         {Self, Inner} = {$Self:ident, $Inner:ident},
         Signedness = $Signedness:ident,
         {nm4, nm1, n} = {$nm4:literal, $nm1:literal, $n:literal},
+        nc2 = $nc2:literal,
         {USelf, UInner} = {$USelf:ident, $UInner:ident},
         NonZeroUInner = $NonZeroUInner:ident,
     ) => {
@@ -184,6 +185,11 @@ can be used to start an exponent, which is then followed by an optional sign
 “`+`” or “`-`”, and then by a decimal integer which is the exponent. The parsed
 value is scaled by 10 to the power of the exponent.
 
+The time taken does not depend on the magnitude of the exponent. An exponent
+that is too large for the value to be represented results in an overflow error,
+and a very negative exponent rounds the value to zero. An exponent that does
+not fit in an [`i32`] results in an error even if the digits are all zero.
+
 # Examples
 
 ```rust
@@ -201,6 +207,8 @@ assert_eq!(Fix::from_str("1.75"), Ok(Fix::from_num(1.75)));
                 },
                 r#"assert_eq!(Fix::from_str("0.00625E+3"), Ok(Fix::from_num(6.25)));
 assert_eq!(Fix::from_str("1.25e-1"), Ok(Fix::from_num(0.125)));
+assert!(Fix::from_str("1e1000").is_err());
+assert_eq!(Fix::from_str("1e-1000"), Ok(Fix::ZERO));
 ```
 "#;
                 #[inline]
@@ -346,6 +354,185 @@ assert_eq!(Fix::from_str_hex(".01C@+2"), Ok(Fix::from_num(1.75)));
                 }
             }
 
+            comment! {
+                "Parses a string slice containing a decimal percentage to return a
+fixed-point number.
+
+The string must end with a percent sign “`%`”, which is preceded by a decimal
+number in the same format accepted by [`from_str`][Self::from_str]. The number
+is divided by 100, and rounding is to the nearest, with ties rounded to even.
+Since the division is performed before rounding, the result is rounded only
+once.
+
+# Errors
+
+Returns an error if the string does not end with a percent sign, if the number
+is malformed, or if the value does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<4>;
+assert_eq!(Fix::from_percent_str("50%"), Ok(Fix::from_num(0.5)));
+assert_eq!(Fix::from_percent_str("12.5%"), Ok(Fix::from_num(0.125)));
+"#,
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    r#"assert_eq!(Fix::from_percent_str("-175%"), Ok(Fix::from_num(-1.75)));
+"#,
+                },
+                r#"assert!(Fix::from_percent_str("50").is_err());
+```
+"#;
+                #[inline]
+                pub const fn from_percent_str(src: &str) -> Result<$Self<FRAC>, ParseFixedError> {
+                    match from_str::$Inner::from_percent_str(src, FRAC as u32) {
+                        Ok(bits) => Ok($Self::from_bits(bits)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a decimal number with grouped
+digits to return a fixed-point number.
+
+The integer part can be split into groups of three digits by the `group`
+character, for example “`1,234,567`”, and the `decimal` character separates the
+integer and fractional parts. The first group can have one to three digits, all
+other groups must have exactly three digits, and the fractional part cannot
+contain group separators. An optional sign can precede the number, but
+exponents are not supported. Rounding is to the nearest, with ties rounded to
+even.
+
+# Errors
+
+Returns an error if a group separator is misplaced, if the number is otherwise
+malformed, or if the value does not fit.
+
+# Panics
+
+Panics if `group` and `decimal` are the same character, or if either of them is
+an ASCII letter or digit or a sign.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<4>;
+assert_eq!(Fix::from_grouped_str("1.5", ',', '.'), Ok(Fix::from_num(1.5)));
+assert_eq!(Fix::from_grouped_str("7,25", '.', ','), Ok(Fix::from_num(7.25)));
+"#,
+                if_signed_unsigned!(
+                    $Signedness,
+                    r#"
+use fixed::types::I16F16;
+assert_eq!(
+    I16F16::from_grouped_str("12,345.5", ',', '.'),
+    Ok(I16F16::from_num(12_345.5))
+);
+assert_eq!(
+    I16F16::from_grouped_str("-12 345,5", ' ', ','),
+    Ok(I16F16::from_num(-12_345.5))
+);
+// misplaced group separators
+assert!(I16F16::from_grouped_str("1,23.5", ',', '.').is_err());
+assert!(I16F16::from_grouped_str("1,2345", ',', '.').is_err());
+"#,
+                    r#"
+use fixed::types::U16F16;
+assert_eq!(
+    U16F16::from_grouped_str("12,345.5", ',', '.'),
+    Ok(U16F16::from_num(12_345.5))
+);
+assert_eq!(
+    U16F16::from_grouped_str("12 345,5", ' ', ','),
+    Ok(U16F16::from_num(12_345.5))
+);
+// misplaced group separators
+assert!(U16F16::from_grouped_str("1,23.5", ',', '.').is_err());
+assert!(U16F16::from_grouped_str("1,2345", ',', '.').is_err());
+"#,
+                ),
+                "```
+";
+                #[inline]
+                #[track_caller]
+                pub const fn from_grouped_str(
+                    src: &str,
+                    group: char,
+                    decimal: char,
+                ) -> Result<$Self<FRAC>, ParseFixedError> {
+                    match from_str::$Inner::from_grouped_str(src, group, decimal, FRAC as u32) {
+                        Ok(bits) => Ok($Self::from_bits(bits)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing decimal digits to return a
+fixed-point number, using the given characters as the decimal separator and as
+the exponent separator.
+
+This is like [`from_str`][Self::from_str], but the `decimal` character separates
+the integer and fractional parts instead of “`.`”, and the `exp` character
+starts the exponent instead of “`e`”, “`E`” or “`@`”. The `exp` character is
+matched exactly, so for example “`e`” does not match “`E`”. This is useful for
+strings using locales where “`,`” is the decimal separator. Underscores are
+not accepted. Rounding is to the nearest, with ties rounded to even.
+
+# Errors
+
+Returns an error if the number is malformed, or if the value does not fit.
+
+# Panics
+
+Panics if `decimal` and `exp` are the same character, if `decimal` is an ASCII
+letter or digit or a sign, or if `exp` is an ASCII digit or a sign.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<4>;
+assert_eq!(Fix::from_str_with_separators("1,5", ',', 'e'), Ok(Fix::from_num(1.5)));
+assert_eq!(Fix::from_str_with_separators("0,125e1", ',', 'e'), Ok(Fix::from_num(1.25)));
+"#,
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    r#"assert_eq!(Fix::from_str_with_separators("-2,75", ',', 'E'), Ok(Fix::from_num(-2.75)));
+"#,
+                },
+                r#"// the point is not a decimal separator here
+assert!(Fix::from_str_with_separators("1.5", ',', 'e').is_err());
+```
+"#;
+                #[inline]
+                #[track_caller]
+                pub const fn from_str_with_separators(
+                    src: &str,
+                    decimal: char,
+                    exp: char,
+                ) -> Result<$Self<FRAC>, ParseFixedError> {
+                    match from_str::$Inner::from_str_with_separators(src, decimal, exp, FRAC as u32)
+                    {
+                        Ok(bits) => Ok($Self::from_bits(bits)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
             comment! {
                 "Parses a string slice containing decimal digits to return a fixed-point number,
 saturating on overflow.
@@ -494,6 +681,78 @@ assert_eq!(U8F8::saturating_from_str_hex("-1"), Ok(U8F8::ZERO));
                 }
             }
 
+            comment! {
+                "Parses a string slice containing decimal digits to return a
+fixed-point number, using the given characters as the decimal separator and as
+the exponent separator, saturating on overflow.
+
+See [`from_str_with_separators`][Self::from_str_with_separators] for the
+accepted format. Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if `decimal` and `exp` are the same character, if `decimal` is an ASCII
+letter or digit or a sign, or if `exp` is an ASCII digit or a sign.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    r#"use fixed::types::I8F8;
+assert_eq!(
+    I8F8::saturating_from_str_with_separators("1,5", ',', 'e'),
+    Ok(I8F8::from_num(1.5))
+);
+assert_eq!(
+    I8F8::saturating_from_str_with_separators("9999,5", ',', 'e'),
+    Ok(I8F8::MAX)
+);
+assert_eq!(
+    I8F8::saturating_from_str_with_separators("-1,5e9", ',', 'e'),
+    Ok(I8F8::MIN)
+);
+"#,
+                    r#"use fixed::types::U8F8;
+assert_eq!(
+    U8F8::saturating_from_str_with_separators("1,5", ',', 'e'),
+    Ok(U8F8::from_num(1.5))
+);
+assert_eq!(
+    U8F8::saturating_from_str_with_separators("9999,5", ',', 'e'),
+    Ok(U8F8::MAX)
+);
+assert_eq!(
+    U8F8::saturating_from_str_with_separators("-1,5", ',', 'e'),
+    Ok(U8F8::ZERO)
+);
+"#,
+                ),
+                "```
+";
+                #[inline]
+                #[track_caller]
+                pub const fn saturating_from_str_with_separators(
+                    src: &str,
+                    decimal: char,
+                    exp: char,
+                ) -> Result<$Self<FRAC>, ParseFixedError> {
+                    match from_str::$Inner::saturating_from_str_with_separators(
+                        src,
+                        decimal,
+                        exp,
+                        FRAC as u32,
+                    ) {
+                        Ok(bits) => Ok($Self::from_bits(bits)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
             comment! {
                 "Parses a string slice containing decimal digits to return a fixed-point number,
 wrapping on overflow.
@@ -1164,6 +1423,9 @@ assert_eq!(Fix::from_num(0.1875).checked_int_log(5), Some(-2));
             comment! {
                 "Returns the reciprocal (inverse) of the fixed-point number, 1/`self`.
 
+The result is rounded towards zero, like division, so for example the
+reciprocal of 3 is rounded down to the representable value just below 1/3.
+
 # Panics
 
 Panics if the fixed-point number is zero.
@@ -1183,6 +1445,8 @@ change if in the future it panics; if wrapping is required use
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
 assert_eq!(Fix::from_num(2).recip(), Fix::from_num(0.5));
+// 1/3 = 0.0101... in binary, rounded towards zero to 0.0101
+assert_eq!(Fix::from_num(3).recip(), Fix::from_num(0.3125));
 ```
 
 [`wrapping_recip`]: Self::wrapping_recip
@@ -1197,6 +1461,67 @@ assert_eq!(Fix::from_num(2).recip(), Fix::from_num(0.5));
                 }
             }
 
+            comment! {
+                "Integer power. Returns `self` raised to the power of `exp`.
+
+The power is computed using exponentiation by squaring with intermediate
+results that have 128 significant bits, and for negative `exp` the reciprocal
+of the positive power is then computed with the same precision. The relative
+error before the final rounding is
+&lt;&nbsp;(|`exp`|&nbsp;+&nbsp;1)&nbsp;×&nbsp;2<sup>&minus;127</sup>, and the
+result is then rounded to the nearest, so the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA] unless the result has many significant bits
+or `exp` is very large. Unlike [`recip`][Self::recip], which rounds towards
+zero, `powi(-1)` rounds to the nearest.
+
+If `exp` is zero, the result is one, even if `self` is zero, and the result
+overflows if one cannot be represented.
+
+# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_powi`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).powi(2), Fix::from_num(2.25));
+assert_eq!(Fix::from_num(2).powi(-1), Fix::from_num(0.5));
+// 1/9 = 0.111..., which is rounded to 0.125
+assert_eq!(Fix::from_num(3).powi(-2), Fix::from_num(0.125));
+assert_eq!(Fix::ZERO.powi(0), Fix::ONE);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.5).powi(3), Fix::from_num(-3.375));
+",
+                },
+                "```
+
+[`wrapping_powi`]: Self::wrapping_powi
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn powi(self, exp: i32) -> Self {
+                    let (bits, overflow) = match powi::$Inner(self.to_bits(), FRAC as u32, exp as i64) {
+                        Some(s) => s,
+                        None => panic!("division by zero"),
+                    };
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(bits)
+                }
+            }
+
             comment! {
                 "Euclidean division.
 
@@ -1239,6 +1564,65 @@ assert_eq!(Fix::from_num(7.5).div_euclid(Fix::from_num(2)), Fix::from_num(3));
                 }
             }
 
+            comment! {
+                "Division rounding to the nearest, with ties rounding to even.
+
+While the `/` operator rounds the quotient towards zero, this method rounds it
+to the nearest representable value. If the exact quotient lies halfway between
+two representable values, it is rounded to the one whose least significant bit
+is zero.
+
+# Panics
+
+Panics if the divisor is zero.
+
+When debug assertions are enabled, this method also panics if the
+division overflows. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if
+in the future it panics; if wrapping is required use
+[`wrapping_div_round`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let (two, three) = (Fix::from_num(2), Fix::from_num(3));
+// 2/3 = 0.1010…, which is rounded up
+assert_eq!(two / three, Fix::from_bits(0b1010));
+assert_eq!(two.div_round(three), Fix::from_bits(0b1011));
+// 1/3 = 0.0101…, which is rounded down
+assert_eq!(Fix::ONE.div_round(three), Fix::from_bits(0b0101));
+// 1.5 × DELTA and 2.5 × DELTA are ties, which are rounded to 2 × DELTA
+assert_eq!(Fix::from_bits(3).div_round(two), Fix::from_bits(2));
+assert_eq!(Fix::from_bits(5).div_round(two), Fix::from_bits(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-two).div_round(three), Fix::from_bits(-0b1011));
+",
+                },
+                "```
+
+[`wrapping_div_round`]: Self::wrapping_div_round
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn div_round(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    let (ans, overflow) = arith::$Inner::overflowing_div_round(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        FRAC as u32,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
             comment! {
                 "Euclidean division by an integer.
 
@@ -1373,20 +1757,20 @@ assert_eq!(Fix::from_num(2).sqrt(), Fix::SQRT_2);
             }
 
             comment! {
-                "Linear interpolation between `start` and `end`.
+                "Returns the reciprocal square root, 1/√`self`.
 
-Returns
-`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
-is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
-linear interpolation for all other values of `self`. Linear extrapolation is
-performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+This is useful for normalizing vectors. The result can be rounded either way, and the error is
+&lt;&nbsp;2&nbsp;×&nbsp;[`DELTA`][Self::DELTA].
 
 # Panics
 
-When debug assertions are enabled, this method panics if the result overflows.
-When debug assertions are not enabled, the wrapped value can be returned, but it
-is not considered a breaking change if in the future it panics; if wrapping is
-required use [`wrapping_lerp`] instead.
+Panics if the fixed-point number is zero",
+                if_signed_else_empty_str! { $Signedness; " or negative" },
+                ".
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics.
 
 # Examples
 
@@ -1396,44 +1780,46 @@ required use [`wrapping_lerp`] instead.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let start = Fix::from_num(2);
-let end = Fix::from_num(3.5);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-1.0).lerp(start, end), 0.5);
-",
-                },
-                "assert_eq!(Fix::from_num(0.0).lerp(start, end), 2);
-assert_eq!(Fix::from_num(0.5).lerp(start, end), 2.75);
-assert_eq!(Fix::from_num(1.0).lerp(start, end), 3.5);
-assert_eq!(Fix::from_num(2.0).lerp(start, end), 5);
+assert_eq!(Fix::from_num(4).rsqrt(), Fix::from_num(0.5));
+assert_eq!(Fix::from_num(0.25).rsqrt(), Fix::from_num(2));
 ```
-
-[`wrapping_lerp`]: Self::wrapping_lerp
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use]
-                pub const fn lerp<const RANGE_FRAC: i32>(
-                    self,
-                    start: $Self<RANGE_FRAC>,
-                    end: $Self<RANGE_FRAC>,
-                ) -> $Self<RANGE_FRAC> {
-                    let (ans, overflow) = lerp::$Inner(
-                        self.to_bits(),
-                        start.to_bits(),
-                        end.to_bits(),
-                        FRAC as u32,
-                    );
+                pub const fn rsqrt(self) -> Self {
+                    let (val, overflow) = self.overflowing_rsqrt();
                     debug_assert!(!overflow, "overflow");
-                    $Self::from_bits(ans)
+                    val
                 }
             }
 
             comment! {
-                "Checked division. Returns the quotient, or [`None`] if
-the divisor is zero or on overflow.
+                "Sine. Returns the sine of `self`.
+
+The angle is in radians. It is reduced modulo π/2 with enough precision that
+the reduction itself never overflows and does not add any significant error,
+and then the [CORDIC] algorithm is used. The internal computations have an
+error &lt;&nbsp;2<sup>&minus;119</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more
+than 118 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The sine can only overflow if there are fewer than two integer bits,
+as then 1 or &minus;1 cannot be represented.",
+                    "The sine overflows if it is negative, or if it is 1 and there are no
+integer bits.",
+                ),
+                "
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_sin`] instead.
 
 # Examples
 
@@ -1443,27 +1829,58 @@ the divisor is zero or on overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::MAX.checked_div(Fix::ONE), Some(Fix::MAX));
-assert_eq!(Fix::MAX.checked_div(Fix::ONE / 2), None);
-```
+assert_eq!(Fix::ZERO.sin(), Fix::ZERO);
+assert_eq!(Fix::FRAC_PI_2.sin(), Fix::ONE);
+// sin(0.5) = 0.479..., which is rounded to 0.5
+assert_eq!(Fix::from_num(0.5).sin(), Fix::from_num(0.5));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-0.5).sin(), Fix::from_num(-0.5));
+",
+                    "",
+                ),
+                "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+[`wrapping_sin`]: Self::wrapping_sin
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_div(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    if rhs.to_bits() == 0 {
-                        return None;
-                    }
-                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), FRAC as u32)
-                    {
-                        (ans, false) => Some(Self::from_bits(ans)),
-                        (_, true) => None,
-                    }
+                #[track_caller]
+                #[must_use]
+                pub const fn sin(self) -> Self {
+                    let ((sin, overflow), _) = trig::cordic::$Inner(self.to_bits(), FRAC as u32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(sin)
                 }
             }
 
             comment! {
-                "Checked reciprocal. Returns the reciprocal, or
-[`None`] if `self` is zero or on overflow.
+                "Cosine. Returns the cosine of `self`.
+
+The angle is in radians. It is reduced modulo π/2 with enough precision that
+the reduction itself never overflows and does not add any significant error,
+and then the [CORDIC] algorithm is used. The internal computations have an
+error &lt;&nbsp;2<sup>&minus;119</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more
+than 118 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The cosine can only overflow if there are fewer than two integer bits,
+as then 1 or &minus;1 cannot be represented.",
+                    "The cosine overflows if it is negative, or if it is 1 and there are no
+integer bits.",
+                ),
+                "
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_cos`] instead.
 
 # Examples
 
@@ -1473,27 +1890,60 @@ assert_eq!(Fix::MAX.checked_div(Fix::ONE / 2), None);
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(2).checked_recip(), Some(Fix::from_num(0.5)));
-assert_eq!(Fix::ZERO.checked_recip(), None);
-```
+assert_eq!(Fix::ZERO.cos(), Fix::ONE);
+// cos(1) = 0.540..., which is rounded to 0.5625
+assert_eq!(Fix::from_num(1).cos(), Fix::from_num(0.5625));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::PI.cos(), Fix::NEG_ONE);
+",
+                    "",
+                ),
+                "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+[`wrapping_cos`]: Self::wrapping_cos
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use]
-                pub const fn checked_recip(self) -> Option<$Self<FRAC>> {
-                    if self.to_bits() == 0 {
-                        None
-                    } else {
-                        match self.overflowing_recip() {
-                            (ans, false) => Some(ans),
-                            (_, true) => None,
-                        }
-                    }
+                pub const fn cos(self) -> Self {
+                    let (_, (cos, overflow)) = trig::cordic::$Inner(self.to_bits(), FRAC as u32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(cos)
                 }
             }
 
             comment! {
-                "Checked Euclidean division. Returns the quotient, or
-[`None`] if the divisor is zero or on overflow.
+                "Sine and cosine. Returns a [tuple] of the sine and cosine of `self`.
+
+This is more efficient than calling both [`sin`][Self::sin] and
+[`cos`][Self::cos].
+
+The angle is in radians. It is reduced modulo π/2 with enough precision that
+the reduction itself never overflows and does not add any significant error,
+and then the [CORDIC] algorithm is used. The internal computations have an
+error &lt;&nbsp;2<sup>&minus;119</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more
+than 118 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The sine or cosine can only overflow if there are fewer than two integer bits,
+as then 1 or &minus;1 cannot be represented.",
+                    "The sine or cosine overflows if it is negative, or if it is 1 and there are no
+integer bits.",
+                ),
+                "
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_sin_cos`] instead.
 
 # Examples
 
@@ -1503,44 +1953,50 @@ assert_eq!(Fix::ZERO.checked_recip(), None);
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(7.5).checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(3)));
-assert_eq!(Fix::from_num(7.5).checked_div_euclid(Fix::ZERO), None);
-assert_eq!(Fix::MAX.checked_div_euclid(Fix::from_num(0.25)), None);
+assert_eq!(Fix::ZERO.sin_cos(), (Fix::ZERO, Fix::ONE));
+assert_eq!(Fix::FRAC_PI_2.sin_cos(), (Fix::ONE, Fix::ZERO));
 ",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(-4)));
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::PI.sin_cos(), (Fix::ZERO, Fix::NEG_ONE));
 ",
-                },
+                    "",
+                ),
                 "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+[`wrapping_sin_cos`]: Self::wrapping_sin_cos
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_div_euclid(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    let q = match self.checked_div(rhs) {
-                        Some(s) => s.round_to_zero(),
-                        None => return None,
-                    };
-                    if_signed! {
-                        $Signedness;
-                        if self.unwrapped_rem(rhs).is_negative() {
-                            let Some(neg_one) = Self::TRY_NEG_ONE else {
-                                return None;
-                            };
-                            return if rhs.is_positive() {
-                                q.checked_add(neg_one)
-                            } else {
-                                q.checked_sub(neg_one)
-                            };
-                        }
-                    }
-                    Some(q)
+                #[track_caller]
+                #[must_use]
+                pub const fn sin_cos(self) -> (Self, Self) {
+                    let ((sin, sin_overflow), (cos, cos_overflow)) = trig::cordic::$Inner(self.to_bits(), FRAC as u32);
+                    debug_assert!(!sin_overflow && !cos_overflow, "overflow");
+                    (Self::from_bits(sin), Self::from_bits(cos))
                 }
             }
 
             comment! {
-                "Checked fixed-point remainder for division by an integer.
-Returns the remainder, or [`None`] if the divisor is zero.
+                "Four-quadrant arctangent. Returns the angle of the point
+(`other`,&nbsp;`self`) in radians.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The angle is in the range &minus;π&nbsp;<&nbsp;<i>θ</i>&nbsp;≤&nbsp;π.",
+                    "Both coordinates are non-negative, so the angle is in the range
+0&nbsp;≤&nbsp;<i>θ</i>&nbsp;≤&nbsp;π/2.",
+                ),
+                " If both `self` and `other` are zero, zero is returned.
+
+The angle is computed using the [CORDIC] algorithm in vectoring mode. The internal computations have an error &lt;&nbsp;2<sup>&minus;119</sup>, and
+the result is then rounded to the nearest, so the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more than 118 fractional bits.
+
+This method requires enough integer bits to hold π, that is at most ", $nc2, "
+fractional bits, so the result cannot overflow. [`checked_atan2`] is available
+for all numbers of fractional bits.
 
 # Examples
 
@@ -1550,62 +2006,50 @@ Returns the remainder, or [`None`] if the divisor is zero.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(3.75).checked_rem_int(2), Some(Fix::from_num(1.75)));
-assert_eq!(Fix::from_num(3.75).checked_rem_int(0), None);
+assert_eq!(Fix::ZERO.atan2(Fix::ONE), Fix::ZERO);
+assert_eq!(Fix::ONE.atan2(Fix::ZERO), Fix::FRAC_PI_2);
+// π/4 = 0.785..., which is rounded to 0.8125
+assert_eq!(Fix::ONE.atan2(Fix::ONE), Fix::from_num(0.8125));
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-3.75).checked_rem_int(2), Some(Fix::from_num(-1.75)));
+                    "assert_eq!(Fix::ZERO.atan2(Fix::NEG_ONE), Fix::PI);
+assert_eq!(Fix::NEG_ONE.atan2(Fix::ZERO), -Fix::FRAC_PI_2);
 ",
                 },
                 "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+[`checked_atan2`]: Self::checked_atan2
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_rem_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
-                    // Overflow converting rhs to $Self<FRAC> means that either
-                    //   * |rhs| > |self|, and so remainder is self, or
-                    //   * self is signed min with at least one integer bit,
-                    //     and the value of rhs is -self, so remainder is 0.
-                    if rhs == 0 {
-                        return None;
-                    }
-                    let can_shift = if_signed_unsigned!(
-                        $Signedness,
-                        if rhs.is_negative() {
-                            rhs.leading_ones() - 1
-                        } else {
-                            rhs.leading_zeros() - 1
-                        },
-                        rhs.leading_zeros(),
-                    );
-                    if Self::FRAC_BITS as u32 <= can_shift {
-                        let fixed_rhs = Self::from_bits(rhs << Self::FRAC_BITS);
-                        self.checked_rem(fixed_rhs)
-                    } else {
-                        if_signed_unsigned!(
-                            $Signedness,
-                            if self.to_bits() == $Inner::MIN
-                                && (Self::INT_BITS > 0 && rhs == 1 << (Self::INT_BITS - 1))
-                            {
-                                Some(Self::ZERO)
-                            } else {
-                                Some(self)
-                            },
-                            Some(self),
-                        )
-                    }
+                #[must_use]
+                pub const fn atan2(self, other: Self) -> Self
+                where
+                    If<{ FRAC <= $nc2 }>: True,
+                {
+                    let (angle, _) =
+                        trig::atan2::$Inner(self.to_bits(), other.to_bits(), FRAC as u32);
+                    Self::from_bits(angle)
                 }
             }
 
             comment! {
-                "Checked Euclidean division by an integer. Returns the
-quotient, or [`None`] if the divisor is zero",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    " or if the division results in overflow",
-                },
-                ".
+                "Arctangent. Returns the arctangent of `self` in radians.
+
+The result is in the range ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;π/2&nbsp;<&nbsp;<i>θ</i>&nbsp;<&nbsp;π/2",
+                    "0&nbsp;≤&nbsp;<i>θ</i>&nbsp;<&nbsp;π/2",
+                ),
+                ", and its magnitude is never larger than the magnitude of `self`, so it
+cannot overflow.
+
+This is computed like <code>self.[atan2][Self::atan2](1)</code> using the
+[CORDIC] algorithm in vectoring mode. The internal computations have an error &lt;&nbsp;2<sup>&minus;119</sup>, and
+the result is then rounded to the nearest, so the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more than 118 fractional bits.
 
 # Examples
 
@@ -1615,48 +2059,100 @@ quotient, or [`None`] if the divisor is zero",
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(2), Some(Fix::from_num(3)));
-assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(0), None);
+assert_eq!(Fix::ZERO.atan(), Fix::ZERO);
+// π/4 = 0.785..., which is rounded to 0.8125
+assert_eq!(Fix::ONE.atan(), Fix::from_num(0.8125));
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::MIN.checked_div_euclid_int(-1), None);
+                    "assert_eq!(Fix::NEG_ONE.atan(), Fix::from_num(-0.8125));
 ",
                 },
                 "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_div_euclid_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
-                    let q = match self.checked_div_int(rhs) {
-                        Some(s) => s.round_to_zero(),
-                        None => return None,
-                    };
-                    if_signed! {
-                        $Signedness;
-                        if self.unwrapped_rem_int(rhs).is_negative() {
-                            let Some(neg_one) = Self::TRY_NEG_ONE else {
-                                return None;
-                            };
-                            return if rhs.is_positive() {
-                                q.checked_add(neg_one)
-                            } else {
-                                q.checked_sub(neg_one)
-                            };
-                        }
-                    }
-                    Some(q)
+                #[must_use]
+                pub const fn atan(self) -> Self {
+                    Self::from_bits(trig::atan::$Inner(self.to_bits(), FRAC as u32))
                 }
             }
 
             comment! {
-                "Checked remainder for Euclidean division by an integer.
-Returns the remainder, or [`None`] if the divisor is zero",
+                "Exponential. Returns <i>e</i> raised to the power of `self`.
+
+The argument is first multiplied by log<sub>2</sub>&nbsp;<i>e</i> with enough
+precision not to add any significant error, then it is split into an integer
+part and a fractional part, and 2 raised to the fractional part is computed
+using a table and a polynomial without any floating-point operations. The
+internal computations have a relative error &lt;&nbsp;2<sup>&minus;120</sup>,
+and the result is then rounded to the nearest, so the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA] unless the result has more than 119
+significant bits, which is only possible for 128-bit numbers.
+
+The result is never negative. It overflows if it is too large to be
+represented, while results too small to be represented are rounded to zero.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_exp`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.exp(), Fix::ONE);
+// e = 2.718..., which is rounded to 2.6875
+assert_eq!(Fix::ONE.exp(), Fix::from_num(2.6875));
+",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    " or if the remainder results in overflow",
+                    "// 1/e = 0.367..., which is rounded to 0.375
+assert_eq!(Fix::NEG_ONE.exp(), Fix::from_num(0.375));
+",
                 },
-                ".
+                "```
+
+[`wrapping_exp`]: Self::wrapping_exp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn exp(self) -> Self {
+                    let (bits, overflow) = exp::$Inner(self.to_bits(), FRAC as u32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(bits)
+                }
+            }
+
+            comment! {
+                "Base-2 exponential. Returns 2 raised to the power of `self`.
+
+The argument is split into an integer part and a fractional part, and 2 raised
+to the fractional part is computed using a table and a polynomial without any
+floating-point operations. The internal computations have a relative error
+&lt;&nbsp;2<sup>&minus;120</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless the result
+has more than 119 significant bits, which is only possible for 128-bit
+numbers.
+
+The result is never negative. It overflows if it is too large to be
+represented, while results too small to be represented are rounded to zero.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_exp2`] instead.
 
 # Examples
 
@@ -1665,88 +2161,1277 @@ Returns the remainder, or [`None`] if the divisor is zero",
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<", $nm4, ">;
-assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(2), Some(Fix::from_num(1.5)));
-assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(0), None);
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.exp2(), Fix::ONE);
+assert_eq!(Fix::from_num(2).exp2(), Fix::from_num(4));
+// 2^0.5 = 1.414..., which is rounded to 1.4375
+assert_eq!(Fix::from_num(0.5).exp2(), Fix::from_num(1.4375));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::NEG_ONE.exp2(), Fix::from_num(0.5));
+",
+                },
+                "```
+
+[`wrapping_exp2`]: Self::wrapping_exp2
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn exp2(self) -> Self {
+                    let (bits, overflow) = exp::exp2::$Inner(self.to_bits(), FRAC as u32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(bits)
+                }
+            }
+
+            comment! {
+                "Natural logarithm. Returns the natural logarithm of `self`.
+
+The number is split into a power of two and a mantissa in the range
+[1,&nbsp;2), and the base-2 logarithm of the mantissa is computed using a
+table and a short series without any floating-point operations. The internal
+computations have an error &lt;&nbsp;2<sup>&minus;122</sup>, and the result is
+then rounded to the nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA]
+unless there are more than 121 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The result overflows if there are not enough integer bits to represent
+it.",
+                    "The result overflows if it is negative, as unsigned numbers cannot
+represent it, or if there are not enough integer bits to represent it.",
+                ),
+                "
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if overflow is possible use [`checked_ln`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.ln(), Fix::ZERO);
+// ln 2 = 0.693..., which is rounded to 0.6875
+assert_eq!(Fix::from_num(2).ln(), Fix::from_num(0.6875));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(0.5).ln(), Fix::from_num(-0.6875));
+",
+                    "",
+                ),
+                "```
+
+[`checked_ln`]: Self::checked_ln
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn ln(self) -> Self {
+                    if self.to_bits() <= 0 {
+                        panic!("log of non-positive number");
+                    }
+                    let (bits, overflow) = ln::$Inner(self.to_bits(), FRAC as u32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(bits)
+                }
+            }
+
+            comment! {
+                "Base-2 logarithm. Returns the base-2 logarithm of `self`.
+
+The number is split into a power of two and a mantissa in the range
+[1,&nbsp;2), and the base-2 logarithm of the mantissa is computed using a
+table and a short series without any floating-point operations. The internal
+computations have an error &lt;&nbsp;2<sup>&minus;122</sup>, and the result is
+then rounded to the nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA]
+unless there are more than 121 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The result overflows if there are not enough integer bits to represent
+it.",
+                    "The result overflows if it is negative, as unsigned numbers cannot
+represent it, or if there are not enough integer bits to represent it.",
+                ),
+                "
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if overflow is possible use [`checked_log2`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).log2(), Fix::from_num(2));
+// log2 3 = 1.584..., which is rounded to 1.5625
+assert_eq!(Fix::from_num(3).log2(), Fix::from_num(1.5625));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(0.25).log2(), Fix::from_num(-2));
+",
+                    "",
+                ),
+                "```
+
+[`checked_log2`]: Self::checked_log2
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn log2(self) -> Self {
+                    if self.to_bits() <= 0 {
+                        panic!("log of non-positive number");
+                    }
+                    let (bits, overflow) = ln::log2::$Inner(self.to_bits(), FRAC as u32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(bits)
+                }
+            }
+
+            comment! {
+                "Base-10 logarithm. Returns the base-10 logarithm of `self`.
+
+The number is split into a power of two and a mantissa in the range
+[1,&nbsp;2), and the base-2 logarithm of the mantissa is computed using a
+table and a short series without any floating-point operations. The internal
+computations have an error &lt;&nbsp;2<sup>&minus;122</sup>, and the result is
+then rounded to the nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA]
+unless there are more than 121 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The result overflows if there are not enough integer bits to represent
+it.",
+                    "The result overflows if it is negative, as unsigned numbers cannot
+represent it, or if there are not enough integer bits to represent it.",
+                ),
+                "
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if overflow is possible use [`checked_log10`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.log10(), Fix::ZERO);
+// log10 4 = 0.602..., which is rounded to 0.625
+assert_eq!(Fix::from_num(4).log10(), Fix::from_num(0.625));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(0.25).log10(), Fix::from_num(-0.625));
+",
+                    "",
+                ),
+                "```
+
+[`checked_log10`]: Self::checked_log10
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn log10(self) -> Self {
+                    if self.to_bits() <= 0 {
+                        panic!("log of non-positive number");
+                    }
+                    let (bits, overflow) = ln::log10::$Inner(self.to_bits(), FRAC as u32);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(bits)
+                }
+            }
+
+            comment! {
+                "Logarithm to the specified base. Returns the logarithm of `self` to base `base`.
+
+The result is computed by dividing the base-2 logarithms of `self` and `base`,
+which are computed as in [`log2`][Self::log2] with an error
+&lt;&nbsp;2<sup>&minus;122</sup> each. The quotient is then rounded to the
+nearest, so the error is small unless the result has many significant bits or
+`self` or `base` is close to one.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The result overflows if there are not enough integer bits to represent
+it.",
+                    "The result overflows if it is negative, as unsigned numbers cannot
+represent it, or if there are not enough integer bits to represent it.",
+                ),
+                "
+
+# Panics
+
+Panics if the fixed-point number or the base is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ", or if the base is
+one.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if overflow is possible use [`checked_log`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).log(Fix::from_num(2)), Fix::from_num(2));
+assert_eq!(Fix::from_num(0.5).log(Fix::from_num(0.25)), Fix::from_num(0.5));
+// log2 3 = 1.584..., which is rounded to 1.5625
+assert_eq!(Fix::from_num(3).log(Fix::from_num(2)), Fix::from_num(1.5625));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(0.25).log(Fix::from_num(2)), Fix::from_num(-2));
+",
+                    "",
+                ),
+                "```
+
+[`checked_log`]: Self::checked_log
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn log(self, base: Self) -> Self {
+                    if self.to_bits() <= 0 {
+                        panic!("log of non-positive number");
+                    }
+                    if base.to_bits() <= 0 {
+                        panic!("log with non-positive base");
+                    }
+                    let (bits, overflow) = match ln::log::$Inner(self.to_bits(), base.to_bits(), FRAC as u32) {
+                        Some(s) => s,
+                        None => panic!("log with base one"),
+                    };
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(bits)
+                }
+            }
+
+            comment! {
+                "Linear interpolation between `start` and `end`.
+
+Returns
+`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
+is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
+linear interpolation for all other values of `self`. Linear extrapolation is
+performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+The endpoints `start` and `end` can have a different number of fractional bits
+from `self`, and the result has the same type as the endpoints. The endpoints
+are not converted to the precision of `self`; instead the difference
+`end`&nbsp;&minus;&nbsp;`start` is multiplied by the full-precision factor and
+the product is rounded down to the precision of the endpoints. All the overflow
+variants of this method work the same way.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_lerp`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let start = Fix::from_num(2);
+let end = Fix::from_num(3.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.0).lerp(start, end), 0.5);
+",
+                },
+                "assert_eq!(Fix::from_num(0.0).lerp(start, end), 2);
+assert_eq!(Fix::from_num(0.5).lerp(start, end), 2.75);
+assert_eq!(Fix::from_num(1.0).lerp(start, end), 3.5);
+assert_eq!(Fix::from_num(2.0).lerp(start, end), 5);
+
+// endpoints with fewer fractional bits than the factor
+type Coarse = ", stringify!($Self), "<1>;
+let (start, end) = (Coarse::from_num(2), Coarse::from_num(4));
+assert_eq!(Fix::from_num(0.25).lerp(start, end), 2.5);
+// 0.375 × 2 = 0.75 is rounded down to 0.5
+assert_eq!(Fix::from_num(0.375).lerp(start, end), 2.5);
+```
+
+[`wrapping_lerp`]: Self::wrapping_lerp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn lerp<const RANGE_FRAC: i32>(
+                    self,
+                    start: $Self<RANGE_FRAC>,
+                    end: $Self<RANGE_FRAC>,
+                ) -> $Self<RANGE_FRAC> {
+                    let (ans, overflow) = lerp::$Inner(
+                        self.to_bits(),
+                        start.to_bits(),
+                        end.to_bits(),
+                        FRAC as u32,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    $Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Linear interpolation between `start` and `end`, with `self`
+clamped to the range 0&nbsp;≤&nbsp;`self`&nbsp;≤&nbsp;1 first.
+
+This is the same as [`lerp`][Self::lerp], except that there is no extrapolation:
+if `self`&nbsp;<&nbsp;0 the result is `start`, and if `self`&nbsp;>&nbsp;1 the
+result is `end`. If 1 cannot be represented, `self` is clamped to
+[`MAX`][Self::MAX] instead. Since the result always lies between `start` and
+`end`, this method cannot overflow, so it has no overflow variants.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let start = Fix::from_num(2);
+let end = Fix::from_num(3.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.0).lerp_clamped(start, end), 2);
+",
+                },
+                "assert_eq!(Fix::from_num(0.5).lerp_clamped(start, end), 2.75);
+assert_eq!(Fix::from_num(1.0).lerp_clamped(start, end), 3.5);
+assert_eq!(Fix::from_num(2.0).lerp_clamped(start, end), 3.5);
+assert_eq!(Fix::from_num(2.0).lerp(start, end), 5);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn lerp_clamped<const RANGE_FRAC: i32>(
+                    self,
+                    start: $Self<RANGE_FRAC>,
+                    end: $Self<RANGE_FRAC>,
+                ) -> $Self<RANGE_FRAC> {
+                    let upper = match Self::TRY_ONE {
+                        Some(one) => one.to_bits(),
+                        None => $Inner::MAX,
+                    };
+                    let bits = self.to_bits();
+                    if_signed! {
+                        $Signedness;
+                        if bits < 0 {
+                            return start;
+                        }
+                    }
+                    let bits = if bits > upper { upper } else { bits };
+                    // the result is between start and end, so it cannot overflow
+                    let (ans, _) = lerp::$Inner(bits, start.to_bits(), end.to_bits(), FRAC as u32);
+                    $Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Checked division. Returns the quotient, or [`None`] if
+the divisor is zero or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.checked_div(Fix::ONE), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_div(Fix::ONE / 2), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    if rhs.to_bits() == 0 {
+                        return None;
+                    }
+                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), FRAC as u32)
+                    {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked reciprocal. Returns the reciprocal, or
+[`None`] if `self` is zero or on overflow.
+
+The result is rounded towards zero, like division.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).checked_recip(), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::ZERO.checked_recip(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_recip(self) -> Option<$Self<FRAC>> {
+                    if self.to_bits() == 0 {
+                        None
+                    } else {
+                        match self.overflowing_recip() {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Checked integer power. Returns `self` raised to the power of `exp`, or
+[`None`] if `self` is zero and `exp` is negative or on overflow.
+
+See [`powi`][Self::powi] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).checked_powi(2), Some(Fix::from_num(4)));
+assert_eq!(Fix::MAX.checked_powi(2), None);
+assert_eq!(Fix::ZERO.checked_powi(-1), None);
+// one cannot be represented without integer bits
+type Frac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(Frac::ZERO.checked_powi(0), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_powi(self, exp: i32) -> Option<Self> {
+                    match powi::$Inner(self.to_bits(), FRAC as u32, exp as i64) {
+                        Some((bits, false)) => Some(Self::from_bits(bits)),
+                        Some((_, true)) | None => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked Euclidean division. Returns the quotient, or
+[`None`] if the divisor is zero or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(7.5).checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(3)));
+assert_eq!(Fix::from_num(7.5).checked_div_euclid(Fix::ZERO), None);
+assert_eq!(Fix::MAX.checked_div_euclid(Fix::from_num(0.25)), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(-4)));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div_euclid(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    if rhs.to_bits() == 0 {
+                        return None;
+                    }
+                    match self.overflowing_div_euclid(rhs) {
+                        (q, false) => Some(q),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked division rounding to the nearest, with ties rounding to
+even. Returns the quotient, or [`None`] if the divisor is zero or on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let (two, three) = (Fix::from_num(2), Fix::from_num(3));
+assert_eq!(two.checked_div_round(three), Some(Fix::from_bits(0b1011)));
+assert_eq!(two.checked_div_round(Fix::ZERO), None);
+assert_eq!(Fix::MAX.checked_div_round(Fix::ONE / 2), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div_round(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    if rhs.to_bits() == 0 {
+                        return None;
+                    }
+                    match arith::$Inner::overflowing_div_round(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        FRAC as u32,
+                    ) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked fixed-point remainder for division by an integer.
+Returns the remainder, or [`None`] if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3.75).checked_rem_int(2), Some(Fix::from_num(1.75)));
+assert_eq!(Fix::from_num(3.75).checked_rem_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-3.75).checked_rem_int(2), Some(Fix::from_num(-1.75)));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_rem_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
+                    // Overflow converting rhs to $Self<FRAC> means that either
+                    //   * |rhs| > |self|, and so remainder is self, or
+                    //   * self is signed min with at least one integer bit,
+                    //     and the value of rhs is -self, so remainder is 0.
+                    if rhs == 0 {
+                        return None;
+                    }
+                    let can_shift = if_signed_unsigned!(
+                        $Signedness,
+                        if rhs.is_negative() {
+                            rhs.leading_ones() - 1
+                        } else {
+                            rhs.leading_zeros() - 1
+                        },
+                        rhs.leading_zeros(),
+                    );
+                    if Self::FRAC_BITS as u32 <= can_shift {
+                        let fixed_rhs = Self::from_bits(rhs << Self::FRAC_BITS);
+                        self.checked_rem(fixed_rhs)
+                    } else {
+                        if_signed_unsigned!(
+                            $Signedness,
+                            if self.to_bits() == $Inner::MIN
+                                && (Self::INT_BITS > 0 && rhs == 1 << (Self::INT_BITS - 1))
+                            {
+                                Some(Self::ZERO)
+                            } else {
+                                Some(self)
+                            },
+                            Some(self),
+                        )
+                    }
+                }
+            }
+
+            comment! {
+                "Checked Euclidean division by an integer. Returns the
+quotient, or [`None`] if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or if the division results in overflow",
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(2), Some(Fix::from_num(3)));
+assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.checked_div_euclid_int(-1), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div_euclid_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
+                    let q = match self.checked_div_int(rhs) {
+                        Some(s) => s.round_to_zero(),
+                        None => return None,
+                    };
+                    if_signed! {
+                        $Signedness;
+                        if self.unwrapped_rem_int(rhs).is_negative() {
+                            let Some(neg_one) = Self::TRY_NEG_ONE else {
+                                return None;
+                            };
+                            return if rhs.is_positive() {
+                                q.checked_add(neg_one)
+                            } else {
+                                q.checked_sub(neg_one)
+                            };
+                        }
+                    }
+                    Some(q)
+                }
+            }
+
+            comment! {
+                "Checked remainder for Euclidean division by an integer.
+Returns the remainder, or [`None`] if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or if the remainder results in overflow",
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<", $nm4, ">;
+assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(2), Some(Fix::from_num(1.5)));
+assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(2), Some(Fix::from_num(0.5)));
+// -8 ≤ Fix < 8, so the answer 12.5 overflows
+assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(20), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_rem_euclid_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
+                    if_signed! {
+                        $Signedness;
+                        let Some(rem) = self.checked_rem_int(rhs) else {
+                            return None;
+                        };
+                        if !rem.is_negative() {
+                            return Some(rem);
+                        }
+                        // Work in unsigned.
+                        // Answer required is |rhs| - |rem|, but rhs is int, rem is fixed.
+                        // INT_BITS == 0 is a special case, as fraction can be negative.
+                        if Self::INT_BITS == 0 {
+                            // -0.5 <= rem < 0, so euclidean remainder is in the range
+                            // 0.5 <= answer < 1, which does not fit.
+                            return None;
+                        }
+                        let rhs_abs = rhs.wrapping_abs() as $UInner;
+                        let remb = rem.to_bits();
+                        let remb_abs = remb.wrapping_neg() as $UInner;
+                        let rem_int_abs = remb_abs >> Self::FRAC_BITS;
+                        let rem_frac = remb & Self::FRAC_MASK;
+                        let ans_int = rhs_abs - rem_int_abs - if rem_frac > 0 { 1 } else { 0 };
+                        let ansb_abs = if ans_int == 0 {
+                            0
+                        } else if Self::FRAC_BITS as u32 <= ans_int.leading_zeros() {
+                            ans_int << Self::FRAC_BITS
+                        } else {
+                            return None
+                        };
+                        let ansb = ansb_abs as $Inner;
+                        if ansb.is_negative() {
+                            return None;
+                        }
+                        Some(Self::from_bits(ansb | rem_frac))
+                    }
+                    if_unsigned! {
+                        $Signedness;
+                        self.checked_rem_int(rhs)
+                    }
+                }
+            }
+
+            comment! {
+                "Checked square root. ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Returns [`None`] for negative numbers and on overflow.",
+                    "Always returns the square root for unsigned numbers."
+                ),
+                "
+
+This method uses an iterative method, with up to ", $n, " iterations for
+ [`", stringify!($Self), "`]. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method returns [`None`] for an input value ≥&nbsp;0.25.
+
+"
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).checked_sqrt(), Some(Fix::SQRT_2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_sqrt(), None);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::from_num(0.25).checked_sqrt(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sqrt(self) -> Option<Self> {
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() {
+                            return None;
+                        }
+                    }
+                    match self.overflowing_sqrt() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked reciprocal square root. Returns 1/√`self`, or [`None`]
+if `self` is ",
+                if_signed_else_empty_str! { $Signedness; "negative or " },
+                "zero or on overflow.
+
+The result can be rounded either way, and the error is
+&lt;&nbsp;2&nbsp;×&nbsp;[`DELTA`][Self::DELTA].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).checked_rsqrt(), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::ZERO.checked_rsqrt(), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-4).checked_rsqrt(), None);
+",
+                },
+                "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::DELTA.checked_rsqrt(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_rsqrt(self) -> Option<Self> {
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() {
+                            return None;
+                        }
+                    }
+                    if self.to_bits() == 0 {
+                        return None;
+                    }
+                    match self.overflowing_rsqrt() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked sine. Returns the sine of `self`, or [`None`] on overflow.
+
+The angle is in radians. It is reduced modulo π/2 with enough precision that
+the reduction itself never overflows and does not add any significant error,
+and then the [CORDIC] algorithm is used. The internal computations have an
+error &lt;&nbsp;2<sup>&minus;119</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more
+than 118 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The sine can only overflow if there are fewer than two integer bits,
+as then 1 or &minus;1 cannot be represented.",
+                    "The sine overflows if it is negative, or if it is 1 and there are no
+integer bits.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.checked_sin(), Some(Fix::ONE));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-0.5).checked_sin(), Some(Fix::from_num(-0.5)));
+",
+                    "// sin(4) is negative
+assert_eq!(Fix::from_num(4).checked_sin(), None);
+",
+                ),
+                "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sin(self) -> Option<Self> {
+                    let ((sin, overflow), _) = trig::cordic::$Inner(self.to_bits(), FRAC as u32);
+                    if overflow {
+                        None
+                    } else {
+                        Some(Self::from_bits(sin))
+                    }
+                }
+            }
+
+            comment! {
+                "Checked cosine. Returns the cosine of `self`, or [`None`] on overflow.
+
+The angle is in radians. It is reduced modulo π/2 with enough precision that
+the reduction itself never overflows and does not add any significant error,
+and then the [CORDIC] algorithm is used. The internal computations have an
+error &lt;&nbsp;2<sup>&minus;119</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more
+than 118 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The cosine can only overflow if there are fewer than two integer bits,
+as then 1 or &minus;1 cannot be represented.",
+                    "The cosine overflows if it is negative, or if it is 1 and there are no
+integer bits.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_cos(), Some(Fix::ONE));
+// no integer bits, so 1 cannot be represented
+type Small = ", stringify!($Self), "<", $n, ">;
+assert_eq!(Small::ZERO.checked_cos(), None);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::PI.checked_cos(), Some(Fix::NEG_ONE));
+",
+                    "// cos(π) is negative
+assert_eq!(Fix::PI.checked_cos(), None);
+",
+                ),
+                "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_cos(self) -> Option<Self> {
+                    let (_, (cos, overflow)) = trig::cordic::$Inner(self.to_bits(), FRAC as u32);
+                    if overflow {
+                        None
+                    } else {
+                        Some(Self::from_bits(cos))
+                    }
+                }
+            }
+
+            comment! {
+                "Checked sine and cosine. Returns a [tuple] of the sine and cosine of
+`self`, or [`None`] if either overflows.
+
+The angle is in radians. It is reduced modulo π/2 with enough precision that
+the reduction itself never overflows and does not add any significant error,
+and then the [CORDIC] algorithm is used. The internal computations have an
+error &lt;&nbsp;2<sup>&minus;119</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more
+than 118 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The sine or cosine can only overflow if there are fewer than two integer bits,
+as then 1 or &minus;1 cannot be represented.",
+                    "The sine or cosine overflows if it is negative, or if it is 1 and there are no
+integer bits.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_sin_cos(), Some((Fix::ZERO, Fix::ONE)));
+// no integer bits, so 1 cannot be represented
+type Small = ", stringify!($Self), "<", $n, ">;
+assert_eq!(Small::ZERO.checked_sin_cos(), None);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::PI.checked_sin_cos(), Some((Fix::ZERO, Fix::NEG_ONE)));
+",
+                    "// cos(π) is negative
+assert_eq!(Fix::PI.checked_sin_cos(), None);
+",
+                ),
+                "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sin_cos(self) -> Option<(Self, Self)> {
+                    let ((sin, sin_overflow), (cos, cos_overflow)) = trig::cordic::$Inner(self.to_bits(), FRAC as u32);
+                    if sin_overflow || cos_overflow {
+                        None
+                    } else {
+                        Some((Self::from_bits(sin), Self::from_bits(cos)))
+                    }
+                }
+            }
+
+            comment! {
+                "Checked four-quadrant arctangent. Returns the angle of the point
+(`other`,&nbsp;`self`) in radians, or [`None`] if the angle does not fit.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The angle is in the range &minus;π&nbsp;<&nbsp;<i>θ</i>&nbsp;≤&nbsp;π.",
+                    "Both coordinates are non-negative, so the angle is in the range
+0&nbsp;≤&nbsp;<i>θ</i>&nbsp;≤&nbsp;π/2.",
+                ),
+                " If both `self` and `other` are zero, zero is returned. See
+[`atan2`][Self::atan2] for details on the accuracy. Unlike `atan2`, this method
+is available for all numbers of fractional bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_atan2(Fix::ZERO), Some(Fix::FRAC_PI_2));
+// no integer bits, so π/2 cannot be represented
+type Small = ", stringify!($Self), "<", $n, ">;
+let quarter = Small::from_num(0.25);
+assert_eq!(quarter.checked_atan2(Small::ZERO), None);
+assert!(Small::from_num(0.125).checked_atan2(quarter).is_some());
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_atan2(self, other: Self) -> Option<Self> {
+                    match trig::atan2::$Inner(self.to_bits(), other.to_bits(), FRAC as u32) {
+                        (angle, false) => Some(Self::from_bits(angle)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked exponential. Returns <i>e</i> raised to the power of `self`,
+or [`None`] on overflow.
+
+See [`exp`][Self::exp] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_exp(), Some(Fix::from_num(2.6875)));
+assert_eq!(Fix::MAX.checked_exp(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_exp(self) -> Option<Self> {
+                    match exp::$Inner(self.to_bits(), FRAC as u32) {
+                        (bits, false) => Some(Self::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked base-2 exponential. Returns 2 raised to the power of `self`,
+or [`None`] on overflow.
+
+See [`exp2`][Self::exp2] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).checked_exp2(), Some(Fix::from_num(4)));
+assert_eq!(Fix::MAX.checked_exp2(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_exp2(self) -> Option<Self> {
+                    match exp::exp2::$Inner(self.to_bits(), FRAC as u32) {
+                        (bits, false) => Some(Self::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked natural logarithm. Returns the natural logarithm of `self`, or [`None`]
+if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or on overflow.
+
+See [`ln`][Self::ln] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_ln(), None);
+assert_eq!(Fix::ONE.checked_ln(), Some(Fix::ZERO));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::NEG_ONE.checked_ln(), None);
+",
+                    "// the result is negative
+assert_eq!(Fix::from_num(0.5).checked_ln(), None);
+",
+                ),
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_ln(self) -> Option<Self> {
+                    if self.to_bits() <= 0 {
+                        return None;
+                    }
+                    match ln::$Inner(self.to_bits(), FRAC as u32) {
+                        (bits, false) => Some(Self::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked base-2 logarithm. Returns the base-2 logarithm of `self`, or [`None`]
+if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or on overflow.
+
+See [`log2`][Self::log2] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_log2(), None);
+assert_eq!(Fix::from_num(4).checked_log2(), Some(Fix::from_num(2)));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::NEG_ONE.checked_log2(), None);
+assert_eq!(Fix::DELTA.checked_log2(), Some(Fix::from_num(-4)));
+",
+                    "// the result is negative
+assert_eq!(Fix::DELTA.checked_log2(), None);
+",
+                ),
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_log2(self) -> Option<Self> {
+                    if self.to_bits() <= 0 {
+                        return None;
+                    }
+                    match ln::log2::$Inner(self.to_bits(), FRAC as u32) {
+                        (bits, false) => Some(Self::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked base-10 logarithm. Returns the base-10 logarithm of `self`, or [`None`]
+if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or on overflow.
+
+See [`log10`][Self::log10] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.checked_log10(), None);
+assert_eq!(Fix::ONE.checked_log10(), Some(Fix::ZERO));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::NEG_ONE.checked_log10(), None);
 ",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(2), Some(Fix::from_num(0.5)));
-// -8 ≤ Fix < 8, so the answer 12.5 overflows
-assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(20), None);
+                    "// the result is negative
+assert_eq!(Fix::from_num(0.5).checked_log10(), None);
 ",
-                },
+                ),
                 "```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_rem_euclid_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
-                    if_signed! {
-                        $Signedness;
-                        let Some(rem) = self.checked_rem_int(rhs) else {
-                            return None;
-                        };
-                        if !rem.is_negative() {
-                            return Some(rem);
-                        }
-                        // Work in unsigned.
-                        // Answer required is |rhs| - |rem|, but rhs is int, rem is fixed.
-                        // INT_BITS == 0 is a special case, as fraction can be negative.
-                        if Self::INT_BITS == 0 {
-                            // -0.5 <= rem < 0, so euclidean remainder is in the range
-                            // 0.5 <= answer < 1, which does not fit.
-                            return None;
-                        }
-                        let rhs_abs = rhs.wrapping_abs() as $UInner;
-                        let remb = rem.to_bits();
-                        let remb_abs = remb.wrapping_neg() as $UInner;
-                        let rem_int_abs = remb_abs >> Self::FRAC_BITS;
-                        let rem_frac = remb & Self::FRAC_MASK;
-                        let ans_int = rhs_abs - rem_int_abs - if rem_frac > 0 { 1 } else { 0 };
-                        let ansb_abs = if ans_int == 0 {
-                            0
-                        } else if Self::FRAC_BITS as u32 <= ans_int.leading_zeros() {
-                            ans_int << Self::FRAC_BITS
-                        } else {
-                            return None
-                        };
-                        let ansb = ansb_abs as $Inner;
-                        if ansb.is_negative() {
-                            return None;
-                        }
-                        Some(Self::from_bits(ansb | rem_frac))
+                #[must_use]
+                pub const fn checked_log10(self) -> Option<Self> {
+                    if self.to_bits() <= 0 {
+                        return None;
                     }
-                    if_unsigned! {
-                        $Signedness;
-                        self.checked_rem_int(rhs)
+                    match ln::log10::$Inner(self.to_bits(), FRAC as u32) {
+                        (bits, false) => Some(Self::from_bits(bits)),
+                        (_, true) => None,
                     }
                 }
             }
 
             comment! {
-                "Checked square root. ",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "Returns [`None`] for negative numbers and on overflow.",
-                    "Always returns the square root for unsigned numbers."
-                ),
-                "
-
-This method uses an iterative method, with up to ", $n, " iterations for
- [`", stringify!($Self), "`]. The result is rounded down, and the error is
-&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
-result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+                "Checked logarithm to the specified base. Returns the logarithm of `self` to base `base`, or [`None`]
+if the fixed-point number or the base is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ",
+if the base is one, or on overflow.
 
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "Overflow can only occur when there are no integer bits and
-the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
-In this case, the method returns [`None`] for an input value ≥&nbsp;0.25.
+See [`log`][Self::log] for details on the accuracy.
 
-"
-                },
-                "# Examples
+# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -1754,30 +3439,31 @@ In this case, the method returns [`None`] for an input value ≥&nbsp;0.25.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(2).checked_sqrt(), Some(Fix::SQRT_2));
+let two = Fix::from_num(2);
+assert_eq!(Fix::ZERO.checked_log(two), None);
+assert_eq!(Fix::from_num(4).checked_log(two), Some(two));
+// the base cannot be 1
+assert_eq!(Fix::from_num(4).checked_log(Fix::ONE), None);
 ",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-1).checked_sqrt(), None);
-
-type AllFrac = ", stringify!($Self), "<", $n, ">;
-assert_eq!(AllFrac::from_num(0.25).checked_sqrt(), None);
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(4).checked_log(-two), None);
 ",
-                },
+                    "// the result is negative
+assert_eq!(Fix::from_num(0.5).checked_log(two), None);
+",
+                ),
                 "```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn checked_sqrt(self) -> Option<Self> {
-                    if_signed! {
-                        $Signedness;
-                        if self.is_negative() {
-                            return None;
-                        }
+                pub const fn checked_log(self, base: Self) -> Option<Self> {
+                    if self.to_bits() <= 0 || base.to_bits() <= 0 {
+                        return None;
                     }
-                    match self.overflowing_sqrt() {
-                        (val, false) => Some(val),
-                        (_, true) => None,
+                    match ln::log::$Inner(self.to_bits(), base.to_bits(), FRAC as u32) {
+                        Some((bits, false)) => Some(Self::from_bits(bits)),
+                        Some((_, true)) | None => None,
                     }
                 }
             }
@@ -1802,6 +3488,13 @@ use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
 assert_eq!(Fix::from_num(0.5).checked_lerp(Fix::ZERO, Fix::MAX), Some(Fix::MAX / 2));
 assert_eq!(Fix::from_num(1.5).checked_lerp(Fix::ZERO, Fix::MAX), None);
+
+// the endpoints can have a different number of fractional bits
+type Coarse = ", stringify!($Self), "<1>;
+let (start, end) = (Coarse::ZERO, Coarse::from_num(5));
+assert_eq!(Fix::from_num(0.5).checked_lerp(start, end), Some(Coarse::from_num(2.5)));
+assert_eq!(Fix::from_num(0.5).checked_lerp(Coarse::ZERO, Coarse::MAX), Some(Coarse::MAX / 2));
+assert_eq!(Fix::from_num(1.5).checked_lerp(Coarse::ZERO, Coarse::MAX), None);
 ```
 ";
                 #[inline]
@@ -1864,6 +3557,8 @@ assert_eq!(Fix::MAX.saturating_div(one_half), Fix::MAX);
                 "Saturating reciprocal. Returns the reciprocal,
 saturating on overflow.
 
+The result is rounded towards zero, like division.
+
 # Panics
 
 Panics if the fixed-point number is zero.
@@ -1907,6 +3602,55 @@ assert_eq!(Fix::from_num(0.25).saturating_recip(), Fix::MAX);
                 }
             }
 
+            comment! {
+                "Saturating integer power. Returns `self` raised to the power of `exp`,
+saturating on overflow.
+
+See [`powi`][Self::powi] for details on the accuracy.
+
+# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).saturating_powi(2), Fix::from_num(4));
+assert_eq!(Fix::MAX.saturating_powi(2), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_powi(2), Fix::MAX);
+assert_eq!(Fix::MIN.saturating_powi(3), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_powi(self, exp: i32) -> Self {
+                    match powi::$Inner(self.to_bits(), FRAC as u32, exp as i64) {
+                        Some((bits, false)) => Self::from_bits(bits),
+                        Some((_, true)) => if_signed_unsigned!(
+                            $Signedness,
+                            if self.is_negative() && exp % 2 != 0 {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            },
+                            Self::MAX,
+                        ),
+                        None => panic!("division by zero"),
+                    }
+                }
+            }
+
             comment! {
                 "Saturating Euclidean division. Returns the quotient,
 saturating on overflow.
@@ -1955,6 +3699,58 @@ assert_eq!(Fix::MIN.saturating_div_euclid(Fix::from_num(0.25)), Fix::MIN);
                 }
             }
 
+            comment! {
+                "Saturating division rounding to the nearest, with ties rounding
+to even. Returns the quotient, saturating on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let (two, three) = (Fix::from_num(2), Fix::from_num(3));
+assert_eq!(two.saturating_div_round(three), Fix::from_bits(0b1011));
+assert_eq!(Fix::MAX.saturating_div_round(Fix::ONE / 2), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_div_round(Fix::ONE / 2), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_div_round(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match arith::$Inner::overflowing_div_round(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        FRAC as u32,
+                    ) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() != rhs.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Saturating Euclidean division by an integer. Returns the quotient",
                 if_signed_unsigned!(
@@ -2085,22 +3881,130 @@ Panics if the number is negative.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(2).saturating_sqrt(), Fix::SQRT_2);
+assert_eq!(Fix::from_num(2).saturating_sqrt(), Fix::SQRT_2);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::from_num(0.25).saturating_sqrt(), AllFrac::MAX);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sqrt(self) -> Self {
+                    match self.overflowing_sqrt() {
+                        (val, false) => val,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating reciprocal square root. Returns 1/√`self`,
+saturating on overflow. Zero saturates to [`MAX`][Self::MAX].
+
+The result can be rounded either way, and the error is
+&lt;&nbsp;2&nbsp;×&nbsp;[`DELTA`][Self::DELTA].
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the fixed-point number is negative.
+
+",
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).saturating_rsqrt(), Fix::from_num(0.5));
+assert_eq!(Fix::ZERO.saturating_rsqrt(), Fix::MAX);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert_eq!(AllFrac::DELTA.saturating_rsqrt(), AllFrac::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_rsqrt(self) -> Self {
+                    if self.is_zero() {
+                        return Self::MAX;
+                    }
+                    match self.overflowing_rsqrt() {
+                        (val, false) => val,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating exponential. Returns <i>e</i> raised to the power of
+`self`, saturating on overflow.
+
+See [`exp`][Self::exp] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.saturating_exp(), Fix::from_num(2.6875));
+assert_eq!(Fix::MAX.saturating_exp(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_exp(self) -> Self {
+                    match exp::$Inner(self.to_bits(), FRAC as u32) {
+                        (bits, false) => Self::from_bits(bits),
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating base-2 exponential. Returns 2 raised to the power of
+`self`, saturating on overflow.
+
+See [`exp2`][Self::exp2] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).saturating_exp2(), Fix::from_num(4));
+assert_eq!(Fix::MAX.saturating_exp2(), Fix::MAX);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "
-type AllFrac = ", stringify!($Self), "<", $n, ">;
-assert_eq!(AllFrac::from_num(0.25).saturating_sqrt(), AllFrac::MAX);
+                    "// 2^MIN is too small to be represented
+assert_eq!(Fix::MIN.saturating_exp2(), Fix::ZERO);
 ",
                 },
                 "```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn saturating_sqrt(self) -> Self {
-                    match self.overflowing_sqrt() {
-                        (val, false) => val,
+                pub const fn saturating_exp2(self) -> Self {
+                    match exp::exp2::$Inner(self.to_bits(), FRAC as u32) {
+                        (bits, false) => Self::from_bits(bits),
                         (_, true) => Self::MAX,
                     }
                 }
@@ -2199,6 +4103,8 @@ assert_eq!(Fix::MAX.wrapping_div(quarter), wrapped);
                 "Wrapping reciprocal. Returns the reciprocal,
 wrapping on overflow.
 
+The result is rounded towards zero, like division.
+
 # Panics
 
 Panics if the fixed-point number is zero.
@@ -2224,6 +4130,42 @@ assert_eq!(Fix::from_num(0.25).wrapping_recip(), Fix::ZERO);
                 }
             }
 
+            comment! {
+                "Wrapping integer power. Returns `self` raised to the power of `exp`,
+wrapping on overflow.
+
+See [`powi`][Self::powi] for details on the accuracy. When the result
+overflows, the wrapped value is computed from the intermediate result with 128
+significant bits.
+
+# Panics
+
+Panics if `self` is zero and `exp` is negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).wrapping_powi(2), Fix::from_num(4));
+// 2^", $nm4, " needs ", $n, " bits before the point, so it wraps to 0
+assert_eq!(Fix::from_num(2).wrapping_powi(", $nm4, "), Fix::ZERO);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_powi(self, exp: i32) -> Self {
+                    match powi::$Inner(self.to_bits(), FRAC as u32, exp as i64) {
+                        Some((bits, _)) => Self::from_bits(bits),
+                        None => panic!("division by zero"),
+                    }
+                }
+            }
+
             comment! {
                 "Wrapping Euclidean division. Returns the quotient, wrapping on overflow.
 
@@ -2240,7 +4182,8 @@ Panics if the divisor is zero.
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
 assert_eq!(Fix::from_num(7.5).wrapping_div_euclid(Fix::from_num(2)), Fix::from_num(3));
-let wrapped = Fix::MAX.wrapping_mul_int(4).round_to_zero();
+// MAX / 0.25 = MAX × 4, and the integer part of the exact quotient wraps
+let wrapped = Fix::from_bits((Fix::MAX.to_bits() / 4) << 4);
 assert_eq!(Fix::MAX.wrapping_div_euclid(Fix::from_num(0.25)), wrapped);
 ```
 ";
@@ -2252,6 +4195,42 @@ assert_eq!(Fix::MAX.wrapping_div_euclid(Fix::from_num(0.25)), wrapped);
                 }
             }
 
+            comment! {
+                "Wrapping division rounding to the nearest, with ties rounding to
+even. Returns the quotient, wrapping on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let (two, three) = (Fix::from_num(2), Fix::from_num(3));
+assert_eq!(two.wrapping_div_round(three), Fix::from_bits(0b1011));
+let quarter = Fix::ONE / 4;
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::MAX.wrapping_div_round(quarter), wrapped);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_div_round(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    let (ans, _) = arith::$Inner::overflowing_div_round(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        FRAC as u32,
+                    );
+                    Self::from_bits(ans)
+                }
+            }
+
             comment! {
                 "Wrapping Euclidean division by an integer. Returns the quotient",
                 if_signed_unsigned!(
@@ -2397,6 +4376,221 @@ assert_eq!(AllFrac::from_num(0.25).wrapping_sqrt(), AllFrac::from_num(-0.5));
                 }
             }
 
+            comment! {
+                "Wrapping sine. Returns the sine of `self`, wrapping on overflow.
+
+The angle is in radians. It is reduced modulo π/2 with enough precision that
+the reduction itself never overflows and does not add any significant error,
+and then the [CORDIC] algorithm is used. The internal computations have an
+error &lt;&nbsp;2<sup>&minus;119</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more
+than 118 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The sine can only overflow if there are fewer than two integer bits,
+as then 1 or &minus;1 cannot be represented.",
+                    "The sine overflows if it is negative, or if it is 1 and there are no
+integer bits.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::FRAC_PI_2.wrapping_sin(), Fix::ONE);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-0.5).wrapping_sin(), Fix::from_num(-0.5));
+",
+                    "// sin(4) = −0.757..., which is rounded to −0.75 and wrapped
+assert_eq!(
+    Fix::from_num(4).wrapping_sin(),
+    Fix::ZERO.wrapping_sub(Fix::from_num(0.75))
+);
+",
+                ),
+                "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sin(self) -> Self {
+                    let ((sin, _), _) = trig::cordic::$Inner(self.to_bits(), FRAC as u32);
+                    Self::from_bits(sin)
+                }
+            }
+
+            comment! {
+                "Wrapping cosine. Returns the cosine of `self`, wrapping on overflow.
+
+The angle is in radians. It is reduced modulo π/2 with enough precision that
+the reduction itself never overflows and does not add any significant error,
+and then the [CORDIC] algorithm is used. The internal computations have an
+error &lt;&nbsp;2<sup>&minus;119</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more
+than 118 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The cosine can only overflow if there are fewer than two integer bits,
+as then 1 or &minus;1 cannot be represented.",
+                    "The cosine overflows if it is negative, or if it is 1 and there are no
+integer bits.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.wrapping_cos(), Fix::ONE);
+// no integer bits, so 1 wraps to 0
+type Small = ", stringify!($Self), "<", $n, ">;
+assert_eq!(Small::ZERO.wrapping_cos(), Small::ZERO);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::PI.wrapping_cos(), Fix::NEG_ONE);
+",
+                    "// cos(π) = −1 wraps
+assert_eq!(Fix::PI.wrapping_cos(), Fix::ZERO.wrapping_sub(Fix::ONE));
+",
+                ),
+                "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_cos(self) -> Self {
+                    let (_, (cos, _)) = trig::cordic::$Inner(self.to_bits(), FRAC as u32);
+                    Self::from_bits(cos)
+                }
+            }
+
+            comment! {
+                "Wrapping sine and cosine. Returns a [tuple] of the sine and cosine of
+`self`, wrapping on overflow.
+
+The angle is in radians. It is reduced modulo π/2 with enough precision that
+the reduction itself never overflows and does not add any significant error,
+and then the [CORDIC] algorithm is used. The internal computations have an
+error &lt;&nbsp;2<sup>&minus;119</sup>, and the result is then rounded to the
+nearest, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA] unless there are more
+than 118 fractional bits.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The sine or cosine can only overflow if there are fewer than two integer bits,
+as then 1 or &minus;1 cannot be represented.",
+                    "The sine or cosine overflows if it is negative, or if it is 1 and there are no
+integer bits.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ZERO.wrapping_sin_cos(), (Fix::ZERO, Fix::ONE));
+// no integer bits, so 1 wraps to 0
+type Small = ", stringify!($Self), "<", $n, ">;
+assert_eq!(Small::ZERO.wrapping_sin_cos(), (Small::ZERO, Small::ZERO));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::PI.wrapping_sin_cos(), (Fix::ZERO, Fix::NEG_ONE));
+",
+                    "// cos(π) = −1 wraps
+assert_eq!(
+    Fix::PI.wrapping_sin_cos(),
+    (Fix::ZERO, Fix::ZERO.wrapping_sub(Fix::ONE))
+);
+",
+                ),
+                "```
+
+[CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sin_cos(self) -> (Self, Self) {
+                    let ((sin, _), (cos, _)) = trig::cordic::$Inner(self.to_bits(), FRAC as u32);
+                    (Self::from_bits(sin), Self::from_bits(cos))
+                }
+            }
+
+            comment! {
+                "Wrapping exponential. Returns <i>e</i> raised to the power of `self`,
+wrapping on overflow.
+
+See [`exp`][Self::exp] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.wrapping_exp(), Fix::from_num(2.6875));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_exp(self) -> Self {
+                    let (bits, _) = exp::$Inner(self.to_bits(), FRAC as u32);
+                    Self::from_bits(bits)
+                }
+            }
+
+            comment! {
+                "Wrapping base-2 exponential. Returns 2 raised to the power of `self`,
+wrapping on overflow.
+
+See [`exp2`][Self::exp2] for details on the accuracy.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2).wrapping_exp2(), Fix::from_num(4));
+// 2^", $nm4, " needs ", $n, " bits before the point, so it wraps to 0
+assert_eq!(Fix::from_num(", $nm4, ").wrapping_exp2(), Fix::ZERO);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_exp2(self) -> Self {
+                    let (bits, _) = exp::exp2::$Inner(self.to_bits(), FRAC as u32);
+                    Self::from_bits(bits)
+                }
+            }
+
             comment! {
                 "Linear interpolation between `start` and `end`, wrapping on
 overflow.
@@ -2481,6 +4675,8 @@ let _overflow = Fix::MAX.unwrapped_div(quarter);
                 "Unwrapped reciprocal. Returns the reciprocal,
 panicking on overflow.
 
+The result is rounded towards zero, like division.
+
 # Panics
 
 Panics if the fixed-point number is zero or on overflow.
@@ -2883,6 +5079,8 @@ assert_eq!(Fix::MAX.overflowing_div(quarter), (wrapped, true));
 Returns a [tuple] of the reciprocal and a [`bool`] indicating whether
 an overflow has occurred. On overflow, the wrapped value is returned.
 
+The result is rounded towards zero, like division.
+
 # Panics
 
 Panics if the fixed-point number is zero.
@@ -2957,7 +5155,8 @@ use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
 let check = Fix::from_num(3);
 assert_eq!(Fix::from_num(7.5).overflowing_div_euclid(Fix::from_num(2)), (check, false));
-let wrapped = Fix::MAX.wrapping_mul_int(4).round_to_zero();
+// MAX / 0.25 = MAX × 4, and the integer part of the exact quotient wraps
+let wrapped = Fix::from_bits((Fix::MAX.to_bits() / 4) << 4);
 assert_eq!(Fix::MAX.overflowing_div_euclid(Fix::from_num(0.25)), (wrapped, true));
 ```
 ";
@@ -2968,23 +5167,27 @@ assert_eq!(Fix::MAX.overflowing_div_euclid(Fix::from_num(0.25)), (wrapped, true)
                     self,
                     rhs: $Self<FRAC>,
                 ) -> ($Self<FRAC>, bool) {
-                    let (mut q, overflow) = self.overflowing_div(rhs);
-                    q = q.round_to_zero();
+                    // The integer quotient of the bits is the integer quotient
+                    // of the values, so it can be computed exactly before it is
+                    // shifted into place, and the wrapped value is correct.
+                    let (self_bits, rhs_bits) = (self.to_bits(), rhs.to_bits());
+                    // overflow here only for MIN / -1, where the remainder is 0
+                    let (q, overflow1) = self_bits.overflowing_div(rhs_bits);
                     if_signed! {
                         $Signedness;
-                        if self.unwrapped_rem(rhs).is_negative() {
-                            let Some(neg_one) = Self::TRY_NEG_ONE else {
-                                return (q, true);
-                            };
-                            let (q, overflow2) = if rhs.is_positive() {
-                                q.overflowing_add(neg_one)
-                            } else {
-                                q.overflowing_sub(neg_one)
-                            };
-                            return (q, overflow | overflow2);
-                        }
+                        // |q| < |MIN| / 2 when adjusting, so this cannot overflow
+                        let q = if self_bits.wrapping_rem(rhs_bits) < 0 {
+                            if rhs_bits > 0 { q - 1 } else { q + 1 }
+                        } else {
+                            q
+                        };
                     }
-                    (q, overflow)
+                    if FRAC as u32 == $n {
+                        return (Self::ZERO, overflow1 || q != 0);
+                    }
+                    let shifted = q << FRAC;
+                    let overflow2 = (shifted >> FRAC) != q;
+                    (Self::from_bits(shifted), overflow1 || overflow2)
                 }
             }
 
@@ -3213,6 +5416,64 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Overflowing reciprocal square root.
+
+Returns a [tuple] of 1/√`self` and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The result can be rounded either way, and the error is
+&lt;&nbsp;2&nbsp;×&nbsp;[`DELTA`][Self::DELTA].
+
+# Panics
+
+Panics if the fixed-point number is zero",
+                if_signed_else_empty_str! { $Signedness; " or negative" },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(4).overflowing_rsqrt(),
+    (Fix::from_num(0.5), false)
+);
+
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+assert!(AllFrac::DELTA.overflowing_rsqrt().1);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_rsqrt(self) -> (Self, bool) {
+                    if_signed_unsigned!(
+                        $Signedness,
+                        {
+                            if self.is_negative() {
+                                panic!("square root of negative number");
+                            }
+                            let u = $USelf::<FRAC>::from_bits(self.to_bits() as $UInner);
+                            let (r, overflow) = u.overflowing_rsqrt();
+                            let r = $Self::from_bits(r.to_bits() as $Inner);
+                            (r, overflow || r.is_negative())
+                        },
+                        {
+                            let Some(nz) = $NonZeroUInner::new(self.to_bits()) else {
+                                panic!("division by zero");
+                            };
+                            let (ret, overflow) = sqrt::rsqrt::$UInner(nz, Self::FRAC_BITS as u32);
+                            (Self::from_bits(ret), overflow)
+                        }
+                    )
+                }
+            }
+
             comment! {
                 "Overflowing linear interpolation between `start` and `end`.
 
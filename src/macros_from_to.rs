@@ -32,7 +32,10 @@ The other number can be:
   * A floating-point number of type [`f16`], [`f32`], [`f64`], [`f128`],
     <code>[half]::[f16][half::f16]</code> or
     <code>[half]::[bf16][half::bf16]</code>. For this conversion, the method
-    rounds to the nearest, with ties rounding to even.
+    rounds to the nearest, with ties rounding to even. In particular, numbers
+    with a magnitude up to half of [`DELTA`][Self::DELTA] are rounded to zero;
+    [`from_num_nonzero`][Self::from_num_nonzero] can be used to round them
+    to [`DELTA`][Self::DELTA] instead.
   * Any other number `src` for which [`ToFixed`] is implemented, in
     which case this method returns
     <code>src.[to\_fixed][ToFixed::to_fixed]\()</code>.
@@ -295,6 +298,482 @@ assert_eq!(one_point_625.checked_to_num::<f32>(), Some(1.625f32));
             }
         }
 
+        comment! {
+            r#"Returns [`true`] if the fixed-point number can be converted
+to another number without overflow.
+
+This can be used to check whether a narrowing conversion, for example to a
+fixed-point number with fewer integer bits, will succeed before performing it.
+As with [`checked_to_num`][Self::checked_to_num], any extra fractional bits are
+discarded when converting, so the result only depends on whether the integer
+part fits.
+
+This method returns
+<code>Dst::[checked\_from\_fixed][FromFixed::checked_from_fixed]\(self).[is\_some][Option::is_some]\()</code>.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// AllFrac has no integer bits
+type AllFrac = ", stringify!($Self), "<", $n, ">;
+
+let quarter = Fix::from_num(0.25);
+let one_point_75 = Fix::from_num(1.75);
+assert!(quarter.fits_in::<AllFrac>());
+assert!(!one_point_75.fits_in::<AllFrac>());
+assert!(Fix::MAX.fits_in::<", stringify!($Self), "<0>>());
+assert!(one_point_75.fits_in::<u8>());
+assert!(Fix::MAX.fits_in::<i", $n, ">());
+```
+";
+            #[inline]
+            #[must_use]
+            pub fn fits_in<Dst: FromFixed>(self) -> bool {
+                Dst::checked_from_fixed(self).is_some()
+            }
+        }
+
+        comment! {
+            r#"Returns [`true`] if the fixed-point number can be converted
+to another number and back without changing its value.
+
+The number is converted to `Dst` using
+<code>Dst::[checked\_from\_fixed][FromFixed::checked_from_fixed]\(self)</code>,
+and the result is converted back using
+<code>[checked\_to\_fixed][ToFixed::checked_to_fixed]</code>. This method
+returns [`false`] if either conversion overflows, or if the final value is not
+exactly equal to `self`, for example because fractional bits were discarded.
+
+This is a convenience for testing that conversions are lossless.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type MoreFrac = ", stringify!($Self), "<2>;
+
+// 1.75 is 1.11 in binary, which is exact with two fractional bits
+assert!(Fix::from_num(1.75).roundtrips_through::<MoreFrac>());
+// 1.625 is 1.101 in binary, which needs three fractional bits
+assert!(!Fix::from_num(1.625).roundtrips_through::<MoreFrac>());
+assert!(Fix::from_num(3).roundtrips_through::<u8>());
+assert!(!Fix::from_num(3.5).roundtrips_through::<u8>());
+assert!(Fix::from_num(2.5).roundtrips_through::<f32>());
+```
+";
+            #[inline]
+            #[must_use]
+            pub fn roundtrips_through<Dst: FromFixed + ToFixed>(self) -> bool {
+                match Dst::checked_from_fixed(self) {
+                    Some(dst) => match dst.checked_to_fixed::<$Self<FRAC>>() {
+                        Some(back) => back.to_bits() == self.to_bits(),
+                        None => false,
+                    },
+                    None => false,
+                }
+            }
+        }
+
+        comment! {
+            r#"Converts the fixed-point number to [`f64`] and scales it by
+10 to the power of `decimal_exp`.
+
+This is useful to display values in different units, for example to display a
+number of millivolts in volts using a `decimal_exp` of &minus;3.
+
+The scaling is performed on the exact value before rounding, so that the
+result is rounded only once, to the nearest with ties rounded to even, when
+|`decimal_exp`|&nbsp;≤&nbsp;55. For larger exponents, the scaling is
+performed in steps keeping at least 128 significant bits, so that double
+rounding is extremely rare. Values that are too large for [`f64`] are
+converted to infinity.
+
+Since 10<sup>&minus;1</sup> cannot be represented exactly in binary, this method
+can be more accurate than
+<code>self.[to\_num][Self::to_num]::&lt;[f64]&gt;() / 10.0</code>.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.5).to_f64_scaled(0), 2.5);
+assert_eq!(Fix::from_num(2.5).to_f64_scaled(2), 250.0);
+assert_eq!(Fix::from_num(2.5).to_f64_scaled(-3), 0.0025);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(-1.5).to_f64_scaled(-1), -0.15);
+",
+            },
+            "```
+";
+            #[inline]
+            #[must_use]
+            pub fn to_f64_scaled(self, decimal_exp: i32) -> f64 {
+                let bits = self.to_bits();
+                let (neg, abs) = if_signed_unsigned!(
+                    $Signedness,
+                    (bits < 0, u128::from(bits.unsigned_abs())),
+                    (false, u128::from(bits)),
+                );
+                crate::float_helper::f64_from_neg_abs_dec_exp(neg, abs, FRAC, decimal_exp)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number if it
+fits, otherwise returns `default`.
+
+This is useful when ingesting floating-point data, as a [NaN] or
+infinite value, as well as any value that does not fit, is replaced by
+`default` instead of causing a panic or saturating.
+
+The other number can be any number for which [`ToFixed`] is
+implemented, and this method returns
+<code>src.[checked\_to\_fixed][ToFixed::checked_to_fixed]\().[unwrap\_or][Option::unwrap_or]\(default)</code>.
+See [`checked_from_num`][Self::checked_from_num] for the rounding
+behavior.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+
+let default = Fix::from_num(5);
+assert_eq!(Fix::from_num_or(1.75f32, default), Fix::from_num(1.75));
+assert_eq!(Fix::from_num_or(f64::NAN, default), default);
+assert_eq!(Fix::from_num_or(f32::INFINITY, default), default);
+assert_eq!(Fix::from_num_or(2e38, default), default);
+assert_eq!(Fix::from_num_or(3, default), Fix::from_num(3));
+```
+
+[NaN]: f64::is_nan
+";
+            #[inline]
+            pub fn from_num_or<Src: ToFixed>(src: Src, default: $Self<FRAC>) -> $Self<FRAC> {
+                match src.checked_to_fixed() {
+                    Some(val) => val,
+                    None => default,
+                }
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from an optional number.
+
+Returns [`None`] if `src` is [`None`], otherwise converts the number with
+[`from_num`][Self::from_num]. This is useful in [`map`][Option::map]-like
+chains.
+
+This method returns
+<code>src.[map][Option::map]\([from\_num][Self::from_num])</code>.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_from_num`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num_opt(Some(2.5)), Some(Fix::from_num(2.5)));
+assert_eq!(Fix::from_num_opt(None::<i32>), None);
+```
+
+[`wrapping_from_num`]: Self::wrapping_from_num
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_opt<Src: ToFixed>(src: Option<Src>) -> Option<$Self<FRAC>> {
+                match src {
+                    Some(src) => Some(src.to_fixed()),
+                    None => None,
+                }
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from an [`f64`], rounding non-zero values
+that are too small to be represented away from zero instead of to zero.
+
+[`from_num`][Self::from_num] rounds to the nearest, so a floating-point
+number with a magnitude up to half of [`DELTA`][Self::DELTA] is rounded to
+zero. This method returns [`DELTA`][Self::DELTA] for such positive numbers
+instead, which is useful when zero is a degenerate input, for example when
+the result is used as a divisor or its reciprocal is computed. "#,
+            if_signed_unsigned!(
+                $Signedness,
+                "Similarly, such negative numbers give
+&minus;[`DELTA`][Self::DELTA].",
+                "Negative numbers are converted as with [`from_num`][Self::from_num], so
+small negative numbers still give zero.",
+            ),
+            " Zero and negative zero give zero, and all other numbers give the same
+result as [`from_num`][Self::from_num].
+
+# Panics
+
+Panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_from_num`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// DELTA is 1/16, and 1/64 is too small to be represented
+assert_eq!(Fix::from_num(1.0 / 64.0), Fix::ZERO);
+assert_eq!(Fix::from_num_nonzero(1.0 / 64.0), Fix::DELTA);
+assert_eq!(Fix::from_num_nonzero(0.0), Fix::ZERO);
+assert_eq!(Fix::from_num_nonzero(1.5), Fix::from_num(1.5));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num_nonzero(-1.0 / 64.0), -Fix::DELTA);
+",
+            },
+            "```
+
+[`wrapping_from_num`]: Self::wrapping_from_num
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_nonzero(src: f64) -> $Self<FRAC> {
+                let val: $Self<FRAC> = src.to_fixed();
+                if val.to_bits() != 0 || src == 0.0 {
+                    return val;
+                }
+                if_signed_unsigned!(
+                    $Signedness,
+                    if src < 0.0 { -Self::DELTA } else { Self::DELTA },
+                    if src < 0.0 { val } else { Self::DELTA },
+                )
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from an [`f64`], rounding with a
+rounding mode chosen at run time.
+
+Unlike [`from_num`][Self::from_num], which always rounds floating-point numbers
+to the nearest with ties rounding to even, this method rounds as specified by
+`mode`, which can for example be read from a configuration file. The conversion
+is exact before rounding, so only values that lie exactly halfway between two
+representable numbers are ties.
+
+# Panics
+
+Panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{"#, stringify!($Self), r#", Round};
+type Fix = "#, stringify!($Self), r#"<4>;
+// 1/32 is halfway between 0 and DELTA = 1/16
+let tie = 1.0 / 32.0;
+assert_eq!(Fix::from_num_dyn(tie, Round::Nearest), Fix::DELTA);
+assert_eq!(Fix::from_num_dyn(tie, Round::ToEven), Fix::ZERO);
+assert_eq!(Fix::from_num_dyn(tie, Round::TowardZero), Fix::ZERO);
+assert_eq!(Fix::from_num_dyn(tie, Round::TowardPosInf), Fix::DELTA);
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num_dyn(-tie, Round::Nearest), -Fix::DELTA);
+assert_eq!(Fix::from_num_dyn(-tie, Round::TowardNegInf), -Fix::DELTA);
+assert_eq!(Fix::from_num_dyn(-tie, Round::TowardZero), Fix::ZERO);
+",
+            },
+            "```
+
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_dyn(src: f64, mode: Round) -> $Self<FRAC> {
+                let (neg, abs, overflow1) = round::from_f64(src, FRAC, mode);
+                let (wrapped, overflow2) = $Self::overflowing_from_neg_abs(neg, abs);
+                debug_assert!(!overflow1 && !overflow2, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another fixed-point number,
+rounding with the given rounding mode.
+
+Unlike [`from_num`][Self::from_num], which discards any extra fractional bits
+and thus rounds towards &minus;∞, this method rounds as specified by `mode`. If
+`src` has no more fractional bits than `Self`, the conversion is exact and
+`mode` has no effect. For [`f64`] sources, see
+[`from_num_dyn`][Self::from_num_dyn].
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, "#, stringify!($Self), r#", Round};
+type Fix = "#, stringify!($Self), r#"<4>;
+// 1/32 is halfway between 0 and DELTA = 1/16
+let tie = I16F16::from_num(1.0 / 32.0);
+assert_eq!(Fix::from_num(tie), Fix::ZERO);
+assert_eq!(Fix::from_num_round(tie, Round::Nearest), Fix::DELTA);
+assert_eq!(Fix::from_num_round(tie, Round::ToEven), Fix::ZERO);
+assert_eq!(Fix::from_num_round(tie, Round::TowardPosInf), Fix::DELTA);
+// 3/32 is halfway between DELTA and 2 × DELTA
+let tie3 = tie * 3;
+assert_eq!(Fix::from_num_round(tie3, Round::ToEven), Fix::DELTA * 2);
+assert_eq!(Fix::from_num_round(tie3, Round::TowardZero), Fix::DELTA);
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num_round(-tie, Round::Nearest), -Fix::DELTA);
+assert_eq!(Fix::from_num_round(-tie, Round::TowardNegInf), -Fix::DELTA);
+assert_eq!(Fix::from_num_round(-tie, Round::TowardZero), Fix::ZERO);
+",
+            },
+            "```
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_round<Src: Fixed>(src: Src, mode: Round) -> $Self<FRAC> {
+                let shift = i64::from(Src::FRAC_BITS) - i64::from(FRAC);
+                if shift <= 0 {
+                    return $Self::from_num(src);
+                }
+                let shift = u32::try_from(shift).unwrap_or(u32::MAX);
+                let (neg, abs) = if Src::IS_SIGNED {
+                    let bits: i128 = az::wrapping_cast(src.to_bits());
+                    (bits < 0, bits.unsigned_abs())
+                } else {
+                    (false, az::wrapping_cast(src.to_bits()))
+                };
+                let abs = round::round_abs(neg, abs, shift, mode);
+                let (wrapped, overflow) = $Self::overflowing_from_neg_abs(neg, abs);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another fixed-point number,
+rounding with the given rounding mode.
+
+This returns
+<code>Dst::[from\_num\_round][Fixed::from_num_round]\(self, mode)</code>.
+Unlike [`to_num`][Self::to_num], which discards any extra fractional bits and
+thus rounds towards &minus;∞, this method rounds as specified by `mode`. To round
+to an integer, <code>[round\_to\_frac\_round][Self::round_to_frac_round]\(0,
+mode)</code> can be followed by [`to_num`][Self::to_num].
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I30F2, "#, stringify!($Self), r#", Round};
+type Fix = "#, stringify!($Self), r#"<4>;
+// 1.125 is 1.001 in binary, halfway between 1 and 1.25
+let tie = Fix::from_bits(0b1_0010);
+assert_eq!(tie.to_num::<I30F2>(), 1);
+assert_eq!(tie.to_num_round::<I30F2>(Round::Nearest), 1.25);
+assert_eq!(tie.to_num_round::<I30F2>(Round::ToEven), 1);
+// 1.375 is 1.011 in binary, halfway between 1.25 and 1.5
+let tie3 = Fix::from_bits(0b1_0110);
+assert_eq!(tie3.to_num_round::<I30F2>(Round::ToEven), 1.5);
+assert_eq!(tie3.to_num_round::<I30F2>(Round::TowardZero), 1.25);
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!((-tie).to_num_round::<I30F2>(Round::Nearest), -1.25);
+assert_eq!((-tie).to_num_round::<I30F2>(Round::TowardPosInf), -1);
+",
+            },
+            "```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn to_num_round<Dst: Fixed>(self, mode: Round) -> Dst {
+                Dst::from_num_round(self, mode)
+            }
+        }
+
+        // Returns the number with the sign neg and the magnitude abs bits,
+        // wrapping if it does not fit.
+        #[inline]
+        pub(crate) const fn overflowing_from_neg_abs(neg: bool, abs: u128) -> ($Self<FRAC>, bool) {
+            let max = if !neg {
+                $Inner::MAX as u128
+            } else if $Inner::MIN == 0 {
+                0
+            } else {
+                $Inner::MAX as u128 + 1
+            };
+            let wrapped = if neg { abs.wrapping_neg() } else { abs };
+            ($Self::from_bits(wrapped as $Inner), abs > max)
+        }
+
         comment! {
             r#"Creates a fixed-point number from another number,
 saturating if it does not fit.
@@ -310,14 +789,12 @@ The other number can be:
     <code>[half]::[f16][half::f16]</code> or
     <code>[half]::[bf16][half::bf16]</code>. For this conversion, the method
     rounds to the nearest, with ties rounding to even.
+    Infinite values saturate to [`MIN`][Self::MIN] or
+    [`MAX`][Self::MAX], and [NaN] is converted to [`ZERO`][Self::ZERO].
   * Any other number `src` for which [`ToFixed`] is implemented, in
     which case this method returns
     <code>src.[saturating\_to\_fixed][ToFixed::saturating_to_fixed]\()</code>.
 
-# Panics
-
-This method panics if the value is a floating-point [NaN].
-
 # Examples
 
 ```rust
@@ -354,13 +831,13 @@ assert_eq!(Fix::saturating_from_num(",
             ),
             "expected);
 assert_eq!(Fix::saturating_from_num(2e38), Fix::MAX);
-assert_eq!(Fix::saturating_from_num(std::f64::NEG_INFINITY), Fix::MIN);
+assert_eq!(Fix::saturating_from_num(f64::NEG_INFINITY), Fix::MIN);
+assert_eq!(Fix::saturating_from_num(f64::NAN), Fix::ZERO);
 ```
 
 [NaN]: f64::is_nan
 ";
             #[inline]
-            #[track_caller]
             pub fn saturating_from_num<Src: ToFixed>(src: Src) -> $Self<FRAC> {
                 src.saturating_to_fixed()
             }
@@ -864,8 +1341,50 @@ assert_eq!(one_point_625.overflowing_to_num::<f32>(), (1.625f32, false));
         #[track_caller]
         #[must_use]
         pub const fn const_from_fixed<const SRC_FRAC: i32>(src: $Self<SRC_FRAC>) -> $Self<FRAC> {
-            let shift_left = FRAC - SRC_FRAC;
-            let nbits = $Inner::BITS as i32;
+            match Self::const_checked_from_fixed(src) {
+                Some(val) => val,
+                None => panic!("overflow"),
+            }
+        }
+
+        /// Creates a fixed-point number from a fixed-point number with the same
+        /// underlying integer type if it fits, otherwise returns [`None`].
+        /// Usable in constant context.
+        ///
+        /// This is equivalent to the [`checked_from_num`] method with
+        #[doc = concat!("<code>[", stringify!($Self), "]&lt;OtherFrac></code>")]
+        /// as its generic parameter, but can also be used in constant context.
+        /// Since only the number of fractional bits differs, the conversion is
+        /// a single shift of the underlying integer, with any discarded
+        /// fractional bits rounding towards &minus;∞.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// #![feature(generic_const_exprs)]
+        /// # #![allow(incomplete_features)]
+        ///
+        #[doc = concat!("use fixed::", stringify!($Self), ";")]
+        #[doc = concat!("type FixA = ", stringify!($Self), "<2>;")]
+        #[doc = concat!("type FixB = ", stringify!($Self), "<4>;")]
+        /// const A: FixA = FixA::unwrapped_from_str("3.5");
+        /// const B: Option<FixB> = FixB::const_checked_from_fixed(A);
+        /// assert_eq!(B, Some(FixB::from_num(3.5)));
+        /// assert_eq!(FixB::const_checked_from_fixed(FixA::MAX), None);
+        /// // 3.25 is rounded down to 3 when converted to a single fractional bit
+        /// let quarters = FixB::from_num(3.25);
+        #[doc = concat!("let halves = ", stringify!($Self), "::<1>::const_checked_from_fixed(quarters);")]
+        /// assert_eq!(halves.unwrap(), 3);
+        /// ```
+        ///
+        /// [`checked_from_num`]: Self::checked_from_num
+        #[inline]
+        #[must_use]
+        pub const fn const_checked_from_fixed<const SRC_FRAC: i32>(
+            src: $Self<SRC_FRAC>,
+        ) -> Option<$Self<FRAC>> {
+            let shift_left = FRAC as i64 - SRC_FRAC as i64;
+            let nbits = $Inner::BITS as i64;
             let src_bits = src.to_bits();
             let bits = if shift_left <= -nbits {
                 src_bits >> (nbits / 2) >> (nbits / 2)
@@ -873,17 +1392,17 @@ assert_eq!(one_point_625.overflowing_to_num::<f32>(), (1.625f32, false));
                 src_bits >> -shift_left
             } else if shift_left >= nbits {
                 if src_bits != 0 {
-                    panic!("overflow");
+                    return None;
                 }
                 0
             } else {
                 let shifted = src_bits << shift_left;
                 if (shifted >> shift_left) != src_bits {
-                    panic!("overflow");
+                    return None;
                 }
                 shifted
             };
-            Self::from_bits(bits)
+            Some(Self::from_bits(bits))
         }
 
         /// Creates a fixed-point number from the underlying integer type
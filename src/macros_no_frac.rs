@@ -245,6 +245,77 @@ assert_eq!(Fix::from_bits(0b10_0000), 2);
                 }
             }
 
+            comment! {
+                "Creates a fixed-point number from its bitwise representation,
+checking that reserved bits are clear.
+
+Returns [`None`] if any of the bits set in `reserved_mask` is also set in
+`bits`, otherwise returns the same value as [`from_bits`][Self::from_bits].
+This can be used to reject malformed data when fixed-point numbers are packed
+into bit fields.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// the two most significant bits are reserved
+let reserved = 0b11 << (", $n, " - 2);
+assert_eq!(Fix::from_bits_masked(0b10_0000, reserved), Some(Fix::from_num(2)));
+assert_eq!(Fix::from_bits_masked(0b01 << (", $n, " - 2), reserved), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_bits_masked(bits: $Inner, reserved_mask: $Inner) -> Option<$Self<FRAC>> {
+                    if bits & reserved_mask != 0 {
+                        None
+                    } else {
+                        Some($Self::from_bits(bits))
+                    }
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from an array of ", $n, " bits, with
+the most significant bit first.
+
+Element <i>i</i> of the array is bit ", $nm1, "&nbsp;&minus;&nbsp;<i>i</i> of
+the [bitwise representation][Self::from_bits], so that the array reads in the
+same order as a binary literal. Since the array length must be equal to the
+number of bits, a mismatch is caught at compile time.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 0010.1000 = 2.5
+let mut bits = [false; ", $n, "];
+bits[", $n, " - 6] = true;
+bits[", $n, " - 4] = true;
+assert_eq!(Fix::from_bit_array(bits), 2.5);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_bit_array(bits: [bool; $n]) -> $Self<FRAC> {
+                    let mut val: $UInner = 0;
+                    let mut i = 0;
+                    while i < $n {
+                        val = (val << 1) | bits[i] as $UInner;
+                        i += 1;
+                    }
+                    $Self::from_bits(val as $Inner)
+                }
+            }
+
             comment! {
                 "Creates an integer that has a bitwise representation
 identical to the given fixed-point number.
@@ -372,6 +443,72 @@ if cfg!(target_endian = \"little\") {
                 }
             }
 
+            comment! {
+                "Returns the underlying integer representation in big endian,
+byte-swapping it from the target’s endianness if needed.
+
+This is equivalent to <code>self.[to\\_be][Self::to_be]\\().[to\\_bits][Self::to_bits]\\()</code>.
+It can be useful when an integer in a known byte order is required, for
+example for protocol code; for a byte array use
+[`to_be_bytes`][Self::to_be_bytes] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let f = Fix::from_bits(", $bytes_val, ");
+if cfg!(target_endian = \"big\") {
+    assert_eq!(f.to_bits_be(), f.to_bits());
+} else {
+    assert_eq!(f.to_bits_be(), f.to_bits().swap_bytes());
+}
+assert_eq!(f.to_bits_be().to_ne_bytes(), f.to_be_bytes());
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_bits_be(self) -> $Inner {
+                    self.to_bits().to_be()
+                }
+            }
+
+            comment! {
+                "Returns the underlying integer representation in little endian,
+byte-swapping it from the target’s endianness if needed.
+
+This is equivalent to <code>self.[to\\_le][Self::to_le]\\().[to\\_bits][Self::to_bits]\\()</code>.
+It can be useful when an integer in a known byte order is required, for
+example for protocol code; for a byte array use
+[`to_le_bytes`][Self::to_le_bytes] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let f = Fix::from_bits(", $bytes_val, ");
+if cfg!(target_endian = \"little\") {
+    assert_eq!(f.to_bits_le(), f.to_bits());
+} else {
+    assert_eq!(f.to_bits_le(), f.to_bits().swap_bytes());
+}
+assert_eq!(f.to_bits_le().to_ne_bytes(), f.to_le_bytes());
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_bits_le(self) -> $Inner {
+                    self.to_bits().to_le()
+                }
+            }
+
             comment! {
                 "Reverses the byte order of the fixed-point number.
 
@@ -556,6 +693,148 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Returns a canonical 16-byte representation of this fixed-point
+number.
+
+The ", $nbytes, " bytes of [`to_be_bytes`][Self::to_be_bytes] are placed at the end
+of the array, and any leading bytes before them are zero. The padding is zero
+even for negative numbers; it is not a sign extension. The result does not
+depend on the platform, so it can be used as a stable key for hashing or
+storage.
+
+The representation does not encode the width, the number of fractional bits or
+the signedness, so keys are only unique among numbers of a single type. For
+example, [`FixedI8`]`::<0>::from_num(-1)` and
+[`FixedI16`]`::<0>::from_num(255)` have the same canonical bytes, and so do
+1/16 with four fractional bits and 1/256 with eight fractional bits.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let val = Fix::from_bits(", $bytes_val, ");
+let bytes = val.to_canonical_bytes();
+assert_eq!(bytes[16 - ", $nbytes, "..], val.to_be_bytes());
+assert!(bytes[..16 - ", $nbytes, "].iter().all(|&b| b == 0));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "let bytes = Fix::NEG_ONE.to_canonical_bytes();
+assert_eq!(bytes[16 - ", $nbytes, "..], Fix::NEG_ONE.to_be_bytes());
+assert!(bytes[..16 - ", $nbytes, "].iter().all(|&b| b == 0));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_canonical_bytes(self) -> [u8; 16] {
+                    let bytes = self.to_be_bytes();
+                    let mut ret = [0; 16];
+                    let mut i = 0;
+                    while i < $nbytes {
+                        ret[16 - $nbytes + i] = bytes[i];
+                        i += 1;
+                    }
+                    ret
+                }
+            }
+
+            comment! {
+                "Writes the memory representation of a slice of fixed-point numbers
+as a contiguous sequence of bytes in big-endian byte order.
+
+Each value takes ", $nbytes, " bytes, so ", $nbytes, "&nbsp;×&nbsp;`values.len()`
+bytes are written to the start of `out`, and the number of bytes written is
+returned. Any remaining bytes of `out` are left unchanged.
+
+# Errors
+
+Returns [`BufferTooSmall`] if `out` is shorter than
+", $nbytes, "&nbsp;×&nbsp;`values.len()` bytes, in which case nothing is written.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let vals = [Fix::from_bits(", $bytes_val, "), Fix::from_bits(", $rev_bytes_val, ")];
+let mut buf = [0; 2 * ", $nbytes, "];
+assert_eq!(Fix::write_be_slice(&vals, &mut buf), Ok(2 * ", $nbytes, "));
+assert_eq!(buf[..", $nbytes, "], ", $be_bytes, ");
+assert_eq!(buf[", $nbytes, "..], ", $le_bytes, ");
+assert!(Fix::write_be_slice(&vals, &mut buf[1..]).is_err());
+```
+";
+                #[inline]
+                pub fn write_be_slice(
+                    values: &[$Self<FRAC>],
+                    out: &mut [u8],
+                ) -> Result<usize, BufferTooSmall> {
+                    let len = values.len() * $nbytes;
+                    let Some(out) = out.get_mut(..len) else {
+                        return Err(BufferTooSmall { _priv: () });
+                    };
+                    for (chunk, val) in out.chunks_exact_mut($nbytes).zip(values) {
+                        chunk.copy_from_slice(&val.to_be_bytes());
+                    }
+                    Ok(len)
+                }
+            }
+
+            comment! {
+                "Writes the memory representation of a slice of fixed-point numbers
+as a contiguous sequence of bytes in little-endian byte order.
+
+Each value takes ", $nbytes, " bytes, so ", $nbytes, "&nbsp;×&nbsp;`values.len()`
+bytes are written to the start of `out`, and the number of bytes written is
+returned. Any remaining bytes of `out` are left unchanged.
+
+# Errors
+
+Returns [`BufferTooSmall`] if `out` is shorter than
+", $nbytes, "&nbsp;×&nbsp;`values.len()` bytes, in which case nothing is written.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let vals = [Fix::from_bits(", $bytes_val, "), Fix::from_bits(", $rev_bytes_val, ")];
+let mut buf = [0; 2 * ", $nbytes, "];
+assert_eq!(Fix::write_le_slice(&vals, &mut buf), Ok(2 * ", $nbytes, "));
+assert_eq!(buf[..", $nbytes, "], ", $le_bytes, ");
+assert_eq!(buf[", $nbytes, "..], ", $be_bytes, ");
+assert!(Fix::write_le_slice(&vals, &mut buf[1..]).is_err());
+```
+";
+                #[inline]
+                pub fn write_le_slice(
+                    values: &[$Self<FRAC>],
+                    out: &mut [u8],
+                ) -> Result<usize, BufferTooSmall> {
+                    let len = values.len() * $nbytes;
+                    let Some(out) = out.get_mut(..len) else {
+                        return Err(BufferTooSmall { _priv: () });
+                    };
+                    for (chunk, val) in out.chunks_exact_mut($nbytes).zip(values) {
+                        chunk.copy_from_slice(&val.to_le_bytes());
+                    }
+                    Ok(len)
+                }
+            }
+
             fixed_from_to! {
                 {Self, Inner} = {$Self, $Inner},
                 Signedness = $Signedness,
@@ -707,6 +986,139 @@ assert_eq!(f.trailing_zeros(), 5);
                 }
             }
 
+            comment! {
+                "Returns the number of leading bits that are redundant in all the
+elements of a slice.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "For each element, the redundant bits are the leading bits that are
+equal to the sign bit, excluding the sign bit itself, so that the result is
+", $n, "&nbsp;&minus;&nbsp;[`signed_bits`][Self::signed_bits]."
+                    ),
+                    "For each element, the redundant bits are the [leading
+zeros][Self::leading_zeros].",
+                ),
+                " The minimum over all the elements is returned, which is the common shift
+that normalizes the whole slice without overflow, as used in block floating
+point. If the slice is empty, ", if_signed_unsigned!($Signedness, $nm1, $n), " is
+returned, just as for a slice of zeros.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "let vals = [Fix::from_num(1.5), Fix::from_num(-0.25), Fix::from_num(3)];
+// 3 is “_011.0000”, so it has the fewest redundant bits
+assert_eq!(Fix::block_leading_zeros(&vals), ", $n, " - 7);"
+                    ),
+                    concat!(
+                        "let vals = [Fix::from_num(1.5), Fix::from_num(0.25), Fix::from_num(3)];
+// 3 is “__11.0000”, so it has the fewest leading zeros
+assert_eq!(Fix::block_leading_zeros(&vals), ", $n, " - 6);"
+                    ),
+                ),
+                "
+// all the elements can be shifted by the common shift without overflow
+let shift = Fix::block_leading_zeros(&vals);
+assert!(vals.iter().all(|&v| (v << shift) >> shift == v));
+assert_eq!(Fix::block_leading_zeros(&[]), ",
+                if_signed_unsigned!($Signedness, $nm1, $n),
+                ");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn block_leading_zeros(values: &[$Self<FRAC>]) -> u32 {
+                    let mut min = if_signed_unsigned!($Signedness, $nm1, $n);
+                    let mut i = 0;
+                    while i < values.len() {
+                        let val = values[i];
+                        let redundant = if_signed_unsigned!(
+                            $Signedness,
+                            $Inner::BITS - val.signed_bits(),
+                            val.leading_zeros(),
+                        );
+                        if redundant < min {
+                            min = redundant;
+                        }
+                        i += 1;
+                    }
+                    min
+                }
+            }
+
+            comment! {
+                "Shifts all the elements of a slice left by the same amount in place,
+saturating the elements that overflow.
+
+This can be used to normalize a slice to block floating point, where `n` is
+the common shift returned by [`block_leading_zeros`]. If `n` is not larger
+than the value returned by [`block_leading_zeros`], no element overflows. Zero
+elements remain zero for any shift, even if `n`&nbsp;≥&nbsp;", $n, ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "let mut vals = [Fix::from_num(0.5), Fix::from_num(-0.25), Fix::ZERO];",
+                    "let mut vals = [Fix::from_num(0.5), Fix::from_num(0.25), Fix::ZERO];",
+                ),
+                "
+let shift = Fix::block_leading_zeros(&vals);
+Fix::shift_block_left(&mut vals, shift);
+assert_eq!(Fix::block_leading_zeros(&vals), 0);
+assert_eq!(vals[0], Fix::from_num(0.5) << shift);
+assert_eq!(vals[2], Fix::ZERO);
+
+// shifting further saturates
+let mut vals = [Fix::from_num(0.5)];
+Fix::shift_block_left(&mut vals, ", $n, ");
+assert_eq!(vals[0], Fix::MAX);
+```
+
+[`block_leading_zeros`]: Self::block_leading_zeros
+";
+                #[inline]
+                pub fn shift_block_left(values: &mut [$Self<FRAC>], n: u32) {
+                    for val in values {
+                        let redundant = if_signed_unsigned!(
+                            $Signedness,
+                            $Inner::BITS - val.signed_bits(),
+                            val.leading_zeros(),
+                        );
+                        *val = if val.to_bits() == 0 {
+                            *val
+                        } else if n <= redundant {
+                            Self::from_bits(val.to_bits() << n)
+                        } else {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if val.is_negative() { Self::MIN } else { Self::MAX },
+                                Self::MAX,
+                            )
+                        };
+                    }
+                }
+            }
+
             if_unsigned! {
                 $Signedness;
                 comment! {
@@ -842,9 +1254,24 @@ assert_eq!(Fix::from_num(0.1875).checked_int_log2(), Some(-3));
             }
 
             comment! {
-                "Reverses the order of the bits of the fixed-point number.
+                "Integer square root, rounded down.
 
-# Examples
+Returns the largest integer whose square is ≤&nbsp;`self`. If `FRAC` is
+negative and that integer is not representable, the largest representable value
+whose square is ≤&nbsp;`self` is returned instead.
+
+Unlike [`sqrt`], this does not compute any fractional bits of the result.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative.
+
+"
+                },
+                "# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -852,21 +1279,32 @@ assert_eq!(Fix::from_num(0.1875).checked_int_log2(), Some(-3));
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let bits = ", $bytes_val, "_", stringify!($Inner), ";
-let rev_bits = bits.reverse_bits();
-assert_eq!(Fix::from_bits(bits).reverse_bits(), Fix::from_bits(rev_bits));
+assert_eq!(Fix::from_num(4).int_sqrt(), Fix::from_num(2));
+assert_eq!(Fix::from_num(3.9375).int_sqrt(), Fix::from_num(1));
+assert_eq!(Fix::from_num(0.9375).int_sqrt(), Fix::ZERO);
 ```
+
+[`sqrt`]: Self::sqrt
 ";
+                #[doc(alias("isqrt"))]
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn reverse_bits(self) -> $Self<FRAC> {
-                    $Self::from_bits(self.to_bits().reverse_bits())
+                #[track_caller]
+                #[must_use]
+                pub const fn int_sqrt(self) -> $Self<FRAC> {
+                    match self.checked_int_sqrt() {
+                        Some(ans) => ans,
+                        None => panic!("square root of negative number"),
+                    }
                 }
             }
 
             comment! {
-                "Shifts to the left by `n` bits, wrapping the
-truncated bits to the right end.
+                "Checked integer square root, rounded down.
+Returns the square root or [`None`] if the fixed-point number is negative.
+
+Returns the largest integer whose square is ≤&nbsp;`self`. If `FRAC` is
+negative and that integer is not representable, the largest representable value
+whose square is ≤&nbsp;`self` is returned instead.
 
 # Examples
 
@@ -876,14 +1314,84 @@ truncated bits to the right end.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let bits: ", stringify!($Inner), " = (0b111 << (", $n, " - 3)) | 0b1010;
-let rot = 0b1010111;
-assert_eq!(bits.rotate_left(3), rot);
-assert_eq!(Fix::from_bits(bits).rotate_left(3), Fix::from_bits(rot));
-```
-";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
+assert_eq!(Fix::from_num(4).checked_int_sqrt(), Some(Fix::from_num(2)));
+assert_eq!(Fix::from_num(3.9375).checked_int_sqrt(), Some(Fix::from_num(1)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_int_sqrt(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[doc(alias("checked_isqrt"))]
+                #[must_use]
+                pub const fn checked_int_sqrt(self) -> Option<$Self<FRAC>> {
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() {
+                            return None;
+                        }
+                    }
+                    // Since self ≥ 0, we can work with unsigned.
+                    let bits = self.to_bits() as $UInner;
+                    let shift = FRAC.unsigned_abs();
+                    if shift >= $Inner::BITS {
+                        return Some(Self::ZERO);
+                    }
+                    // For FRAC ≥ 0, bits >> FRAC is the integer part. For
+                    // FRAC < 0, the value is bits × 2^shift, and the largest
+                    // representable root has bits ⌊√(bits / 2^shift)⌋.
+                    let root = int_helper::$UInner::isqrt(bits >> shift);
+                    let ans = if FRAC >= 0 { root << shift } else { root };
+                    Some(Self::from_bits(ans as $Inner))
+                }
+            }
+
+            comment! {
+                "Reverses the order of the bits of the fixed-point number.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let bits = ", $bytes_val, "_", stringify!($Inner), ";
+let rev_bits = bits.reverse_bits();
+assert_eq!(Fix::from_bits(bits).reverse_bits(), Fix::from_bits(rev_bits));
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn reverse_bits(self) -> $Self<FRAC> {
+                    $Self::from_bits(self.to_bits().reverse_bits())
+                }
+            }
+
+            comment! {
+                "Shifts to the left by `n` bits, wrapping the
+truncated bits to the right end.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let bits: ", stringify!($Inner), " = (0b111 << (", $n, " - 3)) | 0b1010;
+let rot = 0b1010111;
+assert_eq!(bits.rotate_left(3), rot);
+assert_eq!(Fix::from_bits(bits).rotate_left(3), Fix::from_bits(rot));
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
                 pub const fn rotate_left(self, n: u32) -> $Self<FRAC> {
                     Self::from_bits(self.to_bits().rotate_left(n))
                 }
@@ -914,6 +1422,58 @@ assert_eq!(Fix::from_bits(bits).rotate_right(3), Fix::from_bits(rot));
                 }
             }
 
+            comment! {
+                "Shifts to the right by `n` bits, filling the vacated bits on the
+left with zeros.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "The `>>` operator performs an arithmetic shift, which fills the vacated
+bits with copies of the sign bit so that negative numbers stay negative. This
+method instead performs a logical shift on the bits, as if they were unsigned,
+so the result of shifting a negative number is non-negative for `n`&nbsp;>&nbsp;0.",
+                    "For unsigned fixed-point numbers, this is the same as the `>>` operator.",
+                ),
+                "
+
+# Panics
+
+When debug assertions are enabled, this method panics if
+`n`&nbsp;≥&nbsp;", $n, ". When debug assertions are not enabled, the shift
+amount is masked like for the `>>` operator, but it is not considered a
+breaking change if in the future it panics.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "// arithmetic shift keeps the sign
+assert_eq!(Fix::NEG_ONE >> 4, -Fix::DELTA);
+// logical shift fills with zeros
+assert_eq!(Fix::NEG_ONE.logical_shr(4), Fix::MAX >> 3);
+assert_eq!(Fix::from_num(4).logical_shr(3), Fix::from_num(0.5));",
+                    "assert_eq!(Fix::from_num(4).logical_shr(3), Fix::from_num(0.5));
+assert_eq!(Fix::MAX.logical_shr(4), Fix::MAX >> 4);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn logical_shr(self, n: u32) -> $Self<FRAC> {
+                    Self::from_bits((self.to_bits() as $UInner >> n) as $Inner)
+                }
+            }
+
             comment! {
                 "Returns [`true`] if the number is zero.
 
@@ -936,6 +1496,55 @@ assert!(!Fix::from_num(5).is_zero());
                 }
             }
 
+            comment! {
+                "Returns [`true`] if the number is normalized, that is if ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "&minus;1&nbsp;≤&nbsp;`self`&nbsp;<&nbsp;1",
+                    "0&nbsp;≤&nbsp;`self`&nbsp;<&nbsp;1",
+                ),
+                ".
+
+This can be useful in debug assertions to check that values stay in the
+normalized range.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert!(Fix::ZERO.is_normalized());
+assert!(Fix::from_num(0.9375).is_normalized());
+assert!(!Fix::from_num(1).is_normalized());
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert!(Fix::from_num(-1).is_normalized());
+assert!(!Fix::from_num(-1.0625).is_normalized());
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn is_normalized(self) -> bool {
+                    let bits = self.to_bits();
+                    if FRAC < 0 {
+                        // the only representable value in range is zero
+                        return bits == 0;
+                    }
+                    // The integer part, rounded down, must be zero, or
+                    // optionally −1 for signed numbers.
+                    match bits.checked_shr(FRAC as u32) {
+                        None => true,
+                        Some(int) => if_signed_unsigned!($Signedness, int == 0 || int == -1, int == 0),
+                    }
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -1052,6 +1661,212 @@ assert_eq!(a.wide_mul(b), 1.328_125);
                     }
                 }
 
+                comment! {
+                    "Squares a fixed-point number and returns a wider type to
+retain all precision.
+
+If `self` has <i>f</i> fractional bits and ", $n, "&nbsp;&minus;&nbsp;<i>f</i>
+integer bits, then the returned fixed-point number will have
+2&nbsp;×&nbsp;<i>f</i> fractional bits and ", $n2,
+"&nbsp;&minus;&nbsp;2&nbsp;×&nbsp;<i>f</i> integer bits. The square cannot
+overflow, so this can be used to accumulate sums of squares before taking a
+root.
+
+This returns the same value as
+<code>self.[wide\\_mul][Self::wide_mul](self)</code>.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+// 1.0625 × 1.0625 = 1.128_906_25
+let a = ", stringify!($Self), "::<4>::from_num(1.0625);
+assert_eq!(a.wide_square(), 1.128_906_25);
+assert_eq!(", stringify!($Self), "::<4>::MAX.wide_square(), ", stringify!($Self), "::<4>::MAX.wide_mul(", stringify!($Self), "::<4>::MAX));
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn wide_square(self) -> $Double<{ FRAC + FRAC }> {
+                        let bits = self.to_bits() as $DoubleInner;
+                        $Double::from_bits(bits * bits)
+                    }
+                }
+
+                comment! {
+                    "Multiply and add, returning a wider type to retain all
+precision. Returns `self` × `rhs` + `add`.
+
+This is like [`wide_mul`][Self::wide_mul] followed by an addition in the wider
+type, so the product is exact and is never rounded. This can be used to
+accumulate sums of products exactly, for example in FIR filters.
+
+If `self` has <i>f</i> fractional bits and `rhs` has <i>g</i> fractional bits,
+then both `add` and the returned fixed-point number have
+<i>f</i>&nbsp;+&nbsp;<i>g</i> fractional bits and ", $n2,
+"&nbsp;&minus;&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i> integer bits.
+
+# Panics
+
+The product itself cannot overflow, but the addition can. When debug
+assertions are enabled, this method panics if the result overflows. When debug
+assertions are not enabled, the wrapped value can be returned, but it is not
+considered a breaking change if in the future it panics.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Double), "};
+// 1.25 × 1.0625 + 0.5 = 1.828_125
+let a = ", stringify!($Self), "::<2>::from_num(1.25);
+let b = ", stringify!($Self), "::<4>::from_num(1.0625);
+let add = ", stringify!($Double), "::<6>::from_num(0.5);
+assert_eq!(a.wide_mul_add(b, add), 1.828_125);
+
+// exact dot product
+let xs = [", stringify!($Self), "::<4>::from_num(1.5), ", stringify!($Self), "::<4>::from_num(0.0625)];
+let ys = [", stringify!($Self), "::<4>::from_num(2.25), ", stringify!($Self), "::<4>::from_num(0.0625)];
+let mut acc = ", stringify!($Double), "::<8>::ZERO;
+for (x, y) in xs.into_iter().zip(ys) {
+    acc = x.wide_mul_add(y, acc);
+}
+assert_eq!(acc, 3.378_906_25);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn wide_mul_add<const RHS_FRAC: i32>(
+                        self,
+                        rhs: $Self<RHS_FRAC>,
+                        add: $Double<{ FRAC + RHS_FRAC }>,
+                    ) -> $Double<{ FRAC + RHS_FRAC }> {
+                        let self_bits = self.to_bits() as $DoubleInner;
+                        let rhs_bits = rhs.to_bits() as $DoubleInner;
+                        $Double::from_bits(self_bits * rhs_bits + add.to_bits())
+                    }
+                }
+
+                comment! {
+                    "Multiplies by an integer and returns a wider type to retain
+all precision.
+
+The returned fixed-point number has the same number of fractional bits as
+`self`, but it has ", $n, " more integer bits, so the product can never
+overflow, unlike multiplication by an integer using the `*` operator or
+[`checked_mul_int`][Self::checked_mul_int].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Double), "};
+type Fix = ", stringify!($Self), "<4>;
+type Wide = ", stringify!($Double), "<4>;
+assert_eq!(Fix::from_num(1.5).wide_mul_int(3), 4.5);
+// Fix::MAX × 2 does not fit in Fix, but it fits in Wide
+assert_eq!(Fix::MAX.checked_mul_int(2), None);
+assert_eq!(Fix::MAX.wide_mul_int(2), Wide::from_num(Fix::MAX) * 2);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn wide_mul_int(self, rhs: $Inner) -> $Double<FRAC> {
+                        let self_bits = self.to_bits() as $DoubleInner;
+                        $Double::from_bits(self_bits * rhs as $DoubleInner)
+                    }
+                }
+
+                comment! {
+                    "Negates the number and returns a wider signed type, so
+that the negation can never overflow.
+
+The returned fixed-point number has the same number of fractional bits as
+`self`. ",
+                    if_signed_unsigned!(
+                        $Signedness,
+                        "Unlike [`checked_neg`][Self::checked_neg], this works for
+[`MIN`][Self::MIN] too, as &minus;[`MIN`][Self::MIN] fits in the wider type.",
+                        "Unlike [`checked_neg`][Self::checked_neg], this works for
+all values, as the wider type is signed.",
+                    ),
+                    "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($IDouble), "};
+type Fix = ", stringify!($Self), "<4>;
+type Wide = ", stringify!($IDouble), "<4>;
+assert_eq!(Fix::from_num(1.5).wide_neg(), Wide::from_num(-1.5));
+",
+                    if_signed_unsigned!(
+                        $Signedness,
+                        "// -Fix::MIN does not fit in Fix, but it fits in Wide
+assert_eq!(Fix::MIN.checked_neg(), None);
+assert_eq!(Fix::MIN.wide_neg(), -Wide::from_num(Fix::MIN));
+",
+                        "// -Fix::MAX does not fit in Fix, but it fits in Wide
+assert_eq!(Fix::MAX.checked_neg(), None);
+assert_eq!(Fix::MAX.wide_neg(), -Wide::from_num(Fix::MAX));
+",
+                    ),
+                    "```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn wide_neg(self) -> $IDouble<FRAC> {
+                        $IDouble::from_bits(-(self.to_bits() as $IDoubleInner))
+                    }
+                }
+
+                comment! {
+                    "Converts to a fixed-point number with a wider inner type
+and the same number of fractional bits.
+
+The underlying integer is ", if_signed_unsigned!($Signedness, "sign", "zero"), "-extended from ", $n,
+" to ", $n2, " bits, so the conversion is lossless and can be used in constant
+context. The reverse conversion is [`", stringify!($Double), "::const_narrow_inner`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Double), "};
+type Fix = ", stringify!($Self), "<4>;
+type Wide = ", stringify!($Double), "<4>;
+const WIDE_MAX: Wide = Fix::MAX.const_widen_inner();
+assert_eq!(WIDE_MAX, Fix::MAX);
+assert_eq!(Fix::from_num(1.5).const_widen_inner(), Wide::from_num(1.5));
+",
+                    if_signed_else_empty_str! {
+                        $Signedness;
+                        "assert_eq!(Fix::MIN.const_widen_inner(), Fix::MIN);
+",
+                    },
+                    "```
+
+[`", stringify!($Double), "::const_narrow_inner`]: crate::", stringify!($Double), "::const_narrow_inner
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn const_widen_inner(self) -> $Double<FRAC> {
+                        $Double::from_bits(self.to_bits() as $DoubleInner)
+                    }
+                }
+
                 if_signed! {
                     $Signedness;
                     /// Multiplies an unsigned fixed-point number and returns a
@@ -1098,21 +1913,230 @@ assert_eq!(a.wide_mul(b), 1.328_125);
                         }
                         $Double::from_bits(wide_prod)
                     }
-                }
 
-                if_unsigned! {
-                    $Signedness;
-                    /// Multiplies a signed fixed-point number and returns a
-                    /// wider signed type to retain all precision.
+                    /// Multiplication by an unsigned fixed-point number.
                     ///
-                    /// If `self` has <i>f</i> fractional bits and
-                    #[doc = concat!($n, "&nbsp;&minus;&nbsp;<i>f</i>")]
-                    /// integer bits, and `rhs` has <i>g</i> fractional bits and
-                    #[doc = concat!($n, "&nbsp;&minus;&nbsp;<i>g</i>")]
-                    /// integer bits, then the returned fixed-point number will
-                    /// have <i>f</i>&nbsp;+&nbsp;<i>g</i> fractional bits and
-                    #[doc = concat!($n2, "&nbsp;&minus;&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>")]
-                    /// integer bits.
+                    /// The product has the same type as `self`, and like the
+                    /// product of two signed fixed-point numbers, it is rounded
+                    /// down.
+                    ///
+                    /// # Panics
+                    ///
+                    /// When debug assertions are enabled, this method panics if
+                    /// the result overflows. When debug assertions are not
+                    /// enabled, the wrapped value can be returned, but it is not
+                    /// considered a breaking change if in the future it panics;
+                    /// if wrapping is required use [`wrapping_mul_unsigned`]
+                    /// instead.
+                    ///
+                    /// # Examples
+                    ///
+                    /// ```rust
+                    /// #![feature(generic_const_exprs)]
+                    /// # #![allow(incomplete_features)]
+                    ///
+                    #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                    #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                    #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                    /// assert_eq!(Fix::from_num(-2.5).mul_unsigned(UFix::from_num(3)), -7.5);
+                    /// ```
+                    ///
+                    /// [`wrapping_mul_unsigned`]: Self::wrapping_mul_unsigned
+                    #[inline]
+                    #[track_caller]
+                    #[must_use]
+                    pub const fn mul_unsigned(self, rhs: $USelf<FRAC>) -> $Self<FRAC> {
+                        let (ans, overflow) = self.overflowing_mul_unsigned(rhs);
+                        debug_assert!(!overflow, "overflow");
+                        ans
+                    }
+
+                    /// Checked multiplication by an unsigned fixed-point number.
+                    /// Returns the product, or [`None`] on overflow.
+                    ///
+                    /// # Examples
+                    ///
+                    /// ```rust
+                    /// #![feature(generic_const_exprs)]
+                    /// # #![allow(incomplete_features)]
+                    ///
+                    #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                    #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                    #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                    /// assert_eq!(
+                    ///     Fix::from_num(-2.5).checked_mul_unsigned(UFix::from_num(3)),
+                    ///     Some(Fix::from_num(-7.5))
+                    /// );
+                    /// assert_eq!(Fix::MIN.checked_mul_unsigned(UFix::from_num(2)), None);
+                    /// ```
+                    #[inline]
+                    #[must_use]
+                    pub const fn checked_mul_unsigned(
+                        self,
+                        rhs: $USelf<FRAC>,
+                    ) -> Option<$Self<FRAC>> {
+                        match self.overflowing_mul_unsigned(rhs) {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+
+                    /// Saturating multiplication by an unsigned fixed-point
+                    /// number. Returns the product, saturating on overflow.
+                    ///
+                    /// # Examples
+                    ///
+                    /// ```rust
+                    /// #![feature(generic_const_exprs)]
+                    /// # #![allow(incomplete_features)]
+                    ///
+                    #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                    #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                    #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                    /// assert_eq!(
+                    ///     Fix::from_num(-2.5).saturating_mul_unsigned(UFix::from_num(3)),
+                    ///     -7.5
+                    /// );
+                    /// assert_eq!(Fix::MIN.saturating_mul_unsigned(UFix::from_num(2)), Fix::MIN);
+                    /// assert_eq!(Fix::MAX.saturating_mul_unsigned(UFix::from_num(2)), Fix::MAX);
+                    /// ```
+                    #[inline]
+                    #[must_use]
+                    pub const fn saturating_mul_unsigned(self, rhs: $USelf<FRAC>) -> $Self<FRAC> {
+                        match self.overflowing_mul_unsigned(rhs) {
+                            (ans, false) => ans,
+                            (_, true) => {
+                                if self.to_bits() < 0 {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                }
+                            }
+                        }
+                    }
+
+                    /// Wrapping multiplication by an unsigned fixed-point
+                    /// number. Returns the product, wrapping on overflow.
+                    ///
+                    /// # Examples
+                    ///
+                    /// ```rust
+                    /// #![feature(generic_const_exprs)]
+                    /// # #![allow(incomplete_features)]
+                    ///
+                    #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                    #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                    #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                    /// assert_eq!(
+                    ///     Fix::from_num(-2.5).wrapping_mul_unsigned(UFix::from_num(3)),
+                    ///     -7.5
+                    /// );
+                    /// assert_eq!(Fix::MIN.wrapping_mul_unsigned(UFix::from_num(2)), 0);
+                    /// ```
+                    #[inline]
+                    #[must_use]
+                    pub const fn wrapping_mul_unsigned(self, rhs: $USelf<FRAC>) -> $Self<FRAC> {
+                        self.overflowing_mul_unsigned(rhs).0
+                    }
+
+                    /// Unwrapped multiplication by an unsigned fixed-point
+                    /// number. Returns the product, panicking on overflow.
+                    ///
+                    /// # Panics
+                    ///
+                    /// Panics if the result does not fit.
+                    ///
+                    /// # Examples
+                    ///
+                    /// ```rust
+                    /// #![feature(generic_const_exprs)]
+                    /// # #![allow(incomplete_features)]
+                    ///
+                    #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                    #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                    #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                    /// assert_eq!(
+                    ///     Fix::from_num(-2.5).unwrapped_mul_unsigned(UFix::from_num(3)),
+                    ///     -7.5
+                    /// );
+                    /// ```
+                    ///
+                    /// The following panics because of overflow.
+                    ///
+                    /// ```rust,should_panic
+                    /// #![feature(generic_const_exprs)]
+                    /// # #![allow(incomplete_features)]
+                    ///
+                    #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                    #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                    #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                    /// let _overflow = Fix::MIN.unwrapped_mul_unsigned(UFix::from_num(2));
+                    /// ```
+                    #[inline]
+                    #[track_caller]
+                    #[must_use]
+                    pub const fn unwrapped_mul_unsigned(self, rhs: $USelf<FRAC>) -> $Self<FRAC> {
+                        match self.overflowing_mul_unsigned(rhs) {
+                            (ans, false) => ans,
+                            (_, true) => panic!("overflow"),
+                        }
+                    }
+
+                    /// Overflowing multiplication by an unsigned fixed-point
+                    /// number.
+                    ///
+                    /// Returns a [tuple] of the product and a [`bool`]
+                    /// indicating whether an overflow has occurred. On
+                    /// overflow, the wrapped value is returned.
+                    ///
+                    /// # Examples
+                    ///
+                    /// ```rust
+                    /// #![feature(generic_const_exprs)]
+                    /// # #![allow(incomplete_features)]
+                    ///
+                    #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                    #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                    #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                    /// assert_eq!(
+                    ///     Fix::from_num(-2.5).overflowing_mul_unsigned(UFix::from_num(3)),
+                    ///     (Fix::from_num(-7.5), false)
+                    /// );
+                    /// assert_eq!(
+                    ///     Fix::MIN.overflowing_mul_unsigned(UFix::from_num(2)),
+                    ///     (Fix::ZERO, true)
+                    /// );
+                    /// ```
+                    #[inline]
+                    #[must_use]
+                    pub const fn overflowing_mul_unsigned(
+                        self,
+                        rhs: $USelf<FRAC>,
+                    ) -> ($Self<FRAC>, bool) {
+                        // the product of the bits is exact in the double-width
+                        // type, so only the narrowing conversion can overflow
+                        let lhs_bits = self.to_bits() as $DoubleInner;
+                        let rhs_bits = rhs.to_bits() as $DoubleInner;
+                        let (wide, overflow) =
+                            arith::$DoubleInner::overflowing_mul(lhs_bits, rhs_bits, FRAC);
+                        let ans = wide as $Inner;
+                        (Self::from_bits(ans), overflow || ans as $DoubleInner != wide)
+                    }
+                }
+
+                if_unsigned! {
+                    $Signedness;
+                    /// Multiplies a signed fixed-point number and returns a
+                    /// wider signed type to retain all precision.
+                    ///
+                    /// If `self` has <i>f</i> fractional bits and
+                    #[doc = concat!($n, "&nbsp;&minus;&nbsp;<i>f</i>")]
+                    /// integer bits, and `rhs` has <i>g</i> fractional bits and
+                    #[doc = concat!($n, "&nbsp;&minus;&nbsp;<i>g</i>")]
+                    /// integer bits, then the returned fixed-point number will
+                    /// have <i>f</i>&nbsp;+&nbsp;<i>g</i> fractional bits and
+                    #[doc = concat!($n2, "&nbsp;&minus;&nbsp;<i>f</i>&nbsp;&minus;&nbsp;<i>g</i>")]
+                    /// integer bits.
                     ///
                     /// # Examples
                     ///
@@ -1407,6 +2431,53 @@ let _overflow = Fix::MIN.wide_div(-Fix::DELTA);
                 }
             )?
 
+            comment! {
+                "Square. Returns `self` × `self`.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+The result is never negative.
+",
+                },
+                "
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_square`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).square(), Fix::from_num(2.25));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.5).square(), Fix::from_num(2.25));
+",
+                },
+                "```
+
+[`wrapping_square`]: Self::wrapping_square
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn square(self) -> $Self<FRAC> {
+                    let (ans, overflow) =
+                        arith::$Inner::overflowing_mul(self.to_bits(), self.to_bits(), FRAC);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+
             comment! {
                 r#"Multiply and add. Returns `self` × `mul` + `add`.
 
@@ -1440,30 +2511,21 @@ instead.
             }
 
             comment! {
-                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`.
-
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
-overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
-is representable; in these cases this method returns the correct result without
-overflow.
+                "Multiply and subtract. Returns `self` × `mul` &minus; `sub`.
 
-",
-                },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different [number of fractional bits].
+For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its own,
+but the final result is representable; in these cases this method returns the
+correct result without overflow.
 
-The [`mul_acc`] method performs the same operation as this method but mutates
-`self` instead of returning the result.
+The product is computed exactly before `sub` is subtracted, and any fractional
+bits that do not fit are discarded, which rounds towards &minus;∞.
 
 # Panics
 
 When debug assertions are enabled, this method panics if the result overflows.
 When debug assertions are not enabled, the wrapped value can be returned, but it
 is not considered a breaking change if in the future it panics; if wrapping is
-required use [`wrapping_add_prod`] instead.
+required use [`wrapping_mul_sub`] instead.
 
 # Examples
 
@@ -1473,33 +2535,26 @@ required use [`wrapping_add_prod`] instead.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(3).add_prod(Fix::from_num(4), Fix::from_num(0.5)), 5);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
-assert_eq!((-Fix::MAX).add_prod(Fix::MAX, Fix::from_num(1.5)), Fix::MAX / 2);
-"
-                },
-                "```
+assert_eq!(Fix::from_num(3).mul_sub(Fix::from_num(0.5), Fix::ONE), 0.5);
+// MAX × 2 - MAX = MAX, which does not overflow
+assert_eq!(Fix::MAX.mul_sub(Fix::from_num(2), Fix::MAX), Fix::MAX);
+```
 
-[`mul_acc`]: Self::mul_acc
-[`wrapping_add_prod`]: Self::wrapping_add_prod
-[number of fractional bits]: Self::FRAC_BITS
+[`wrapping_mul_sub`]: Self::wrapping_mul_sub
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use]
-                pub const fn add_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn mul_sub<const MUL_FRAC: i32>(
                     self,
-                    a: $Self<A_FRAC>,
-                    b: $Self<B_FRAC>,
+                    mul: $Self<MUL_FRAC>,
+                    sub: $Self<FRAC>,
                 ) -> $Self<FRAC> {
-                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
-                        a.to_bits(),
-                        b.to_bits(),
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_sub(
                         self.to_bits(),
-                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                        mul.to_bits(),
+                        sub.to_bits(),
+                        MUL_FRAC,
                     );
                     debug_assert!(!overflow, "overflow");
                     Self::from_bits(ans)
@@ -1507,30 +2562,23 @@ assert_eq!((-Fix::MAX).add_prod(Fix::MAX, Fix::from_num(1.5)), Fix::MAX / 2);
             }
 
             comment! {
-                "Multiply and accumulate. Adds (`a` × `b`) to `self`.
-
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "For some cases, the product `a` × `b` would overflow on its
-own, but the final result `self` + `a` × `b` is representable; in these cases
-this method saves the correct result without overflow.
+                "Multiply and divide. Returns `self` × `mul` / `div`.
 
-",
-                },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different [number of fractional bits].
+For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its own,
+but the final result is representable; in these cases this method returns the
+correct result without overflow.
 
-The [`add_prod`] method performs the same operation as this method but returns
-the result instead of mutating `self`.
+The product is computed exactly before it is divided by `div`, and the result is
+rounded towards zero.
 
 # Panics
 
-When debug assertions are enabled, this method panics if the result
-overflows. When debug assertions are not enabled, the wrapped value
-can be returned, but it is not considered a breaking change if in the
-future it panics; if wrapping is required use [`wrapping_mul_acc`]
-instead.
+Panics if `div` is zero.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_mul_div`] instead.
 
 # Examples
 
@@ -1540,104 +2588,83 @@ instead.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let mut acc = Fix::from_num(3);
-acc.mul_acc(Fix::from_num(4), Fix::from_num(0.5));
-assert_eq!(acc, 5);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "
-// MAX × 1.5 - MAX = MAX / 2, which does not overflow
-acc = -Fix::MAX;
-acc.mul_acc(Fix::MAX, Fix::from_num(1.5));
-assert_eq!(acc, Fix::MAX / 2);
-"
-                },
-                "```
+assert_eq!(Fix::from_num(6).mul_div(Fix::from_num(1.5), Fix::from_num(2)), 4.5);
+// MAX × 2 overflows, but MAX × 2 / 4 does not
+let half_max = Fix::from_bits(Fix::MAX.to_bits() / 2);
+assert_eq!(Fix::MAX.mul_div(Fix::from_num(2), Fix::from_num(4)), half_max);
+```
 
-[`add_prod`]: Self::add_prod
-[`wrapping_mul_acc`]: Self::wrapping_mul_acc
-[number of fractional bits]: Self::FRAC_BITS
+[`wrapping_mul_div`]: Self::wrapping_mul_div
 ";
                 #[inline]
                 #[track_caller]
-                pub fn mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
-                    &mut self,
-                    a: $Self<A_FRAC>,
-                    b: $Self<B_FRAC>,
-                ) {
-                    *self = self.add_prod(a, b);
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    div: $Self<DIV_FRAC>,
+                ) -> $Self<FRAC> {
+                    let shift = DIV_FRAC as i64 - MUL_FRAC as i64;
+                    match mul_div::$Inner(self.to_bits(), mul.to_bits(), div.to_bits(), shift) {
+                        None => panic!("division by zero"),
+                        Some((ans, overflow)) => {
+                            debug_assert!(!overflow, "overflow");
+                            Self::from_bits(ans)
+                        }
+                    }
                 }
             }
 
             comment! {
-                "Remainder for Euclidean division.
+                r#"Negated multiply and add. Returns `add` &minus; `self` × `mul`.
 
-# Panics
-
-Panics if the divisor is zero.
+This method returns the same value as
+<code>add.[sub\_prod][`sub_prod`](self, mul)</code>.
+The [`sub_prod`] method is more flexible because `neg_mul_add`
+requires that `self` and `add` must have the same [number of fractional bits].
 
-# Examples
+# Panics
 
-```rust
-#![feature(generic_const_exprs)]
-# #![allow(incomplete_features)]
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`wrapping_neg_mul_add`]
+instead.
 
-use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(7.5).rem_euclid(Fix::from_num(2)), Fix::from_num(1.5));
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).rem_euclid(Fix::from_num(2)), Fix::from_num(0.5));
-",
-                },
-                "```
-";
+[`wrapping_neg_mul_add`]: Self::wrapping_neg_mul_add
+[`sub_prod`]: Self::sub_prod
+[number of fractional bits]: Self::FRAC_BITS
+"#;
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn rem_euclid(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    let rhs_bits = rhs.to_bits();
-                    if_signed! {
-                        $Signedness;
-                        if rhs_bits == -1 {
-                            return Self::ZERO;
-                        }
-                    }
-                    Self::from_bits(self.to_bits().rem_euclid(rhs_bits))
+                pub const fn neg_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    add.sub_prod(self, mul)
                 }
             }
 
-            if_signed! {
-                $Signedness;
-                comment! {
-                    "Returns the absolute value.
-
-# Examples
+            comment! {
+                "Multiplies two fixed-point numbers and returns the product
+with `OUT_FRAC` fractional bits.
 
-```rust
-#![feature(generic_const_exprs)]
-# #![allow(incomplete_features)]
+The operands can have different [numbers of fractional bits], and the
+product is computed at full precision before being rescaled, so only the
+final result is rounded; any extra fractional bits are discarded, which
+rounds towards &minus;∞. This generalizes the `wide_mul` method of
+fixed-point numbers narrower than 128 bits, which always keeps all the
+fractional bits of the product.
 
-use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<4>;
-let five = Fix::from_num(5);
-let minus_five = Fix::from_num(-5);
-assert_eq!(five.abs(), five);
-assert_eq!(minus_five.abs(), five);
-```
-";
-                    #[inline]
-                    #[must_use]
-                    pub const fn abs(self) -> $Self<FRAC> {
-                        Self::from_bits(self.to_bits().abs())
-                    }
-                }
+# Panics
 
-                comment! {
-                    "Returns the absolute value using an unsigned type
-without any wrapping or panicking.
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`wrapping_mul_to`]
+instead.
 
 # Examples
 
@@ -1645,41 +2672,48 @@ without any wrapping or panicking.
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
-use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};
+use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-type UFix = ", stringify!($USelf), "<4>;
-assert_eq!(Fix::from_num(-5).unsigned_abs(), UFix::from_num(5));
-// min_as_unsigned has only highest bit set
-let min_as_unsigned = UFix::ONE << (UFix::INT_BITS - 1);
-assert_eq!(Fix::MIN.unsigned_abs(), min_as_unsigned);
+// 0.75 × 1.25 = 0.9375, which is 0.1111 in binary
+let a = Fix::from_num(0.75);
+let b = ", stringify!($Self), "::<2>::from_num(1.25);
+let full: ", stringify!($Self), "<6> = a.mul_to(b);
+assert_eq!(full, 0.9375);
+// with only one fractional bit, 0.9375 is rounded down to 0.5
+let coarse: ", stringify!($Self), "<1> = a.mul_to(b);
+assert_eq!(coarse, 0.5);
 ```
+
+[`wrapping_mul_to`]: Self::wrapping_mul_to
+[numbers of fractional bits]: Self::FRAC_BITS
 ";
-                    #[inline]
-                    #[must_use]
-                    pub const fn unsigned_abs(self) -> $USelf<FRAC> {
-                        $USelf::from_bits(self.to_bits().unsigned_abs())
-                    }
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn mul_to<const OUT_FRAC: i32, const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> $Self<OUT_FRAC> {
+                    let (ans, overflow) = self.overflowing_mul_to(rhs);
+                    debug_assert!(!overflow, "overflow");
+                    ans
                 }
             }
 
             comment! {
-                "Returns the distance from `self` to `other`.
-
-The distance is the absolute value of the difference.
+                "Multiplies by a number with no integer bits, which cannot
+overflow.
 
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "# Panics
+Since `rhs` has at least ", $n, " [fractional bits], its magnitude is less
+than one, so the magnitude of the product is never larger than the magnitude of
+`self`. This is checked at compile time, so no overflow check is needed and
+the method compiles to a plain multiplication.
 
-When debug assertions are enabled, this method panics if the result overflows.
-When debug assertions are not enabled, the wrapped value can be returned, but it
-is not considered a breaking change if in the future it panics; if wrapping is
-required use [`wrapping_dist`] instead.
+The product is rounded down like in the [`Mul`] implementation, that is any
+fractional bits that do not fit are discarded, which rounds towards
+&minus;∞.
 
-",
-                },
-                "# Examples
+# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -1687,75 +2721,63 @@ required use [`wrapping_dist`] instead.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::ONE.dist(Fix::from_num(5)), Fix::from_num(4));
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::NEG_ONE.dist(Fix::from_num(2)), Fix::from_num(3));
-",
-                },
-                "```
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "
-[`wrapping_dist`]: Self::wrapping_dist
-",
-                };
-                #[inline]
-                #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn dist(self, other: $Self<FRAC>) -> $Self<FRAC> {
-                    let s = self.to_bits();
-                    let o = other.to_bits();
-                    let d = if_signed_unsigned!($Signedness, (s - o).abs(), s.abs_diff(o));
-                    Self::from_bits(d)
-                }
-            }
-
-            if_signed! {
-                $Signedness;
-                comment! {
-                    "Returns the distance from `self` to `other` using an
-unsigned type without any wrapping or panicking.
-
-The distance is the absolute value of the difference.
+type Frac = ", stringify!($Self), "<", $n, ">;
+let quarter = Frac::from_num(0.25);
+assert_eq!(Fix::from_num(3).mul_no_overflow(quarter), Fix::from_num(0.75));
+assert_eq!(Fix::MAX.mul_no_overflow(quarter), Fix::MAX / 4);
+```
 
-# Examples
+The following example fails to compile, since `rhs` has integer bits, so the
+product could overflow.
 
-```rust
+```rust,compile_fail
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
-use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};
+use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-type UFix = ", stringify!($USelf), "<4>;
-assert_eq!(Fix::NEG_ONE.unsigned_dist(Fix::from_num(2)), UFix::from_num(3));
-assert_eq!(Fix::MIN.unsigned_dist(Fix::MAX), UFix::MAX);
+let _ = Fix::from_num(3).mul_no_overflow(Fix::from_num(0.25));
 ```
+
+[`Mul`]: core::ops::Mul
+[fractional bits]: Self::FRAC_BITS
 ";
-                    #[inline]
-                    #[must_use = "this returns the result of the operation, without modifying the original"]
-                    pub const fn unsigned_dist(self, other: $Self<FRAC>) -> $USelf<FRAC> {
-                        self.abs_diff(other)
-                    }
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn mul_no_overflow<const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> $Self<FRAC>
+                where
+                    If<{ $n <= RHS_FRAC }>: True,
+                {
+                    let (ans, _) =
+                        arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), RHS_FRAC);
+                    Self::from_bits(ans)
                 }
+            }
 
-                comment! {
-                    "Returns a number representing the sign of `self`.
+            comment! {
+                "Adds two fixed-point numbers with possibly different [numbers
+of fractional bits], returning the sum with the larger number of fractional
+bits.
 
-# Panics
+The operand with fewer fractional bits is converted exactly to the finer
+type before the addition, so no fractional bits are lost; the finer type has
+fewer integer bits, so the conversion can overflow.
 
-When debug assertions are enabled, this method panics
-  * if the value is positive and the fixed-point number has zero
-    or one integer bits such that it cannot hold the value 1.
-  * if the value is negative and the fixed-point number has zero
-    integer bits, such that it cannot hold the value &minus;1.
+The type of the sum can be named in generic code using the associated type
+[`Output`] of the [`AddFrac`] trait, which is implemented for all pairs of
+fixed-point numbers with the same underlying integer type, or using
+[`max_frac`].
 
-When debug assertions are not enabled, the wrapped value can be
-returned in those cases, but it is not considered a breaking change if
-in the future it panics; using this method when 1 and &minus;1 cannot be
-represented is almost certainly a bug.
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`overflowing_add_frac`]
+instead.
 
 # Examples
 
@@ -1764,49 +2786,57 @@ represented is almost certainly a bug.
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(5).signum(), 1);
-assert_eq!(Fix::ZERO.signum(), 0);
-assert_eq!(Fix::from_num(-5).signum(), -1);
+let coarse = ", stringify!($Self), "::<2>::from_num(1.25);
+let fine = ", stringify!($Self), "::<4>::from_num(0.5625);
+let sum: ", stringify!($Self), "<4> = coarse.add_frac(fine);
+assert_eq!(sum, 1.8125);
+assert_eq!(fine.add_frac(coarse), sum);
 ```
+
+[`AddFrac`]: crate::traits::AddFrac
+[`Output`]: crate::traits::AddFrac::Output
+[`max_frac`]: crate::types::extra::max_frac
+[`overflowing_add_frac`]: Self::overflowing_add_frac
+[numbers of fractional bits]: Self::FRAC_BITS
 ";
-                    #[inline]
-                    #[track_caller]
-                    #[must_use]
-                    pub const fn signum(self) -> $Self<FRAC> {
-                        let (ans, overflow) = self.overflowing_signum();
-                        debug_assert!(!overflow, "overflow");
-                        ans
-                    }
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn add_frac<const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> $Self<{ max_frac(FRAC, RHS_FRAC) }> {
+                    let (ans, overflow) = self.overflowing_add_frac(rhs);
+                    debug_assert!(!overflow, "overflow");
+                    ans
                 }
             }
 
             comment! {
-                if_signed_unsigned!(
-                    $Signedness,
-                    "Returns the absolute value of the difference between `self`
-and `other` using an unsigned type without any wrapping or panicking.
+                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`.
 
-This method is the same as [`unsigned_dist`] for signed fixed-point numbers.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
 
-[`unsigned_dist`]: Self::unsigned_dist
 ",
-                    "Returns the absolute value of the difference between `self` and `other`.
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different [number of fractional bits].
 
-This method is the same as [`dist`] for unsigned fixed-point numbers.
+The [`mul_acc`] method performs the same operation as this method but mutates
+`self` instead of returning the result.
 
-[`dist`]: Self::dist
-",
-                );
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn abs_diff(self, other: $Self<FRAC>) -> $USelf<FRAC> {
-                    $USelf::from_bits(self.to_bits().abs_diff(other.to_bits()))
-                }
-            }
+# Panics
 
-            comment! {
-                "Returns the mean of `self` and `other`.
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_add_prod`] instead.
 
 # Examples
 
@@ -1816,44 +2846,63 @@ This method is the same as [`dist`] for unsigned fixed-point numbers.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(3).mean(Fix::from_num(4)), Fix::from_num(3.5));
+assert_eq!(Fix::from_num(3).add_prod(Fix::from_num(4), Fix::from_num(0.5)), 5);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-3).mean(Fix::from_num(4)), Fix::from_num(0.5));
-",
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+assert_eq!((-Fix::MAX).add_prod(Fix::MAX, Fix::from_num(1.5)), Fix::MAX / 2);
+"
                 },
                 "```
+
+[`mul_acc`]: Self::mul_acc
+[`wrapping_add_prod`]: Self::wrapping_add_prod
+[number of fractional bits]: Self::FRAC_BITS
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn mean(self, other: $Self<FRAC>) -> $Self<FRAC> {
-                    // a & b == common bits
-                    // a ^ b == different bits
-                    // a + b == 2 * (a & b) + (a ^ b)
-                    // (a + b) / 2 = (a & b) + (a ^ b) / 2
-                    let (a, b) = (self.to_bits(), other.to_bits());
-                    $Self::from_bits((a & b) + ((a ^ b) >> 1))
+                #[track_caller]
+                #[must_use]
+                pub const fn add_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) -> $Self<FRAC> {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
                 }
             }
 
             comment! {
-                "Compute the hypotenuse of a right triange.
+                "Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`.
 
-The hypotenuse is equal to the square root of the sum of the squares of the
-operands.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, one of the products or the intermediate sum
+`self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b` would overflow on its own, but the final
+result is representable; in these cases this method returns the correct result
+without overflow.
 
-This method uses an iterative method for its square root, with up to ", $n, "
-iterations for [`", stringify!($Self), "`]. The result is rounded down, and the
-error is &lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
-result&nbsp;≤&nbsp;√(`self`²&nbsp;+&nbsp;`other`²)&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+",
+                },
+                "Each product is rounded down on its own, so when there is no
+overflow the result is the same as that of [`add_prod`] called twice.
+The parameters `a`, `b`, `c` and `d` can have a fixed-point type like `self` but
+with a different [number of fractional bits].
 
 # Panics
 
 When debug assertions are enabled, this method panics if the result overflows.
 When debug assertions are not enabled, the wrapped value can be returned, but it
 is not considered a breaking change if in the future it panics; if wrapping is
-required use [`wrapping_hypot`] instead.
+required use [`wrapping_add_prod2`] instead.
 
 # Examples
 
@@ -1862,41 +2911,79 @@ required use [`wrapping_hypot`] instead.
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<", stringify!($nm4), ">;
-
-// hypot(3, 4) == 5
-assert_eq!(Fix::from_num(3).hypot(Fix::from_num(4)), Fix::from_num(5));
-```
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).add_prod2(Fix::from_num(4), Fix::from_num(0.5), Fix::from_num(2), Fix::from_num(0.25)),
+    5.5
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 3 - MAX = MAX, although -MAX + MAX × 3 overflows
+assert_eq!((-Fix::MAX).add_prod2(Fix::MAX, Fix::from_num(3), -Fix::MAX, Fix::ONE), Fix::MAX);
+"
+                },
+                "```
 
-[`wrapping_hypot`]: Self::wrapping_hypot
+[`add_prod`]: Self::add_prod
+[`wrapping_add_prod2`]: Self::wrapping_add_prod2
+[number of fractional bits]: Self::FRAC_BITS
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn hypot(self, other: $Self<FRAC>) -> $Self<FRAC> {
-                    let (val, overflow) = self.overflowing_hypot(other);
-                    debug_assert!(!overflow, "overflow");
-                    val
+                #[must_use]
+                pub const fn add_prod2<
+                    const A_FRAC: i32,
+                    const B_FRAC: i32,
+                    const C_FRAC: i32,
+                    const D_FRAC: i32,
+                >(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                    c: $Self<C_FRAC>,
+                    d: $Self<D_FRAC>,
+                ) -> $Self<FRAC> {
+                    let (ans, ord) = arith::$Inner::overflowing_mul_add2(
+                        a.to_bits(),
+                        b.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                        c.to_bits(),
+                        d.to_bits(),
+                        arith::saturating_add_sub_i32(C_FRAC, D_FRAC, FRAC),
+                        self.to_bits(),
+                    );
+                    debug_assert!(matches!(ord, Ordering::Equal), "overflow");
+                    Self::from_bits(ans)
                 }
             }
 
             comment! {
-                "Returns the smallest multiple of `other` that is ≥&nbsp;`self`",
+                "Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`.
+
+",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    " if `other` is positive, and the largest multiple of
-`other` that is ≤&nbsp;`self` if `other` is negative",
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;&minus;&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
+
+",
                 },
-                ".
+                "The product is computed exactly before being subtracted, and any fractional
+bits that do not fit in `self` are discarded, which rounds the result towards
+&minus;∞.
 
-# Panics
+The `a` and `b` parameters can have a fixed-point type like `self` but with a
+different [number of fractional bits].
 
-Panics if `other` is zero.
+# Panics
 
-When debug assertions are enabled, this method also panics if the result
-overflows. When debug assertions are not enabled, the wrapped value can be
-returned, but it is not considered a breaking change if in the future it panics;
-if wrapping is required use [`wrapping_next_multiple_of`] instead.
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_sub_prod`] instead.
 
 # Examples
 
@@ -1906,54 +2993,66 @@ if wrapping is required use [`wrapping_next_multiple_of`] instead.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(
-    Fix::from_num(4).next_multiple_of(Fix::from_num(1.5)),
-    Fix::from_num(4.5)
-);
+assert_eq!(Fix::from_num(5).sub_prod(Fix::from_num(4), Fix::from_num(0.5)), 3);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(
-    Fix::from_num(4).next_multiple_of(Fix::from_num(-1.5)),
-    Fix::from_num(3)
+                    "// MIN - MIN × 1.5 = -MIN / 2, which does not overflow
+assert_eq!(
+    Fix::MIN.sub_prod(Fix::MIN, Fix::from_num(1.5)),
+    -(Fix::MIN / 2)
 );
-",
+"
                 },
                 "```
 
-[`wrapping_next_multiple_of`]: Self::wrapping_next_multiple_of
+[`wrapping_sub_prod`]: Self::wrapping_sub_prod
+[number of fractional bits]: Self::FRAC_BITS
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn next_multiple_of(self, other: $Self<FRAC>) -> $Self<FRAC> {
-                    let (ans, overflow) = self.overflowing_next_multiple_of(other);
+                #[must_use]
+                pub const fn sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) -> $Self<FRAC> {
+                    let (ans, overflow) = arith::$Inner::overflowing_neg_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                    );
                     debug_assert!(!overflow, "overflow");
-                    ans
+                    Self::from_bits(ans)
                 }
             }
 
             comment! {
-                "Inverse linear interpolation between `start` and `end`.
+                "Multiply and accumulate. Adds (`a` × `b`) to `self`.
 
-The computed value can have a fixed-point type like `self` but with a different
-[number of fractional bits].
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
 
-Returns
-(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
-This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different [number of fractional bits].
 
-This method is implemented for
-0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+The [`add_prod`] method performs the same operation as this method but returns
+the result instead of mutating `self`.
 
 # Panics
 
-Panics when `start`&nbsp;=&nbsp;`end`.
-
-When debug assertions are enabled, this method also panics if the result
-overflows. When debug assertions are not enabled, the wrapped value can be
-returned, but it is not considered a breaking change if in the future it panics;
-if wrapping is required use [`wrapping_inv_lerp`] instead.
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`wrapping_mul_acc`]
+instead.
 
 # Examples
 
@@ -1963,53 +3062,42 @@ if wrapping is required use [`wrapping_inv_lerp`] instead.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let start = Fix::from_num(2);
-let end = Fix::from_num(3.5);
+let mut acc = Fix::from_num(3);
+acc.mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(acc, 5);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(0.5).inv_lerp::<4>(start, end), -1);
-",
+                    "
+// MAX × 1.5 - MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+acc.mul_acc(Fix::MAX, Fix::from_num(1.5));
+assert_eq!(acc, Fix::MAX / 2);
+"
                 },
-                "assert_eq!(Fix::from_num(2).inv_lerp::<4>(start, end), 0);
-assert_eq!(Fix::from_num(2.75).inv_lerp::<4>(start, end), 0.5);
-assert_eq!(Fix::from_num(3.5).inv_lerp::<4>(start, end), 1);
-assert_eq!(Fix::from_num(5).inv_lerp::<4>(start, end), 2);
-```
+                "```
 
-[`wrapping_inv_lerp`]: Self::wrapping_inv_lerp
+[`add_prod`]: Self::add_prod
+[`wrapping_mul_acc`]: Self::wrapping_mul_acc
 [number of fractional bits]: Self::FRAC_BITS
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use]
-                pub const fn inv_lerp<const RET_FRAC: i32>(
-                    self,
-                    start: $Self<FRAC>,
-                    end: $Self<FRAC>,
-                ) -> $Self<RET_FRAC>
-                where
-                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
-                {
-                    let (ans, overflow) = inv_lerp::$Inner(
-                        self.to_bits(),
-                        start.to_bits(),
-                        end.to_bits(),
-                        RET_FRAC as u32,
-                    );
-                    debug_assert!(!overflow, "overflow");
-                    $Self::from_bits(ans)
+                pub fn mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
+                    &mut self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) {
+                    *self = self.add_prod(a, b);
                 }
             }
 
-            if_unsigned! {
-                $Signedness;
-                comment! {
-                    "Returns the highest one in the binary
-representation, or zero if `self` is zero.
+            comment! {
+                "Remainder for Euclidean division.
 
-If `self`&nbsp;>&nbsp;0, the highest one is equal to the largest power of two
-that is ≤&nbsp;`self`.
+# Panics
+
+Panics if the divisor is zero.
 
 # Examples
 
@@ -2019,37 +3107,51 @@ that is ≤&nbsp;`self`.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_bits(0b11_0010).highest_one(), Fix::from_bits(0b10_0000));
-assert_eq!(Fix::from_num(0.3).highest_one(), Fix::from_num(0.25));
-assert_eq!(Fix::from_num(4).highest_one(), Fix::from_num(4));
-assert_eq!(Fix::from_num(6.5).highest_one(), Fix::from_num(4));
-assert_eq!(Fix::ZERO.highest_one(), Fix::ZERO);
-```
+assert_eq!(Fix::from_num(7.5).rem_euclid(Fix::from_num(2)), Fix::from_num(1.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).rem_euclid(Fix::from_num(2)), Fix::from_num(0.5));
+",
+                },
+                "```
 ";
-                    #[inline]
-                    #[must_use]
-                    pub const fn highest_one(self) -> $Self<FRAC> {
-                        const ONE: $Inner = 1;
-                        let bits = self.to_bits();
-                        if bits == 0 {
-                            self
-                        } else {
-                            $Self::from_bits(ONE << (ONE.leading_zeros() - bits.leading_zeros()))
-                        }
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn rem_euclid(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    let rhs_bits = rhs.to_bits();
+                    let rhs_abs = if_signed_unsigned!($Signedness, rhs_bits.unsigned_abs(), rhs_bits);
+                    if rhs_abs.is_power_of_two() {
+                        // The remainder is a mask for both signs of self and
+                        // rhs, and this also handles rhs == -DELTA.
+                        return Self::from_bits(self.to_bits() & (rhs_abs - 1) as $Inner);
                     }
+                    Self::from_bits(self.to_bits().rem_euclid(rhs_bits))
                 }
+            }
 
-                comment! {
-                    "Returns the smallest power of two that is ≥&nbsp;`self`.
+            comment! {
+                "Remainder for Euclidean division by a power of two. Returns `self`
+modulo 2<sup>`exp`</sup>.
 
+The result is in the range 0&nbsp;≤&nbsp;result&nbsp;<&nbsp;2<sup>`exp`</sup>,
+just like for [`rem_euclid`][Self::rem_euclid] with a divisor of
+2<sup>`exp`</sup>, but it is computed using a mask, and the divisor does not
+need to be representable.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
 # Panics
 
-When debug assertions are enabled, panics if the next power of two is
-too large to represent. When debug assertions are not enabled, zero
-can be returned, but it is not considered a breaking change if in the
-future it panics; if this is not desirable use
-[`checked_next_power_of_two`] instead.
-
+When debug assertions are enabled, this method panics if the result
+overflows, which happens if `self` is negative and 2<sup>`exp`</sup> is
+too large to be represented. When debug assertions are not enabled,
+`self` is returned, which is the wrapped value.
+",
+                },
+                "
 # Examples
 
 ```rust
@@ -2058,169 +3160,103 @@ future it panics; if this is not desirable use
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_bits(0b11_0010).next_power_of_two(), Fix::from_bits(0b100_0000));
-assert_eq!(Fix::from_num(0.3).next_power_of_two(), Fix::from_num(0.5));
-assert_eq!(Fix::from_num(4).next_power_of_two(), Fix::from_num(4));
-assert_eq!(Fix::from_num(6.5).next_power_of_two(), Fix::from_num(8));
-```
-
-[`checked_next_power_of_two`]: Self::checked_next_power_of_two
-";
-                    #[inline]
-                    #[track_caller]
-                    #[must_use]
-                    pub const fn next_power_of_two(self) -> $Self<FRAC> {
-                        Self::from_bits(self.to_bits().next_power_of_two())
+assert_eq!(Fix::from_num(7.5).rem_pow2(1), Fix::from_num(1.5));
+assert_eq!(Fix::from_num(7.5).rem_pow2(0), Fix::from_num(0.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).rem_pow2(1), Fix::from_num(0.5));
+",
+                },
+                "// 2^", $n, " is not representable, but 7.5 is not negative
+assert_eq!(Fix::from_num(7.5).rem_pow2(", $n, "), Fix::from_num(7.5));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn rem_pow2(self, exp: u32) -> $Self<FRAC> {
+                    let shift = FRAC.saturating_add_unsigned(exp);
+                    if shift <= 0 {
+                        // 2^exp ≤ DELTA, and all numbers are multiples of DELTA
+                        return Self::ZERO;
+                    }
+                    if shift >= $n {
+                        if_signed! {
+                            $Signedness;
+                            debug_assert!(!self.is_negative(), "overflow");
+                        }
+                        return self;
                     }
+                    let mask = ((1 as $UInner) << shift) - 1;
+                    Self::from_bits(self.to_bits() & mask as $Inner)
                 }
             }
 
-            if_signed! {
-                $Signedness;
-                /// Addition with an unsigned fixed-point number.
-                ///
-                /// # Panics
-                ///
-                /// When debug assertions are enabled, this method panics if the
-                /// result overflows. When debug assertions are not enabled, the
-                /// wrapped value can be returned, but it is not considered a
-                /// breaking change if in the future it panics; if wrapping is
-                /// required use [`wrapping_add_unsigned`] instead.
-                ///
-                /// # Examples
-                ///
-                /// ```rust
-                /// #![feature(generic_const_exprs)]
-                /// # #![allow(incomplete_features)]
-                ///
-                #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
-                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
-                #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
-                /// assert_eq!(Fix::from_num(-5).add_unsigned(UFix::from_num(3)), -2);
-                /// ```
-                ///
-                /// [`wrapping_add_unsigned`]: Self::wrapping_add_unsigned
-                #[inline]
-                #[track_caller]
-                #[must_use]
-                pub const fn add_unsigned(self, rhs: $USelf<FRAC>) -> $Self<FRAC> {
-                    let (ans, overflow) = self.overflowing_add_unsigned(rhs);
-                    debug_assert!(!overflow, "overflow");
-                    ans
-                }
+            comment! {
+                "Returns [`true`] if `self` is an integer multiple of `other`.
 
-                /// Subtraction with an unsigned fixed-point number.
-                ///
-                /// # Panics
-                ///
-                /// When debug assertions are enabled, this method panics if the
-                /// result overflows. When debug assertions are not enabled, the
-                /// wrapped value can be returned, but it is not considered a
-                /// breaking change if in the future it panics; if wrapping is
-                /// required use [`wrapping_sub_unsigned`] instead.
-                ///
-                /// # Examples
-                ///
-                /// ```rust
-                /// #![feature(generic_const_exprs)]
-                /// # #![allow(incomplete_features)]
-                ///
-                #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
-                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
-                #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
-                /// assert_eq!(Fix::from_num(3).sub_unsigned(UFix::from_num(5)), -2);
-                /// ```
-                ///
-                /// [`wrapping_sub_unsigned`]: Self::wrapping_sub_unsigned
-                #[inline]
-                #[track_caller]
-                #[must_use]
-                pub const fn sub_unsigned(self, rhs: $USelf<FRAC>) -> $Self<FRAC> {
-                    let (ans, overflow) = self.overflowing_sub_unsigned(rhs);
-                    debug_assert!(!overflow, "overflow");
-                    ans
-                }
-            }
+This is the case if `self`&nbsp;=&nbsp;<i>k</i>&nbsp;×&nbsp;`other` for some
+integer <i>k</i>, so it is the same as checking that the remainder of `self` /
+`other` is zero, but it never panics or overflows. If `other` is zero, this
+returns [`true`] only if `self` is also zero, like the `is_multiple_of` method
+of integers.
 
-            if_unsigned! {
-                $Signedness;
-                /// Addition with a signed fixed-point number.
-                ///
-                /// # Panics
-                ///
-                /// When debug assertions are enabled, this method panics if the
-                /// result overflows. When debug assertions are not enabled, the
-                /// wrapped value can be returned, but it is not considered a
-                /// breaking change if in the future it panics; if wrapping is
-                /// required use [`wrapping_add_signed`] instead.
-                ///
-                /// # Examples
-                ///
-                /// ```rust
-                /// #![feature(generic_const_exprs)]
-                /// # #![allow(incomplete_features)]
-                ///
-                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
-                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
-                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
-                /// assert_eq!(Fix::from_num(5).add_signed(IFix::from_num(-3)), 2);
-                /// ```
-                ///
-                /// [`wrapping_add_signed`]: Self::wrapping_add_signed
-                #[inline]
-                #[track_caller]
-                #[must_use]
-                pub const fn add_signed(self, rhs: $ISelf<FRAC>) -> $Self<FRAC> {
-                    let (ans, overflow) = self.overflowing_add_signed(rhs);
-                    debug_assert!(!overflow, "overflow");
-                    ans
-                }
+# Examples
 
-                /// Subtraction with a signed fixed-point number.
-                ///
-                /// # Panics
-                ///
-                /// When debug assertions are enabled, this method panics if the
-                /// result overflows. When debug assertions are not enabled, the
-                /// wrapped value can be returned, but it is not considered a
-                /// breaking change if in the future it panics; if wrapping is
-                /// required use [`wrapping_sub_signed`] instead.
-                ///
-                /// # Examples
-                ///
-                /// ```rust
-                /// #![feature(generic_const_exprs)]
-                /// # #![allow(incomplete_features)]
-                ///
-                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
-                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
-                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
-                /// assert_eq!(Fix::from_num(5).sub_signed(IFix::from_num(-3)), 8);
-                /// ```
-                ///
-                /// [`wrapping_sub_signed`]: Self::wrapping_sub_signed
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert!(Fix::from_num(7.5).is_multiple_of(Fix::from_num(2.5)));
+assert!(!Fix::from_num(7.5).is_multiple_of(Fix::from_num(2)));
+assert!(Fix::from_num(7.5).is_multiple_of(Fix::DELTA));
+assert!(Fix::ZERO.is_multiple_of(Fix::ZERO));
+assert!(!Fix::ONE.is_multiple_of(Fix::ZERO));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert!(Fix::from_num(-7.5).is_multiple_of(Fix::from_num(2.5)));
+assert!(Fix::MIN.is_multiple_of(-Fix::DELTA));
+",
+                },
+                "```
+";
                 #[inline]
-                #[track_caller]
                 #[must_use]
-                pub const fn sub_signed(self, rhs: $ISelf<FRAC>) -> $Self<FRAC> {
-                    let (ans, overflow) = self.overflowing_sub_signed(rhs);
-                    debug_assert!(!overflow, "overflow");
-                    ans
+                pub const fn is_multiple_of(self, other: $Self<FRAC>) -> bool {
+                    let (self_bits, other_bits) = (self.to_bits(), other.to_bits());
+                    if other_bits == 0 {
+                        return self_bits == 0;
+                    }
+                    if_signed! {
+                        $Signedness;
+                        // MIN % -1 overflows
+                        if other_bits == -1 {
+                            return true;
+                        }
+                    }
+                    self_bits % other_bits == 0
                 }
             }
 
             comment! {
-                "Bitwise NOT. Usable in constant context.
+                "Rounds to `frac_bits` fractional bits using the given rounding
+mode.
 
-This is equivalent to the `!` operator and
-<code>[Not][core::ops::Not]::[not][core::ops::Not::not]</code>, but
-can also be used in constant context. Unless required in constant
-context, use the operator or trait instead.
+The result has the same type as `self`, but all fractional bits after the first
+`frac_bits` are cleared after rounding as specified by `mode`. If `frac_bits` is
+not less than `FRAC`, `self` is returned unchanged; a negative `frac_bits`
+rounds to a multiple of a power of two greater than one.
 
-# Planned deprecation
+# Panics
 
-This method will be deprecated when the `!` operator and the
-[`Not`][core::ops::Not] trait are usable in constant context.
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
 
 # Examples
 
@@ -2228,32 +3264,70 @@ This method will be deprecated when the `!` operator and the
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
-use fixed::", stringify!($Self), ";
+use fixed::{", stringify!($Self), ", Round};
 type Fix = ", stringify!($Self), "<4>;
-const A: Fix = Fix::from_bits(0x3E);
-const NOT_A: Fix = A.const_not();
-assert_eq!(NOT_A, !A);
-```
+let tie = Fix::from_num(2.5);
+assert_eq!(tie.round_to_frac_round(0, Round::Nearest), 3);
+assert_eq!(tie.round_to_frac_round(0, Round::ToEven), 2);
+assert_eq!(tie.round_to_frac_round(0, Round::TowardZero), 2);
+// 2.25 is 10.01 in binary, halfway between 2 and 2.5
+let tie1 = Fix::from_num(2.25);
+assert_eq!(tie1.round_to_frac_round(1, Round::ToEven), 2);
+assert_eq!(tie1.round_to_frac_round(1, Round::TowardPosInf), 2.5);
+assert_eq!(tie1.round_to_frac_round(4, Round::TowardPosInf), tie1);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-tie).round_to_frac_round(0, Round::Nearest), -3);
+assert_eq!((-tie).round_to_frac_round(0, Round::ToEven), -2);
+assert_eq!((-tie).round_to_frac_round(0, Round::TowardNegInf), -3);
+",
+                },
+                "```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn const_not(self) -> $Self<FRAC> {
-                    Self::from_bits(!self.to_bits())
+                #[track_caller]
+                pub const fn round_to_frac_round(self, frac_bits: i32, mode: Round) -> $Self<FRAC> {
+                    let shift = FRAC as i64 - frac_bits as i64;
+                    if shift <= 0 {
+                        return self;
+                    }
+                    let shift = if shift > u32::MAX as i64 {
+                        u32::MAX
+                    } else {
+                        shift as u32
+                    };
+                    let (neg, abs) = int_helper::$Inner::neg_abs(self.to_bits());
+                    let rounded = round::round_abs(neg, abs as u128, shift, mode);
+                    let (abs, overflow1) = if rounded == 0 {
+                        (0, false)
+                    } else if shift >= 128 {
+                        (0, true)
+                    } else {
+                        (rounded << shift, rounded > u128::MAX >> shift)
+                    };
+                    let (wrapped, overflow2) = Self::overflowing_from_neg_abs(neg, abs);
+                    debug_assert!(!overflow1 && !overflow2, "overflow");
+                    wrapped
                 }
             }
 
             comment! {
-                "Bitwise AND. Usable in constant context.
+                "Rounds to the nearest multiple of 2<sup>&minus;`frac_bits`</sup>, with
+ties rounded to even.
 
-This is equivalent to the `&` operator and
-<code>[BitAnd][core::ops::BitAnd]::[bitand][core::ops::BitAnd::bitand]</code>,
-but can also be used in constant context. Unless required in constant
-context, use the operator or trait instead.
+This is the same as
+<code>[round\\_to\\_frac\\_round][Self::round_to_frac_round]\\(frac\\_bits, Round::ToEven)</code>.
+If `frac_bits` is not less than `FRAC`, `self` is returned unchanged; a negative
+`frac_bits` rounds to a multiple of a power of two greater than one.
 
-# Planned deprecation
+# Panics
 
-This method will be deprecated when the `&` operator and the
-[`BitAnd`][core::ops::BitAnd] trait are usable in constant context.
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
 
 # Examples
 
@@ -2263,31 +3337,44 @@ This method will be deprecated when the `&` operator and the
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-const A: Fix = Fix::from_bits(0x3E);
-const B: Fix = Fix::from_bits(0x55);
-const A_BITAND_B: Fix = A.const_bitand(B);
-assert_eq!(A_BITAND_B, A & B);
-```
+// 2.3125 is 10.0101 in binary
+assert_eq!(Fix::from_num(2.3125).round_to_frac(2), 2.25);
+// 2.375 is 10.011 in binary, halfway between 2.25 and 2.5
+assert_eq!(Fix::from_num(2.375).round_to_frac(2), 2.5);
+assert_eq!(Fix::from_num(2.125).round_to_frac(2), 2);
+assert_eq!(Fix::from_num(2.3125).round_to_frac(4), 2.3125);
+assert_eq!(Fix::from_num(2.5).round_to_frac(-2), 4);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.3125).round_to_frac(2), -2.25);
+assert_eq!(Fix::from_num(-2.375).round_to_frac(2), -2.5);
+",
+                },
+                "```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn const_bitand(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    Self::from_bits(self.to_bits() & rhs.to_bits())
+                #[must_use]
+                #[track_caller]
+                pub const fn round_to_frac(self, frac_bits: i32) -> $Self<FRAC> {
+                    self.round_to_frac_round(frac_bits, Round::ToEven)
                 }
             }
 
             comment! {
-                "Bitwise OR. Usable in constant context.
+                "Rounds up to the next multiple of 2<sup>&minus;`frac_bits`</sup>.
 
-This is equivalent to the `|` operator and
-<code>[BitOr][core::ops::BitOr]::[bitor][core::ops::BitOr::bitor]</code>,
-but can also be used in constant context. Unless required in constant
-context, use the operator or trait instead.
+This is the same as
+<code>[round\\_to\\_frac\\_round][Self::round_to_frac_round]\\(frac\\_bits, Round::TowardPosInf)</code>.
+If `frac_bits` is not less than `FRAC`, `self` is returned unchanged; a negative
+`frac_bits` rounds to a multiple of a power of two greater than one.
 
-# Planned deprecation
+# Panics
 
-This method will be deprecated when the `|` operator and the
-[`BitOr`][core::ops::BitOr] trait are usable in constant context.
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
 
 # Examples
 
@@ -2297,31 +3384,41 @@ This method will be deprecated when the `|` operator and the
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-const A: Fix = Fix::from_bits(0x3E);
-const B: Fix = Fix::from_bits(0x55);
-const A_BITOR_B: Fix = A.const_bitor(B);
-assert_eq!(A_BITOR_B, A | B);
-```
+assert_eq!(Fix::from_num(2.3125).ceil_to_frac(2), 2.5);
+assert_eq!(Fix::from_num(2.25).ceil_to_frac(2), 2.25);
+assert_eq!(Fix::from_num(2.3125).ceil_to_frac(4), 2.3125);
+assert_eq!(Fix::from_num(1).ceil_to_frac(-2), 4);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.3125).ceil_to_frac(2), -2.25);
+",
+                },
+                "```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn const_bitor(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    Self::from_bits(self.to_bits() | rhs.to_bits())
+                #[must_use]
+                #[track_caller]
+                pub const fn ceil_to_frac(self, frac_bits: i32) -> $Self<FRAC> {
+                    self.round_to_frac_round(frac_bits, Round::TowardPosInf)
                 }
             }
 
             comment! {
-                "Bitwise XOR. Usable in constant context.
+                "Rounds down to the previous multiple of
+2<sup>&minus;`frac_bits`</sup>.
 
-This is equivalent to the `^` operator and
-<code>[BitXor][core::ops::BitXor]::[bitxor][core::ops::BitXor::bitxor]</code>,
-but can also be used in constant context. Unless required in constant
-context, use the operator or trait instead.
+This is the same as
+<code>[round\\_to\\_frac\\_round][Self::round_to_frac_round]\\(frac\\_bits, Round::TowardNegInf)</code>.
+If `frac_bits` is not less than `FRAC`, `self` is returned unchanged; a negative
+`frac_bits` rounds to a multiple of a power of two greater than one.
 
-# Planned deprecation
+# Panics
 
-This method will be deprecated when the `^` operator and the
-[`BitXor`][core::ops::BitXor] trait are usable in constant context.
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
 
 # Examples
 
@@ -2331,29 +3428,40 @@ This method will be deprecated when the `^` operator and the
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-const A: Fix = Fix::from_bits(0x3E);
-const B: Fix = Fix::from_bits(0x55);
-const A_BITXOR_B: Fix = A.const_bitxor(B);
-assert_eq!(A_BITXOR_B, A ^ B);
-```
+assert_eq!(Fix::from_num(2.4375).floor_to_frac(2), 2.25);
+assert_eq!(Fix::from_num(2.25).floor_to_frac(2), 2.25);
+assert_eq!(Fix::from_num(2.3125).floor_to_frac(4), 2.3125);
+assert_eq!(Fix::from_num(7).floor_to_frac(-2), 4);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.3125).floor_to_frac(2), -2.5);
+",
+                },
+                "```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn const_bitxor(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    Self::from_bits(self.to_bits() ^ rhs.to_bits())
+                #[must_use]
+                #[track_caller]
+                pub const fn floor_to_frac(self, frac_bits: i32) -> $Self<FRAC> {
+                    self.round_to_frac_round(frac_bits, Round::TowardNegInf)
                 }
             }
 
             comment! {
-                "Checked negation. Returns the negated value, or [`None`] on overflow.
+                "Rounds towards zero to a multiple of 2<sup>&minus;`frac_bits`</sup>.
 
-",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "Overflow can only occur when negating the minimum value.",
-                    "Only zero can be negated without overflow.",
-                ),
-                "
+This is the same as
+<code>[round\\_to\\_frac\\_round][Self::round_to_frac_round]\\(frac\\_bits, Round::TowardZero)</code>.
+If `frac_bits` is not less than `FRAC`, `self` is returned unchanged; a negative
+`frac_bits` rounds to a multiple of a power of two greater than one.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics.
 
 # Examples
 
@@ -2363,29 +3471,52 @@ assert_eq!(A_BITXOR_B, A ^ B);
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(2.4375).trunc_to_frac(2), 2.25);
+assert_eq!(Fix::from_num(2.3125).trunc_to_frac(4), 2.3125);
+assert_eq!(Fix::from_num(7).trunc_to_frac(-2), 4);
 ",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "assert_eq!(Fix::from_num(5).checked_neg(), Some(Fix::from_num(-5)));
-assert_eq!(Fix::MIN.checked_neg(), None);",
-                    "assert_eq!(Fix::ZERO.checked_neg(), Some(Fix::ZERO));
-assert_eq!(Fix::from_num(5).checked_neg(), None);",
-                ),
-                "
-```
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.4375).trunc_to_frac(2), -2.25);
+",
+                },
+                "```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn checked_neg(self) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_neg() {
-                        None => None,
-                        Some(bits) => Some(Self::from_bits(bits)),
-                    }
+                #[track_caller]
+                pub const fn trunc_to_frac(self, frac_bits: i32) -> $Self<FRAC> {
+                    self.round_to_frac_round(frac_bits, Round::TowardZero)
                 }
             }
 
             comment! {
-                "Checked addition. Returns the sum, or [`None`] on overflow.
+                "Division by an integer, returning both the quotient and the
+remainder.
+
+The quotient is the same as `self / rhs`, that is it is truncated towards zero
+to a multiple of [`DELTA`][Self::DELTA], and the remainder is what is left over,
+so that quotient&nbsp;×&nbsp;`rhs`&nbsp;+&nbsp;remainder&nbsp;=&nbsp;`self`.
+Both are obtained from a single division of the underlying integers.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "The remainder has the same sign as `self`.
+",
+                },
+                "
+Note that the remainder is not the same as <code>self&nbsp;%&nbsp;rhs</code>,
+which is the remainder after truncating the quotient to an integer.
+
+# Panics
+
+Panics if the divisor is zero",
+                if_signed_unsigned!(
+                    $Signedness,
+                    " or if the division results in overflow.",
+                    ".",
+                ),
+                "
 
 # Examples
 
@@ -2395,22 +3526,46 @@ assert_eq!(Fix::from_num(5).checked_neg(), None);",
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!((Fix::MAX - Fix::ONE).checked_add(Fix::ONE), Some(Fix::MAX));
-assert_eq!(Fix::MAX.checked_add(Fix::ONE), None);
-```
+let (quot, rem) = Fix::ONE.div_rem_int(3);
+assert_eq!(quot, Fix::from_bits(5));
+assert_eq!(rem, Fix::DELTA);
+assert_eq!(quot * 3 + rem, Fix::ONE);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(
+    Fix::NEG_ONE.div_rem_int(3),
+    (Fix::from_bits(-5), -Fix::DELTA)
+);
+",
+                },
+                "```
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_add(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_add(rhs.to_bits()) {
-                        None => None,
-                        Some(bits) => Some($Self::from_bits(bits)),
-                    }
+                pub const fn div_rem_int(self, rhs: $Inner) -> ($Self<FRAC>, $Self<FRAC>) {
+                    let bits = self.to_bits();
+                    (Self::from_bits(bits / rhs), Self::from_bits(bits % rhs))
                 }
             }
 
             comment! {
-                "Checked subtraction. Returns the difference, or [`None`] on overflow.
+                "Multiplies by a power of ten. Returns
+`self`&nbsp;×&nbsp;10<sup>`exp`</sup>.
+
+If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+result is rounded to the nearest, with ties rounded to even. No intermediate
+value can overflow, so the result is correct whenever it is representable. This
+is useful to convert between decimal units.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`wrapping_scale_pow10`]
+instead.
 
 # Examples
 
@@ -2420,22 +3575,30 @@ assert_eq!(Fix::MAX.checked_add(Fix::ONE), None);
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!((Fix::MIN + Fix::ONE).checked_sub(Fix::ONE), Some(Fix::MIN));
-assert_eq!(Fix::MIN.checked_sub(Fix::ONE), None);
+// 0.0625 × 10^2 = 6.25
+assert_eq!(Fix::from_num(0.0625).scale_pow10(2), Fix::from_num(6.25));
+// 7.5 / 10 = 0.75
+assert_eq!(Fix::from_num(7.5).scale_pow10(-1), Fix::from_num(0.75));
+// 1 / 10 = 0.1 is rounded to 0.125, binary 0.001
+assert_eq!(Fix::from_num(1).scale_pow10(-1), Fix::from_num(0.125));
 ```
+
+[`wrapping_scale_pow10`]: Self::wrapping_scale_pow10
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_sub(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_sub(rhs.to_bits()) {
-                        None => None,
-                        Some(bits) => Some($Self::from_bits(bits)),
-                    }
+                pub const fn scale_pow10(self, exp: i32) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_scale_pow10(exp);
+                    debug_assert!(!overflow, "overflow");
+                    ans
                 }
             }
 
-            comment! {
-                "Checked multiplication. Returns the product, or [`None`] on overflow.
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Returns the absolute value.
 
 # Examples
 
@@ -2445,23 +3608,22 @@ assert_eq!(Fix::MIN.checked_sub(Fix::ONE), None);
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::MAX.checked_mul(Fix::ONE), Some(Fix::MAX));
-assert_eq!(Fix::MAX.checked_mul(Fix::from_num(2)), None);
+let five = Fix::from_num(5);
+let minus_five = Fix::from_num(-5);
+assert_eq!(five.abs(), five);
+assert_eq!(minus_five.abs(), five);
 ```
 ";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_mul(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    match arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), FRAC) {
-                        (ans, false) => Some(Self::from_bits(ans)),
-                        (_, true) => None,
+                    #[inline]
+                    #[must_use]
+                    pub const fn abs(self) -> $Self<FRAC> {
+                        Self::from_bits(self.to_bits().abs())
                     }
                 }
-            }
 
-            comment! {
-                "Checked remainder. Returns the remainder, or [`None`] if
-the divisor is zero.
+                comment! {
+                    "Returns the absolute value using an unsigned type
+without any wrapping or panicking.
 
 # Examples
 
@@ -2469,69 +3631,41 @@ the divisor is zero.
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
-use fixed::", stringify!($Self), ";
+use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(1.5).checked_rem(Fix::ONE), Some(Fix::from_num(0.5)));
-assert_eq!(Fix::from_num(1.5).checked_rem(Fix::ZERO), None);
+type UFix = ", stringify!($USelf), "<4>;
+assert_eq!(Fix::from_num(-5).unsigned_abs(), UFix::from_num(5));
+// min_as_unsigned has only highest bit set
+let min_as_unsigned = UFix::ONE << (UFix::INT_BITS - 1);
+assert_eq!(Fix::MIN.unsigned_abs(), min_as_unsigned);
 ```
 ";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_rem(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    let rhs_bits = rhs.to_bits();
-                    if_signed! {
-                        $Signedness;
-                        if rhs_bits == -1 {
-                            return Some(Self::ZERO);
-                        }
-                    }
-                    match self.to_bits().checked_rem(rhs_bits) {
-                        None => None,
-                        Some(bits) => Some(Self::from_bits(bits)),
+                    #[inline]
+                    #[must_use]
+                    pub const fn unsigned_abs(self) -> $USelf<FRAC> {
+                        $USelf::from_bits(self.to_bits().unsigned_abs())
                     }
                 }
             }
 
             comment! {
-                r#"Checked multiply and add.
-Returns `self` × `mul` + `add`, or [`None`] on overflow.
-
-This method retuns the same value as
-<code>add.[checked\_add\_prod][`checked_add_prod`](self, mul)</code>.
-The [`checked_add_prod`] method is more flexible because `checked_mul_add`
-requires that `self` and `add` must have the same [number of fractional bits].
-
-[`checked_add_prod`]: Self::checked_add_prod
-[number of fractional bits]: Self::FRAC_BITS
-"#;
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_mul_add<const MUL_FRAC: i32>(
-                    self,
-                    mul: $Self<MUL_FRAC>,
-                    add: $Self<FRAC>,
-                ) -> Option<$Self<FRAC>> {
-                    add.checked_add_prod(self, mul)
-                }
-            }
+                "Returns the distance from `self` to `other`.
 
-            comment! {
-                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, returning [`None`] on overflow.
+The distance is the absolute value of the difference.
 
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
-overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
-is representable; in these cases this method returns the correct result without
-overflow.
+                    "# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_dist`] instead.
 
 ",
                 },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different [number of fractional bits].
-
-# Examples
+                "# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -2539,69 +3673,51 @@ overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(
-    Fix::from_num(3).checked_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
-    Some(Fix::from_num(5))
-);
-assert_eq!(Fix::DELTA.checked_add_prod(Fix::MAX, Fix::ONE), None);
+assert_eq!(Fix::ONE.dist(Fix::from_num(5)), Fix::from_num(4));
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
-assert_eq!(
-    (-Fix::MAX).checked_add_prod(Fix::MAX, Fix::from_num(1.5)),
-    Some(Fix::MAX / 2)
-);
-"
+                    "assert_eq!(Fix::NEG_ONE.dist(Fix::from_num(2)), Fix::from_num(3));
+",
                 },
                 "```
-
-[number of fractional bits]: Self::FRAC_BITS
-";
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+[`wrapping_dist`]: Self::wrapping_dist
+",
+                };
                 #[inline]
-                #[must_use]
-                pub const fn checked_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
-                    self,
-                    a: $Self<A_FRAC>,
-                    b: $Self<B_FRAC>,
-                ) -> Option<$Self<FRAC>> {
-                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
-                        a.to_bits(),
-                        b.to_bits(),
-                        self.to_bits(),
-                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
-                    );
-                    if overflow {
-                        return None;
-                    }
-                    Some(Self::from_bits(ans))
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn dist(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    let s = self.to_bits();
+                    let o = other.to_bits();
+                    let d = if_signed_unsigned!($Signedness, (s - o).abs(), s.abs_diff(o));
+                    Self::from_bits(d)
                 }
             }
 
             comment! {
-                r#"Checked multiply and accumulate. Adds (`a` × `b`) to `self`,
-or returns [`None`] on overflow.
-
-Like all other checked methods, this method wraps the successful return value in
-an [`Option`]. Since the unchecked [`mul_acc`] method does not return a value,
-which is equivalent to returning [`()`][unit], this method wraps [`()`][unit]
-into <code>[Some]\([()][unit])</code> on success.
+                "Returns the positive difference between `self` and `other`.
 
-When overflow occurs, `self` is not modified and retains its previous value.
+The positive difference is `self`&nbsp;&minus;&nbsp;`other` if `self` is
+greater than `other`, and zero otherwise.
 
-"#,
+",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "For some cases, the product `a` × `b` would overflow on its
-own, but the final result `self` + `a` × `b` is representable; in these cases
-this method saves the correct result without overflow.
+                    "# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_positive_diff`] instead.
 
 ",
                 },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different [number of fractional bits].
-
-# Examples
+                "# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -2609,52 +3725,71 @@ this method saves the correct result without overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let mut acc = Fix::from_num(3);
-let check = acc.checked_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
-assert_eq!(check, Some(()));
-assert_eq!(acc, 5);
-
-acc = Fix::DELTA;
-let check = acc.checked_mul_acc(Fix::MAX, Fix::ONE);
-assert_eq!(check, None);
-// acc is unchanged on error
-assert_eq!(acc, Fix::DELTA);
+assert_eq!(Fix::from_num(5).positive_diff(Fix::ONE), Fix::from_num(4));
+assert_eq!(Fix::ONE.positive_diff(Fix::from_num(5)), Fix::ZERO);
+```
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "
-// MAX × 1.5 - MAX = MAX / 2, which does not overflow
-acc = -Fix::MAX;
-let check = acc.checked_mul_acc(Fix::MAX, Fix::from_num(1.5));
-assert_eq!(check, Some(()));
-assert_eq!(acc, Fix::MAX / 2);
-"
-                },
-                "```
-
-[`mul_acc`]: Self::mul_acc
-[number of fractional bits]: Self::FRAC_BITS
-";
+[`wrapping_positive_diff`]: Self::wrapping_positive_diff
+",
+                };
                 #[inline]
-                #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
-                pub fn checked_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
-                    &mut self,
-                    a: $Self<A_FRAC>,
-                    b: $Self<B_FRAC>,
-                ) -> Option<()> {
-                    match self.checked_add_prod(a, b) {
-                        Some(s) => {
-                            *self = s;
-                            Some(())
-                        }
-                        None => None,
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn positive_diff(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    if self.to_bits() > other.to_bits() {
+                        Self::from_bits(self.to_bits() - other.to_bits())
+                    } else {
+                        Self::ZERO
                     }
                 }
             }
 
-            comment! {
-                "Checked multiplication by an integer. Returns the
-product, or [`None`] on overflow.
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Returns the distance from `self` to `other` using an
+unsigned type without any wrapping or panicking.
+
+The distance is the absolute value of the difference.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};
+type Fix = ", stringify!($Self), "<4>;
+type UFix = ", stringify!($USelf), "<4>;
+assert_eq!(Fix::NEG_ONE.unsigned_dist(Fix::from_num(2)), UFix::from_num(3));
+assert_eq!(Fix::MIN.unsigned_dist(Fix::MAX), UFix::MAX);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn unsigned_dist(self, other: $Self<FRAC>) -> $USelf<FRAC> {
+                        self.abs_diff(other)
+                    }
+                }
+
+                comment! {
+                    "Returns a number representing the sign of `self`.
+
+# Panics
+
+When debug assertions are enabled, this method panics
+  * if the value is positive and the fixed-point number has zero
+    or one integer bits such that it cannot hold the value 1.
+  * if the value is negative and the fixed-point number has zero
+    integer bits, such that it cannot hold the value &minus;1.
+
+When debug assertions are not enabled, the wrapped value can be
+returned in those cases, but it is not considered a breaking change if
+in the future it panics; using this method when 1 and &minus;1 cannot be
+represented is almost certainly a bug.
 
 # Examples
 
@@ -2664,30 +3799,86 @@ product, or [`None`] on overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::MAX.checked_mul_int(1), Some(Fix::MAX));
-assert_eq!(Fix::MAX.checked_mul_int(2), None);
+assert_eq!(Fix::from_num(5).signum(), 1);
+assert_eq!(Fix::ZERO.signum(), 0);
+assert_eq!(Fix::from_num(-5).signum(), -1);
 ```
 ";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_mul_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_mul(rhs) {
-                        None => None,
-                        Some(bits) => Some(Self::from_bits(bits)),
+                    #[inline]
+                    #[track_caller]
+                    #[must_use]
+                    pub const fn signum(self) -> $Self<FRAC> {
+                        let (ans, overflow) = self.overflowing_signum();
+                        debug_assert!(!overflow, "overflow");
+                        ans
+                    }
+                }
+
+                comment! {
+                    "Returns a number with the magnitude of `self` and the sign of
+`sign`.
+
+Zero has no sign, so if `sign` is zero the result is non-negative.
+
+# Panics
+
+When debug assertions are enabled, this method panics if `self` is
+[`MIN`][Self::MIN] and `sign` is not negative, as the magnitude of
+[`MIN`][Self::MIN] cannot be represented as a positive number. When debug
+assertions are not enabled, [`MIN`][Self::MIN] is returned in that case, but it
+is not considered a breaking change if in the future it panics; if this is not
+desired use [`checked_copysign`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let pos = Fix::from_num(2.5);
+let neg = Fix::from_num(-0.5);
+assert_eq!(pos.copysign(neg), -pos);
+assert_eq!((-pos).copysign(Fix::DELTA), pos);
+assert_eq!(pos.copysign(Fix::ZERO), pos);
+assert_eq!((-pos).copysign(Fix::ZERO), pos);
+```
+
+[`checked_copysign`]: Self::checked_copysign
+";
+                    #[inline]
+                    #[track_caller]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn copysign(self, sign: $Self<FRAC>) -> $Self<FRAC> {
+                        if self.is_negative() == sign.is_negative() {
+                            return self;
+                        }
+                        let (ans, overflow) = self.overflowing_neg();
+                        debug_assert!(!overflow, "overflow");
+                        ans
                     }
                 }
             }
 
             comment! {
-                "Checked division by an integer. Returns the quotient, or
-[`None`] if the divisor is zero",
+                "Returns the sign of `self` as an integer of the underlying type: ",
                 if_signed_unsigned!(
                     $Signedness,
-                    " or if the division results in overflow.",
-                    ".",
+                    "&minus;1 if the number is negative, 0 if it is zero, and 1 if
+it is positive.
+
+Unlike [`signum`], this method never overflows, whatever the number of
+integer bits.
+
+[`signum`]: Self::signum
+",
+                    "0 if the number is zero, and 1 if it is positive.
+
+This method never overflows, whatever the number of integer bits.
+",
                 ),
                 "
-
 # Examples
 
 ```rust
@@ -2696,29 +3887,36 @@ assert_eq!(Fix::MAX.checked_mul_int(2), None);
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::MAX.checked_div_int(1), Some(Fix::MAX));
-assert_eq!(Fix::ONE.checked_div_int(0), None);
+assert_eq!(Fix::from_num(5).signum_int(), 1);
+assert_eq!(Fix::ZERO.signum_int(), 0);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::MIN.checked_div_int(-1), None);
-",
+                    "assert_eq!(Fix::from_num(-5).signum_int(), -1);
+"
                 },
-                "```
+                "// works even when 1 is not representable
+type AllFrac = ", stringify!($Self), "<", stringify!($n), ">;
+assert_eq!(AllFrac::DELTA.signum_int(), 1);
+```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_div_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_div(rhs) {
-                        None => None,
-                        Some(bits) => Some(Self::from_bits(bits)),
-                    }
+                #[must_use]
+                pub const fn signum_int(self) -> $Inner {
+                    if_signed_unsigned!(
+                        $Signedness,
+                        self.to_bits().signum(),
+                        if self.to_bits() == 0 { 0 } else { 1 },
+                    )
                 }
             }
 
             comment! {
-                "Checked remainder for Euclidean division. Returns the
-remainder, or [`None`] if the divisor is zero.
+                "Returns the sign of `self` as an [`Ordering`] compared to zero.
+
+This is the same as comparing `self` with [`ZERO`][Self::ZERO], which is
+convenient for branching with `match`. Like [`signum_int`][Self::signum_int],
+this method never overflows, whatever the number of integer bits.
 
 # Examples
 
@@ -2726,39 +3924,74 @@ remainder, or [`None`] if the divisor is zero.
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
+use core::cmp::Ordering;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let num = Fix::from_num(7.5);
-assert_eq!(num.checked_rem_euclid(Fix::from_num(2)), Some(Fix::from_num(1.5)));
-assert_eq!(num.checked_rem_euclid(Fix::ZERO), None);
+assert_eq!(Fix::from_num(5).sign(), Ordering::Greater);
+assert_eq!(Fix::ZERO.sign(), Ordering::Equal);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!((-num).checked_rem_euclid(Fix::from_num(2)), Some(Fix::from_num(0.5)));
-",
+                    "assert_eq!(Fix::from_num(-5).sign(), Ordering::Less);
+"
                 },
                 "```
+
+[`Ordering`]: core::cmp::Ordering
 ";
+                #[inline]
+                #[must_use]
+                pub const fn sign(self) -> Ordering {
+                    if_signed_unsigned!(
+                        $Signedness,
+                        if self.to_bits() < 0 {
+                            Ordering::Less
+                        } else if self.to_bits() == 0 {
+                            Ordering::Equal
+                        } else {
+                            Ordering::Greater
+                        },
+                        if self.to_bits() == 0 {
+                            Ordering::Equal
+                        } else {
+                            Ordering::Greater
+                        },
+                    )
+                }
+            }
+
+            comment! {
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Returns the absolute value of the difference between `self`
+and `other` using an unsigned type without any wrapping or panicking.
+
+This method is the same as [`unsigned_dist`] for signed fixed-point numbers.
+
+[`unsigned_dist`]: Self::unsigned_dist
+",
+                    "Returns the absolute value of the difference between `self` and `other`.
+
+This method is the same as [`dist`] for unsigned fixed-point numbers.
+
+[`dist`]: Self::dist
+",
+                );
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_rem_euclid(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    let rhs_bits = rhs.to_bits();
-                    if_signed! {
-                        $Signedness;
-                        if rhs_bits == -1 {
-                            return Some(Self::ZERO);
-                        }
-                    }
-                    match self.to_bits().checked_rem_euclid(rhs_bits) {
-                        None => None,
-                        Some(bits) => Some(Self::from_bits(bits)),
-                    }
+                pub const fn abs_diff(self, other: $Self<FRAC>) -> $USelf<FRAC> {
+                    $USelf::from_bits(self.to_bits().abs_diff(other.to_bits()))
                 }
             }
 
             comment! {
-                "Checked shift left. Returns the shifted number,
-or [`None`] if `rhs`&nbsp;≥&nbsp;", $n, ".
+                "Returns the number of representable steps between `self` and
+`other`, that is the number of [units in the last place (ULPs)][ULP] that they
+are apart.
+
+This is the same as
+<code>self.[abs\\_diff][Self::abs_diff]\\(other).[to\\_bits][", stringify!($USelf), "::to_bits]\\()</code>,
+and is useful for stopping criteria in iterative algorithms.
 
 # Examples
 
@@ -2768,23 +4001,28 @@ or [`None`] if `rhs`&nbsp;≥&nbsp;", $n, ".
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!((Fix::ONE / 2).checked_shl(3), Some(Fix::from_num(4)));
-assert_eq!((Fix::ONE / 2).checked_shl(", $n, "), None);
+let one = Fix::ONE;
+assert_eq!(one.ulps_between(one), 0);
+assert_eq!(one.ulps_between(one + Fix::DELTA), 1);
+assert_eq!((one + Fix::DELTA).ulps_between(one), 1);
+assert_eq!(Fix::MIN.ulps_between(Fix::MAX), ", stringify!($UInner), "::MAX);
 ```
+
+[ULP]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_shl(self, rhs: u32) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_shl(rhs) {
-                        None => None,
-                        Some(bits) => Some(Self::from_bits(bits)),
-                    }
+                #[must_use]
+                pub const fn ulps_between(self, other: $Self<FRAC>) -> $UInner {
+                    self.to_bits().abs_diff(other.to_bits())
                 }
             }
 
             comment! {
-                "Checked shift right. Returns the shifted number,
-or [`None`] if `rhs`&nbsp;≥&nbsp;", $n, ".
+                "Returns the next representable number after `self`, that is
+`self`&nbsp;+&nbsp;[`DELTA`][Self::DELTA], saturating at
+[`MAX`][Self::MAX].
+
+This is similar to the `next_up` method of floating-point numbers.
 
 # Examples
 
@@ -2794,26 +4032,24 @@ or [`None`] if `rhs`&nbsp;≥&nbsp;", $n, ".
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(4).checked_shr(3), Some(Fix::ONE / 2));
-assert_eq!(Fix::from_num(4).checked_shr(", $n, "), None);
+assert_eq!(Fix::ONE.next_up(), Fix::ONE + Fix::DELTA);
+assert_eq!(Fix::ZERO.next_up(), Fix::DELTA);
+assert_eq!(Fix::MAX.next_up(), Fix::MAX);
 ```
 ";
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_shr(self, rhs: u32) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_shr(rhs) {
-                        None => None,
-                        Some(bits) => Some(Self::from_bits(bits)),
-                    }
+                pub const fn next_up(self) -> $Self<FRAC> {
+                    $Self::from_bits(self.to_bits().saturating_add(1))
                 }
             }
 
-            if_signed! {
-                $Signedness;
-                comment! {
-                    "Checked absolute value. Returns the absolute value, or [`None`] on overflow.
+            comment! {
+                "Returns the previous representable number before `self`, that
+is `self`&nbsp;&minus;&nbsp;[`DELTA`][Self::DELTA], saturating at
+[`MIN`][Self::MIN].
 
-Overflow can only occur when trying to find the absolute value of the minimum value.
+This is similar to the `next_down` method of floating-point numbers.
 
 # Examples
 
@@ -2823,36 +4059,35 @@ Overflow can only occur when trying to find the absolute value of the minimum va
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(-5).checked_abs(), Some(Fix::from_num(5)));
-assert_eq!(Fix::MIN.checked_abs(), None);
-```
-";
-                    #[inline]
-                    #[must_use]
-                    pub const fn checked_abs(self) -> Option<$Self<FRAC>> {
-                        match self.to_bits().checked_abs() {
-                            None => None,
-                            Some(bits) => Some(Self::from_bits(bits)),
-                        }
-                    }
+assert_eq!(Fix::ONE.next_down(), Fix::ONE - Fix::DELTA);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::ZERO.next_down(), -Fix::DELTA);
+",
+                    "assert_eq!(Fix::ZERO.next_down(), Fix::ZERO);
+",
+                ),
+                "assert_eq!(Fix::MIN.next_down(), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn next_down(self) -> $Self<FRAC> {
+                    $Self::from_bits(self.to_bits().saturating_sub(1))
                 }
             }
 
             comment! {
-                "Checked distance. Returns the distance from `self` to `other`",
-                if_signed_else_empty_str! { $Signedness; ", or [`None`] on overflow" },
-                ".
-
-The distance is the absolute value of the difference.
+                "Increments by [`DELTA`][Self::DELTA], that is the smallest step
+between two representable numbers, wrapping on overflow.
 
-",
-                if_unsigned_else_empty_str! {
-                    $Signedness;
-                    "Can never overflow for unsigned types.
+This is the same as `self`&nbsp;+&nbsp;`DELTA`, except that it wraps on overflow
+even when debug assertions are enabled, so that [`MAX`][Self::MAX] is followed by
+[`MIN`][Self::MIN]. Use [`checked_inc`][Self::checked_inc] or
+[`saturating_inc`][Self::saturating_inc] to detect or avoid wrapping.
 
-",
-                },
-                "# Examples
+# Examples
 
 ```rust
 #![feature(generic_const_exprs)]
@@ -2860,45 +4095,26 @@ The distance is the absolute value of the difference.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::ONE.checked_dist(Fix::from_num(5)), Some(Fix::from_num(4)));
-",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "assert_eq!(Fix::MIN.checked_dist(Fix::ZERO), None);",
-                    "assert_eq!(Fix::ZERO.checked_dist(Fix::MAX), Some(Fix::MAX));",
-                ),
-                "
+assert_eq!(Fix::ONE.inc(), Fix::ONE + Fix::DELTA);
+assert_eq!(Fix::ZERO.inc(), Fix::DELTA);
+assert_eq!(Fix::MAX.inc(), Fix::MIN);
 ```
 ";
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_dist(self, other: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    if_signed! {
-                        $Signedness;
-                        if self.to_bits() < other.to_bits() {
-                            other.checked_sub(self)
-                        } else {
-                            self.checked_sub(other)
-                        }
-                    }
-                    if_unsigned! {
-                        $Signedness;
-                        Some(self.dist(other))
-                    }
+                pub const fn inc(self) -> $Self<FRAC> {
+                    $Self::from_bits(self.to_bits().wrapping_add(1))
                 }
             }
 
-            if_signed! {
-                $Signedness;
-                comment! {
-                    "Checked signum. Returns a number representing the
-sign of `self`, or [`None`] on overflow.
+            comment! {
+                "Decrements by [`DELTA`][Self::DELTA], that is the smallest step
+between two representable numbers, wrapping on overflow.
 
-Overflow can only occur
-  * if the value is positive and the fixed-point number has zero
-    or one integer bits such that it cannot hold the value 1.
-  * if the value is negative and the fixed-point number has zero
-    integer bits, such that it cannot hold the value &minus;1.
+This is the same as `self`&nbsp;&minus;&nbsp;`DELTA`, except that it wraps on
+overflow even when debug assertions are enabled, so that [`MIN`][Self::MIN] is
+preceded by [`MAX`][Self::MAX]. Use [`checked_dec`][Self::checked_dec] or
+[`saturating_dec`][Self::saturating_dec] to detect or avoid wrapping.
 
 # Examples
 
@@ -2908,38 +4124,20 @@ Overflow can only occur
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(5).checked_signum(), Some(Fix::ONE));
-assert_eq!(Fix::ZERO.checked_signum(), Some(Fix::ZERO));
-assert_eq!(Fix::from_num(-5).checked_signum(), Some(Fix::NEG_ONE));
-
-type OneIntBit = ", stringify!($Self), "<", $nm1, ">;
-type ZeroIntBits = ", stringify!($Self), "<", $n, ">;
-assert_eq!(OneIntBit::from_num(0.5).checked_signum(), None);
-assert_eq!(ZeroIntBits::from_num(0.25).checked_signum(), None);
-assert_eq!(ZeroIntBits::from_num(-0.5).checked_signum(), None);
+assert_eq!(Fix::ONE.dec(), Fix::ONE - Fix::DELTA);
+assert_eq!(Fix::DELTA.dec(), Fix::ZERO);
+assert_eq!(Fix::MIN.dec(), Fix::MAX);
 ```
 ";
-                    #[inline]
-                    #[must_use]
-                    pub const fn checked_signum(self) -> Option<$Self<FRAC>> {
-                        match self.overflowing_signum() {
-                            (ans, false) => Some(ans),
-                            (_, true) => None,
-                        }
-                    }
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn dec(self) -> $Self<FRAC> {
+                    $Self::from_bits(self.to_bits().wrapping_sub(1))
                 }
             }
 
             comment! {
-                "Compute the hypotenuse of a right triange, returning [`None`] on overflow.
-
-The hypotenuse is equal to the square root of the sum of the squares of the
-operands.
-
-This method uses an iterative method for its square root, with up to ", $n, "
-iterations for [`", stringify!($Self), "`]. The result is rounded down, and the
-error is &lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
-result&nbsp;≤&nbsp;√(`self`²&nbsp;+&nbsp;`other`²)&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+                "Returns the mean of `self` and `other`.
 
 # Examples
 
@@ -2948,57 +4146,45 @@ result&nbsp;≤&nbsp;√(`self`²&nbsp;+&nbsp;`other`²)&nbsp;&lt;&nbsp;result&n
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<", stringify!($nm4), ">;
-
-// hypot(3, 4) == 5
-assert_eq!(
-    Fix::from_num(3).checked_hypot(Fix::from_num(4)),
-    Some(Fix::from_num(5))
-);
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3).mean(Fix::from_num(4)), Fix::from_num(3.5));
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "
-// hypot(2, 7.875) == 8.125, which does not fit
-assert_eq!(
-    Fix::from_num(2).checked_hypot(Fix::from_num(7.875)),
-    None
-);
-",
-                },
-                if_unsigned_else_empty_str! {
-                    $Signedness;
-                    "
-// hypot(8, 15) == 17, which does not fit
-assert_eq!(
-    Fix::from_num(8).checked_hypot(Fix::from_num(15)),
-    None
-);
+                    "assert_eq!(Fix::from_num(-3).mean(Fix::from_num(4)), Fix::from_num(0.5));
 ",
                 },
                 "```
 ";
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_hypot(self, other: $Self<FRAC>) -> Option<$Self<FRAC>> {
-                    match self.overflowing_hypot(other) {
-                        (val, false) => Some(val),
-                        (_, true) => None,
-                    }
+                pub const fn mean(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    // a & b == common bits
+                    // a ^ b == different bits
+                    // a + b == 2 * (a & b) + (a ^ b)
+                    // (a + b) / 2 = (a & b) + (a ^ b) / 2
+                    let (a, b) = (self.to_bits(), other.to_bits());
+                    $Self::from_bits((a & b) + ((a ^ b) >> 1))
                 }
             }
 
             comment! {
-                "Checked next multiple of `other`. Returns the next multiple, or
-[`None`] if `other` is zero or on overflow.
+                "Compute the hypotenuse of a right triange.
 
-The next multiple is the smallest multiple of `other` that is ≥&nbsp;`self`",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    " if `other` is positive, and the largest multiple of
-`other` that is ≤&nbsp;`self` if `other` is negative",
-                },
-                ".
+The hypotenuse is equal to the square root of the sum of the squares of the
+operands.
+
+This method uses an iterative method for its square root, with up to ", $n, "
+iterations for [`", stringify!($Self), "`]. The result is rounded down, and the
+error is &lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√(`self`²&nbsp;+&nbsp;`other`²)&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_hypot`] instead.
 
 # Examples
 
@@ -3007,45 +4193,45 @@ The next multiple is the smallest multiple of `other` that is ≥&nbsp;`self`",
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<4>;
-assert_eq!(
-    Fix::from_num(4).checked_next_multiple_of(Fix::from_num(1.5)),
-    Some(Fix::from_num(4.5))
-);
-assert_eq!(Fix::from_num(4).checked_next_multiple_of(Fix::ZERO), None);
-assert_eq!(Fix::MAX.checked_next_multiple_of(Fix::from_num(2)), None);
+type Fix = ", stringify!($Self), "<", stringify!($nm4), ">;
+
+// hypot(3, 4) == 5
+assert_eq!(Fix::from_num(3).hypot(Fix::from_num(4)), Fix::from_num(5));
 ```
+
+[`wrapping_hypot`]: Self::wrapping_hypot
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_next_multiple_of(
-                    self,
-                    other: $Self<FRAC>,
-                ) -> Option<$Self<FRAC>> {
-                    if other.to_bits() == 0 {
-                        None
-                    } else {
-                        match self.overflowing_next_multiple_of(other) {
-                            (ans, false) => Some(ans),
-                            (_, true) => None,
-                        }
-                    }
+                pub const fn hypot(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    let (val, overflow) = self.overflowing_hypot(other);
+                    debug_assert!(!overflow, "overflow");
+                    val
                 }
             }
 
             comment! {
-                "Checked inverse linear interpolation between `start` and `end`.
-Returns [`None`] on overflow or when `start`&nbsp;=&nbsp;`end`.
+                "Computes the Euclidean norm of a vector.
 
-The computed value can have a fixed-point type like `self` but with a different
-[number of fractional bits].
+The norm is equal to the square root of the sum of the squares of the elements
+of `values`, so it generalizes [`hypot`][Self::hypot] to any number of
+dimensions.
 
-Returns
-(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
-This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+The squares are summed exactly in an accumulator twice as wide as
+[`", stringify!($Self), "`], and then a single iterative square root is taken,
+so unlike chaining [`hypot`][Self::hypot] calls, rounding errors do not
+accumulate. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√(Σ&nbsp;<i>x</i><sub><i>i</i></sub>²)&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+An empty slice gives zero.
 
-This method is implemented for
-0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the saturated value is returned, but it
+is not considered a breaking change if in the future it panics; if saturation
+is required use [`saturating_norm`] instead.
 
 # Examples
 
@@ -3054,43 +4240,54 @@ This method is implemented for
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<4>;
-let two = Fix::from_num(2);
-let four = Fix::from_num(4);
-assert_eq!(Fix::from_num(3).checked_inv_lerp::<4>(two, four), Some(Fix::from_num(0.5)));
-assert_eq!(Fix::from_num(2).checked_inv_lerp::<4>(two, two), None);
-assert_eq!(Fix::MAX.checked_inv_lerp::<4>(Fix::ZERO, Fix::from_num(0.5)), None);
-```
+type Fix = ", stringify!($Self), "<", stringify!($nm4), ">;
 
-[number of fractional bits]: Self::FRAC_BITS
+// norm(2, 3, 6) == 7
+let v = [Fix::from_num(2), Fix::from_num(3), Fix::from_num(6)];
+assert_eq!(Fix::norm(&v), Fix::from_num(7));
+assert_eq!(Fix::norm(&[]), Fix::ZERO);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+// the signs of the elements do not matter
+let v = [Fix::from_num(-2), Fix::from_num(3), Fix::from_num(-6)];
+assert_eq!(Fix::norm(&v), Fix::from_num(7));
+",
+                },
+                "```
+
+[`saturating_norm`]: Self::saturating_norm
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use]
-                pub const fn checked_inv_lerp<const RET_FRAC: i32>(
-                    self,
-                    start: $Self<FRAC>,
-                    end: $Self<FRAC>,
-                ) -> Option<$Self<RET_FRAC>>
-                where
-                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
-                {
-                    let start = start.to_bits();
-                    let end = end.to_bits();
-                    if start == end {
-                        return None;
-                    }
-                    match inv_lerp::$Inner(self.to_bits(), start, end, RET_FRAC as u32) {
-                        (bits, false) => Some($Self::from_bits(bits)),
-                        (_, true) => None,
-                    }
+                pub fn norm(values: &[$Self<FRAC>]) -> $Self<FRAC> {
+                    let ans = $Self::checked_norm(values);
+                    debug_assert!(ans.is_some(), "overflow");
+                    ans.unwrap_or($Self::MAX)
                 }
             }
 
-            if_unsigned! {
-                $Signedness;
-                comment! {
-                    "Returns the smallest power of two that is ≥&nbsp;`self`, or
-[`None`] if the next power of two is too large to represent.
+            comment! {
+                "Approximates the hypotenuse of a right triangle.
+
+This method uses the alpha&nbsp;max&nbsp;plus&nbsp;beta&nbsp;min approximation
+<i>α</i>&nbsp;×&nbsp;max(|`self`|,&nbsp;|`other`|)&nbsp;+&nbsp;<i>β</i>&nbsp;×&nbsp;min(|`self`|,&nbsp;|`other`|)
+with <i>α</i>&nbsp;=&nbsp;123/128 and <i>β</i>&nbsp;=&nbsp;51/128, which only
+needs a few multiplications by constants and no square root, so it is much
+cheaper than [`hypot`][Self::hypot].
+
+The relative error is within about 4%: before rounding, the approximation lies
+between 0.9609 and 1.0403 times the exact hypotenuse. The result is then rounded
+down, which can introduce a further error of less than
+[`DELTA`][Self::DELTA].
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics.
 
 # Examples
 
@@ -3099,19 +4296,2331 @@ assert_eq!(Fix::MAX.checked_inv_lerp::<4>(Fix::ZERO, Fix::from_num(0.5)), None);
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<4>;
-// 3/8 is 0.0110
-let three_eights = Fix::from_bits(0b0110);
-// 1/2 is 0.1000
-let half = Fix::from_bits(0b1000);
-assert_eq!(three_eights.checked_next_power_of_two(), Some(half));
-assert!(Fix::MAX.checked_next_power_of_two().is_none());
+type Fix = ", stringify!($Self), "<", stringify!($nm4), ">;
+
+// hypot(3, 4) == 5
+let approx = Fix::from_num(3).fast_hypot(Fix::from_num(4));
+assert!(approx.dist(Fix::from_num(5)) <= Fix::from_num(5) / 25);
 ```
 ";
-                    #[inline]
-                    #[must_use]
-                    pub const fn checked_next_power_of_two(self) -> Option<$Self<FRAC>> {
-                        match self.to_bits().checked_next_power_of_two() {
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn fast_hypot(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    if_signed! {
+                        $Signedness;
+                        let (uns, overflow) = hypot::fast::$UInner(
+                            self.unsigned_abs().to_bits(),
+                            other.unsigned_abs().to_bits(),
+                        );
+                        let bits = uns as $Inner;
+                        debug_assert!(!overflow && bits >= 0, "overflow");
+                        $Self::from_bits(bits)
+                    }
+                    if_unsigned! {
+                        $Signedness;
+                        let (bits, overflow) = hypot::fast::$Inner(self.to_bits(), other.to_bits());
+                        debug_assert!(!overflow, "overflow");
+                        $Self::from_bits(bits)
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the smallest multiple of `other` that is ≥&nbsp;`self`",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " if `other` is positive, and the largest multiple of
+`other` that is ≤&nbsp;`self` if `other` is negative",
+                },
+                ".
+
+# Panics
+
+Panics if `other` is zero.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_next_multiple_of`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(4).next_multiple_of(Fix::from_num(1.5)),
+    Fix::from_num(4.5)
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(
+    Fix::from_num(4).next_multiple_of(Fix::from_num(-1.5)),
+    Fix::from_num(3)
+);
+",
+                },
+                "```
+
+[`wrapping_next_multiple_of`]: Self::wrapping_next_multiple_of
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn next_multiple_of(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_next_multiple_of(other);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Inverse linear interpolation between `start` and `end`.
+
+The computed value can have a fixed-point type like `self` but with a different
+[number of fractional bits].
+
+Returns
+(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+
+This method is implemented for
+0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+
+# Panics
+
+Panics when `start`&nbsp;=&nbsp;`end`.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_inv_lerp`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let start = Fix::from_num(2);
+let end = Fix::from_num(3.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(0.5).inv_lerp::<4>(start, end), -1);
+",
+                },
+                "assert_eq!(Fix::from_num(2).inv_lerp::<4>(start, end), 0);
+assert_eq!(Fix::from_num(2.75).inv_lerp::<4>(start, end), 0.5);
+assert_eq!(Fix::from_num(3.5).inv_lerp::<4>(start, end), 1);
+assert_eq!(Fix::from_num(5).inv_lerp::<4>(start, end), 2);
+```
+
+[`wrapping_inv_lerp`]: Self::wrapping_inv_lerp
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn inv_lerp<const RET_FRAC: i32>(
+                    self,
+                    start: $Self<FRAC>,
+                    end: $Self<FRAC>,
+                ) -> $Self<RET_FRAC>
+                where
+                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
+                {
+                    let (ans, overflow) = inv_lerp::$Inner(
+                        self.to_bits(),
+                        start.to_bits(),
+                        end.to_bits(),
+                        RET_FRAC as u32,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    $Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Inverse linear interpolation between `start` and `end`, where `start` and
+`end` can have a different number of fractional bits from `self`.
+
+The computed value can have a fixed-point type like `self` but with a different
+[number of fractional bits].
+
+Returns
+(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+
+Before the computation, the operands are brought to the smaller of the two
+numbers of fractional bits: if `start` and `end` have more fractional bits than
+`self`, they are rounded, otherwise `self` is rounded. This rounding is towards
+&minus;∞, and it cannot overflow.
+
+This method is implemented for
+0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+
+# Panics
+
+Panics when `start`&nbsp;=&nbsp;`end` after rounding.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_inv_lerp_mixed`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Range = ", stringify!($Self), "<5>;
+let start = Range::from_num(2);
+let end = Range::from_num(3.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(0.5).inv_lerp_mixed::<5, 4>(start, end), -1);
+",
+                },
+                "assert_eq!(Fix::from_num(2).inv_lerp_mixed::<5, 4>(start, end), 0);
+assert_eq!(Fix::from_num(2.75).inv_lerp_mixed::<5, 4>(start, end), 0.5);
+assert_eq!(Fix::from_num(3.5).inv_lerp_mixed::<5, 4>(start, end), 1);
+assert_eq!(Fix::from_num(5).inv_lerp_mixed::<5, 4>(start, end), 2);
+
+// 2.03125 has too many fractional bits for Fix, so it is rounded down to 2
+let fine_start = Range::from_num(2.03125);
+assert_eq!(Fix::from_num(2).inv_lerp_mixed::<5, 4>(fine_start, end), 0);
+```
+
+[`wrapping_inv_lerp_mixed`]: Self::wrapping_inv_lerp_mixed
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn inv_lerp_mixed<const RANGE_FRAC: i32, const RET_FRAC: i32>(
+                    self,
+                    start: $Self<RANGE_FRAC>,
+                    end: $Self<RANGE_FRAC>,
+                ) -> $Self<RET_FRAC>
+                where
+                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
+                {
+                    let (self_bits, start, end) = inv_lerp::align::$Inner(
+                        self.to_bits(),
+                        FRAC,
+                        start.to_bits(),
+                        end.to_bits(),
+                        RANGE_FRAC,
+                    );
+                    let (ans, overflow) = inv_lerp::$Inner(self_bits, start, end, RET_FRAC as u32);
+                    debug_assert!(!overflow, "overflow");
+                    $Self::from_bits(ans)
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Returns the highest one in the binary
+representation, or zero if `self` is zero.
+
+If `self`&nbsp;>&nbsp;0, the highest one is equal to the largest power of two
+that is ≤&nbsp;`self`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_bits(0b11_0010).highest_one(), Fix::from_bits(0b10_0000));
+assert_eq!(Fix::from_num(0.3).highest_one(), Fix::from_num(0.25));
+assert_eq!(Fix::from_num(4).highest_one(), Fix::from_num(4));
+assert_eq!(Fix::from_num(6.5).highest_one(), Fix::from_num(4));
+assert_eq!(Fix::ZERO.highest_one(), Fix::ZERO);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn highest_one(self) -> $Self<FRAC> {
+                        const ONE: $Inner = 1;
+                        let bits = self.to_bits();
+                        if bits == 0 {
+                            self
+                        } else {
+                            $Self::from_bits(ONE << (ONE.leading_zeros() - bits.leading_zeros()))
+                        }
+                    }
+                }
+
+                comment! {
+                    "Returns the smallest power of two that is ≥&nbsp;`self`.
+
+# Panics
+
+When debug assertions are enabled, panics if the next power of two is
+too large to represent. When debug assertions are not enabled, zero
+can be returned, but it is not considered a breaking change if in the
+future it panics; if this is not desirable use
+[`checked_next_power_of_two`] instead.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_bits(0b11_0010).next_power_of_two(), Fix::from_bits(0b100_0000));
+assert_eq!(Fix::from_num(0.3).next_power_of_two(), Fix::from_num(0.5));
+assert_eq!(Fix::from_num(4).next_power_of_two(), Fix::from_num(4));
+assert_eq!(Fix::from_num(6.5).next_power_of_two(), Fix::from_num(8));
+```
+
+[`checked_next_power_of_two`]: Self::checked_next_power_of_two
+";
+                    #[inline]
+                    #[track_caller]
+                    #[must_use]
+                    pub const fn next_power_of_two(self) -> $Self<FRAC> {
+                        Self::from_bits(self.to_bits().next_power_of_two())
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                /// Addition with an unsigned fixed-point number.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, this method panics if the
+                /// result overflows. When debug assertions are not enabled, the
+                /// wrapped value can be returned, but it is not considered a
+                /// breaking change if in the future it panics; if wrapping is
+                /// required use [`wrapping_add_unsigned`] instead.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                /// assert_eq!(Fix::from_num(-5).add_unsigned(UFix::from_num(3)), -2);
+                /// ```
+                ///
+                /// [`wrapping_add_unsigned`]: Self::wrapping_add_unsigned
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn add_unsigned(self, rhs: $USelf<FRAC>) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_add_unsigned(rhs);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+
+                /// Subtraction with an unsigned fixed-point number.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, this method panics if the
+                /// result overflows. When debug assertions are not enabled, the
+                /// wrapped value can be returned, but it is not considered a
+                /// breaking change if in the future it panics; if wrapping is
+                /// required use [`wrapping_sub_unsigned`] instead.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                /// assert_eq!(Fix::from_num(3).sub_unsigned(UFix::from_num(5)), -2);
+                /// ```
+                ///
+                /// [`wrapping_sub_unsigned`]: Self::wrapping_sub_unsigned
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn sub_unsigned(self, rhs: $USelf<FRAC>) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_sub_unsigned(rhs);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+                /// Addition with a signed fixed-point number.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, this method panics if the
+                /// result overflows. When debug assertions are not enabled, the
+                /// wrapped value can be returned, but it is not considered a
+                /// breaking change if in the future it panics; if wrapping is
+                /// required use [`wrapping_add_signed`] instead.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(Fix::from_num(5).add_signed(IFix::from_num(-3)), 2);
+                /// ```
+                ///
+                /// [`wrapping_add_signed`]: Self::wrapping_add_signed
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn add_signed(self, rhs: $ISelf<FRAC>) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_add_signed(rhs);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+
+                /// Subtraction with a signed fixed-point number.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, this method panics if the
+                /// result overflows. When debug assertions are not enabled, the
+                /// wrapped value can be returned, but it is not considered a
+                /// breaking change if in the future it panics; if wrapping is
+                /// required use [`wrapping_sub_signed`] instead.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(Fix::from_num(5).sub_signed(IFix::from_num(-3)), 8);
+                /// ```
+                ///
+                /// [`wrapping_sub_signed`]: Self::wrapping_sub_signed
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn sub_signed(self, rhs: $ISelf<FRAC>) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_sub_signed(rhs);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+
+                /// Multiplication by a signed integer, returning a signed
+                /// fixed-point number.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, this method panics if the
+                /// result overflows. When debug assertions are not enabled, the
+                /// wrapped value can be returned, but it is not considered a
+                /// breaking change if in the future it panics; if wrapping is
+                /// required use [`wrapping_mul_signed_int`] instead.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(Fix::from_num(1.5).mul_signed_int(-3), IFix::from_num(-4.5));
+                /// ```
+                ///
+                /// [`wrapping_mul_signed_int`]: Self::wrapping_mul_signed_int
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn mul_signed_int(self, rhs: $IInner) -> $ISelf<FRAC> {
+                    let (ans, overflow) = self.overflowing_mul_signed_int(rhs);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Bitwise NOT. Usable in constant context.
+
+This is equivalent to the `!` operator and
+<code>[Not][core::ops::Not]::[not][core::ops::Not::not]</code>, but
+can also be used in constant context. Unless required in constant
+context, use the operator or trait instead.
+
+# Planned deprecation
+
+This method will be deprecated when the `!` operator and the
+[`Not`][core::ops::Not] trait are usable in constant context.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+const A: Fix = Fix::from_bits(0x3E);
+const NOT_A: Fix = A.const_not();
+assert_eq!(NOT_A, !A);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn const_not(self) -> $Self<FRAC> {
+                    Self::from_bits(!self.to_bits())
+                }
+            }
+
+            comment! {
+                "Bitwise AND. Usable in constant context.
+
+This is equivalent to the `&` operator and
+<code>[BitAnd][core::ops::BitAnd]::[bitand][core::ops::BitAnd::bitand]</code>,
+but can also be used in constant context. Unless required in constant
+context, use the operator or trait instead.
+
+# Planned deprecation
+
+This method will be deprecated when the `&` operator and the
+[`BitAnd`][core::ops::BitAnd] trait are usable in constant context.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+const A: Fix = Fix::from_bits(0x3E);
+const B: Fix = Fix::from_bits(0x55);
+const A_BITAND_B: Fix = A.const_bitand(B);
+assert_eq!(A_BITAND_B, A & B);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn const_bitand(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    Self::from_bits(self.to_bits() & rhs.to_bits())
+                }
+            }
+
+            comment! {
+                "Bitwise OR. Usable in constant context.
+
+This is equivalent to the `|` operator and
+<code>[BitOr][core::ops::BitOr]::[bitor][core::ops::BitOr::bitor]</code>,
+but can also be used in constant context. Unless required in constant
+context, use the operator or trait instead.
+
+# Planned deprecation
+
+This method will be deprecated when the `|` operator and the
+[`BitOr`][core::ops::BitOr] trait are usable in constant context.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+const A: Fix = Fix::from_bits(0x3E);
+const B: Fix = Fix::from_bits(0x55);
+const A_BITOR_B: Fix = A.const_bitor(B);
+assert_eq!(A_BITOR_B, A | B);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn const_bitor(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    Self::from_bits(self.to_bits() | rhs.to_bits())
+                }
+            }
+
+            comment! {
+                "Bitwise XOR. Usable in constant context.
+
+This is equivalent to the `^` operator and
+<code>[BitXor][core::ops::BitXor]::[bitxor][core::ops::BitXor::bitxor]</code>,
+but can also be used in constant context. Unless required in constant
+context, use the operator or trait instead.
+
+# Planned deprecation
+
+This method will be deprecated when the `^` operator and the
+[`BitXor`][core::ops::BitXor] trait are usable in constant context.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+const A: Fix = Fix::from_bits(0x3E);
+const B: Fix = Fix::from_bits(0x55);
+const A_BITXOR_B: Fix = A.const_bitxor(B);
+assert_eq!(A_BITXOR_B, A ^ B);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn const_bitxor(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    Self::from_bits(self.to_bits() ^ rhs.to_bits())
+                }
+            }
+
+            comment! {
+                "Bitwise AND with a raw bit pattern. Usable in constant context.
+
+This is equivalent to the `&` operator and
+<code>[BitAnd][core::ops::BitAnd]&lt;", stringify!($Inner), "&gt;::[bitand][core::ops::BitAnd::bitand]</code>
+with an operand of the underlying integer type, but can also be used
+in constant context. Unless required in constant context, use the
+operator or trait instead.
+
+# Planned deprecation
+
+This method will be deprecated when the `&` operator and the
+[`BitAnd`][core::ops::BitAnd] trait are usable in constant context.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+const A: Fix = Fix::from_bits(0x3E);
+const RESULT: Fix = A.const_bitand_bits(!0x0F);
+assert_eq!(RESULT, A & !0x0F);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn const_bitand_bits(self, rhs: $Inner) -> $Self<FRAC> {
+                    Self::from_bits(self.to_bits() & rhs)
+                }
+            }
+
+            comment! {
+                "Bitwise OR with a raw bit pattern. Usable in constant context.
+
+This is equivalent to the `|` operator and
+<code>[BitOr][core::ops::BitOr]&lt;", stringify!($Inner), "&gt;::[bitor][core::ops::BitOr::bitor]</code>
+with an operand of the underlying integer type, but can also be used
+in constant context. Unless required in constant context, use the
+operator or trait instead.
+
+# Planned deprecation
+
+This method will be deprecated when the `|` operator and the
+[`BitOr`][core::ops::BitOr] trait are usable in constant context.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+const A: Fix = Fix::from_bits(0x3E);
+const RESULT: Fix = A.const_bitor_bits(0x41);
+assert_eq!(RESULT, A | 0x41);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn const_bitor_bits(self, rhs: $Inner) -> $Self<FRAC> {
+                    Self::from_bits(self.to_bits() | rhs)
+                }
+            }
+
+            comment! {
+                "Bitwise XOR with a raw bit pattern. Usable in constant context.
+
+This is equivalent to the `^` operator and
+<code>[BitXor][core::ops::BitXor]&lt;", stringify!($Inner), "&gt;::[bitxor][core::ops::BitXor::bitxor]</code>
+with an operand of the underlying integer type, but can also be used
+in constant context. Unless required in constant context, use the
+operator or trait instead.
+
+# Planned deprecation
+
+This method will be deprecated when the `^` operator and the
+[`BitXor`][core::ops::BitXor] trait are usable in constant context.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+const A: Fix = Fix::from_bits(0x3E);
+const RESULT: Fix = A.const_bitxor_bits(0x55);
+assert_eq!(RESULT, A ^ 0x55);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn const_bitxor_bits(self, rhs: $Inner) -> $Self<FRAC> {
+                    Self::from_bits(self.to_bits() ^ rhs)
+                }
+            }
+
+            comment! {
+                "Checked negation. Returns the negated value, or [`None`] on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).checked_neg(), Some(Fix::from_num(-5)));
+assert_eq!(Fix::MIN.checked_neg(), None);",
+                    "assert_eq!(Fix::ZERO.checked_neg(), Some(Fix::ZERO));
+assert_eq!(Fix::from_num(5).checked_neg(), None);",
+                ),
+                "
+```
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "
+To negate into the signed type with the same number of bits, use
+[`checked_neg_to_signed`].
+
+[`checked_neg_to_signed`]: Self::checked_neg_to_signed
+",
+                };
+                #[inline]
+                #[must_use]
+                pub const fn checked_neg(self) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_neg() {
+                        None => None,
+                        Some(bits) => Some(Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+                /// Checked negation into the signed fixed-point type with the
+                /// same number of bits. Returns the negated value, or [`None`]
+                /// if it cannot be represented in the signed type.
+                ///
+                /// Unlike [`checked_neg`], which can only succeed for zero, this
+                /// succeeds for every value up to the magnitude of the signed
+                /// type’s [`MIN`].
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(Fix::from_num(5).checked_neg_to_signed(), Some(IFix::from_num(-5)));
+                /// assert_eq!(Fix::ZERO.checked_neg_to_signed(), Some(IFix::ZERO));
+                /// // IFix::MAX + DELTA still fits once negated
+                /// let max_plus_delta = Fix::from_bits(IFix::MAX.to_bits().unsigned_abs() + 1);
+                /// assert_eq!(max_plus_delta.checked_neg_to_signed(), Some(IFix::MIN));
+                /// assert_eq!((max_plus_delta + Fix::DELTA).checked_neg_to_signed(), None);
+                /// assert_eq!(Fix::MAX.checked_neg_to_signed(), None);
+                /// ```
+                ///
+                /// [`checked_neg`]: Self::checked_neg
+                #[doc = concat!("[`MIN`]: ", stringify!($ISelf), "::MIN")]
+                #[inline]
+                #[must_use]
+                pub const fn checked_neg_to_signed(self) -> Option<$ISelf<FRAC>> {
+                    let bits = self.to_bits();
+                    if bits > <$IInner>::MIN.unsigned_abs() {
+                        None
+                    } else {
+                        Some($ISelf::from_bits((bits as $IInner).wrapping_neg()))
+                    }
+                }
+            }
+
+            comment! {
+                "Checked addition. Returns the sum, or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!((Fix::MAX - Fix::ONE).checked_add(Fix::ONE), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_add(Fix::ONE), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_add(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_add(rhs.to_bits()) {
+                        None => None,
+                        Some(bits) => Some($Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            comment! {
+                "Sums the values of an iterator with overflow detection. Returns
+the sum, or [`ArithError`] on overflow.
+
+The values are added in order using [`checked_add`][Self::checked_add], and the
+error is returned as soon as an intermediate sum overflows, without consuming
+the rest of the iterator. This is convenient with the `?` operator.
+
+# Errors
+
+Returns [`ArithError`] if an intermediate sum overflows.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let vals = [Fix::ONE, Fix::from_num(1.5), Fix::from_num(0.25)];
+assert_eq!(Fix::try_sum(vals), Ok(Fix::from_num(2.75)));
+assert!(Fix::try_sum([Fix::MAX, Fix::DELTA]).is_err());
+```
+";
+                #[inline]
+                pub fn try_sum<I>(iter: I) -> Result<$Self<FRAC>, ArithError>
+                where
+                    I: IntoIterator<Item = $Self<FRAC>>,
+                {
+                    let mut sum = $Self::ZERO;
+                    for val in iter {
+                        match sum.checked_add(val) {
+                            Some(s) => sum = s,
+                            None => return Err(ArithError { _priv: () }),
+                        }
+                    }
+                    Ok(sum)
+                }
+            }
+
+            comment! {
+                "Checked subtraction. Returns the difference, or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!((Fix::MIN + Fix::ONE).checked_sub(Fix::ONE), Some(Fix::MIN));
+assert_eq!(Fix::MIN.checked_sub(Fix::ONE), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_sub(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_sub(rhs.to_bits()) {
+                        None => None,
+                        Some(bits) => Some($Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiplication. Returns the product, or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.checked_mul(Fix::ONE), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_mul(Fix::from_num(2)), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_mul(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    match arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), FRAC) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked square. Returns `self` × `self`, or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).checked_square(), Some(Fix::from_num(2.25)));
+assert_eq!(Fix::MAX.checked_square(), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_square(self) -> Option<$Self<FRAC>> {
+                    match arith::$Inner::overflowing_mul(self.to_bits(), self.to_bits(), FRAC) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked remainder. Returns the remainder, or [`None`] if
+the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).checked_rem(Fix::ONE), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::from_num(1.5).checked_rem(Fix::ZERO), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_rem(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    let rhs_bits = rhs.to_bits();
+                    if_signed! {
+                        $Signedness;
+                        if rhs_bits == -1 {
+                            return Some(Self::ZERO);
+                        }
+                    }
+                    match self.to_bits().checked_rem(rhs_bits) {
+                        None => None,
+                        Some(bits) => Some(Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            comment! {
+                r#"Checked multiply and add.
+Returns `self` × `mul` + `add`, or [`None`] on overflow.
+
+This method retuns the same value as
+<code>add.[checked\_add\_prod][`checked_add_prod`](self, mul)</code>.
+The [`checked_add_prod`] method is more flexible because `checked_mul_add`
+requires that `self` and `add` must have the same [number of fractional bits].
+
+[`checked_add_prod`]: Self::checked_add_prod
+[number of fractional bits]: Self::FRAC_BITS
+"#;
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    add.checked_add_prod(self, mul)
+                }
+            }
+
+            comment! {
+                "Checked multiply and subtract.
+Returns `self` × `mul` &minus; `sub`, or [`None`] on overflow.
+
+For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its own,
+but the final result is representable; in these cases this method returns the
+correct result without overflow.
+
+The product is computed exactly before `sub` is subtracted, and any fractional
+bits that do not fit are discarded, which rounds towards &minus;∞.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).checked_mul_sub(Fix::from_num(0.5), Fix::ONE),
+    Some(Fix::from_num(0.5))
+);
+assert_eq!(Fix::MAX.checked_mul_sub(Fix::from_num(2), Fix::MAX), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_mul_sub(Fix::from_num(3), Fix::MAX), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_mul_sub<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    sub: $Self<FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    match arith::$Inner::overflowing_mul_sub(
+                        self.to_bits(),
+                        mul.to_bits(),
+                        sub.to_bits(),
+                        MUL_FRAC,
+                    ) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiply and divide. Returns `self` × `mul` / `div`, or
+[`None`] if `div` is zero or on overflow.
+
+For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its own,
+but the final result is representable; in these cases this method returns the
+correct result without overflow.
+
+The product is computed exactly before it is divided by `div`, and the result is
+rounded towards zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let half_max = Fix::from_bits(Fix::MAX.to_bits() / 2);
+assert_eq!(
+    Fix::MAX.checked_mul_div(Fix::from_num(2), Fix::from_num(4)),
+    Some(half_max)
+);
+assert_eq!(Fix::MAX.checked_mul_div(Fix::from_num(4), Fix::from_num(2)), None);
+assert_eq!(Fix::ONE.checked_mul_div(Fix::ONE, Fix::ZERO), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    div: $Self<DIV_FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    let shift = DIV_FRAC as i64 - MUL_FRAC as i64;
+                    match mul_div::$Inner(self.to_bits(), mul.to_bits(), div.to_bits(), shift) {
+                        Some((ans, false)) => Some(Self::from_bits(ans)),
+                        None | Some((_, true)) => None,
+                    }
+                }
+            }
+
+            comment! {
+                r#"Checked negated multiply and add.
+Returns `add` &minus; `self` × `mul`, or [`None`] on overflow.
+
+This method returns the same value as
+<code>add.[checked\_sub\_prod][`checked_sub_prod`](self, mul)</code>.
+The [`checked_sub_prod`] method is more flexible because `checked_neg_mul_add`
+requires that `self` and `add` must have the same [number of fractional bits].
+
+[`checked_sub_prod`]: Self::checked_sub_prod
+[number of fractional bits]: Self::FRAC_BITS
+"#;
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_neg_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    add.checked_sub_prod(self, mul)
+                }
+            }
+
+            comment! {
+                "Checked multiplication to a chosen number of fractional
+bits. Returns the product with `OUT_FRAC` fractional bits, or [`None`] on
+overflow.
+
+See [`mul_to`][Self::mul_to] for how the product is rescaled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 0.75 × 1.25 = 0.9375, which is 0.1111 in binary
+let a = Fix::from_num(0.75);
+let b = ", stringify!($Self), "::<2>::from_num(1.25);
+assert_eq!(
+    a.checked_mul_to::<6, 2>(b),
+    Some(", stringify!($Self), "::<6>::from_num(0.9375))
+);
+assert_eq!(Fix::MAX.checked_mul_to::<4, 4>(Fix::from_num(2)), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_mul_to<const OUT_FRAC: i32, const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> Option<$Self<OUT_FRAC>> {
+                    match self.overflowing_mul_to(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked addition of fixed-point numbers with possibly
+different numbers of fractional bits. Returns the sum with the larger number
+of fractional bits, or [`None`] on overflow.
+
+See [`add_frac`][Self::add_frac] for how the operands are converted.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+let coarse = ", stringify!($Self), "::<2>::from_num(1.25);
+let fine = ", stringify!($Self), "::<4>::from_num(0.5625);
+assert_eq!(
+    coarse.checked_add_frac(fine),
+    Some(", stringify!($Self), "::<4>::from_num(1.8125))
+);
+// MAX with 2 fractional bits does not fit in 4 fractional bits
+assert_eq!(", stringify!($Self), "::<2>::MAX.checked_add_frac(fine), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_add_frac<const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> Option<$Self<{ max_frac(FRAC, RHS_FRAC) }>> {
+                    match self.overflowing_add_frac(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, returning [`None`] on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
+
+",
+                },
+                "The product is computed exactly before being added, and any
+fractional bits that do not fit in `self` are discarded, which rounds towards
+&minus;∞. [`None`] is returned only if this final result does not fit.
+
+The `a` and `b` parameters can have a fixed-point type like `self` but with a
+different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).checked_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    Some(Fix::from_num(5))
+);
+assert_eq!(Fix::DELTA.checked_add_prod(Fix::MAX, Fix::ONE), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+assert_eq!(
+    (-Fix::MAX).checked_add_prod(Fix::MAX, Fix::from_num(1.5)),
+    Some(Fix::MAX / 2)
+);
+"
+                },
+                "```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                    );
+                    if overflow {
+                        return None;
+                    }
+                    Some(Self::from_bits(ans))
+                }
+            }
+
+            comment! {
+                "Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`,
+returning [`None`] on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, one of the products or the intermediate sum
+`self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b` would overflow on its own, but the final
+result is representable; in these cases this method returns the correct result
+without overflow.
+
+",
+                },
+                "Each product is rounded down on its own, so when there is no
+overflow the result is the same as that of [`checked_add_prod`] called twice.
+The parameters `a`, `b`, `c` and `d` can have a fixed-point type like `self` but
+with a different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).checked_add_prod2(Fix::from_num(4), Fix::from_num(0.5), Fix::from_num(2), Fix::from_num(0.25)),
+    Some(Fix::from_num(5.5))
+);
+assert_eq!(Fix::DELTA.checked_add_prod2(Fix::MAX, Fix::ONE, Fix::ZERO, Fix::ONE), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 3 - MAX = MAX, although -MAX + MAX × 3 overflows
+assert_eq!(
+    (-Fix::MAX).checked_add_prod2(Fix::MAX, Fix::from_num(3), -Fix::MAX, Fix::ONE),
+    Some(Fix::MAX)
+);
+"
+                },
+                "```
+
+[`checked_add_prod`]: Self::checked_add_prod
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_add_prod2<
+                    const A_FRAC: i32,
+                    const B_FRAC: i32,
+                    const C_FRAC: i32,
+                    const D_FRAC: i32,
+                >(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                    c: $Self<C_FRAC>,
+                    d: $Self<D_FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    let (ans, ord) = arith::$Inner::overflowing_mul_add2(
+                        a.to_bits(),
+                        b.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                        c.to_bits(),
+                        d.to_bits(),
+                        arith::saturating_add_sub_i32(C_FRAC, D_FRAC, FRAC),
+                        self.to_bits(),
+                    );
+                    match ord {
+                        Ordering::Equal => Some(Self::from_bits(ans)),
+                        Ordering::Less | Ordering::Greater => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`, returning [`None`]
+on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;&minus;&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
+
+",
+                },
+                "The product is computed exactly before being subtracted, and any fractional
+bits that do not fit in `self` are discarded, which rounds the result towards
+&minus;∞. [`None`] is returned only if this final result does not fit.
+
+The `a` and `b` parameters can have a fixed-point type like `self` but with a
+different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(5).checked_sub_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    Some(Fix::from_num(3))
+);
+assert_eq!(Fix::MIN.checked_sub_prod(Fix::ONE, Fix::DELTA), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// MIN - MIN × 1.5 = -MIN / 2, which does not overflow
+assert_eq!(
+    Fix::MIN.checked_sub_prod(Fix::MIN, Fix::from_num(1.5)),
+    Some(-(Fix::MIN / 2))
+);
+// 0 - DELTA × 0.5 is rounded down to -DELTA
+assert_eq!(
+    Fix::ZERO.checked_sub_prod(Fix::DELTA, Fix::from_num(0.5)),
+    Some(-Fix::DELTA)
+);
+"
+                },
+                "```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    let (ans, overflow) = arith::$Inner::overflowing_neg_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                    );
+                    if overflow {
+                        return None;
+                    }
+                    Some(Self::from_bits(ans))
+                }
+            }
+
+            comment! {
+                r#"Checked multiply and accumulate. Adds (`a` × `b`) to `self`,
+or returns [`None`] on overflow.
+
+Like all other checked methods, this method wraps the successful return value in
+an [`Option`]. Since the unchecked [`mul_acc`] method does not return a value,
+which is equivalent to returning [`()`][unit], this method wraps [`()`][unit]
+into <code>[Some]\([()][unit])</code> on success.
+
+When overflow occurs, `self` is not modified and retains its previous value.
+
+"#,
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
+
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let mut acc = Fix::from_num(3);
+let check = acc.checked_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(check, Some(()));
+assert_eq!(acc, 5);
+
+acc = Fix::DELTA;
+let check = acc.checked_mul_acc(Fix::MAX, Fix::ONE);
+assert_eq!(check, None);
+// acc is unchanged on error
+assert_eq!(acc, Fix::DELTA);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+// MAX × 1.5 - MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+let check = acc.checked_mul_acc(Fix::MAX, Fix::from_num(1.5));
+assert_eq!(check, Some(()));
+assert_eq!(acc, Fix::MAX / 2);
+"
+                },
+                "```
+
+[`mul_acc`]: Self::mul_acc
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
+                pub fn checked_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
+                    &mut self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) -> Option<()> {
+                    match self.checked_add_prod(a, b) {
+                        Some(s) => {
+                            *self = s;
+                            Some(())
+                        }
+                        None => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiply and accumulate over slices. Adds the sum of the
+products `a[i]`&nbsp;×&nbsp;`b[i]` to `self`, or returns [`None`] on overflow.
+
+This is an in-place dot-product accumulator. The products are added to `self`
+one at a time in order, as with [`checked_mul_acc`][Self::checked_mul_acc], and
+overflow is detected for each intermediate sum. When overflow occurs, `self` is
+not modified and retains its previous value, even if some products were
+already accumulated.
+
+The `a` and `b` slices can have a fixed-point type like `self` but with a
+different [number of fractional bits].
+
+# Panics
+
+Panics if `a` and `b` have different lengths.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let a = [Fix::from_num(1.5), Fix::from_num(2), Fix::from_num(0.25)];
+let b = [Fix::from_num(2), Fix::from_num(1), Fix::from_num(4)];
+let mut acc = Fix::ONE;
+// 1 + 1.5 × 2 + 2 × 1 + 0.25 × 4 = 7
+assert_eq!(acc.checked_mul_acc_slice(&a, &b), Some(()));
+assert_eq!(acc, 7);
+
+let big = [Fix::MAX, Fix::ONE];
+let ones = [Fix::ONE, Fix::ONE];
+assert_eq!(acc.checked_mul_acc_slice(&big, &ones), None);
+// acc is unchanged on error
+assert_eq!(acc, 7);
+```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
+                pub fn checked_mul_acc_slice<const A_FRAC: i32, const B_FRAC: i32>(
+                    &mut self,
+                    a: &[$Self<A_FRAC>],
+                    b: &[$Self<B_FRAC>],
+                ) -> Option<()> {
+                    assert_eq!(a.len(), b.len(), "slices have different lengths");
+                    let mut acc = *self;
+                    for (&a, &b) in a.iter().zip(b) {
+                        acc.checked_mul_acc(a, b)?;
+                    }
+                    *self = acc;
+                    Some(())
+                }
+            }
+
+            comment! {
+                "Checked multiplication by an integer. Returns the
+product, or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.checked_mul_int(1), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_mul_int(2), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_mul_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_mul(rhs) {
+                        None => None,
+                        Some(bits) => Some(Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            comment! {
+                "Checked division by an integer. Returns the quotient, or
+[`None`] if the divisor is zero",
+                if_signed_unsigned!(
+                    $Signedness,
+                    " or if the division results in overflow.",
+                    ".",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::MAX.checked_div_int(1), Some(Fix::MAX));
+assert_eq!(Fix::ONE.checked_div_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.checked_div_int(-1), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div_int(self, rhs: $Inner) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_div(rhs) {
+                        None => None,
+                        Some(bits) => Some(Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            comment! {
+                "Checked division by an integer, returning both the
+quotient and the remainder. Returns [`None`] if the divisor is zero",
+                if_signed_unsigned!(
+                    $Signedness,
+                    " or if the division results in overflow.",
+                    ".",
+                ),
+                "
+
+See [`div_rem_int`][Self::div_rem_int] for how the quotient and remainder are
+defined.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::ONE.checked_div_rem_int(3),
+    Some((Fix::from_bits(5), Fix::DELTA))
+);
+assert_eq!(Fix::ONE.checked_div_rem_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.checked_div_rem_int(-1), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div_rem_int(
+                    self,
+                    rhs: $Inner,
+                ) -> Option<($Self<FRAC>, $Self<FRAC>)> {
+                    let bits = self.to_bits();
+                    match bits.checked_div(rhs) {
+                        None => None,
+                        // the division succeeded, so the remainder cannot fail
+                        Some(quot) => Some((Self::from_bits(quot), Self::from_bits(bits % rhs))),
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiplication by a power of ten. Returns
+`self`&nbsp;×&nbsp;10<sup>`exp`</sup>, or [`None`] on overflow.
+
+If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+result is rounded to the nearest, with ties rounded to even. Overflow can only
+occur when `exp` is positive.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(0.0625).checked_scale_pow10(2),
+    Some(Fix::from_num(6.25))
+);
+assert_eq!(Fix::MAX.checked_scale_pow10(1), None);
+assert_eq!(Fix::MAX.checked_scale_pow10(-100), Some(Fix::ZERO));
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_scale_pow10(self, exp: i32) -> Option<$Self<FRAC>> {
+                    match self.overflowing_scale_pow10(exp) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked remainder for Euclidean division. Returns the
+remainder, or [`None`] if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let num = Fix::from_num(7.5);
+assert_eq!(num.checked_rem_euclid(Fix::from_num(2)), Some(Fix::from_num(1.5)));
+assert_eq!(num.checked_rem_euclid(Fix::ZERO), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-num).checked_rem_euclid(Fix::from_num(2)), Some(Fix::from_num(0.5)));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_rem_euclid(self, rhs: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    let rhs_bits = rhs.to_bits();
+                    let rhs_abs = if_signed_unsigned!($Signedness, rhs_bits.unsigned_abs(), rhs_bits);
+                    if rhs_abs.is_power_of_two() {
+                        return Some(Self::from_bits(self.to_bits() & (rhs_abs - 1) as $Inner));
+                    }
+                    match self.to_bits().checked_rem_euclid(rhs_bits) {
+                        None => None,
+                        Some(bits) => Some(Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            comment! {
+                "Checked shift left. Returns the shifted number,
+or [`None`] if `rhs`&nbsp;≥&nbsp;", $n, ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!((Fix::ONE / 2).checked_shl(3), Some(Fix::from_num(4)));
+assert_eq!((Fix::ONE / 2).checked_shl(", $n, "), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_shl(self, rhs: u32) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_shl(rhs) {
+                        None => None,
+                        Some(bits) => Some(Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            comment! {
+                "Checked shift right. Returns the shifted number,
+or [`None`] if `rhs`&nbsp;≥&nbsp;", $n, ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(4).checked_shr(3), Some(Fix::ONE / 2));
+assert_eq!(Fix::from_num(4).checked_shr(", $n, "), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_shr(self, rhs: u32) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_shr(rhs) {
+                        None => None,
+                        Some(bits) => Some(Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Checked absolute value. Returns the absolute value, or [`None`] on overflow.
+
+Overflow can only occur when trying to find the absolute value of the minimum value.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(-5).checked_abs(), Some(Fix::from_num(5)));
+assert_eq!(Fix::MIN.checked_abs(), None);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn checked_abs(self) -> Option<$Self<FRAC>> {
+                        match self.to_bits().checked_abs() {
+                            None => None,
+                            Some(bits) => Some(Self::from_bits(bits)),
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Checked distance. Returns the distance from `self` to `other`",
+                if_signed_else_empty_str! { $Signedness; ", or [`None`] on overflow" },
+                ".
+
+The distance is the absolute value of the difference.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Can never overflow for unsigned types.
+
+",
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_dist(Fix::from_num(5)), Some(Fix::from_num(4)));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MIN.checked_dist(Fix::ZERO), None);",
+                    "assert_eq!(Fix::ZERO.checked_dist(Fix::MAX), Some(Fix::MAX));",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_dist(self, other: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    if_signed! {
+                        $Signedness;
+                        if self.to_bits() < other.to_bits() {
+                            other.checked_sub(self)
+                        } else {
+                            self.checked_sub(other)
+                        }
+                    }
+                    if_unsigned! {
+                        $Signedness;
+                        Some(self.dist(other))
+                    }
+                }
+            }
+
+            comment! {
+                "Checked positive difference. Returns the positive difference
+between `self` and `other`",
+                if_signed_else_empty_str! { $Signedness; ", or [`None`] on overflow" },
+                ".
+
+The positive difference is `self`&nbsp;&minus;&nbsp;`other` if `self` is
+greater than `other`, and zero otherwise.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Can never overflow for unsigned types.
+
+",
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(5).checked_positive_diff(Fix::ONE),
+    Some(Fix::from_num(4))
+);
+assert_eq!(Fix::ONE.checked_positive_diff(Fix::from_num(5)), Some(Fix::ZERO));
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MAX.checked_positive_diff(Fix::NEG_ONE), None);",
+                    "assert_eq!(Fix::MAX.checked_positive_diff(Fix::ZERO), Some(Fix::MAX));",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_positive_diff(self, other: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    if self.to_bits() > other.to_bits() {
+                        self.checked_sub(other)
+                    } else {
+                        Some(Self::ZERO)
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Checked signum. Returns a number representing the
+sign of `self`, or [`None`] on overflow.
+
+Overflow can only occur
+  * if the value is positive and the fixed-point number has zero
+    or one integer bits such that it cannot hold the value 1.
+  * if the value is negative and the fixed-point number has zero
+    integer bits, such that it cannot hold the value &minus;1.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(5).checked_signum(), Some(Fix::ONE));
+assert_eq!(Fix::ZERO.checked_signum(), Some(Fix::ZERO));
+assert_eq!(Fix::from_num(-5).checked_signum(), Some(Fix::NEG_ONE));
+
+type OneIntBit = ", stringify!($Self), "<", $nm1, ">;
+type ZeroIntBits = ", stringify!($Self), "<", $n, ">;
+assert_eq!(OneIntBit::from_num(0.5).checked_signum(), None);
+assert_eq!(ZeroIntBits::from_num(0.25).checked_signum(), None);
+assert_eq!(ZeroIntBits::from_num(-0.5).checked_signum(), None);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn checked_signum(self) -> Option<$Self<FRAC>> {
+                        match self.overflowing_signum() {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+
+                comment! {
+                    "Checked sign copy. Returns a number with the magnitude of
+`self` and the sign of `sign`, or [`None`] on overflow.
+
+Overflow can only occur when `self` is [`MIN`][Self::MIN] and `sign` is not
+negative.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let pos = Fix::from_num(2.5);
+assert_eq!(pos.checked_copysign(-Fix::DELTA), Some(-pos));
+assert_eq!(Fix::MIN.checked_copysign(Fix::NEG_ONE), Some(Fix::MIN));
+assert_eq!(Fix::MIN.checked_copysign(Fix::ONE), None);
+```
+";
+                    #[inline]
+                    #[must_use = "this returns the result of the operation, without modifying the original"]
+                    pub const fn checked_copysign(self, sign: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                        if self.is_negative() == sign.is_negative() {
+                            Some(self)
+                        } else {
+                            self.checked_neg()
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Compute the hypotenuse of a right triange, returning [`None`] on overflow.
+
+The hypotenuse is equal to the square root of the sum of the squares of the
+operands.
+
+This method uses an iterative method for its square root, with up to ", $n, "
+iterations for [`", stringify!($Self), "`]. The result is rounded down, and the
+error is &lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√(`self`²&nbsp;+&nbsp;`other`²)&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<", stringify!($nm4), ">;
+
+// hypot(3, 4) == 5
+assert_eq!(
+    Fix::from_num(3).checked_hypot(Fix::from_num(4)),
+    Some(Fix::from_num(5))
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+// hypot(2, 7.875) == 8.125, which does not fit
+assert_eq!(
+    Fix::from_num(2).checked_hypot(Fix::from_num(7.875)),
+    None
+);
+",
+                },
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "
+// hypot(8, 15) == 17, which does not fit
+assert_eq!(
+    Fix::from_num(8).checked_hypot(Fix::from_num(15)),
+    None
+);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_hypot(self, other: $Self<FRAC>) -> Option<$Self<FRAC>> {
+                    match self.overflowing_hypot(other) {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked Euclidean norm of a vector. Returns the square root of
+the sum of the squares of the elements of `values`, or [`None`] on overflow.
+
+The squares are summed exactly in an accumulator twice as wide as
+[`", stringify!($Self), "`], and then a single iterative square root is taken,
+so unlike chaining [`hypot`][Self::hypot] calls, rounding errors do not
+accumulate. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√(Σ&nbsp;<i>x</i><sub><i>i</i></sub>²)&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+An empty slice gives zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<", stringify!($nm4), ">;
+
+// norm(2, 3, 6) == 7
+let v = [Fix::from_num(2), Fix::from_num(3), Fix::from_num(6)];
+assert_eq!(Fix::checked_norm(&v), Some(Fix::from_num(7)));
+assert_eq!(Fix::checked_norm(&[Fix::MAX, Fix::MAX]), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn checked_norm(values: &[$Self<FRAC>]) -> Option<$Self<FRAC>> {
+                    let abs = values
+                        .iter()
+                        .map(|x| int_helper::$Inner::neg_abs(x.to_bits()).1);
+                    let norm = hypot::norm::$UInner(abs)?;
+                    if_signed! {
+                        $Signedness;
+                        if norm > $Inner::MAX as $UInner {
+                            return None;
+                        }
+                    }
+                    Some($Self::from_bits(norm as $Inner))
+                }
+            }
+
+            comment! {
+                "Checked increment. Returns
+`self`&nbsp;+&nbsp;[`DELTA`][Self::DELTA], or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_inc(), Some(Fix::ONE + Fix::DELTA));
+assert_eq!(Fix::MAX.checked_inc(), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_inc(self) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_add(1) {
+                        Some(bits) => Some($Self::from_bits(bits)),
+                        None => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked decrement. Returns
+`self`&nbsp;&minus;&nbsp;[`DELTA`][Self::DELTA], or [`None`] on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.checked_dec(), Some(Fix::ONE - Fix::DELTA));
+assert_eq!(Fix::MIN.checked_dec(), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_dec(self) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_sub(1) {
+                        Some(bits) => Some($Self::from_bits(bits)),
+                        None => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked next multiple of `other`. Returns the next multiple, or
+[`None`] if `other` is zero or on overflow.
+
+The next multiple is the smallest multiple of `other` that is ≥&nbsp;`self`",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " if `other` is positive, and the largest multiple of
+`other` that is ≤&nbsp;`self` if `other` is negative",
+                },
+                ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(4).checked_next_multiple_of(Fix::from_num(1.5)),
+    Some(Fix::from_num(4.5))
+);
+assert_eq!(Fix::from_num(4).checked_next_multiple_of(Fix::ZERO), None);
+assert_eq!(Fix::MAX.checked_next_multiple_of(Fix::from_num(2)), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_next_multiple_of(
+                    self,
+                    other: $Self<FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    if other.to_bits() == 0 {
+                        None
+                    } else {
+                        match self.overflowing_next_multiple_of(other) {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Checked inverse linear interpolation between `start` and `end`.
+Returns [`None`] on overflow or when `start`&nbsp;=&nbsp;`end`.
+
+The computed value can have a fixed-point type like `self` but with a different
+[number of fractional bits].
+
+Returns
+(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+
+This method is implemented for
+0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let two = Fix::from_num(2);
+let four = Fix::from_num(4);
+assert_eq!(Fix::from_num(3).checked_inv_lerp::<4>(two, four), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::from_num(2).checked_inv_lerp::<4>(two, two), None);
+assert_eq!(Fix::MAX.checked_inv_lerp::<4>(Fix::ZERO, Fix::from_num(0.5)), None);
+```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_inv_lerp<const RET_FRAC: i32>(
+                    self,
+                    start: $Self<FRAC>,
+                    end: $Self<FRAC>,
+                ) -> Option<$Self<RET_FRAC>>
+                where
+                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
+                {
+                    let start = start.to_bits();
+                    let end = end.to_bits();
+                    if start == end {
+                        return None;
+                    }
+                    match inv_lerp::$Inner(self.to_bits(), start, end, RET_FRAC as u32) {
+                        (bits, false) => Some($Self::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked inverse linear interpolation between `start` and `end`, where
+`start` and `end` can have a different number of fractional bits from `self`.
+Returns [`None`] on overflow or when `start`&nbsp;=&nbsp;`end` after rounding.
+
+The computed value can have a fixed-point type like `self` but with a different
+[number of fractional bits].
+
+Returns
+(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+
+Before the computation, the operands are brought to the smaller of the two
+numbers of fractional bits: if `start` and `end` have more fractional bits than
+`self`, they are rounded, otherwise `self` is rounded. This rounding is towards
+&minus;∞, and it cannot overflow.
+
+This method is implemented for
+0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Range = ", stringify!($Self), "<5>;
+let one = Range::from_num(1);
+let three = Range::from_num(3);
+assert_eq!(
+    Fix::from_num(2).checked_inv_lerp_mixed::<5, 4>(one, three),
+    Some(Fix::from_num(0.5))
+);
+// 1.03125 is rounded down to 1, so the range is empty
+let fine = Range::from_num(1.03125);
+assert_eq!(Fix::from_num(2).checked_inv_lerp_mixed::<5, 4>(one, fine), None);
+let half = Range::from_num(0.5);
+assert_eq!(Fix::MAX.checked_inv_lerp_mixed::<5, 4>(Range::ZERO, half), None);
+```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_inv_lerp_mixed<const RANGE_FRAC: i32, const RET_FRAC: i32>(
+                    self,
+                    start: $Self<RANGE_FRAC>,
+                    end: $Self<RANGE_FRAC>,
+                ) -> Option<$Self<RET_FRAC>>
+                where
+                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
+                {
+                    let (self_bits, start, end) = inv_lerp::align::$Inner(
+                        self.to_bits(),
+                        FRAC,
+                        start.to_bits(),
+                        end.to_bits(),
+                        RANGE_FRAC,
+                    );
+                    if start == end {
+                        return None;
+                    }
+                    match inv_lerp::$Inner(self_bits, start, end, RET_FRAC as u32) {
+                        (bits, false) => Some($Self::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+                comment! {
+                    "Returns the smallest power of two that is ≥&nbsp;`self`, or
+[`None`] if the next power of two is too large to represent.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 3/8 is 0.0110
+let three_eights = Fix::from_bits(0b0110);
+// 1/2 is 0.1000
+let half = Fix::from_bits(0b1000);
+assert_eq!(three_eights.checked_next_power_of_two(), Some(half));
+assert!(Fix::MAX.checked_next_power_of_two().is_none());
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn checked_next_power_of_two(self) -> Option<$Self<FRAC>> {
+                        match self.to_bits().checked_next_power_of_two() {
                             Some(bits) => Some(Self::from_bits(bits)),
                             None => None,
                         }
@@ -3119,136 +6628,560 @@ assert!(Fix::MAX.checked_next_power_of_two().is_none());
                 }
             }
 
-            if_signed! {
-                $Signedness;
-                /// Checked addition with an unsigned fixed-point number.
-                /// Returns the sum, or [`None`] on overflow.
-                ///
-                /// # Examples
-                ///
-                /// ```rust
-                /// #![feature(generic_const_exprs)]
-                /// # #![allow(incomplete_features)]
-                ///
-                #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
-                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
-                #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
-                /// assert_eq!(
-                ///     Fix::from_num(-5).checked_add_unsigned(UFix::from_num(3)),
-                ///     Some(Fix::from_num(-2))
-                /// );
-                /// assert_eq!(Fix::MAX.checked_add_unsigned(UFix::DELTA), None);
-                /// ```
+            if_signed! {
+                $Signedness;
+                /// Checked addition with an unsigned fixed-point number.
+                /// Returns the sum, or [`None`] on overflow.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                /// assert_eq!(
+                ///     Fix::from_num(-5).checked_add_unsigned(UFix::from_num(3)),
+                ///     Some(Fix::from_num(-2))
+                /// );
+                /// assert_eq!(Fix::MAX.checked_add_unsigned(UFix::DELTA), None);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn checked_add_unsigned(
+                    self,
+                    rhs: $USelf<FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_add_unsigned(rhs.to_bits()) {
+                        None => None,
+                        Some(bits) => Some($Self::from_bits(bits)),
+                    }
+                }
+
+                /// Checked subtraction with an unsigned fixed-point number.
+                /// Returns the difference, or [`None`] on overflow.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
+                /// assert_eq!(
+                ///     Fix::from_num(3).checked_sub_unsigned(UFix::from_num(5)),
+                ///     Some(Fix::from_num(-2))
+                /// );
+                /// assert_eq!(Fix::MIN.checked_sub_unsigned(UFix::DELTA), None);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn checked_sub_unsigned(
+                    self,
+                    rhs: $USelf<FRAC>,
+                ) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_sub_unsigned(rhs.to_bits()) {
+                        None => None,
+                        Some(bits) => Some($Self::from_bits(bits)),
+                    }
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+                /// Checked addition with a signed fixed-point number.
+                /// Returns the sum, or [`None`] on overflow.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(
+                ///     Fix::from_num(5).checked_add_signed(IFix::from_num(-3)),
+                ///     Some(Fix::from_num(2))
+                /// );
+                /// assert_eq!(Fix::from_num(2).checked_add_signed(IFix::from_num(-3)), None);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn checked_add_signed(self, rhs: $ISelf<FRAC>) -> Option<$Self<FRAC>> {
+                    match self.to_bits().checked_add_signed(rhs.to_bits()) {
+                        None => None,
+                        Some(bits) => Some($Self::from_bits(bits)),
+                    }
+                }
+
+                /// Checked subtraction with a signed fixed-point number.
+                /// Returns the difference, or [`None`] on overflow.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(
+                ///     Fix::from_num(5).checked_sub_signed(IFix::from_num(-3)),
+                ///     Some(Fix::from_num(8))
+                /// );
+                /// assert_eq!(Fix::from_num(2).checked_sub_signed(IFix::from_num(3)), None);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn checked_sub_signed(self, rhs: $ISelf<FRAC>) -> Option<$Self<FRAC>> {
+                    match self.overflowing_sub_signed(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+
+                /// Checked multiplication by a signed integer. Returns the
+                /// signed product, or [`None`] on overflow.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(
+                ///     Fix::from_num(1.5).checked_mul_signed_int(-3),
+                ///     Some(IFix::from_num(-4.5))
+                /// );
+                /// assert_eq!(Fix::MAX.checked_mul_signed_int(-1), None);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn checked_mul_signed_int(self, rhs: $IInner) -> Option<$ISelf<FRAC>> {
+                    match self.overflowing_mul_signed_int(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating negation. Returns the negated value, saturating on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "This method always returns zero.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).saturating_neg(), Fix::from_num(-5));
+assert_eq!(Fix::MIN.saturating_neg(), Fix::MAX);",
+                    "assert_eq!(Fix::ZERO.saturating_neg(), Fix::from_num(0));
+assert_eq!(Fix::from_num(5).saturating_neg(), Fix::ZERO);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_neg(self) -> $Self<FRAC> {
+                    if_signed_unsigned!(
+                        $Signedness,
+                        {
+                            match self.overflowing_neg() {
+                                (val, false) => val,
+                                (_, true) => Self::MAX,
+                            }
+                        },
+                        Self::ZERO,
+                    )
+                }
+            }
+
+            comment! {
+                "Saturating addition. Returns the sum, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3).saturating_add(Fix::from_num(2)), Fix::from_num(5));
+assert_eq!(Fix::MAX.saturating_add(Fix::ONE), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_add(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    $Self::from_bits(self.to_bits().saturating_add(rhs.to_bits()))
+                }
+            }
+
+            comment! {
+                "Saturating subtraction. Returns the difference, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::ONE.saturating_sub(Fix::from_num(3)), Fix::from_num(-2));
+assert_eq!(Fix::MIN.saturating_sub(Fix::ONE), Fix::MIN);",
+                    "assert_eq!(Fix::from_num(5).saturating_sub(Fix::from_num(3)), Fix::from_num(2));
+assert_eq!(Fix::ZERO.saturating_sub(Fix::ONE), Fix::ZERO);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_sub(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    $Self::from_bits(self.to_bits().saturating_sub(rhs.to_bits()))
+                }
+            }
+
+            comment! {
+                "Saturating multiplication. Returns the product, saturating on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3).saturating_mul(Fix::from_num(2)), Fix::from_num(6));
+assert_eq!(Fix::MAX.saturating_mul(Fix::from_num(2)), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_mul(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), FRAC) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if (self.to_bits() < 0) != (rhs.to_bits() < 0) {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating square. Returns `self` × `self`, saturating on overflow.
+
+Since the square is never negative, it can only saturate to [`MAX`][Self::MAX].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).saturating_square(), Fix::from_num(2.25));
+assert_eq!(Fix::MAX.saturating_square(), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_square(), Fix::MAX);
+",
+                },
+                "```
+";
                 #[inline]
-                #[must_use]
-                pub const fn checked_add_unsigned(
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_square(self) -> $Self<FRAC> {
+                    match arith::$Inner::overflowing_mul(self.to_bits(), self.to_bits(), FRAC) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                r#"Saturating multiply and add.
+Returns `self` × `mul` + `add`, saturating on overflow.
+
+This method retuns the same value as
+<code>add.[saturating\_add\_prod][`saturating_add_prod`](self, mul)</code>.
+The [`saturating_add_prod`] method is more flexible because `saturating_mul_add`
+requires that `self` and `add` must have the same [number of fractional bits].
+
+"#,
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Only the final result is saturated: if the intermediate product
+`self`&nbsp;×&nbsp;`mul` overflows on its own but the final result is
+representable, the correct result is returned.
+
+",
+                },
+                "[`saturating_add_prod`]: Self::saturating_add_prod
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_mul_add<const MUL_FRAC: i32>(
                     self,
-                    rhs: $USelf<FRAC>,
-                ) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_add_unsigned(rhs.to_bits()) {
-                        None => None,
-                        Some(bits) => Some($Self::from_bits(bits)),
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    add.saturating_add_prod(self, mul)
+                }
+            }
+
+            comment! {
+                "Saturating multiply and subtract.
+Returns `self` × `mul` &minus; `sub`, saturating on overflow.
+
+For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its own,
+but the final result is representable; in these cases this method returns the
+correct result without overflow.
+
+The product is computed exactly before `sub` is subtracted, and any fractional
+bits that do not fit are discarded, which rounds towards &minus;∞.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).saturating_mul_sub(Fix::from_num(0.5), Fix::ONE),
+    0.5
+);
+assert_eq!(Fix::MAX.saturating_mul_sub(Fix::from_num(3), Fix::MAX), Fix::MAX);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MAX.saturating_mul_sub(Fix::from_num(-3), Fix::ZERO), Fix::MIN);
+",
+                    "assert_eq!(Fix::ONE.saturating_mul_sub(Fix::ONE, Fix::from_num(2)), Fix::MIN);
+",
+                ),
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_mul_sub<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    sub: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    match arith::$Inner::overflowing_mul_sub(
+                        self.to_bits(),
+                        mul.to_bits(),
+                        sub.to_bits(),
+                        MUL_FRAC,
+                    ) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() != mul.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                // the result is negative unless the product overflows
+                                if arith::$Inner::overflowing_mul(
+                                    self.to_bits(),
+                                    mul.to_bits(),
+                                    MUL_FRAC,
+                                )
+                                .1
+                                {
+                                    Self::MAX
+                                } else {
+                                    Self::MIN
+                                },
+                            )
+                        }
                     }
                 }
+            }
 
-                /// Checked subtraction with an unsigned fixed-point number.
-                /// Returns the difference, or [`None`] on overflow.
-                ///
-                /// # Examples
-                ///
-                /// ```rust
-                /// #![feature(generic_const_exprs)]
-                /// # #![allow(incomplete_features)]
-                ///
-                #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};")]
-                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
-                #[doc = concat!("type UFix = ", stringify!($USelf), "<4>;")]
-                /// assert_eq!(
-                ///     Fix::from_num(3).checked_sub_unsigned(UFix::from_num(5)),
-                ///     Some(Fix::from_num(-2))
-                /// );
-                /// assert_eq!(Fix::MIN.checked_sub_unsigned(UFix::DELTA), None);
-                /// ```
+            comment! {
+                "Saturating multiply and divide. Returns `self` × `mul` / `div`,
+saturating on overflow.
+
+For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its own,
+but the final result is representable; in these cases this method returns the
+correct result without overflow.
+
+The product is computed exactly before it is divided by `div`, and the result is
+rounded towards zero.
+
+# Panics
+
+Panics if `div` is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let half_max = Fix::from_bits(Fix::MAX.to_bits() / 2);
+assert_eq!(
+    Fix::MAX.saturating_mul_div(Fix::from_num(2), Fix::from_num(4)),
+    half_max
+);
+assert_eq!(
+    Fix::MAX.saturating_mul_div(Fix::from_num(4), Fix::from_num(2)),
+    Fix::MAX
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(
+    Fix::MAX.saturating_mul_div(Fix::from_num(4), Fix::from_num(-2)),
+    Fix::MIN
+);
+",
+                },
+                "```
+";
                 #[inline]
-                #[must_use]
-                pub const fn checked_sub_unsigned(
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
                     self,
-                    rhs: $USelf<FRAC>,
-                ) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_sub_unsigned(rhs.to_bits()) {
-                        None => None,
-                        Some(bits) => Some($Self::from_bits(bits)),
+                    mul: $Self<MUL_FRAC>,
+                    div: $Self<DIV_FRAC>,
+                ) -> $Self<FRAC> {
+                    let shift = DIV_FRAC as i64 - MUL_FRAC as i64;
+                    match mul_div::$Inner(self.to_bits(), mul.to_bits(), div.to_bits(), shift) {
+                        None => panic!("division by zero"),
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if (self.is_negative() != mul.is_negative()) != div.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
                     }
                 }
             }
 
-            if_unsigned! {
-                $Signedness;
-                /// Checked addition with a signed fixed-point number.
-                /// Returns the sum, or [`None`] on overflow.
-                ///
-                /// # Examples
-                ///
-                /// ```rust
-                /// #![feature(generic_const_exprs)]
-                /// # #![allow(incomplete_features)]
-                ///
-                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
-                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
-                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
-                /// assert_eq!(
-                ///     Fix::from_num(5).checked_add_signed(IFix::from_num(-3)),
-                ///     Some(Fix::from_num(2))
-                /// );
-                /// assert_eq!(Fix::from_num(2).checked_add_signed(IFix::from_num(-3)), None);
-                /// ```
+            comment! {
+                r#"Saturating negated multiply and add.
+Returns `add` &minus; `self` × `mul`, saturating on overflow.
+
+This method returns the same value as
+<code>add.[saturating\_sub\_prod][`saturating_sub_prod`](self, mul)</code>.
+The [`saturating_sub_prod`] method is more flexible because `saturating_neg_mul_add`
+requires that `self` and `add` must have the same [number of fractional bits].
+
+[`saturating_sub_prod`]: Self::saturating_sub_prod
+[number of fractional bits]: Self::FRAC_BITS
+"#;
                 #[inline]
-                #[must_use]
-                pub const fn checked_add_signed(self, rhs: $ISelf<FRAC>) -> Option<$Self<FRAC>> {
-                    match self.to_bits().checked_add_signed(rhs.to_bits()) {
-                        None => None,
-                        Some(bits) => Some($Self::from_bits(bits)),
-                    }
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_neg_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    add.saturating_sub_prod(self, mul)
                 }
+            }
 
-                /// Checked subtraction with a signed fixed-point number.
-                /// Returns the difference, or [`None`] on overflow.
-                ///
-                /// # Examples
-                ///
-                /// ```rust
-                /// #![feature(generic_const_exprs)]
-                /// # #![allow(incomplete_features)]
-                ///
-                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
-                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
-                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
-                /// assert_eq!(
-                ///     Fix::from_num(5).checked_sub_signed(IFix::from_num(-3)),
-                ///     Some(Fix::from_num(8))
-                /// );
-                /// assert_eq!(Fix::from_num(2).checked_sub_signed(IFix::from_num(3)), None);
-                /// ```
+            comment! {
+                "Returns the minimum of two values. If the values are equal, returns
+`self`.
+
+This returns the same value as <code>[Ord]::[min][Ord::min]</code>, but it can
+be used in constant context.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+const SMALL: Fix = Fix::MAX.min(Fix::DELTA);
+assert_eq!(SMALL, Fix::DELTA);
+assert_eq!(Fix::ZERO.min(Fix::MAX), Fix::ZERO);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.5).min(Fix::from_num(0.5)), -1.5);
+",
+                },
+                "```
+";
                 #[inline]
                 #[must_use]
-                pub const fn checked_sub_signed(self, rhs: $ISelf<FRAC>) -> Option<$Self<FRAC>> {
-                    match self.overflowing_sub_signed(rhs) {
-                        (ans, false) => Some(ans),
-                        (_, true) => None,
+                pub const fn min(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    if other.to_bits() < self.to_bits() {
+                        other
+                    } else {
+                        self
                     }
                 }
             }
 
             comment! {
-                "Saturating negation. Returns the negated value, saturating on overflow.
+                "Returns the maximum of two values. If the values are equal, returns
+`self`.
 
-",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "Overflow can only occur when negating the minimum value.",
-                    "This method always returns zero.",
-                ),
-                "
+This returns the same value as <code>[Ord]::[max][Ord::max]</code>, but it can
+be used in constant context.
 
 # Examples
 
@@ -3258,35 +7191,38 @@ assert!(Fix::MAX.checked_next_power_of_two().is_none());
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
+const LARGE: Fix = Fix::ZERO.max(Fix::DELTA);
+assert_eq!(LARGE, Fix::DELTA);
+assert_eq!(Fix::MAX.max(Fix::ZERO), Fix::MAX);
 ",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "assert_eq!(Fix::from_num(5).saturating_neg(), Fix::from_num(-5));
-assert_eq!(Fix::MIN.saturating_neg(), Fix::MAX);",
-                    "assert_eq!(Fix::ZERO.saturating_neg(), Fix::from_num(0));
-assert_eq!(Fix::from_num(5).saturating_neg(), Fix::ZERO);",
-                ),
-                "
-```
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.5).max(Fix::from_num(-2)), -1.5);
+",
+                },
+                "```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn saturating_neg(self) -> $Self<FRAC> {
-                    if_signed_unsigned!(
-                        $Signedness,
-                        {
-                            match self.overflowing_neg() {
-                                (val, false) => val,
-                                (_, true) => Self::MAX,
-                            }
-                        },
-                        Self::ZERO,
-                    )
+                pub const fn max(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    if other.to_bits() > self.to_bits() {
+                        other
+                    } else {
+                        self
+                    }
                 }
             }
 
             comment! {
-                "Saturating addition. Returns the sum, saturating on overflow.
+                "Restricts the value to a range. Returns `max` if `self` is greater
+than `max`, and `min` if `self` is less than `min`. Otherwise returns `self`.
+
+This returns the same value as <code>[Ord]::[clamp][Ord::clamp]</code>, but it
+can be used in constant context.
+
+# Panics
+
+Panics if `min`&nbsp;>&nbsp;`max`.
 
 # Examples
 
@@ -3296,19 +7232,45 @@ assert_eq!(Fix::from_num(5).saturating_neg(), Fix::ZERO);",
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(3).saturating_add(Fix::from_num(2)), Fix::from_num(5));
-assert_eq!(Fix::MAX.saturating_add(Fix::ONE), Fix::MAX);
+const MIN: Fix = Fix::lit(\"1\");
+const MAX: Fix = Fix::lit(\"5\");
+// clamp can be used in constant context
+const LOW: Fix = Fix::ZERO.clamp(MIN, MAX);
+assert_eq!(LOW, 1);
+assert_eq!(Fix::from_num(3.5).clamp(MIN, MAX), 3.5);
+assert_eq!(Fix::MAX.clamp(MIN, MAX), 5);
 ```
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_add(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    $Self::from_bits(self.to_bits().saturating_add(rhs.to_bits()))
+                pub const fn clamp(self, min: $Self<FRAC>, max: $Self<FRAC>) -> $Self<FRAC> {
+                    assert!(min.to_bits() <= max.to_bits(), "min > max");
+                    if self.to_bits() < min.to_bits() {
+                        min
+                    } else if self.to_bits() > max.to_bits() {
+                        max
+                    } else {
+                        self
+                    }
                 }
             }
 
             comment! {
-                "Saturating subtraction. Returns the difference, saturating on overflow.
+                "Multiply and add, clamped to a range.
+Returns `self` × `mul` + `add`, clamped to the range
+`lo`&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;`hi`.
+
+The result is computed using [`saturating_add_prod`], so there is no overflow
+even if `self`&nbsp;×&nbsp;`mul`&nbsp;+&nbsp;`add` is not representable; such
+values lie outside the range and are clamped to `lo` or `hi`.
+
+This is useful for example in control loops, where an output is computed using
+a gain and an offset and then limited to the actuator range.
+
+# Panics
+
+Panics if `lo`&nbsp;>&nbsp;`hi`.
 
 # Examples
 
@@ -3318,26 +7280,46 @@ assert_eq!(Fix::MAX.saturating_add(Fix::ONE), Fix::MAX);
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "assert_eq!(Fix::ONE.saturating_sub(Fix::from_num(3)), Fix::from_num(-2));
-assert_eq!(Fix::MIN.saturating_sub(Fix::ONE), Fix::MIN);",
-                    "assert_eq!(Fix::from_num(5).saturating_sub(Fix::from_num(3)), Fix::from_num(2));
-assert_eq!(Fix::ZERO.saturating_sub(Fix::ONE), Fix::ZERO);",
-                ),
-                "
+let lo = Fix::from_num(1);
+let hi = Fix::from_num(5);
+// 3 × 0.5 + 2 = 3.5, which is within the range
+assert_eq!(Fix::from_num(3).clamped_mul_add(Fix::from_num(0.5), Fix::from_num(2), lo, hi), 3.5);
+// 3 × 2 + 2 = 8, which is clamped to 5
+assert_eq!(Fix::from_num(3).clamped_mul_add(Fix::from_num(2), Fix::from_num(2), lo, hi), hi);
+// MAX × 2 + 2 overflows, but the result is still clamped to 5
+assert_eq!(Fix::MAX.clamped_mul_add(Fix::from_num(2), Fix::from_num(2), lo, hi), hi);
 ```
+
+[`saturating_add_prod`]: Self::saturating_add_prod
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_sub(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    $Self::from_bits(self.to_bits().saturating_sub(rhs.to_bits()))
+                pub const fn clamped_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                    lo: $Self<FRAC>,
+                    hi: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    assert!(lo.to_bits() <= hi.to_bits(), "lo > hi");
+                    let ans = add.saturating_add_prod(self, mul);
+                    if ans.to_bits() < lo.to_bits() {
+                        lo
+                    } else if ans.to_bits() > hi.to_bits() {
+                        hi
+                    } else {
+                        ans
+                    }
                 }
             }
 
             comment! {
-                "Saturating multiplication. Returns the product, saturating on overflow.
+                "Saturating multiplication to a chosen number of fractional
+bits. Returns the product with `OUT_FRAC` fractional bits, saturating on
+overflow.
+
+See [`mul_to`][Self::mul_to] for how the product is rescaled.
 
 # Examples
 
@@ -3347,68 +7329,209 @@ assert_eq!(Fix::ZERO.saturating_sub(Fix::ONE), Fix::ZERO);",
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(3).saturating_mul(Fix::from_num(2)), Fix::from_num(6));
-assert_eq!(Fix::MAX.saturating_mul(Fix::from_num(2)), Fix::MAX);
+// 0.75 × 1.25 = 0.9375, which is 0.1111 in binary
+let a = Fix::from_num(0.75);
+let b = ", stringify!($Self), "::<2>::from_num(1.25);
+let full: ", stringify!($Self), "<6> = a.saturating_mul_to(b);
+assert_eq!(full, 0.9375);
+let too_large: Fix = Fix::MAX.saturating_mul_to(Fix::from_num(2));
+assert_eq!(too_large, Fix::MAX);
 ```
 ";
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_mul(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    match arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), FRAC) {
-                        (ans, false) => Self::from_bits(ans),
+                pub const fn saturating_mul_to<const OUT_FRAC: i32, const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> $Self<OUT_FRAC> {
+                    match self.overflowing_mul_to(rhs) {
+                        (ans, false) => ans,
                         (_, true) => {
                             if_signed_unsigned!(
                                 $Signedness,
-                                if (self.to_bits() < 0) != (rhs.to_bits() < 0) {
-                                    Self::MIN
+                                if self.is_negative() != rhs.is_negative() {
+                                    $Self::MIN
                                 } else {
-                                    Self::MAX
+                                    $Self::MAX
                                 },
-                                Self::MAX,
+                                $Self::MAX,
                             )
                         }
                     }
                 }
             }
 
-            comment! {
-                r#"Saturating multiply and add.
-Returns `self` × `mul` + `add`, saturating on overflow.
+            comment! {
+                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, saturating on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
+
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).saturating_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    5
+);
+assert_eq!(Fix::ONE.saturating_add_prod(Fix::MAX, Fix::from_num(3)), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+assert_eq!(
+    (-Fix::MAX).saturating_add_prod(Fix::MAX, Fix::from_num(1.5)),
+    Fix::MAX / 2
+);
+"
+                },
+                "```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) -> $Self<FRAC> {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                    );
+                    if overflow {
+                        if_signed_unsigned!(
+                            $Signedness,
+                            if a.is_negative() != b.is_negative() {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            },
+                            Self::MAX,
+                        )
+                    } else {
+                        Self::from_bits(ans)
+                    }
+                }
+            }
+
+            comment! {
+                "Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`,
+saturating on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, one of the products or the intermediate sum
+`self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b` would overflow on its own, but the final
+result is representable; in these cases this method returns the correct result
+without overflow.
+
+",
+                },
+                "Each product is rounded down on its own, so when there is no
+overflow the result is the same as that of [`saturating_add_prod`] called twice.
+The parameters `a`, `b`, `c` and `d` can have a fixed-point type like `self` but
+with a different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
 
-This method retuns the same value as
-<code>add.[saturating\_add\_prod][`saturating_add_prod`](self, mul)</code>.
-The [`saturating_add_prod`] method is more flexible because `saturating_mul_add`
-requires that `self` and `add` must have the same [number of fractional bits].
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).saturating_add_prod2(Fix::from_num(4), Fix::from_num(0.5), Fix::from_num(2), Fix::from_num(0.25)),
+    5.5
+);
+assert_eq!(Fix::ONE.saturating_add_prod2(Fix::MAX, Fix::from_num(3), Fix::ONE, Fix::ONE), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 3 - MAX = MAX, although -MAX + MAX × 3 overflows
+assert_eq!(
+    (-Fix::MAX).saturating_add_prod2(Fix::MAX, Fix::from_num(3), -Fix::MAX, Fix::ONE),
+    Fix::MAX
+);
+assert_eq!(Fix::ZERO.saturating_add_prod2(Fix::MAX, -Fix::MAX, Fix::ONE, Fix::ONE), Fix::MIN);
+"
+                },
+                "```
 
 [`saturating_add_prod`]: Self::saturating_add_prod
 [number of fractional bits]: Self::FRAC_BITS
-"#;
+";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_mul_add<const MUL_FRAC: i32>(
+                #[must_use]
+                pub const fn saturating_add_prod2<
+                    const A_FRAC: i32,
+                    const B_FRAC: i32,
+                    const C_FRAC: i32,
+                    const D_FRAC: i32,
+                >(
                     self,
-                    mul: $Self<MUL_FRAC>,
-                    add: $Self<FRAC>,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                    c: $Self<C_FRAC>,
+                    d: $Self<D_FRAC>,
                 ) -> $Self<FRAC> {
-                    add.saturating_add_prod(self, mul)
+                    let (ans, ord) = arith::$Inner::overflowing_mul_add2(
+                        a.to_bits(),
+                        b.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                        c.to_bits(),
+                        d.to_bits(),
+                        arith::saturating_add_sub_i32(C_FRAC, D_FRAC, FRAC),
+                        self.to_bits(),
+                    );
+                    match ord {
+                        Ordering::Less => Self::MIN,
+                        Ordering::Equal => Self::from_bits(ans),
+                        Ordering::Greater => Self::MAX,
+                    }
                 }
             }
 
             comment! {
-                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, saturating on overflow.
+                "Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`, saturating on
+overflow.
 
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "For some cases, the product `a`&nbsp;×&nbsp;`b` would
-overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+overflow on its own, but the final result `self`&nbsp;&minus;&nbsp;`a`&nbsp;×&nbsp;`b`
 is representable; in these cases this method returns the correct result without
 overflow.
 
 ",
                 },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different [number of fractional bits].
+                "The product is computed exactly before being subtracted, and any fractional
+bits that do not fit in `self` are discarded, which rounds the result towards
+&minus;∞.
+
+The `a` and `b` parameters can have a fixed-point type like `self` but with a
+different [number of fractional bits].
 
 # Examples
 
@@ -3419,17 +7542,18 @@ overflow.
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
 assert_eq!(
-    Fix::from_num(3).saturating_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
-    5
+    Fix::from_num(5).saturating_sub_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    3
 );
-assert_eq!(Fix::ONE.saturating_add_prod(Fix::MAX, Fix::from_num(3)), Fix::MAX);
+assert_eq!(Fix::ZERO.saturating_sub_prod(Fix::MAX, Fix::from_num(3)), Fix::MIN);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+                    "assert_eq!(Fix::ZERO.saturating_sub_prod(Fix::MAX, Fix::from_num(-3)), Fix::MAX);
+// MIN - MIN × 1.5 = -MIN / 2, which does not overflow
 assert_eq!(
-    (-Fix::MAX).saturating_add_prod(Fix::MAX, Fix::from_num(1.5)),
-    Fix::MAX / 2
+    Fix::MIN.saturating_sub_prod(Fix::MIN, Fix::from_num(1.5)),
+    -(Fix::MIN / 2)
 );
 "
                 },
@@ -3439,12 +7563,12 @@ assert_eq!(
 ";
                 #[inline]
                 #[must_use]
-                pub const fn saturating_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                pub const fn saturating_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
                     self,
                     a: $Self<A_FRAC>,
                     b: $Self<B_FRAC>,
                 ) -> $Self<FRAC> {
-                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                    let (ans, overflow) = arith::$Inner::overflowing_neg_mul_add(
                         a.to_bits(),
                         b.to_bits(),
                         self.to_bits(),
@@ -3454,11 +7578,11 @@ assert_eq!(
                         if_signed_unsigned!(
                             $Signedness,
                             if a.is_negative() != b.is_negative() {
-                                Self::MIN
-                            } else {
                                 Self::MAX
+                            } else {
+                                Self::MIN
                             },
-                            Self::MAX,
+                            Self::MIN,
                         )
                     } else {
                         Self::from_bits(ans)
@@ -3595,6 +7719,55 @@ assert_eq!(Fix::from_num(3).saturating_div_int(2), one_point_5);
                 }
             }
 
+            comment! {
+                "Saturating multiplication by a power of ten. Returns
+`self`&nbsp;×&nbsp;10<sup>`exp`</sup>, saturating on overflow.
+
+If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+result is rounded to the nearest, with ties rounded to even. Overflow can only
+occur when `exp` is positive.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(0.0625).saturating_scale_pow10(2),
+    Fix::from_num(6.25)
+);
+assert_eq!(Fix::MAX.saturating_scale_pow10(1), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_scale_pow10(1), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_scale_pow10(self, exp: i32) -> $Self<FRAC> {
+                    match self.overflowing_scale_pow10(exp) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -3674,6 +7847,55 @@ assert_eq!(Fix::ONE.saturating_dist(Fix::from_num(5)), Fix::from_num(4));
                 }
             }
 
+            comment! {
+                "Saturating positive difference. Returns the positive difference
+between `self` and `other`",
+                if_signed_else_empty_str! { $Signedness; ", saturating on overflow" },
+                ".
+
+The positive difference is `self`&nbsp;&minus;&nbsp;`other` if `self` is
+greater than `other`, and zero otherwise.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Can never overflow for unsigned types.
+
+",
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(5).saturating_positive_diff(Fix::ONE),
+    Fix::from_num(4)
+);
+assert_eq!(Fix::ONE.saturating_positive_diff(Fix::from_num(5)), Fix::ZERO);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MAX.saturating_positive_diff(Fix::NEG_ONE), Fix::MAX);",
+                    "assert_eq!(Fix::MAX.saturating_positive_diff(Fix::ZERO), Fix::MAX);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_positive_diff(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    if self.to_bits() > other.to_bits() {
+                        self.saturating_sub(other)
+                    } else {
+                        Self::ZERO
+                    }
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -3784,6 +8006,95 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Saturating Euclidean norm of a vector. Returns the square root
+of the sum of the squares of the elements of `values`, saturating on overflow.
+
+The squares are summed exactly in an accumulator twice as wide as
+[`", stringify!($Self), "`], and then a single iterative square root is taken,
+so unlike chaining [`hypot`][Self::hypot] calls, rounding errors do not
+accumulate. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√(Σ&nbsp;<i>x</i><sub><i>i</i></sub>²)&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+An empty slice gives zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<", stringify!($nm4), ">;
+
+// norm(2, 3, 6) == 7
+let v = [Fix::from_num(2), Fix::from_num(3), Fix::from_num(6)];
+assert_eq!(Fix::saturating_norm(&v), Fix::from_num(7));
+assert_eq!(Fix::saturating_norm(&[Fix::MAX, Fix::MAX]), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub fn saturating_norm(values: &[$Self<FRAC>]) -> $Self<FRAC> {
+                    match $Self::checked_norm(values) {
+                        Some(norm) => norm,
+                        None => $Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating increment. Returns
+`self`&nbsp;+&nbsp;[`DELTA`][Self::DELTA], saturating on overflow.
+
+This is an alias of [`next_up`][Self::next_up], provided so that
+[`inc`][Self::inc] has checked and saturating forms with matching names.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.saturating_inc(), Fix::ONE + Fix::DELTA);
+assert_eq!(Fix::MAX.saturating_inc(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_inc(self) -> $Self<FRAC> {
+                    self.next_up()
+                }
+            }
+
+            comment! {
+                "Saturating decrement. Returns
+`self`&nbsp;&minus;&nbsp;[`DELTA`][Self::DELTA], saturating on overflow.
+
+This is an alias of [`next_down`][Self::next_down], provided so that
+[`dec`][Self::dec] has checked and saturating forms with matching names.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::ONE.saturating_dec(), Fix::ONE - Fix::DELTA);
+assert_eq!(Fix::MIN.saturating_dec(), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_dec(self) -> $Self<FRAC> {
+                    self.next_down()
+                }
+            }
+
             comment! {
                 "Saturating next multiple of `other`.
 
@@ -3839,8 +8150,78 @@ assert_eq!(Fix::MAX.saturating_next_multiple_of(Fix::from_num(2)), Fix::MAX);
             }
 
             comment! {
-                "Inverse linear interpolation between `start` and `end`,
-saturating on overflow.
+                "Inverse linear interpolation between `start` and `end`,
+saturating on overflow.
+
+The computed value can have a fixed-point type like `self` but with a different
+[number of fractional bits].
+
+Returns
+(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+
+This method is implemented for
+0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+
+# Panics
+
+Panics when `start`&nbsp;=&nbsp;`end`.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let two = Fix::from_num(2);
+let four = Fix::from_num(4);
+assert_eq!(Fix::from_num(3).saturating_inv_lerp::<4>(two, four), 0.5);
+assert_eq!(Fix::MAX.saturating_inv_lerp::<4>(Fix::ZERO, Fix::from_num(0.5)), Fix::MAX);
+assert_eq!(Fix::MAX.saturating_inv_lerp::<4>(Fix::from_num(0.5), Fix::ZERO), Fix::MIN);
+```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_inv_lerp<const RET_FRAC: i32>(
+                    self,
+                    start: $Self<FRAC>,
+                    end: $Self<FRAC>,
+                ) -> $Self<RET_FRAC>
+                where
+                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
+                {
+                    let self_bits = self.to_bits();
+                    let start = start.to_bits();
+                    let end = end.to_bits();
+                    match inv_lerp::$Inner(self_bits, start, end, RET_FRAC as u32) {
+                        (bits, false) => $Self::from_bits(bits),
+                        (_, true) => if_signed_unsigned!(
+                            $Signedness,
+                            if (self_bits < start) == (end < start) {
+                                $Self::MAX
+                            } else {
+                                $Self::MIN
+                            },
+                            if end < start {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            },
+                        ),
+                    }
+
+                }
+            }
+
+            comment! {
+                "Saturating inverse linear interpolation between `start` and `end`, where
+`start` and `end` can have a different number of fractional bits from `self`.
+Returns the result, saturating on overflow.
 
 The computed value can have a fixed-point type like `self` but with a different
 [number of fractional bits].
@@ -3849,12 +8230,17 @@ Returns
 (`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
 This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
 
+Before the computation, the operands are brought to the smaller of the two
+numbers of fractional bits: if `start` and `end` have more fractional bits than
+`self`, they are rounded, otherwise `self` is rounded. This rounding is towards
+&minus;∞, and it cannot overflow.
+
 This method is implemented for
 0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
 
 # Panics
 
-Panics when `start`&nbsp;=&nbsp;`end`.
+Panics when `start`&nbsp;=&nbsp;`end` after rounding.
 
 # Examples
 
@@ -3864,11 +8250,13 @@ Panics when `start`&nbsp;=&nbsp;`end`.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let two = Fix::from_num(2);
-let four = Fix::from_num(4);
-assert_eq!(Fix::from_num(3).saturating_inv_lerp::<4>(two, four), 0.5);
-assert_eq!(Fix::MAX.saturating_inv_lerp::<4>(Fix::ZERO, Fix::from_num(0.5)), Fix::MAX);
-assert_eq!(Fix::MAX.saturating_inv_lerp::<4>(Fix::from_num(0.5), Fix::ZERO), Fix::MIN);
+type Range = ", stringify!($Self), "<5>;
+let one = Range::from_num(1);
+let three = Range::from_num(3);
+let half = Range::from_num(0.5);
+assert_eq!(Fix::from_num(2).saturating_inv_lerp_mixed::<5, 4>(one, three), 0.5);
+assert_eq!(Fix::MAX.saturating_inv_lerp_mixed::<5, 4>(Range::ZERO, half), Fix::MAX);
+assert_eq!(Fix::MAX.saturating_inv_lerp_mixed::<5, 4>(half, Range::ZERO), Fix::MIN);
 ```
 
 [number of fractional bits]: Self::FRAC_BITS
@@ -3876,17 +8264,21 @@ assert_eq!(Fix::MAX.saturating_inv_lerp::<4>(Fix::from_num(0.5), Fix::ZERO), Fix
                 #[inline]
                 #[track_caller]
                 #[must_use]
-                pub const fn saturating_inv_lerp<const RET_FRAC: i32>(
+                pub const fn saturating_inv_lerp_mixed<const RANGE_FRAC: i32, const RET_FRAC: i32>(
                     self,
-                    start: $Self<FRAC>,
-                    end: $Self<FRAC>,
+                    start: $Self<RANGE_FRAC>,
+                    end: $Self<RANGE_FRAC>,
                 ) -> $Self<RET_FRAC>
                 where
                     If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
                 {
-                    let self_bits = self.to_bits();
-                    let start = start.to_bits();
-                    let end = end.to_bits();
+                    let (self_bits, start, end) = inv_lerp::align::$Inner(
+                        self.to_bits(),
+                        FRAC,
+                        start.to_bits(),
+                        end.to_bits(),
+                        RANGE_FRAC,
+                    );
                     match inv_lerp::$Inner(self_bits, start, end, RET_FRAC as u32) {
                         (bits, false) => $Self::from_bits(bits),
                         (_, true) => if_signed_unsigned!(
@@ -3903,7 +8295,6 @@ assert_eq!(Fix::MAX.saturating_inv_lerp::<4>(Fix::from_num(0.5), Fix::ZERO), Fix
                             },
                         ),
                     }
-
                 }
             }
 
@@ -4006,6 +8397,37 @@ assert_eq!(Fix::MAX.saturating_inv_lerp::<4>(Fix::from_num(0.5), Fix::ZERO), Fix
                         }
                     }
                 }
+
+                /// Saturating multiplication by a signed integer. Returns the
+                /// signed product, saturating on overflow.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(Fix::from_num(1.5).saturating_mul_signed_int(-3), IFix::from_num(-4.5));
+                /// assert_eq!(Fix::MAX.saturating_mul_signed_int(2), IFix::MAX);
+                /// assert_eq!(Fix::MAX.saturating_mul_signed_int(-2), IFix::MIN);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn saturating_mul_signed_int(self, rhs: $IInner) -> $ISelf<FRAC> {
+                    match self.overflowing_mul_signed_int(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if rhs < 0 {
+                                $ISelf::MIN
+                            } else {
+                                $ISelf::MAX
+                            }
+                        }
+                    }
+                }
             }
 
             comment! {
@@ -4127,6 +8549,30 @@ assert_eq!(Fix::MAX.wrapping_mul(Fix::from_num(4)), wrapped);
                 }
             }
 
+            comment! {
+                "Wrapping square. Returns `self` × `self`, wrapping on overflow.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).wrapping_square(), Fix::from_num(2.25));
+assert_eq!(Fix::MAX.wrapping_square(), Fix::MAX.wrapping_mul(Fix::MAX));
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_square(self) -> $Self<FRAC> {
+                    let (ans, _) =
+                        arith::$Inner::overflowing_mul(self.to_bits(), self.to_bits(), FRAC);
+                    Self::from_bits(ans)
+                }
+            }
+
             comment! {
                 r#"Wrapping multiply and add.
 Returns `self` × `mul` + `add`, wrapping on overflow.
@@ -4150,6 +8596,156 @@ requires that `self` and `add` must have the same [number of fractional bits].
                 }
             }
 
+            comment! {
+                "Wrapping multiply and subtract.
+Returns `self` × `mul` &minus; `sub`, wrapping on overflow.
+
+The product is computed exactly before `sub` is subtracted, and any fractional
+bits that do not fit are discarded, which rounds towards &minus;∞.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).wrapping_mul_sub(Fix::from_num(0.5), Fix::ONE),
+    0.5
+);
+assert_eq!(
+    Fix::MAX.wrapping_mul_sub(Fix::from_num(3), Fix::MAX),
+    Fix::MAX.wrapping_mul_int(2)
+);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_mul_sub<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    sub: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    let (ans, _) = arith::$Inner::overflowing_mul_sub(
+                        self.to_bits(),
+                        mul.to_bits(),
+                        sub.to_bits(),
+                        MUL_FRAC,
+                    );
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Wrapping multiply and divide. Returns `self` × `mul` / `div`,
+wrapping on overflow.
+
+For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its own,
+but the final result is representable; in these cases this method returns the
+correct result without overflow.
+
+The product is computed exactly before it is divided by `div`, and the result is
+rounded towards zero.
+
+# Panics
+
+Panics if `div` is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let half_max = Fix::from_bits(Fix::MAX.to_bits() / 2);
+assert_eq!(
+    Fix::MAX.wrapping_mul_div(Fix::from_num(2), Fix::from_num(4)),
+    half_max
+);
+let wrapped = Fix::from_bits(Fix::MAX.to_bits() << 1);
+assert_eq!(
+    Fix::MAX.wrapping_mul_div(Fix::from_num(4), Fix::from_num(2)),
+    wrapped
+);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    div: $Self<DIV_FRAC>,
+                ) -> $Self<FRAC> {
+                    let shift = DIV_FRAC as i64 - MUL_FRAC as i64;
+                    match mul_div::$Inner(self.to_bits(), mul.to_bits(), div.to_bits(), shift) {
+                        None => panic!("division by zero"),
+                        Some((ans, _)) => Self::from_bits(ans),
+                    }
+                }
+            }
+
+            comment! {
+                r#"Wrapping negated multiply and add.
+Returns `add` &minus; `self` × `mul`, wrapping on overflow.
+
+This method returns the same value as
+<code>add.[wrapping\_sub\_prod][`wrapping_sub_prod`](self, mul)</code>.
+The [`wrapping_sub_prod`] method is more flexible because `wrapping_neg_mul_add`
+requires that `self` and `add` must have the same [number of fractional bits].
+
+[`wrapping_sub_prod`]: Self::wrapping_sub_prod
+[number of fractional bits]: Self::FRAC_BITS
+"#;
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_neg_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    add.wrapping_sub_prod(self, mul)
+                }
+            }
+
+            comment! {
+                "Wrapping multiplication to a chosen number of fractional
+bits. Returns the product with `OUT_FRAC` fractional bits, wrapping on
+overflow.
+
+See [`mul_to`][Self::mul_to] for how the product is rescaled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 0.75 × 1.25 = 0.9375, which is 0.1111 in binary
+let a = Fix::from_num(0.75);
+let b = ", stringify!($Self), "::<2>::from_num(1.25);
+let full: ", stringify!($Self), "<6> = a.wrapping_mul_to(b);
+assert_eq!(full, 0.9375);
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::MAX.wrapping_mul_to::<4, 4>(Fix::from_num(4)), wrapped);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_mul_to<const OUT_FRAC: i32, const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> $Self<OUT_FRAC> {
+                    self.overflowing_mul_to(rhs).0
+                }
+            }
+
             comment! {
                 "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, wrapping on overflow.
 
@@ -4193,6 +8789,119 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`,
+wrapping on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, one of the products or the intermediate sum
+`self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b` would overflow on its own, but the final
+result is representable; in these cases this method returns the correct result
+without overflow.
+
+",
+                },
+                "Each product is rounded down on its own, so when there is no
+overflow the result is the same as that of [`wrapping_add_prod`] called twice.
+The parameters `a`, `b`, `c` and `d` can have a fixed-point type like `self` but
+with a different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).wrapping_add_prod2(Fix::from_num(4), Fix::from_num(0.5), Fix::from_num(2), Fix::from_num(0.25)),
+    5.5
+);
+assert_eq!(
+    Fix::MAX.wrapping_add_prod2(Fix::MAX, Fix::from_num(3), Fix::ZERO, Fix::ZERO),
+    Fix::MAX.wrapping_mul_int(4)
+);
+",
+                "```
+
+[`wrapping_add_prod`]: Self::wrapping_add_prod
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_add_prod2<
+                    const A_FRAC: i32,
+                    const B_FRAC: i32,
+                    const C_FRAC: i32,
+                    const D_FRAC: i32,
+                >(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                    c: $Self<C_FRAC>,
+                    d: $Self<D_FRAC>,
+                ) -> $Self<FRAC> {
+                    let (ans, _) = arith::$Inner::overflowing_mul_add2(
+                        a.to_bits(),
+                        b.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                        c.to_bits(),
+                        d.to_bits(),
+                        arith::saturating_add_sub_i32(C_FRAC, D_FRAC, FRAC),
+                        self.to_bits(),
+                    );
+                    Self::from_bits(ans)
+                }
+            }
+
+            comment! {
+                "Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`, wrapping on
+overflow.
+
+The product is computed exactly before being subtracted, and any fractional
+bits that do not fit in `self` are discarded, which rounds the result towards
+&minus;∞.
+
+The `a` and `b` parameters can have a fixed-point type like `self` but with a
+different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(5).wrapping_sub_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    3
+);
+assert_eq!(Fix::MIN.wrapping_sub_prod(Fix::ONE, Fix::DELTA), Fix::MAX);
+```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) -> $Self<FRAC> {
+                    let (ans, _) = arith::$Inner::overflowing_neg_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                    );
+                    Self::from_bits(ans)
+                }
+            }
+
             comment! {
                 "Wrapping multiply and accumulate. Adds (`a` × `b`) to `self`,
 wrapping on overflow.
@@ -4289,10 +8998,43 @@ assert_eq!(Fix::from_num(3).wrapping_div_int(2), one_point_5);
                 "```
 ";
                 #[inline]
-                #[track_caller]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_div_int(self, rhs: $Inner) -> $Self<FRAC> {
+                    Self::from_bits(self.to_bits().wrapping_div(rhs))
+                }
+            }
+
+            comment! {
+                "Wrapping multiplication by a power of ten. Returns
+`self`&nbsp;×&nbsp;10<sup>`exp`</sup>, wrapping on overflow.
+
+If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+result is rounded to the nearest, with ties rounded to even. Overflow can only
+occur when `exp` is positive.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(0.0625).wrapping_scale_pow10(2),
+    Fix::from_num(6.25)
+);
+assert_eq!(
+    Fix::MAX.wrapping_scale_pow10(1),
+    Fix::MAX.wrapping_mul_int(10)
+);
+```
+";
+                #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_div_int(self, rhs: $Inner) -> $Self<FRAC> {
-                    Self::from_bits(self.to_bits().wrapping_div(rhs))
+                pub const fn wrapping_scale_pow10(self, exp: i32) -> $Self<FRAC> {
+                    self.overflowing_scale_pow10(exp).0
                 }
             }
 
@@ -4422,6 +9164,55 @@ assert_eq!(Fix::ONE.wrapping_dist(Fix::from_num(5)), Fix::from_num(4));
                 }
             }
 
+            comment! {
+                "Wrapping positive difference. Returns the positive difference
+between `self` and `other`",
+                if_signed_else_empty_str! { $Signedness; ", wrapping on overflow" },
+                ".
+
+The positive difference is `self`&nbsp;&minus;&nbsp;`other` if `self` is
+greater than `other`, and zero otherwise.
+
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "Can never overflow for unsigned types.
+
+",
+                },
+                "# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(5).wrapping_positive_diff(Fix::ONE),
+    Fix::from_num(4)
+);
+assert_eq!(Fix::ONE.wrapping_positive_diff(Fix::from_num(5)), Fix::ZERO);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MAX.wrapping_positive_diff(-Fix::DELTA), Fix::MIN);",
+                    "assert_eq!(Fix::MAX.wrapping_positive_diff(Fix::ZERO), Fix::MAX);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn wrapping_positive_diff(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    if self.to_bits() > other.to_bits() {
+                        self.wrapping_sub(other)
+                    } else {
+                        Self::ZERO
+                    }
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -4620,6 +9411,74 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Wrapping inverse linear interpolation between `start` and `end`, where
+`start` and `end` can have a different number of fractional bits from `self`.
+Returns the result, wrapping on overflow.
+
+The computed value can have a fixed-point type like `self` but with a different
+[number of fractional bits].
+
+Returns
+(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+
+Before the computation, the operands are brought to the smaller of the two
+numbers of fractional bits: if `start` and `end` have more fractional bits than
+`self`, they are rounded, otherwise `self` is rounded. This rounding is towards
+&minus;∞, and it cannot overflow.
+
+This method is implemented for
+0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+
+# Panics
+
+Panics when `start`&nbsp;=&nbsp;`end` after rounding.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Range = ", stringify!($Self), "<5>;
+let one = Range::from_num(1);
+let three = Range::from_num(3);
+let half = Range::from_num(0.5);
+assert_eq!(Fix::from_num(2).wrapping_inv_lerp_mixed::<5, 4>(one, three), 0.5);
+assert_eq!(
+    Fix::MAX.wrapping_inv_lerp_mixed::<5, 4>(Range::ZERO, half),
+    Fix::MAX.wrapping_mul_int(2)
+);
+```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_inv_lerp_mixed<const RANGE_FRAC: i32, const RET_FRAC: i32>(
+                    self,
+                    start: $Self<RANGE_FRAC>,
+                    end: $Self<RANGE_FRAC>,
+                ) -> $Self<RET_FRAC>
+                where
+                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
+                {
+                    let (self_bits, start, end) = inv_lerp::align::$Inner(
+                        self.to_bits(),
+                        FRAC,
+                        start.to_bits(),
+                        end.to_bits(),
+                        RANGE_FRAC,
+                    );
+                    let (bits, _) = inv_lerp::$Inner(self_bits, start, end, RET_FRAC as u32);
+                    $Self::from_bits(bits)
+                }
+            }
+
             if_unsigned! {
                 $Signedness;
                 comment! {
@@ -4737,23 +9596,193 @@ assert_eq!(Fix::MAX.wrapping_next_power_of_two(), 0);
                 /// assert_eq!(Fix::ZERO.wrapping_sub_signed(IFix::DELTA), Fix::MAX);
                 /// ```
                 #[inline]
-                #[must_use]
-                pub const fn wrapping_sub_signed(self, rhs: $ISelf<FRAC>) -> $Self<FRAC> {
-                    let (ans, _) = self.overflowing_sub_signed(rhs);
-                    ans
+                #[must_use]
+                pub const fn wrapping_sub_signed(self, rhs: $ISelf<FRAC>) -> $Self<FRAC> {
+                    let (ans, _) = self.overflowing_sub_signed(rhs);
+                    ans
+                }
+
+                /// Wrapping multiplication by a signed integer. Returns the
+                /// signed product, wrapping on overflow.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(Fix::from_num(1.5).wrapping_mul_signed_int(-3), IFix::from_num(-4.5));
+                /// assert_eq!(Fix::MAX.wrapping_mul_signed_int(-1), IFix::DELTA);
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_mul_signed_int(self, rhs: $IInner) -> $ISelf<FRAC> {
+                    let (ans, _) = self.overflowing_mul_signed_int(rhs);
+                    ans
+                }
+            }
+
+            comment! {
+                "Unwrapped negation. Returns the negated value, panicking on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "assert_eq!(Fix::from_num(5).unwrapped_neg(), Fix::from_num(-5));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MIN.unwrapped_neg();",
+                    ),
+                    concat!(
+                        "assert_eq!(Fix::ZERO.unwrapped_neg(), Fix::ZERO);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::from_num(5).unwrapped_neg();",
+                    ),
+                ),
+                "
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_neg(self) -> $Self<FRAC> {
+                    match self.checked_neg() {
+                        Some(s) => s,
+                        None => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped addition. Returns the sum, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(3).unwrapped_add(Fix::from_num(2)), Fix::from_num(5));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_add(Fix::DELTA);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_add(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.checked_add(rhs) {
+                        Some(s) => s,
+                        None => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped subtraction. Returns the difference, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(3).unwrapped_sub(Fix::from_num(5)), Fix::from_num(-2));
+",
+                    "assert_eq!(Fix::from_num(5).unwrapped_sub(Fix::from_num(3)), Fix::from_num(2));
+",
+                ),
+                "```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MIN.unwrapped_sub(Fix::DELTA);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_sub(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.checked_sub(rhs) {
+                        Some(s) => s,
+                        None => panic!("overflow"),
+                    }
                 }
             }
 
             comment! {
-                "Unwrapped negation. Returns the negated value, panicking on overflow.
-
-",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "Overflow can only occur when negating the minimum value.",
-                    "Only zero can be negated without overflow.",
-                ),
-                "
+                "Unwrapped multiplication. Returns the product, panicking on overflow.
 
 # Panics
 
@@ -4767,11 +9796,7 @@ Panics if the result does not fit.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-",
-                if_signed_unsigned!(
-                    $Signedness,
-                    concat!(
-                        "assert_eq!(Fix::from_num(5).unwrapped_neg(), Fix::from_num(-5));
+assert_eq!(Fix::from_num(3).unwrapped_mul(Fix::from_num(2)), Fix::from_num(6));
 ```
 
 The following panics because of overflow.
@@ -4782,13 +9807,39 @@ The following panics because of overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let _overflow = Fix::MIN.unwrapped_neg();",
-                    ),
-                    concat!(
-                        "assert_eq!(Fix::ZERO.unwrapped_neg(), Fix::ZERO);
+let _overflow = Fix::MAX.unwrapped_mul(Fix::from_num(4));
 ```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_mul(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.checked_mul(rhs) {
+                        Some(ans) => ans,
+                        None => panic!("overflow"),
+                    }
+                }
+            }
 
-The following panics because of overflow.
+            comment! {
+                "Unwrapped remainder. Returns the remainder, panicking if the divisor is zero.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).unwrapped_rem(Fix::ONE), Fix::from_num(0.5));
+```
+
+The following panics because the divisor is zero.
 
 ```rust,should_panic
 #![feature(generic_const_exprs)]
@@ -4796,25 +9847,62 @@ The following panics because of overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let _overflow = Fix::from_num(5).unwrapped_neg();",
-                    ),
-                ),
-                "
+let _divisor_is_zero = Fix::from_num(1.5).unwrapped_rem(Fix::ZERO);
 ```
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use]
-                pub const fn unwrapped_neg(self) -> $Self<FRAC> {
-                    match self.checked_neg() {
-                        Some(s) => s,
-                        None => panic!("overflow"),
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_rem(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
+                    let rhs_bits = rhs.to_bits();
+                    if_signed! {
+                        $Signedness;
+                        if rhs_bits == -1 {
+                            return Self::ZERO;
+                        }
                     }
+                    Self::from_bits(self.to_bits() % rhs_bits)
                 }
             }
 
             comment! {
-                "Unwrapped addition. Returns the sum, panicking on overflow.
+                r#"Unwrapped multiply and add.
+Returns `self` × `mul` + `add`, panicking on overflow.
+
+This method retuns the same value as
+<code>add.[unwrapped_add\_prod][`unwrapped_add_prod`](self, mul)</code>.
+The [`unwrapped_add_prod`] method is more flexible because `unwrapped_mul_add`
+requires that `self` and `add` must have the same [number of fractional bits].
+
+# Panics
+
+Panics if the result does not fit.
+
+[`unwrapped_add_prod`]: Self::unwrapped_add_prod
+[number of fractional bits]: Self::FRAC_BITS
+"#;
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    add.unwrapped_add_prod(self, mul)
+                }
+            }
+
+            comment! {
+                "Unwrapped multiply and subtract.
+Returns `self` × `mul` &minus; `sub`, panicking on overflow.
+
+For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its own,
+but the final result is representable; in these cases this method returns the
+correct result without overflow.
+
+The product is computed exactly before `sub` is subtracted, and any fractional
+bits that do not fit are discarded, which rounds towards &minus;∞.
 
 # Panics
 
@@ -4828,33 +9916,77 @@ Panics if the result does not fit.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(3).unwrapped_add(Fix::from_num(2)), Fix::from_num(5));
+assert_eq!(
+    Fix::from_num(3).unwrapped_mul_sub(Fix::from_num(0.5), Fix::ONE),
+    0.5
+);
+assert_eq!(Fix::MAX.unwrapped_mul_sub(Fix::from_num(2), Fix::MAX), Fix::MAX);
 ```
 
 The following panics because of overflow.
 
-```rust,should_panic
+```should_panic
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let _overflow = Fix::MAX.unwrapped_add(Fix::DELTA);
+let _overflow = Fix::MAX.unwrapped_mul_sub(Fix::from_num(3), Fix::MAX);
 ```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_add(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    match self.checked_add(rhs) {
-                        Some(s) => s,
-                        None => panic!("overflow"),
-                    }
+                pub const fn unwrapped_mul_sub<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    sub: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_sub(
+                        self.to_bits(),
+                        mul.to_bits(),
+                        sub.to_bits(),
+                        MUL_FRAC,
+                    );
+                    assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
                 }
             }
 
             comment! {
-                "Unwrapped subtraction. Returns the difference, panicking on overflow.
+                r#"Unwrapped negated multiply and add.
+Returns `add` &minus; `self` × `mul`, panicking on overflow.
+
+This method returns the same value as
+<code>add.[unwrapped\_sub\_prod][`unwrapped_sub_prod`](self, mul)</code>.
+The [`unwrapped_sub_prod`] method is more flexible because `unwrapped_neg_mul_add`
+requires that `self` and `add` must have the same [number of fractional bits].
+
+# Panics
+
+Panics if the result does not fit.
+
+[`unwrapped_sub_prod`]: Self::unwrapped_sub_prod
+[number of fractional bits]: Self::FRAC_BITS
+"#;
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_neg_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> $Self<FRAC> {
+                    add.unwrapped_sub_prod(self, mul)
+                }
+            }
+
+            comment! {
+                "Unwrapped multiplication to a chosen number of fractional
+bits. Returns the product with `OUT_FRAC` fractional bits, panicking on
+overflow.
+
+See [`mul_to`][Self::mul_to] for how the product is rescaled.
 
 # Panics
 
@@ -4868,15 +10000,12 @@ Panics if the result does not fit.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "assert_eq!(Fix::from_num(3).unwrapped_sub(Fix::from_num(5)), Fix::from_num(-2));
-",
-                    "assert_eq!(Fix::from_num(5).unwrapped_sub(Fix::from_num(3)), Fix::from_num(2));
-",
-                ),
-                "```
+// 0.75 × 1.25 = 0.9375, which is 0.1111 in binary
+let a = Fix::from_num(0.75);
+let b = ", stringify!($Self), "::<2>::from_num(1.25);
+let full: ", stringify!($Self), "<6> = a.unwrapped_mul_to(b);
+assert_eq!(full, 0.9375);
+```
 
 The following panics because of overflow.
 
@@ -4886,22 +10015,38 @@ The following panics because of overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let _overflow = Fix::MIN.unwrapped_sub(Fix::DELTA);
+let _overflow: Fix = Fix::MAX.unwrapped_mul_to(Fix::from_num(2));
 ```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_sub(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    match self.checked_sub(rhs) {
-                        Some(s) => s,
-                        None => panic!("overflow"),
+                pub const fn unwrapped_mul_to<const OUT_FRAC: i32, const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> $Self<OUT_FRAC> {
+                    match self.overflowing_mul_to(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
                     }
                 }
             }
 
             comment! {
-                "Unwrapped multiplication. Returns the product, panicking on overflow.
+                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, panicking on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
+
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different [number of fractional bits].
 
 # Panics
 
@@ -4915,37 +10060,76 @@ Panics if the result does not fit.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(3).unwrapped_mul(Fix::from_num(2)), Fix::from_num(6));
-```
+assert_eq!(
+    Fix::from_num(3).unwrapped_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    5
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+assert_eq!(
+    (-Fix::MAX).unwrapped_add_prod(Fix::MAX, Fix::from_num(1.5)),
+    Fix::MAX / 2
+);
+"
+                },
+                "```
 
 The following panics because of overflow.
 
-```rust,should_panic
+```should_panic
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let _overflow = Fix::MAX.unwrapped_mul(Fix::from_num(4));
+let _overflow = Fix::DELTA.unwrapped_add_prod(Fix::MAX, Fix::ONE);
 ```
+
+[number of fractional bits]: Self::FRAC_BITS
 ";
                 #[inline]
+                #[must_use]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_mul(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    match self.checked_mul(rhs) {
-                        Some(ans) => ans,
-                        None => panic!("overflow"),
-                    }
+                pub const fn unwrapped_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) -> $Self<FRAC> {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                    );
+                    assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
                 }
             }
 
             comment! {
-                "Unwrapped remainder. Returns the remainder, panicking if the divisor is zero.
+                "Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`,
+panicking on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, one of the products or the intermediate sum
+`self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b` would overflow on its own, but the final
+result is representable; in these cases this method returns the correct result
+without overflow.
+
+",
+                },
+                "Each product is rounded down on its own, so when there is no
+overflow the result is the same as that of [`unwrapped_add_prod`] called twice.
+The parameters `a`, `b`, `c` and `d` can have a fixed-point type like `self` but
+with a different [number of fractional bits].
 
 # Panics
 
-Panics if the divisor is zero.
+Panics if the result does not fit.
 
 # Examples
 
@@ -4955,78 +10139,84 @@ Panics if the divisor is zero.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(1.5).unwrapped_rem(Fix::ONE), Fix::from_num(0.5));
-```
+assert_eq!(
+    Fix::from_num(3).unwrapped_add_prod2(Fix::from_num(4), Fix::from_num(0.5), Fix::from_num(2), Fix::from_num(0.25)),
+    5.5
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 3 - MAX = MAX, although -MAX + MAX × 3 overflows
+assert_eq!(
+    (-Fix::MAX).unwrapped_add_prod2(Fix::MAX, Fix::from_num(3), -Fix::MAX, Fix::ONE),
+    Fix::MAX
+);
+"
+                },
+                "```
 
-The following panics because the divisor is zero.
+The following panics because of overflow.
 
-```rust,should_panic
+```should_panic
 #![feature(generic_const_exprs)]
 # #![allow(incomplete_features)]
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let _divisor_is_zero = Fix::from_num(1.5).unwrapped_rem(Fix::ZERO);
+let _overflow = Fix::DELTA.unwrapped_add_prod2(Fix::MAX, Fix::ONE, Fix::ZERO, Fix::ONE);
 ```
-";
-                #[inline]
-                #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_rem(self, rhs: $Self<FRAC>) -> $Self<FRAC> {
-                    let rhs_bits = rhs.to_bits();
-                    if_signed! {
-                        $Signedness;
-                        if rhs_bits == -1 {
-                            return Self::ZERO;
-                        }
-                    }
-                    Self::from_bits(self.to_bits() % rhs_bits)
-                }
-            }
-
-            comment! {
-                r#"Unwrapped multiply and add.
-Returns `self` × `mul` + `add`, panicking on overflow.
-
-This method retuns the same value as
-<code>add.[unwrapped_add\_prod][`unwrapped_add_prod`](self, mul)</code>.
-The [`unwrapped_add_prod`] method is more flexible because `unwrapped_mul_add`
-requires that `self` and `add` must have the same [number of fractional bits].
-
-# Panics
-
-Panics if the result does not fit.
-
 [`unwrapped_add_prod`]: Self::unwrapped_add_prod
 [number of fractional bits]: Self::FRAC_BITS
-"#;
+";
                 #[inline]
+                #[must_use]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_mul_add<const MUL_FRAC: i32>(
+                pub const fn unwrapped_add_prod2<
+                    const A_FRAC: i32,
+                    const B_FRAC: i32,
+                    const C_FRAC: i32,
+                    const D_FRAC: i32,
+                >(
                     self,
-                    mul: $Self<MUL_FRAC>,
-                    add: $Self<FRAC>,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                    c: $Self<C_FRAC>,
+                    d: $Self<D_FRAC>,
                 ) -> $Self<FRAC> {
-                    add.unwrapped_add_prod(self, mul)
+                    let (ans, ord) = arith::$Inner::overflowing_mul_add2(
+                        a.to_bits(),
+                        b.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                        c.to_bits(),
+                        d.to_bits(),
+                        arith::saturating_add_sub_i32(C_FRAC, D_FRAC, FRAC),
+                        self.to_bits(),
+                    );
+                    assert!(matches!(ord, Ordering::Equal), "overflow");
+                    Self::from_bits(ans)
                 }
             }
 
             comment! {
-                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, panicking on overflow.
+                "Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`, panicking on
+overflow.
 
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "For some cases, the product `a`&nbsp;×&nbsp;`b` would
-overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+overflow on its own, but the final result `self`&nbsp;&minus;&nbsp;`a`&nbsp;×&nbsp;`b`
 is representable; in these cases this method returns the correct result without
 overflow.
 
 ",
                 },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different [number of fractional bits].
+                "The product is computed exactly before being subtracted, and any fractional
+bits that do not fit in `self` are discarded, which rounds the result towards
+&minus;∞.
+
+The `a` and `b` parameters can have a fixed-point type like `self` but with a
+different [number of fractional bits].
 
 # Panics
 
@@ -5041,16 +10231,16 @@ Panics if the result does not fit.
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
 assert_eq!(
-    Fix::from_num(3).unwrapped_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
-    5
+    Fix::from_num(5).unwrapped_sub_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    3
 );
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+                    "// MIN - MIN × 1.5 = -MIN / 2, which does not overflow
 assert_eq!(
-    (-Fix::MAX).unwrapped_add_prod(Fix::MAX, Fix::from_num(1.5)),
-    Fix::MAX / 2
+    Fix::MIN.unwrapped_sub_prod(Fix::MIN, Fix::from_num(1.5)),
+    -(Fix::MIN / 2)
 );
 "
                 },
@@ -5064,7 +10254,7 @@ The following panics because of overflow.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-let _overflow = Fix::DELTA.unwrapped_add_prod(Fix::MAX, Fix::ONE);
+let _overflow = Fix::MIN.unwrapped_sub_prod(Fix::ONE, Fix::DELTA);
 ```
 
 [number of fractional bits]: Self::FRAC_BITS
@@ -5072,12 +10262,12 @@ let _overflow = Fix::DELTA.unwrapped_add_prod(Fix::MAX, Fix::ONE);
                 #[inline]
                 #[must_use]
                 #[track_caller]
-                pub const fn unwrapped_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                pub const fn unwrapped_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
                     self,
                     a: $Self<A_FRAC>,
                     b: $Self<B_FRAC>,
                 ) -> $Self<FRAC> {
-                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                    let (ans, overflow) = arith::$Inner::overflowing_neg_mul_add(
                         a.to_bits(),
                         b.to_bits(),
                         self.to_bits(),
@@ -5265,6 +10455,53 @@ let _overflow = Fix::MIN.unwrapped_div_int(-1);
                 }
             }
 
+            comment! {
+                "Unwrapped multiplication by a power of ten. Returns
+`self`&nbsp;×&nbsp;10<sup>`exp`</sup>, panicking on overflow.
+
+If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+result is rounded to the nearest, with ties rounded to even. Overflow can only
+occur when `exp` is positive.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(0.0625).unwrapped_scale_pow10(2),
+    Fix::from_num(6.25)
+);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_scale_pow10(1);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_scale_pow10(self, exp: i32) -> $Self<FRAC> {
+                    let (ans, overflow) = self.overflowing_scale_pow10(exp);
+                    assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
 
             comment! {
                 "Unwrapped remainder for Euclidean division. Returns the
@@ -5490,6 +10727,66 @@ let _overflow = Fix::MIN.unwrapped_dist(Fix::ZERO);
                 }
             }
 
+            comment! {
+                "Unwrapped positive difference. Returns the positive difference
+between `self` and `other`",
+                if_signed_else_empty_str! { $Signedness; ", panicking on overflow" },
+                ".
+
+The positive difference is `self`&nbsp;&minus;&nbsp;`other` if `self` is
+greater than `other`, and zero otherwise.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "# Panics
+
+Panics if the result does not fit.",
+                    "Can never overflow for unsigned types.",
+                ),
+                "
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(5).unwrapped_positive_diff(Fix::ONE),
+    Fix::from_num(4)
+);
+assert_eq!(Fix::ONE.unwrapped_positive_diff(Fix::from_num(5)), Fix::ZERO);
+```
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+let _overflow = Fix::MAX.unwrapped_positive_diff(Fix::NEG_ONE);
+```
+"
+                };
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_positive_diff(self, other: $Self<FRAC>) -> $Self<FRAC> {
+                    match self.checked_positive_diff(other) {
+                        Some(s) => s,
+                        None => panic!("overflow"),
+                    }
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -5739,6 +11036,98 @@ let _overflow = Fix::MAX.unwrapped_inv_lerp::<4>(Fix::ZERO, Fix::from_num(0.5));
                 }
             }
 
+            comment! {
+                "Inverse linear interpolation between `start` and `end`, where `start` and
+`end` can have a different number of fractional bits from `self`, panicking on
+overflow.
+
+The computed value can have a fixed-point type like `self` but with a different
+[number of fractional bits].
+
+Returns
+(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+
+Before the computation, the operands are brought to the smaller of the two
+numbers of fractional bits: if `start` and `end` have more fractional bits than
+`self`, they are rounded, otherwise `self` is rounded. This rounding is towards
+&minus;∞, and it cannot overflow.
+
+This method is implemented for
+0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+
+# Panics
+
+Panics when `start`&nbsp;=&nbsp;`end` after rounding or when the results
+overflows.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Range = ", stringify!($Self), "<5>;
+let one = Range::from_num(1);
+let three = Range::from_num(3);
+assert_eq!(Fix::from_num(2).unwrapped_inv_lerp_mixed::<5, 4>(one, three), 0.5);
+```
+
+The following panics because `start`&nbsp;=&nbsp;`end` after rounding.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Range = ", stringify!($Self), "<5>;
+let one = Range::from_num(1);
+let fine = Range::from_num(1.03125);
+let _zero_range = Fix::from_num(2).unwrapped_inv_lerp_mixed::<5, 4>(one, fine);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Range = ", stringify!($Self), "<5>;
+let half = Range::from_num(0.5);
+let _overflow = Fix::MAX.unwrapped_inv_lerp_mixed::<5, 4>(Range::ZERO, half);
+```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_inv_lerp_mixed<const RANGE_FRAC: i32, const RET_FRAC: i32>(
+                    self,
+                    start: $Self<RANGE_FRAC>,
+                    end: $Self<RANGE_FRAC>,
+                ) -> $Self<RET_FRAC>
+                where
+                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
+                {
+                    let (self_bits, start, end) = inv_lerp::align::$Inner(
+                        self.to_bits(),
+                        FRAC,
+                        start.to_bits(),
+                        end.to_bits(),
+                        RANGE_FRAC,
+                    );
+                    let (bits, overflow) = inv_lerp::$Inner(self_bits, start, end, RET_FRAC as u32);
+                    assert!(!overflow, "overflow");
+                    $Self::from_bits(bits)
+                }
+            }
+
             if_unsigned! {
                 $Signedness;
                 comment! {
@@ -5951,6 +11340,45 @@ let _overflow = Fix::MAX.unwrapped_next_power_of_two();
                         None => panic!("overflow"),
                     }
                 }
+
+                /// Unwrapped multiplication by a signed integer. Returns the
+                /// signed product, panicking on overflow.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the result does not fit.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(Fix::from_num(1.5).unwrapped_mul_signed_int(-3), IFix::from_num(-4.5));
+                /// ```
+                ///
+                /// The following panics because of overflow.
+                ///
+                /// ```rust,should_panic
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::", stringify!($Self), ";")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                /// let _overflow = Fix::MAX.unwrapped_mul_signed_int(-1);
+                /// ```
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_mul_signed_int(self, rhs: $IInner) -> $ISelf<FRAC> {
+                    match self.checked_mul_signed_int(rhs) {
+                        Some(s) => s,
+                        None => panic!("overflow"),
+                    }
+                }
             }
 
             comment! {
@@ -5987,7 +11415,17 @@ assert_eq!(Fix::from_num(5).overflowing_neg(), (Fix::from_bits(neg_five_bits), t
                 ),
                 "
 ```
-";
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "
+The wrapped value is the two’s complement of the bits, which is rarely useful
+for unsigned numbers. To negate into the signed type with the same number of
+bits, use [`checked_neg_to_signed`].
+
+[`checked_neg_to_signed`]: Self::checked_neg_to_signed
+",
+                };
                 #[inline]
                 #[must_use]
                 pub const fn overflowing_neg(self) -> ($Self<FRAC>, bool) {
@@ -6073,49 +11511,396 @@ overflow has occurred. On overflow, the wrapped value is returned.
 
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
-assert_eq!(Fix::from_num(3).overflowing_mul(Fix::from_num(2)), (Fix::from_num(6), false));
-let wrapped = Fix::from_bits(!0 << 2);
-assert_eq!(Fix::MAX.overflowing_mul(Fix::from_num(4)), (wrapped, true));
-```
-";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn overflowing_mul(self, rhs: $Self<FRAC>) -> ($Self<FRAC>, bool) {
-                    let (ans, overflow) =
-                        arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), FRAC);
-                    (Self::from_bits(ans), overflow)
-                }
-            }
-
-            comment! {
-                r#"Overflowing multiply and add.
-
-Returns a [tuple] of `self` × `mul` + `add` and a [`bool`] indicating
-whether an overflow has occurred. On overflow, the wrapped value is
-returned.
-
-This method retuns the same value as
-<code>add.[overflowing\_add\_prod][`overflowing_add_prod`](self, mul)</code>.
-The [`overflowing_add_prod`] method is more flexible because
-`overflowing_mul_add` requires that `self` and `add` must have the same [number
-of fractional bits].
+assert_eq!(Fix::from_num(3).overflowing_mul(Fix::from_num(2)), (Fix::from_num(6), false));
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::MAX.overflowing_mul(Fix::from_num(4)), (wrapped, true));
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_mul(self, rhs: $Self<FRAC>) -> ($Self<FRAC>, bool) {
+                    let (ans, overflow) =
+                        arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), FRAC);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Overflowing square.
+
+Returns a [tuple] of `self` × `self` and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(Fix::from_num(1.5).overflowing_square(), (Fix::from_num(2.25), false));
+assert_eq!(Fix::MAX.overflowing_square(), (Fix::MAX.wrapping_mul(Fix::MAX), true));
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_square(self) -> ($Self<FRAC>, bool) {
+                    let (ans, overflow) =
+                        arith::$Inner::overflowing_mul(self.to_bits(), self.to_bits(), FRAC);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                r#"Overflowing multiply and add.
+
+Returns a [tuple] of `self` × `mul` + `add` and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+This method retuns the same value as
+<code>add.[overflowing\_add\_prod][`overflowing_add_prod`](self, mul)</code>.
+The [`overflowing_add_prod`] method is more flexible because
+`overflowing_mul_add` requires that `self` and `add` must have the same [number
+of fractional bits].
+
+[`overflowing_add_prod`]: Self::overflowing_add_prod
+[number of fractional bits]: Self::FRAC_BITS
+"#;
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> ($Self<FRAC>, bool) {
+                    add.overflowing_add_prod(self, mul)
+                }
+            }
+
+            comment! {
+                "Overflowing multiply and subtract.
+
+Returns a [tuple] of `self` × `mul` &minus; `sub` and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is returned.
+
+For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its own,
+but the final result is representable; in these cases this method returns the
+correct result without overflow.
+
+The product is computed exactly before `sub` is subtracted, and any fractional
+bits that do not fit are discarded, which rounds towards &minus;∞.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).overflowing_mul_sub(Fix::from_num(0.5), Fix::ONE),
+    (Fix::from_num(0.5), false)
+);
+assert_eq!(
+    Fix::MAX.overflowing_mul_sub(Fix::from_num(3), Fix::MAX),
+    (Fix::MAX.wrapping_mul_int(2), true)
+);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_mul_sub<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    sub: $Self<FRAC>,
+                ) -> ($Self<FRAC>, bool) {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_sub(
+                        self.to_bits(),
+                        mul.to_bits(),
+                        sub.to_bits(),
+                        MUL_FRAC,
+                    );
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                r#"Overflowing negated multiply and add.
+
+Returns a [tuple] of `add` &minus; `self` × `mul` and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+This method returns the same value as
+<code>add.[overflowing\_sub\_prod][`overflowing_sub_prod`](self, mul)</code>.
+The [`overflowing_sub_prod`] method is more flexible because `overflowing_neg_mul_add`
+requires that `self` and `add` must have the same [number of fractional bits].
+
+[`overflowing_sub_prod`]: Self::overflowing_sub_prod
+[number of fractional bits]: Self::FRAC_BITS
+"#;
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_neg_mul_add<const MUL_FRAC: i32>(
+                    self,
+                    mul: $Self<MUL_FRAC>,
+                    add: $Self<FRAC>,
+                ) -> ($Self<FRAC>, bool) {
+                    add.overflowing_sub_prod(self, mul)
+                }
+            }
+
+            comment! {
+                "Overflowing multiplication to a chosen number of
+fractional bits.
+
+Returns a [tuple] of the product with `OUT_FRAC` fractional bits and a
+[`bool`] indicating whether an overflow has occurred. On overflow, the
+wrapped value is returned.
+
+See [`mul_to`][Self::mul_to] for how the product is rescaled.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 0.75 × 1.25 = 0.9375, which is 0.1111 in binary
+let a = Fix::from_num(0.75);
+let b = ", stringify!($Self), "::<2>::from_num(1.25);
+assert_eq!(
+    a.overflowing_mul_to::<6, 2>(b),
+    (", stringify!($Self), "::<6>::from_num(0.9375), false)
+);
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(
+    Fix::MAX.overflowing_mul_to::<4, 4>(Fix::from_num(4)),
+    (wrapped, true)
+);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_mul_to<const OUT_FRAC: i32, const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> ($Self<OUT_FRAC>, bool) {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        arith::saturating_add_sub_i32(FRAC, RHS_FRAC, OUT_FRAC),
+                    );
+                    ($Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Overflowing addition of fixed-point numbers with possibly
+different numbers of fractional bits.
+
+Returns a [tuple] of the sum with the larger number of fractional bits and a
+[`bool`] indicating whether an overflow has occurred. On overflow, the wrapped
+value is returned.
+
+See [`add_frac`][Self::add_frac] for how the operands are converted.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+let coarse = ", stringify!($Self), "::<2>::from_num(1.25);
+let fine = ", stringify!($Self), "::<4>::from_num(0.5625);
+assert_eq!(
+    coarse.overflowing_add_frac(fine),
+    (", stringify!($Self), "::<4>::from_num(1.8125), false)
+);
+assert!(", stringify!($Self), "::<2>::MAX.overflowing_add_frac(fine).1);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_add_frac<const RHS_FRAC: i32>(
+                    self,
+                    rhs: $Self<RHS_FRAC>,
+                ) -> ($Self<{ max_frac(FRAC, RHS_FRAC) }>, bool) {
+                    // The difference of two i32 values can exceed i32::MAX, so the
+                    // shift is kept as u32.
+                    const fn shl(bits: $Inner, shift: u32) -> ($Inner, bool) {
+                        if shift >= $Inner::BITS {
+                            (0, bits != 0)
+                        } else {
+                            let shifted = bits << shift;
+                            (shifted, (shifted >> shift) != bits)
+                        }
+                    }
+                    let (lhs, rhs, overflow1) = if FRAC >= RHS_FRAC {
+                        let (rhs, overflow) = shl(rhs.to_bits(), FRAC.abs_diff(RHS_FRAC));
+                        (self.to_bits(), rhs, overflow)
+                    } else {
+                        let (lhs, overflow) = shl(self.to_bits(), FRAC.abs_diff(RHS_FRAC));
+                        (lhs, rhs.to_bits(), overflow)
+                    };
+                    let (sum, overflow2) = lhs.overflowing_add(rhs);
+                    ($Self::from_bits(sum), overflow1 || overflow2)
+                }
+            }
+
+            comment! {
+                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`.
+
+Returns a [tuple] of the result and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
+
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).overflowing_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    (Fix::from_num(5), false)
+);
+assert_eq!(
+    Fix::MAX.overflowing_add_prod(Fix::MAX, Fix::from_num(3)),
+    (Fix::MAX.wrapping_mul_int(4), true)
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+assert_eq!(
+    (-Fix::MAX).overflowing_add_prod(Fix::MAX, Fix::from_num(1.5)),
+    (Fix::MAX / 2, false)
+);
+"
+                },
+                "```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                    self,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                ) -> ($Self<FRAC>, bool) {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                    );
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`.
+
+Returns a [tuple] of the result and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, one of the products or the intermediate sum
+`self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b` would overflow on its own, but the final
+result is representable; in these cases this method returns the correct result
+without overflow.
+
+",
+                },
+                "Each product is rounded down on its own, so when there is no
+overflow the result is the same as that of [`overflowing_add_prod`] called twice.
+The parameters `a`, `b`, `c` and `d` can have a fixed-point type like `self` but
+with a different [number of fractional bits].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(3).overflowing_add_prod2(Fix::from_num(4), Fix::from_num(0.5), Fix::from_num(2), Fix::from_num(0.25)),
+    (Fix::from_num(5.5), false)
+);
+assert_eq!(
+    Fix::MAX.overflowing_add_prod2(Fix::MAX, Fix::from_num(3), Fix::ZERO, Fix::ZERO),
+    (Fix::MAX.wrapping_mul_int(4), true)
+);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 3 - MAX = MAX, although -MAX + MAX × 3 overflows
+assert_eq!(
+    (-Fix::MAX).overflowing_add_prod2(Fix::MAX, Fix::from_num(3), -Fix::MAX, Fix::ONE),
+    (Fix::MAX, false)
+);
+"
+                },
+                "```
 
 [`overflowing_add_prod`]: Self::overflowing_add_prod
 [number of fractional bits]: Self::FRAC_BITS
-"#;
+";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn overflowing_mul_add<const MUL_FRAC: i32>(
+                #[must_use]
+                pub const fn overflowing_add_prod2<
+                    const A_FRAC: i32,
+                    const B_FRAC: i32,
+                    const C_FRAC: i32,
+                    const D_FRAC: i32,
+                >(
                     self,
-                    mul: $Self<MUL_FRAC>,
-                    add: $Self<FRAC>,
+                    a: $Self<A_FRAC>,
+                    b: $Self<B_FRAC>,
+                    c: $Self<C_FRAC>,
+                    d: $Self<D_FRAC>,
                 ) -> ($Self<FRAC>, bool) {
-                    add.overflowing_add_prod(self, mul)
+                    let (ans, ord) = arith::$Inner::overflowing_mul_add2(
+                        a.to_bits(),
+                        b.to_bits(),
+                        arith::saturating_add_sub_i32(A_FRAC, B_FRAC, FRAC),
+                        c.to_bits(),
+                        d.to_bits(),
+                        arith::saturating_add_sub_i32(C_FRAC, D_FRAC, FRAC),
+                        self.to_bits(),
+                    );
+                    (Self::from_bits(ans), !matches!(ord, Ordering::Equal))
                 }
             }
 
             comment! {
-                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`.
+                "Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`.
 
 Returns a [tuple] of the result and a [`bool`] indicating whether an overflow
 has occurred. On overflow, the wrapped value is returned.
@@ -6124,14 +11909,18 @@ has occurred. On overflow, the wrapped value is returned.
                 if_signed_else_empty_str! {
                     $Signedness;
                     "For some cases, the product `a`&nbsp;×&nbsp;`b` would
-overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+overflow on its own, but the final result `self`&nbsp;&minus;&nbsp;`a`&nbsp;×&nbsp;`b`
 is representable; in these cases this method returns the correct result without
 overflow.
 
 ",
                 },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different [number of fractional bits].
+                "The product is computed exactly before being subtracted, and any fractional
+bits that do not fit in `self` are discarded, which rounds the result towards
+&minus;∞.
+
+The `a` and `b` parameters can have a fixed-point type like `self` but with a
+different [number of fractional bits].
 
 # Examples
 
@@ -6142,20 +11931,20 @@ overflow.
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<4>;
 assert_eq!(
-    Fix::from_num(3).overflowing_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
-    (Fix::from_num(5), false)
+    Fix::from_num(5).overflowing_sub_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    (Fix::from_num(3), false)
 );
 assert_eq!(
-    Fix::MAX.overflowing_add_prod(Fix::MAX, Fix::from_num(3)),
-    (Fix::MAX.wrapping_mul_int(4), true)
+    Fix::MIN.overflowing_sub_prod(Fix::ONE, Fix::DELTA),
+    (Fix::MAX, true)
 );
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+                    "// MIN - MIN × 1.5 = -MIN / 2, which does not overflow
 assert_eq!(
-    (-Fix::MAX).overflowing_add_prod(Fix::MAX, Fix::from_num(1.5)),
-    (Fix::MAX / 2, false)
+    Fix::MIN.overflowing_sub_prod(Fix::MIN, Fix::from_num(1.5)),
+    (-(Fix::MIN / 2), false)
 );
 "
                 },
@@ -6165,12 +11954,12 @@ assert_eq!(
 ";
                 #[inline]
                 #[must_use]
-                pub const fn overflowing_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                pub const fn overflowing_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
                     self,
                     a: $Self<A_FRAC>,
                     b: $Self<B_FRAC>,
                 ) -> ($Self<FRAC>, bool) {
-                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                    let (ans, overflow) = arith::$Inner::overflowing_neg_mul_add(
                         a.to_bits(),
                         b.to_bits(),
                         self.to_bits(),
@@ -6244,6 +12033,10 @@ assert_eq!(acc, Fix::MAX / 2);
 Returns a [tuple] of the product and a [`bool`] indicating whether an
 overflow has occurred. On overflow, the wrapped value is returned.
 
+Multiplication by an integer is exact, so the product is never rounded; if it
+does not fit, it is wrapped, and the wrapped value has the same bits as the
+low bits of the exact product.
+
 # Examples
 
 ```rust
@@ -6310,6 +12103,89 @@ assert_eq!(Fix::from_num(3).overflowing_div_int(2), (one_point_5, false));
                 }
             }
 
+            comment! {
+                "Overflowing multiplication by a power of ten.
+
+Returns a [tuple] of `self`&nbsp;×&nbsp;10<sup>`exp`</sup> and a [`bool`]
+indicating whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+result is rounded to the nearest, with ties rounded to even. Overflow can only
+occur when `exp` is positive.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(0.0625).overflowing_scale_pow10(2),
+    (Fix::from_num(6.25), false)
+);
+assert_eq!(
+    Fix::MAX.overflowing_scale_pow10(1),
+    (Fix::MAX.wrapping_mul_int(10), true)
+);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_scale_pow10(self, exp: i32) -> ($Self<FRAC>, bool) {
+                    let bits = self.to_bits();
+                    if exp >= 0 {
+                        // Multiplying by 10 BITS times multiplies by 2^BITS,
+                        // so any further wrapped products are zero.
+                        let steps = if exp.unsigned_abs() < $Inner::BITS {
+                            exp.unsigned_abs()
+                        } else {
+                            $Inner::BITS
+                        };
+                        let mut ans = bits;
+                        let mut overflow = false;
+                        let mut i = 0;
+                        while i < steps && ans != 0 {
+                            let (next, o) = ans.overflowing_mul(10);
+                            ans = next;
+                            overflow |= o;
+                            i += 1;
+                        }
+                        return (Self::from_bits(ans), overflow);
+                    }
+
+                    // Divide one digit at a time; truncating divisions compose
+                    // exactly, and the last digit together with a sticky flag
+                    // for the earlier digits is enough to round correctly.
+                    let mut abs = if_signed_unsigned!($Signedness, bits.unsigned_abs(), bits);
+                    let mut last = 0;
+                    let mut sticky = false;
+                    let mut i = 0;
+                    while i < exp.unsigned_abs() {
+                        if abs == 0 {
+                            // the quotient is zero and the remainder is < 0.1
+                            return (Self::ZERO, false);
+                        }
+                        sticky |= last != 0;
+                        last = abs % 10;
+                        abs /= 10;
+                        i += 1;
+                    }
+                    if last > 5 || (last == 5 && (sticky || abs % 2 != 0)) {
+                        // abs ≤ MAX / 10, so this cannot overflow
+                        abs += 1;
+                    }
+                    let ans = if_signed_unsigned!(
+                        $Signedness,
+                        if bits < 0 { -(abs as $Inner) } else { abs as $Inner },
+                        abs,
+                    );
+                    (Self::from_bits(ans), false)
+                }
+            }
+
             comment! {
                 "Overflowing shift left.
 
@@ -6455,6 +12331,63 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Overflowing positive difference.
+
+Returns a [tuple] of the positive difference between `self` and `other` and ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "a [`bool`] indicating whether an overflow has
+occurred. On overflow, the wrapped value is returned.",
+                    "[`false`], as overflow can never happen for unsigned types.",
+                ),
+                "
+
+The positive difference is `self`&nbsp;&minus;&nbsp;`other` if `self` is
+greater than `other`, and zero otherwise.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+assert_eq!(
+    Fix::from_num(5).overflowing_positive_diff(Fix::ONE),
+    (Fix::from_num(4), false)
+);
+assert_eq!(
+    Fix::ONE.overflowing_positive_diff(Fix::from_num(5)),
+    (Fix::ZERO, false)
+);
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(
+    Fix::MAX.overflowing_positive_diff(-Fix::DELTA),
+    (Fix::MIN, true)
+);",
+                    "assert_eq!(
+    Fix::MAX.overflowing_positive_diff(Fix::ZERO),
+    (Fix::MAX, false)
+);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_positive_diff(self, other: $Self<FRAC>) -> ($Self<FRAC>, bool) {
+                    if self.to_bits() > other.to_bits() {
+                        self.overflowing_sub(other)
+                    } else {
+                        (Self::ZERO, false)
+                    }
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -6743,6 +12676,79 @@ assert_eq!(
                 }
             }
 
+            comment! {
+                "Overflowing inverse linear interpolation between `start` and `end`, where
+`start` and `end` can have a different number of fractional bits from `self`.
+
+Returns a [tuple] of the result and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The computed value can have a fixed-point type like `self` but with a different
+[number of fractional bits].
+
+Computes
+(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+
+Before the computation, the operands are brought to the smaller of the two
+numbers of fractional bits: if `start` and `end` have more fractional bits than
+`self`, they are rounded, otherwise `self` is rounded. This rounding is towards
+&minus;∞, and it cannot overflow.
+
+This method is implemented for
+0&nbsp;≤&nbsp;`RET_FRAC`&nbsp;≤&nbsp;", $n, ".
+
+# Panics
+
+Panics when `start`&nbsp;=&nbsp;`end` after rounding.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+type Range = ", stringify!($Self), "<5>;
+let one = Range::from_num(1);
+let three = Range::from_num(3);
+let half = Range::from_num(0.5);
+assert_eq!(
+    Fix::from_num(2).overflowing_inv_lerp_mixed::<5, 4>(one, three),
+    (Fix::from_num(0.5), false)
+);
+assert_eq!(
+    Fix::MAX.overflowing_inv_lerp_mixed::<5, 4>(Range::ZERO, half),
+    (Fix::MAX.wrapping_mul_int(2), true)
+);
+```
+
+[number of fractional bits]: Self::FRAC_BITS
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_inv_lerp_mixed<const RANGE_FRAC: i32, const RET_FRAC: i32>(
+                    self,
+                    start: $Self<RANGE_FRAC>,
+                    end: $Self<RANGE_FRAC>,
+                ) -> ($Self<RET_FRAC>, bool)
+                where
+                    If<{ (0 <= RET_FRAC) & (RET_FRAC <= $n) }>: True,
+                {
+                    let (self_bits, start, end) = inv_lerp::align::$Inner(
+                        self.to_bits(),
+                        FRAC,
+                        start.to_bits(),
+                        end.to_bits(),
+                        RANGE_FRAC,
+                    );
+                    let (bits, overflow) = inv_lerp::$Inner(self_bits, start, end, RET_FRAC as u32);
+                    ($Self::from_bits(bits), overflow)
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 /// Overflowing addition with an unsigned fixed-point number.
@@ -6886,6 +12892,44 @@ assert_eq!(
                     // if both overflow1 and overflow2, then they cancel each other out
                     ($Self::from_bits(bits), overflow1 != overflow2)
                 }
+
+                /// Overflowing multiplication by a signed integer.
+                ///
+                /// Returns a [tuple] of the signed product and a [`bool`]
+                /// indicating whether an overflow has occurred. On overflow,
+                /// the wrapped value is returned.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// #![feature(generic_const_exprs)]
+                /// # #![allow(incomplete_features)]
+                ///
+                #[doc = concat!("use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};")]
+                #[doc = concat!("type Fix = ", stringify!($Self), "<4>;")]
+                #[doc = concat!("type IFix = ", stringify!($ISelf), "<4>;")]
+                /// assert_eq!(
+                ///     Fix::from_num(1.5).overflowing_mul_signed_int(-3),
+                ///     (IFix::from_num(-4.5), false)
+                /// );
+                /// assert_eq!(Fix::MAX.overflowing_mul_signed_int(-1), (IFix::DELTA, true));
+                /// ```
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_mul_signed_int(
+                    self,
+                    rhs: $IInner,
+                ) -> ($ISelf<FRAC>, bool) {
+                    let (abs, overflow1) = self.to_bits().overflowing_mul(rhs.unsigned_abs());
+                    let bits = abs as $IInner;
+                    if rhs < 0 {
+                        // abs can be one more than $IInner::MAX, which wraps to $IInner::MIN
+                        let overflow2 = abs > $IInner::MIN.unsigned_abs();
+                        ($ISelf::from_bits(bits.wrapping_neg()), overflow1 || overflow2)
+                    } else {
+                        ($ISelf::from_bits(bits), overflow1 || bits < 0)
+                    }
+                }
             }
 
             /// Unchecked addition. Computes `self`&nbsp;+&nbsp;`rhs`, assuming
@@ -6986,5 +13030,53 @@ assert_eq!(
                 };
             }
         }
+
+        $(
+            /// The items in this block are implemented for all values of `FRAC`.
+            impl<const FRAC: i32> $Double<FRAC> {
+                comment! {
+                    "Converts to a fixed-point number with a narrower inner type
+and the same number of fractional bits, returning [`None`] on overflow.
+
+The underlying integer is narrowed from ", $n2, " to ", $n, " bits, which
+succeeds only if the value fits in [`", stringify!($Self), "`]; in that case the
+conversion is lossless. This can be used in constant context. The reverse
+conversion is [`", stringify!($Self), "::const_widen_inner`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{", stringify!($Self), ", ", stringify!($Double), "};
+type Fix = ", stringify!($Self), "<4>;
+type Wide = ", stringify!($Double), "<4>;
+const NARROW: Option<Fix> = Wide::from_bits(0x35).const_narrow_inner();
+assert_eq!(NARROW, Some(Fix::from_bits(0x35)));
+assert_eq!(Fix::MAX.const_widen_inner().const_narrow_inner(), Some(Fix::MAX));
+assert_eq!((Wide::from_num(Fix::MAX) + Wide::DELTA).const_narrow_inner(), None);
+",
+                    if_signed_else_empty_str! {
+                        $Signedness;
+                        "assert_eq!((Wide::from_num(Fix::MIN) - Wide::DELTA).const_narrow_inner(), None);
+",
+                    },
+                    "```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn const_narrow_inner(self) -> Option<$Self<FRAC>> {
+                        let bits = self.to_bits();
+                        let narrow = bits as $Inner;
+                        if narrow as $DoubleInner == bits {
+                            Some($Self::from_bits(narrow))
+                        } else {
+                            None
+                        }
+                    }
+                }
+            }
+        )?
     };
 }
@@ -132,6 +132,11 @@ round towards &minus;∞ unlike this method which rounds towards zero.",
             ),
             "
 
+The magnitude of the result is never larger than the magnitude of `self`, so
+this method cannot overflow and there are no checked, saturating or wrapping
+versions of it, unlike [`ceil`][Self::ceil], [`floor`][Self::floor],
+[`round`][Self::round] and [`round_ties_even`][Self::round_ties_even].
+
 # Examples
 
 ```rust
@@ -265,6 +270,11 @@ assert_eq!(Fix::from_num(2.5).floor(), Fix::from_num(2));
             "Rounds to the nearest integer, with ties rounded away
 from zero.
 
+This is the round-half-away-from-zero mode; the [`checked_round`],
+[`saturating_round`], [`wrapping_round`], [`unwrapped_round`] and
+[`overflowing_round`] variants use the same mode. To round ties to even instead,
+use [`round_ties_even`].
+
 # Panics
 
 When debug assertions are enabled, panics if the result does not fit.
@@ -289,6 +299,11 @@ assert_eq!(Fix::from_num(2.5).round(), Fix::from_num(3));
             },
             "```
 
+[`checked_round`]: Self::checked_round
+[`overflowing_round`]: Self::overflowing_round
+[`round_ties_even`]: Self::round_ties_even
+[`saturating_round`]: Self::saturating_round
+[`unwrapped_round`]: Self::unwrapped_round
 [`wrapping_round`]: Self::wrapping_round
 ";
             #[inline]
@@ -336,6 +351,89 @@ assert_eq!(Fix::from_num(3.5).round_ties_even(), Fix::from_num(4));
             }
         }
 
+        comment! {
+            "Rounds to the nearest value representable with `C` fractional
+bits, with ties rounded away from zero, keeping the current type.
+
+The fractional bits beyond the first `C` are cleared after rounding, so the
+result is exactly the value that a fixed-point number with `C` fractional bits
+would hold if it rounded to nearest. If `C`&nbsp;≥&nbsp;`FRAC`, `self` is
+returned unchanged.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped result can be
+returned, but it is not considered a breaking change if in the future
+it panics.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<4>;
+// 2.6875 is 10.1011 in binary
+let x = Fix::from_bits(0b10_1011);
+// nearest multiple of 0.25 is 10.11
+assert_eq!(x.snap_to::<2>(), Fix::from_num(2.75));
+// nearest multiple of 0.5 is 10.1
+assert_eq!(x.snap_to::<1>(), Fix::from_num(2.5));
+// nearest multiple of 2 is 10
+assert_eq!(x.snap_to::<-1>(), Fix::from_num(2));
+// tie is rounded away from zero
+assert_eq!(Fix::from_num(1.25).snap_to::<1>(), Fix::from_num(1.5));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(-1.25).snap_to::<1>(), Fix::from_num(-1.5));
+",
+            },
+            "```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn snap_to<const C: i32>(self) -> $Self<FRAC> {
+                let shift = FRAC.saturating_sub(C);
+                if shift <= 0 {
+                    return self;
+                }
+                let bits = self.to_bits();
+                let (snapped, overflow) = if shift >= $n {
+                    // The grid step is at least as large as the whole range,
+                    // so the only possible grid point in range is zero, and
+                    // values at least half a step away round out of range.
+                    let half = shift == $n;
+                    if_signed_unsigned!(
+                        $Signedness,
+                        (0, half && bits == Self::MIN.to_bits()),
+                        (0, half && bits >= Self::DELTA.to_bits() << ($n - 1)),
+                    )
+                } else {
+                    let half = Self::DELTA.to_bits() << (shift - 1);
+                    if_signed_unsigned!(
+                        $Signedness,
+                        if bits < 0 {
+                            // ties round towards -∞, which is away from zero
+                            (((bits + (half - 1)) >> shift) << shift, false)
+                        } else {
+                            let (sum, overflow) = bits.overflowing_add(half);
+                            ((sum >> shift) << shift, overflow)
+                        },
+                        {
+                            let (sum, overflow) = bits.overflowing_add(half);
+                            ((sum >> shift) << shift, overflow)
+                        },
+                    )
+                };
+                debug_assert!(!overflow, "overflow");
+                Self::from_bits(snapped)
+            }
+        }
+
         comment! {
             "Checked ceil. Rounds to the next integer towards +∞,
 returning [`None`] on overflow.
@@ -0,0 +1,572 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::traits::Fixed;
+use crate::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+use core::ops::{Index, IndexMut, Mul};
+
+macro_rules! matrix {
+    ($Mat:ident, $n:literal, $Name:literal) => {
+        #[doc = concat!("A ", $Name, " matrix of fixed-point numbers.")]
+        ///
+        /// The matrix is stored as an array of rows, and elements can be
+        /// accessed by indexing with a (row, column) pair.
+        ///
+        /// Multiplication by another matrix or by a column vector, stored as an
+        /// array, accumulates each element using [`add_prod`], so a product
+        /// that would overflow on its own does not cause an overflow if the
+        /// accumulated sum is representable. Like the arithmetic operators, it
+        /// panics on overflow when debug assertions are enabled.
+        ///
+        /// This requires the `geometry` [optional feature].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// #![feature(generic_const_exprs)]
+        /// # #![allow(incomplete_features)]
+        ///
+        #[doc = concat!("use fixed::{types::I16F16, ", stringify!($Mat), "};")]
+        #[doc = concat!("let mut m = ", stringify!($Mat), "::<I16F16>::TRY_IDENTITY.unwrap();")]
+        /// m[(0, 1)] = I16F16::from_num(2);
+        /// assert_eq!(m[(0, 1)], 2);
+        /// assert_eq!(m.transpose()[(1, 0)], 2);
+        /// ```
+        ///
+        /// [`add_prod`]: crate::FixedI32::add_prod
+        /// [optional feature]: crate#optional-features
+        #[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+        pub struct $Mat<F> {
+            rows: [[F; $n]; $n],
+        }
+
+        impl<F: Fixed> $Mat<F> {
+            /// The zero matrix.
+            pub const ZERO: $Mat<F> = $Mat {
+                rows: [[F::ZERO; $n]; $n],
+            };
+
+            /// The identity matrix if the fixed-point number type can
+            /// represent the value 1, otherwise [`None`].
+            pub const TRY_IDENTITY: Option<$Mat<F>> = match F::TRY_ONE {
+                Some(one) => {
+                    let mut rows = [[F::ZERO; $n]; $n];
+                    let mut i = 0;
+                    while i < $n {
+                        rows[i][i] = one;
+                        i += 1;
+                    }
+                    Some($Mat { rows })
+                }
+                None => None,
+            };
+
+            /// Creates a matrix from an array of rows.
+            #[inline]
+            #[must_use]
+            pub const fn from_rows(rows: [[F; $n]; $n]) -> $Mat<F> {
+                $Mat { rows }
+            }
+
+            /// Returns the array of rows.
+            #[inline]
+            #[must_use]
+            pub const fn to_rows(self) -> [[F; $n]; $n] {
+                self.rows
+            }
+
+            /// Returns the transpose of the matrix.
+            #[inline]
+            #[must_use]
+            pub fn transpose(self) -> $Mat<F> {
+                let mut rows = self.rows;
+                for (i, row) in rows.iter_mut().enumerate() {
+                    for (j, elem) in row.iter_mut().enumerate() {
+                        *elem = self.rows[j][i];
+                    }
+                }
+                $Mat { rows }
+            }
+        }
+
+        impl<F> Index<(usize, usize)> for $Mat<F> {
+            type Output = F;
+            #[inline]
+            fn index(&self, (row, col): (usize, usize)) -> &F {
+                &self.rows[row][col]
+            }
+        }
+
+        impl<F> IndexMut<(usize, usize)> for $Mat<F> {
+            #[inline]
+            fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut F {
+                &mut self.rows[row][col]
+            }
+        }
+    };
+}
+
+matrix! { Mat2, 2, "2×2" }
+matrix! { Mat3, 3, "3×3" }
+
+macro_rules! matrix_mul {
+    ($Mat:ident, $n:literal, $Fixed:ident) => {
+        impl<const FRAC: i32> Mul for $Mat<$Fixed<FRAC>> {
+            type Output = $Mat<$Fixed<FRAC>>;
+            #[inline]
+            #[track_caller]
+            fn mul(self, rhs: $Mat<$Fixed<FRAC>>) -> $Mat<$Fixed<FRAC>> {
+                let mut rows = [[$Fixed::ZERO; $n]; $n];
+                for (i, row) in rows.iter_mut().enumerate() {
+                    for (j, elem) in row.iter_mut().enumerate() {
+                        for k in 0..$n {
+                            *elem = elem.add_prod(self.rows[i][k], rhs.rows[k][j]);
+                        }
+                    }
+                }
+                $Mat { rows }
+            }
+        }
+
+        impl<const FRAC: i32> Mul<[$Fixed<FRAC>; $n]> for $Mat<$Fixed<FRAC>> {
+            type Output = [$Fixed<FRAC>; $n];
+            #[inline]
+            #[track_caller]
+            fn mul(self, rhs: [$Fixed<FRAC>; $n]) -> [$Fixed<FRAC>; $n] {
+                let mut ans = [$Fixed::ZERO; $n];
+                for (row, elem) in self.rows.iter().zip(ans.iter_mut()) {
+                    for (&a, &b) in row.iter().zip(rhs.iter()) {
+                        *elem = elem.add_prod(a, b);
+                    }
+                }
+                ans
+            }
+        }
+    };
+}
+
+// A signed integer in two's complement, stored as little-endian 64-bit limbs.
+// It is wide enough to hold the exact determinant of a 3×3 matrix of 128-bit
+// integers, which is less than 6 × 2^381 in magnitude, so the determinant can be
+// computed without intermediate overflow and narrowed only once at the end.
+#[derive(Clone, Copy, Eq, PartialEq)]
+struct Wide([u64; WIDE_LIMBS]);
+
+const WIDE_LIMBS: usize = 7;
+const WIDE_BITS: u64 = 64 * WIDE_LIMBS as u64;
+
+impl Wide {
+    #[inline]
+    fn from_i128(val: i128) -> Wide {
+        let ext = if val < 0 { u64::MAX } else { 0 };
+        let mut limbs = [ext; WIDE_LIMBS];
+        limbs[0] = val as u64;
+        limbs[1] = (val >> 64) as u64;
+        Wide(limbs)
+    }
+
+    #[inline]
+    fn sign_limb(self) -> u64 {
+        if (self.0[WIDE_LIMBS - 1] as i64) < 0 {
+            u64::MAX
+        } else {
+            0
+        }
+    }
+
+    #[inline]
+    fn add(self, rhs: Wide) -> Wide {
+        let mut limbs = [0; WIDE_LIMBS];
+        let mut carry = false;
+        for (l, (&a, &b)) in limbs.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            let (sum, carry1) = a.overflowing_add(b);
+            let (sum, carry2) = sum.overflowing_add(u64::from(carry));
+            *l = sum;
+            carry = carry1 || carry2;
+        }
+        Wide(limbs)
+    }
+
+    #[inline]
+    fn sub(self, rhs: Wide) -> Wide {
+        let mut limbs = [0; WIDE_LIMBS];
+        let mut borrow = false;
+        for (l, (&a, &b)) in limbs.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            let (diff, borrow1) = a.overflowing_sub(b);
+            let (diff, borrow2) = diff.overflowing_sub(u64::from(borrow));
+            *l = diff;
+            borrow = borrow1 || borrow2;
+        }
+        Wide(limbs)
+    }
+
+    // Wrapping multiplication, which is exact as long as the product fits.
+    #[inline]
+    fn mul(self, rhs: Wide) -> Wide {
+        let mut limbs = [0; WIDE_LIMBS];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in rhs.0[..WIDE_LIMBS - i].iter().enumerate() {
+                // a × b + limb + carry <= 2^128 - 1
+                let t = u128::from(a) * u128::from(b) + u128::from(limbs[i + j]) + carry;
+                limbs[i + j] = t as u64;
+                carry = t >> 64;
+            }
+        }
+        Wide(limbs)
+    }
+
+    // Shifts right, rounding down.
+    fn shr(self, sh: u64) -> Wide {
+        let ext = self.sign_limb();
+        let mut limbs = [ext; WIDE_LIMBS];
+        if sh >= WIDE_BITS {
+            return Wide(limbs);
+        }
+        let (limb_sh, bit_sh) = ((sh / 64) as usize, (sh % 64) as u32);
+        let get = |k: usize| self.0.get(k).copied().unwrap_or(ext);
+        for (i, l) in limbs.iter_mut().enumerate() {
+            let (lo, hi) = (get(i + limb_sh), get(i + limb_sh + 1));
+            *l = if bit_sh == 0 {
+                lo
+            } else {
+                (lo >> bit_sh) | (hi << (64 - bit_sh))
+            };
+        }
+        Wide(limbs)
+    }
+
+    // Shifts left; bits shifted out are lost.
+    fn shl(self, sh: u64) -> Wide {
+        let mut limbs = [0; WIDE_LIMBS];
+        if sh >= WIDE_BITS {
+            return Wide(limbs);
+        }
+        let (limb_sh, bit_sh) = ((sh / 64) as usize, (sh % 64) as u32);
+        let get = |k: Option<usize>| k.map_or(0, |k| self.0[k]);
+        for (i, l) in limbs.iter_mut().enumerate() {
+            let (lo, hi) = (get(i.checked_sub(limb_sh + 1)), get(i.checked_sub(limb_sh)));
+            *l = if bit_sh == 0 {
+                hi
+            } else {
+                (hi << bit_sh) | (lo >> (64 - bit_sh))
+            };
+        }
+        Wide(limbs)
+    }
+
+    // Shifts right rounding down, or left if sh is negative, and returns the
+    // result wrapped to 128 bits together with whether it overflows i128.
+    fn overflowing_shr_to_i128(self, sh: i64) -> (i128, bool) {
+        let (shifted, lost) = if sh >= 0 {
+            (self.shr(sh.unsigned_abs()), false)
+        } else {
+            let shifted = self.shl(sh.unsigned_abs());
+            (shifted, shifted.shr(sh.unsigned_abs()) != self)
+        };
+        let wrapped = i128::from(shifted.0[0]) | (i128::from(shifted.0[1]) << 64);
+        let ext = if wrapped < 0 { u64::MAX } else { 0 };
+        let overflow = lost || shifted.0[2..].iter().any(|&l| l != ext);
+        (wrapped, overflow)
+    }
+}
+
+macro_rules! determinant {
+    ($Fixed:ident, $Inner:ident) => {
+        impl<const FRAC: i32> Mat2<$Fixed<FRAC>> {
+            /// Returns the determinant of the matrix.
+            ///
+            /// The determinant is computed exactly and then rounded towards
+            /// &minus;∞, so a product that would overflow on its own does not
+            /// cause an overflow if the determinant is representable.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, this method panics if the
+            /// result overflows. When debug assertions are not enabled, the
+            /// wrapped value can be returned.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// #![feature(generic_const_exprs)]
+            /// # #![allow(incomplete_features)]
+            ///
+            /// use fixed::{types::I16F16, Mat2};
+            /// let m = Mat2::from_rows([
+            ///     [I16F16::from_num(3), I16F16::from_num(1)],
+            ///     [I16F16::from_num(2), I16F16::from_num(1.5)],
+            /// ]);
+            /// assert_eq!(m.determinant(), 2.5);
+            /// ```
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn determinant(self) -> $Fixed<FRAC> {
+                let to_wide = |x: $Fixed<FRAC>| Wide::from_i128(x.to_bits().into());
+                let [[a, b], [c, d]] = self.rows.map(|row| row.map(to_wide));
+                let det = a.mul(d).sub(b.mul(c));
+                // the products have 2 × FRAC fractional bits
+                let (bits, overflow) = det.overflowing_shr_to_i128(i64::from(FRAC));
+                let narrow = bits as $Inner;
+                debug_assert!(!overflow && i128::from(narrow) == bits, "overflow");
+                $Fixed::from_bits(narrow)
+            }
+        }
+
+        impl<const FRAC: i32> Mat3<$Fixed<FRAC>> {
+            /// Returns the determinant of the matrix.
+            ///
+            /// The determinant is computed exactly and then rounded towards
+            /// &minus;∞, so a product or a 2×2 minor that would overflow on its
+            /// own does not cause an overflow if the determinant is
+            /// representable.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, this method panics if the
+            /// result overflows. When debug assertions are not enabled, the
+            /// wrapped value can be returned.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// #![feature(generic_const_exprs)]
+            /// # #![allow(incomplete_features)]
+            ///
+            /// use fixed::{types::I16F16, Mat3};
+            /// let m = Mat3::from_rows([
+            ///     [I16F16::from_num(2), I16F16::from_num(0), I16F16::from_num(1)],
+            ///     [I16F16::from_num(1), I16F16::from_num(3), I16F16::from_num(2)],
+            ///     [I16F16::from_num(1), I16F16::from_num(1), I16F16::from_num(1.5)],
+            /// ]);
+            /// assert_eq!(m.determinant(), 3);
+            /// ```
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn determinant(self) -> $Fixed<FRAC> {
+                let to_wide = |x: $Fixed<FRAC>| Wide::from_i128(x.to_bits().into());
+                let [[a, b, c], [d, e, f], [g, h, i]] = self.rows.map(|row| row.map(to_wide));
+                let minor = |p: Wide, q: Wide, r: Wide, s: Wide| p.mul(s).sub(q.mul(r));
+                let det = a
+                    .mul(minor(e, f, h, i))
+                    .sub(b.mul(minor(d, f, g, i)))
+                    .add(c.mul(minor(d, e, g, h)));
+                // the products have 3 × FRAC fractional bits
+                let (bits, overflow) = det.overflowing_shr_to_i128(2 * i64::from(FRAC));
+                let narrow = bits as $Inner;
+                debug_assert!(!overflow && i128::from(narrow) == bits, "overflow");
+                $Fixed::from_bits(narrow)
+            }
+        }
+    };
+}
+
+macro_rules! matrix_ops {
+    ($Fixed:ident) => {
+        matrix_mul! { Mat2, 2, $Fixed }
+        matrix_mul! { Mat3, 3, $Fixed }
+    };
+    ($Fixed:ident, $Inner:ident, Signed) => {
+        matrix_ops! { $Fixed }
+        determinant! { $Fixed, $Inner }
+    };
+}
+
+matrix_ops! { FixedI8, i8, Signed }
+matrix_ops! { FixedI16, i16, Signed }
+matrix_ops! { FixedI32, i32, Signed }
+matrix_ops! { FixedI64, i64, Signed }
+matrix_ops! { FixedI128, i128, Signed }
+matrix_ops! { FixedU8 }
+matrix_ops! { FixedU16 }
+matrix_ops! { FixedU32 }
+matrix_ops! { FixedU64 }
+matrix_ops! { FixedU128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I16F16, I32F32, I64F64, I8F8, U8F8};
+    use crate::{FixedI8, Mat2, Mat3};
+
+    #[test]
+    fn identity() {
+        let m = Mat2::from_rows([
+            [I16F16::from_num(1.5), I16F16::from_num(-2)],
+            [I16F16::from_num(0.25), I16F16::from_num(3)],
+        ]);
+        let id = Mat2::<I16F16>::TRY_IDENTITY.unwrap();
+        assert_eq!(m * id, m);
+        assert_eq!(id * m, m);
+        let v = [I16F16::from_num(7), I16F16::from_num(-0.5)];
+        assert_eq!(id * v, v);
+        assert_eq!(id.determinant(), 1);
+
+        let m = Mat3::from_rows([
+            [U8F8::from_num(1), U8F8::from_num(2), U8F8::from_num(3)],
+            [U8F8::from_num(4), U8F8::from_num(5), U8F8::from_num(6)],
+            [U8F8::from_num(7), U8F8::from_num(8), U8F8::from_num(9)],
+        ]);
+        let id = Mat3::<U8F8>::TRY_IDENTITY.unwrap();
+        assert_eq!(m * id, m);
+        assert_eq!(id * m, m);
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(m.transpose()[(0, 2)], 7);
+
+        assert!(Mat2::<crate::types::I1F15>::TRY_IDENTITY.is_none());
+        assert_eq!(Mat3::<I16F16>::ZERO.determinant(), 0);
+    }
+
+    #[test]
+    fn rotation() {
+        // rotation by an angle with cos = 0.6, sin = 0.8
+        let (c, s) = (I32F32::from_num(0.6), I32F32::from_num(0.8));
+        let rot = Mat2::from_rows([[c, -s], [s, c]]);
+        let v = rot * [I32F32::from_num(5), I32F32::ZERO];
+        assert!(v[0].dist(I32F32::from_num(3)) <= I32F32::DELTA * 2);
+        assert!(v[1].dist(I32F32::from_num(4)) <= I32F32::DELTA * 2);
+        // c² + s² = 1
+        assert!(rot.determinant().dist(I32F32::ONE) <= I32F32::DELTA * 2);
+
+        // rotating back with the transpose gives the identity
+        let id = rot * rot.transpose();
+        let exact = Mat2::<I32F32>::TRY_IDENTITY.unwrap();
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!(id[(i, j)].dist(exact[(i, j)]) <= I32F32::DELTA);
+            }
+        }
+
+        // rotation about the z axis, then the determinant is still 1
+        let (c, s) = (I16F16::from_num(0.6), I16F16::from_num(0.8));
+        let (zero, one) = (I16F16::ZERO, I16F16::ONE);
+        let rot = Mat3::from_rows([[c, -s, zero], [s, c, zero], [zero, zero, one]]);
+        let v = rot * [zero, I16F16::from_num(10), I16F16::from_num(2)];
+        assert!(v[0].dist(I16F16::from_num(-8)) <= I16F16::DELTA * 4);
+        assert!(v[1].dist(I16F16::from_num(6)) <= I16F16::DELTA * 4);
+        assert_eq!(v[2], 2);
+        assert!(rot.determinant().dist(one) <= I16F16::DELTA * 2);
+    }
+
+    #[test]
+    fn determinant_no_intermediate_overflow() {
+        let h = I8F8::from_num(100);
+        assert_eq!(Mat2::from_rows([[h; 2]; 2]).determinant(), 0);
+        assert_eq!(Mat3::from_rows([[h; 3]; 3]).determinant(), 0);
+        let h99 = I8F8::from_num(99);
+        let m = Mat2::from_rows([[h, h99], [h, h]]);
+        assert_eq!(m.determinant(), 100);
+        let (zero, one) = (I8F8::ZERO, I8F8::ONE);
+        let m = Mat3::from_rows([[h, h, zero], [h99, h, zero], [zero, zero, one]]);
+        assert_eq!(m.determinant(), 100);
+        let m = Mat3::from_rows([[one, zero, zero], [zero, h, h99], [zero, h, h]]);
+        assert_eq!(m.determinant(), 100);
+
+        let m = Mat2::from_rows([[I64F64::MIN, I64F64::MAX], [I64F64::MIN, I64F64::MAX]]);
+        assert_eq!(m.determinant(), 0);
+        assert_eq!(Mat3::from_rows([[I64F64::MIN; 3]; 3]).determinant(), 0);
+    }
+
+    #[test]
+    fn determinant_min() {
+        let (zero, one, min) = (I8F8::ZERO, I8F8::ONE, I8F8::MIN);
+        assert_eq!(Mat2::from_rows([[min; 2]; 2]).determinant(), 0);
+        assert_eq!(Mat3::from_rows([[min; 3]; 3]).determinant(), 0);
+        assert_eq!(Mat2::from_rows([[one, min], [zero, one]]).determinant(), 1);
+        let half = I8F8::from_num(0.5);
+        assert_eq!(
+            Mat2::from_rows([[zero, min], [half, zero]]).determinant(),
+            64
+        );
+        assert_eq!(
+            Mat2::from_rows([[zero, min], [-half, zero]]).determinant(),
+            -64
+        );
+        let m = Mat3::from_rows([[zero, min, zero], [half, zero, zero], [zero, zero, one]]);
+        assert_eq!(m.determinant(), 64);
+        let m = Mat2::from_rows([[I64F64::MIN, I64F64::MIN], [I64F64::MIN, I64F64::MIN]]);
+        assert_eq!(m.determinant(), 0);
+    }
+
+    #[test]
+    fn determinant_exact() {
+        type F = FixedI8<3>;
+        let vals = (i8::MIN..=i8::MAX).step_by(17).chain([i8::MAX]);
+        for a in vals.clone() {
+            for b in vals.clone() {
+                for c in vals.clone() {
+                    for d in vals.clone() {
+                        let exact =
+                            (i32::from(a) * i32::from(d) - i32::from(b) * i32::from(c)) >> 3;
+                        let Ok(exact) = i8::try_from(exact) else {
+                            continue;
+                        };
+                        let m = Mat2::from_rows([[a, b], [c, d]].map(|row| row.map(F::from_bits)));
+                        assert_eq!(m.determinant(), F::from_bits(exact));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn determinant_overflow() {
+        let m = Mat2::from_rows([[I8F8::MIN, I8F8::ZERO], [I8F8::ZERO, -I8F8::ONE]]);
+        let _ = m.determinant();
+    }
+
+    #[test]
+    fn determinant_rounding() {
+        let (zero, delta) = (I8F8::ZERO, I8F8::DELTA);
+        assert_eq!(
+            Mat2::from_rows([[delta, zero], [zero, delta]]).determinant(),
+            0
+        );
+        let m = Mat2::from_rows([[-delta, zero], [zero, delta]]);
+        assert_eq!(m.determinant(), -delta);
+        let m = Mat3::from_rows([
+            [delta, zero, zero],
+            [zero, delta, zero],
+            [zero, zero, -delta],
+        ]);
+        assert_eq!(m.determinant(), -delta);
+
+        // negative number of fractional bits, and more fractional bits than bits
+        type Fn2 = FixedI8<-2>;
+        let m = Mat2::from_rows([
+            [Fn2::from_bits(3), Fn2::ZERO],
+            [Fn2::ZERO, Fn2::from_bits(3)],
+        ]);
+        assert_eq!(m.determinant(), 144);
+        type F10 = FixedI8<10>;
+        let m = Mat2::from_rows([
+            [F10::from_bits(64), F10::ZERO],
+            [F10::ZERO, F10::from_bits(64)],
+        ]);
+        assert_eq!(m.determinant(), F10::from_bits(4));
+        let m = Mat3::from_rows([
+            [F10::from_bits(64), F10::ZERO, F10::ZERO],
+            [F10::ZERO, F10::from_bits(64), F10::ZERO],
+            [F10::ZERO, F10::ZERO, F10::from_bits(-64)],
+        ]);
+        assert_eq!(m.determinant(), F10::from_bits(-1));
+    }
+}
@@ -0,0 +1,293 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::int256::{self, U256};
+use core::num::NonZeroU128;
+
+const fn mul_mod(a: u128, b: u128, d: NonZeroU128) -> u128 {
+    int256::div_rem_u256_u128(int256::wide_mul_u128(a, b), d).1
+}
+
+// Returns 2^exp mod d.
+const fn pow2_mod(mut exp: u64, d: NonZeroU128) -> u128 {
+    let mut base = 2 % d.get();
+    let mut acc = 1 % d.get();
+    while exp > 0 {
+        if exp & 1 != 0 {
+            acc = mul_mod(acc, base, d);
+        }
+        base = mul_mod(base, base, d);
+        exp >>= 1;
+    }
+    acc
+}
+
+const fn shr(a: U256, sh: u64) -> U256 {
+    if sh >= 256 {
+        U256 { lo: 0, hi: 0 }
+    } else if sh >= 128 {
+        U256 {
+            lo: a.hi >> (sh - 128),
+            hi: 0,
+        }
+    } else {
+        int256::shl_u256_max_128(a, sh as u32)
+    }
+}
+
+// Returns whether val < 2^nbits, where 0 < nbits ≤ 128.
+const fn fits(val: U256, nbits: u32) -> bool {
+    val.hi == 0 && (nbits == 128 || val.lo >> nbits == 0)
+}
+
+// Returns ⌊prod × 2^shift / d⌋ modulo 2^128, and whether the exact quotient is
+// less than 2^nbits, where 0 < nbits ≤ 128.
+const fn quot(prod: U256, shift: i64, d: NonZeroU128, nbits: u32) -> (u128, bool) {
+    if prod.hi == 0 {
+        // fast path when the product fits in a u128 and does not lose bits
+        if shift <= 0 && shift > -128 {
+            let q = (prod.lo >> -shift) / d.get();
+            return (q, nbits == 128 || q >> nbits == 0);
+        }
+        if shift > 0 && shift < 128 && shift <= prod.lo.leading_zeros() as i64 {
+            let q = (prod.lo << shift) / d.get();
+            return (q, nbits == 128 || q >> nbits == 0);
+        }
+    }
+
+    if shift <= 0 {
+        // ⌊⌊prod / 2^-shift⌋ / d⌋ == ⌊prod / (2^-shift × d)⌋
+        let (q, _) = int256::div_rem_u256_u128(shr(prod, shift.unsigned_abs()), d);
+        return (q.lo, fits(q, nbits));
+    }
+
+    // The quotient is q0 × 2^shift + ⌊r0 × 2^shift / d⌋, where the second
+    // term is less than 2^shift since r0 < d.
+    let shift = shift as u64;
+    let (q0, r0) = int256::div_rem_u256_u128(prod, d);
+    let hi_part = if shift >= 128 { 0 } else { q0.lo << shift };
+    // Only the low 128 bits of the second term are required, so for large
+    // shifts the remainder is advanced by all but the last 128 bits.
+    let (r, sh) = if shift > 128 {
+        (mul_mod(r0, pow2_mod(shift - 128, d), d), 128)
+    } else {
+        (r0, shift as u32)
+    };
+    let scaled = if sh == 128 {
+        U256 { lo: 0, hi: r }
+    } else {
+        U256 {
+            lo: r << sh,
+            hi: if sh == 0 { 0 } else { r >> (128 - sh) },
+        }
+    };
+    let (lo_part, _) = int256::div_rem_u256_u128(scaled, d);
+    let wrapped = hi_part.wrapping_add(lo_part.lo);
+
+    let fits = if shift < nbits as u64 {
+        fits(q0, nbits - shift as u32)
+    } else if q0.hi != 0 || q0.lo != 0 {
+        false
+    } else if r0 == 0 {
+        true
+    } else {
+        // ⌊r0 × 2^shift / d⌋ < 2^nbits ⟺ r0 × 2^(shift - nbits) < d
+        let k = shift - nbits as u64;
+        k < 128 && r0.leading_zeros() as u64 >= k && (r0 << k) < d.get()
+    };
+    (wrapped, fits)
+}
+
+macro_rules! impl_mul_div {
+    ($Inner:ident, |$val:ident| $neg_abs:expr) => {
+        // Returns a × m × 2^shift / d rounded towards zero, and a bool
+        // indicating overflow, or None if d is zero.
+        pub const fn $Inner(a: $Inner, m: $Inner, d: $Inner, shift: i64) -> Option<($Inner, bool)> {
+            let (a_neg, a_abs) = {
+                let $val = a;
+                $neg_abs
+            };
+            let (m_neg, m_abs) = {
+                let $val = m;
+                $neg_abs
+            };
+            let (d_neg, d_abs) = {
+                let $val = d;
+                $neg_abs
+            };
+            let Some(d_abs) = NonZeroU128::new(d_abs as u128) else {
+                return None;
+            };
+            let prod = int256::wide_mul_u128(a_abs as u128, m_abs as u128);
+            let (abs, fits) = quot(prod, shift, d_abs, $Inner::BITS);
+            let neg = (a_neg != m_neg) != d_neg && abs != 0;
+            let max = if !neg {
+                $Inner::MAX as u128
+            } else if $Inner::MIN == 0 {
+                0
+            } else {
+                $Inner::MAX as u128 + 1
+            };
+            let wrapped = if neg { abs.wrapping_neg() } else { abs };
+            Some((wrapped as $Inner, !fits || abs > max))
+        }
+    };
+}
+
+impl_mul_div! { i8, |val| (val < 0, val.unsigned_abs()) }
+impl_mul_div! { i16, |val| (val < 0, val.unsigned_abs()) }
+impl_mul_div! { i32, |val| (val < 0, val.unsigned_abs()) }
+impl_mul_div! { i64, |val| (val < 0, val.unsigned_abs()) }
+impl_mul_div! { i128, |val| (val < 0, val.unsigned_abs()) }
+impl_mul_div! { u8, |val| (false, val) }
+impl_mul_div! { u16, |val| (false, val) }
+impl_mul_div! { u32, |val| (false, val) }
+impl_mul_div! { u64, |val| (false, val) }
+impl_mul_div! { u128, |val| (false, val) }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I16F16, I4F4, I64F64, U0F128, U128F0, U4F4};
+
+    // Checks all 8-bit operands against exact arithmetic in i128 for a range of
+    // shifts, including the wrapped result on overflow.
+    #[test]
+    fn exhaustive_8() {
+        for shift in -12i32..=12 {
+            for a in i8::MIN..=i8::MAX {
+                for m in [i8::MIN, -77, -3, -1, 0, 1, 5, 100, i8::MAX] {
+                    for d in [i8::MIN, -9, -2, -1, 1, 3, 16, 101, i8::MAX] {
+                        let num = i128::from(a) * i128::from(m);
+                        let exact = if shift >= 0 {
+                            (num << shift) / i128::from(d)
+                        } else {
+                            num / (i128::from(d) << -shift)
+                        };
+                        let expected = (exact as i8, i8::try_from(exact).is_err());
+                        assert_eq!(super::i8(a, m, d, i64::from(shift)), Some(expected));
+
+                        let (a, m, d) = (a as u8, m as u8, d as u8);
+                        let num = i128::from(a) * i128::from(m);
+                        let exact = if shift >= 0 {
+                            (num << shift) / i128::from(d)
+                        } else {
+                            num / (i128::from(d) << -shift)
+                        };
+                        let expected = (exact as u8, u8::try_from(exact).is_err());
+                        assert_eq!(super::u8(a, m, d, i64::from(shift)), Some(expected));
+
+                        // scaled operands exercise the 256-bit paths
+                        let shift = i64::from(shift);
+                        let (a, m, d) = (u128::from(a), u128::from(m), u128::from(d));
+                        let expected = Some((exact as u128, false));
+                        assert_eq!(super::u128(a << 60, m << 60, d, shift - 120), expected);
+                        assert_eq!(super::u128(a, m, d << 120, shift + 120), expected);
+                        assert_eq!(super::u128(a << 120, m, d << 120, shift), expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn intermediate_overflow() {
+        // a × m overflows, but a × m / d does not
+        let big = I16F16::from_num(20000);
+        assert!(big.checked_mul(big).is_none());
+        assert_eq!(
+            big.mul_div(big, I16F16::from_num(25000)),
+            I16F16::from_num(16000)
+        );
+        assert_eq!(
+            (-big).checked_mul_div(big, I16F16::from_num(25000)),
+            Some(I16F16::from_num(-16000))
+        );
+        assert_eq!(I4F4::MAX.mul_div(I4F4::MAX, I4F4::MAX), I4F4::MAX);
+        assert_eq!(I4F4::MIN.mul_div(I4F4::MIN, I4F4::MIN), I4F4::MIN);
+        assert_eq!(U4F4::MAX.mul_div(U4F4::MAX, U4F4::MAX), U4F4::MAX);
+
+        // the product needs 256 bits
+        assert_eq!(I64F64::MAX.mul_div(I64F64::MAX, I64F64::MAX), I64F64::MAX);
+        assert_eq!(I64F64::MIN.mul_div(I64F64::MAX, I64F64::MAX), I64F64::MIN);
+        assert_eq!(U128F0::MAX.mul_div(U128F0::MAX, U128F0::MAX), U128F0::MAX);
+        assert_eq!(
+            U128F0::MAX.checked_mul_div(U128F0::MAX, U128F0::MAX - U128F0::from_num(2)),
+            None
+        );
+        // (2^128 - 1)^2 / (2^128 - 3) == 2^128 + 1
+        assert_eq!(
+            super::u128(u128::MAX, u128::MAX, u128::MAX - 2, 0),
+            Some((1, true))
+        );
+        // different numbers of fractional bits
+        let x = I64F64::from_num(1u64 << 62);
+        let y = crate::FixedI128::<100>::from_num(0.75);
+        let z = crate::FixedI128::<10>::from_num(0.5);
+        assert_eq!(x.mul_div(y, z), I64F64::from_num(3u64 << 61));
+    }
+
+    #[test]
+    fn saturating_wrapping() {
+        let max = I16F16::MAX;
+        let two = I16F16::from_num(2);
+        let four = I16F16::from_num(4);
+        assert_eq!(max.saturating_mul_div(four, two), I16F16::MAX);
+        assert_eq!(max.saturating_mul_div(-four, two), I16F16::MIN);
+        assert_eq!(max.saturating_mul_div(four, -two), I16F16::MIN);
+        assert_eq!(max.saturating_mul_div(-four, -two), I16F16::MAX);
+        assert_eq!(
+            max.wrapping_mul_div(four, two),
+            I16F16::from_bits(max.to_bits() << 1)
+        );
+        assert_eq!(
+            U0F128::MAX.saturating_mul_div(U0F128::MAX, U0F128::DELTA),
+            U0F128::MAX
+        );
+    }
+
+    #[test]
+    fn large_shifts() {
+        // ⌊3 × 2^1000 / 5⌋ mod 2^128
+        let wrapped = 0x9999_9999_9999_9999_9999_9999_9999_9999;
+        assert_eq!(super::u128(3, 1, 5, 1000), Some((wrapped, true)));
+        // -⌊3 × 2^200 / 7⌋ mod 2^128
+        let wrapped = 0x9249_2492_4924_9249_2492_4924_9249_2493_u128 as i128;
+        assert_eq!(super::i128(-3, 1, 7, 200), Some((wrapped, true)));
+        assert_eq!(super::i32(0, 1, 7, i64::from(i32::MAX)), Some((0, false)));
+        assert_eq!(super::i32(i32::MIN, i32::MIN, 1, -62), Some((1, false)));
+        assert_eq!(super::i32(i32::MIN, i32::MIN, -1, -63), Some((0, false)));
+        assert_eq!(super::u64(u64::MAX, u64::MAX, 1, -1000), Some((0, false)));
+        assert_eq!(
+            super::u64(1, 1, 3, 129),
+            Some((0xAAAA_AAAA_AAAA_AAAA, true))
+        );
+        assert_eq!(
+            super::u64(1, 1, 3, 63),
+            Some((0x2AAA_AAAA_AAAA_AAAA, false))
+        );
+    }
+
+    #[test]
+    fn zero_divisor() {
+        assert_eq!(super::i16(1, 1, 0, 0), None);
+        assert_eq!(I16F16::ONE.checked_mul_div(I16F16::ONE, I16F16::ZERO), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn zero_divisor_panics() {
+        let _ = I16F16::ONE.mul_div(I16F16::ONE, I16F16::ZERO);
+    }
+}
@@ -0,0 +1,298 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::int256::{self, U256};
+use core::num::NonZeroU128;
+
+// Beyond this, the exponent of an intermediate value is clamped, as the
+// result is then zero or overflows for all widths and numbers of fractional
+// bits. The clamped exponent still doubles without overflowing an i32.
+const EXP_LIMIT: i32 = 1024;
+
+// A positive number mant × 2^exp, where 2^127 ≤ mant < 2^128.
+#[derive(Clone, Copy)]
+struct Float {
+    mant: u128,
+    exp: i32,
+}
+
+const ONE: Float = Float {
+    mant: 1 << 127,
+    exp: -127,
+};
+
+const fn clamp(mant: u128, exp: i32) -> Float {
+    let exp = if exp > EXP_LIMIT {
+        EXP_LIMIT
+    } else if exp < -EXP_LIMIT {
+        -EXP_LIMIT
+    } else {
+        exp
+    };
+    Float { mant, exp }
+}
+
+// Multiplies two numbers, rounding the product to 128 significant bits.
+const fn mul(a: Float, b: Float) -> Float {
+    let prod = int256::wide_mul_u128(a.mant, b.mant);
+    let exp = a.exp + b.exp;
+    let (mant, round, exp) = if prod.hi >> 127 != 0 {
+        (prod.hi, prod.lo >> 127, exp + 128)
+    } else {
+        (
+            (prod.hi << 1) | (prod.lo >> 127),
+            (prod.lo >> 126) & 1,
+            exp + 127,
+        )
+    };
+    match mant.checked_add(round) {
+        Some(mant) => clamp(mant, exp),
+        None => clamp(1 << 127, exp + 1),
+    }
+}
+
+// Returns the reciprocal, rounded to 128 significant bits.
+const fn recip(a: Float) -> Float {
+    if a.mant == 1 << 127 {
+        return clamp(1 << 127, -a.exp - 254);
+    }
+    // 2^127 < mant < 2^128, so 2^127 < 2^255 / mant < 2^128
+    // SAFETY: mant ≥ 2^127, so it is not zero.
+    let mant = unsafe { NonZeroU128::new_unchecked(a.mant) };
+    let num = U256 {
+        lo: 0,
+        hi: 1 << 127,
+    };
+    let (quot, rem) = int256::div_rem_u256_u128(num, mant);
+    let round = rem >= a.mant - rem;
+    match quot.lo.checked_add(round as u128) {
+        Some(q) => clamp(q, -a.exp - 255),
+        None => clamp(1 << 127, -a.exp - 254),
+    }
+}
+
+// Returns abs^exp, where abs ≠ 0 has frac_nbits fractional bits.
+const fn pow(abs: u128, frac_nbits: u32, exp: i64) -> Float {
+    let lz = abs.leading_zeros();
+    let mut base = Float {
+        mant: abs << lz,
+        exp: -(lz as i32) - frac_nbits as i32,
+    };
+    let mut acc = ONE;
+    let mut rem = exp.unsigned_abs();
+    while rem != 0 {
+        if rem & 1 != 0 {
+            acc = mul(acc, base);
+        }
+        rem >>= 1;
+        if rem != 0 {
+            base = mul(base, base);
+        }
+    }
+    if exp < 0 {
+        recip(acc)
+    } else {
+        acc
+    }
+}
+
+// Rounds the number to frac_nbits fractional bits. Returns the wrapped bits,
+// and whether the result fits in a u128.
+const fn round(a: Float, frac_nbits: u32) -> (u128, bool) {
+    let shift = a.exp + frac_nbits as i32;
+    if shift > 0 {
+        let wrapped = if shift < 128 { a.mant << shift } else { 0 };
+        (wrapped, false)
+    } else if shift == 0 {
+        (a.mant, true)
+    } else if shift < -128 {
+        (0, true)
+    } else if shift == -128 {
+        // 2^127 ≤ mant < 2^128, so the result rounds to 1
+        (1, true)
+    } else {
+        let shift = shift.unsigned_abs();
+        let rounded = (a.mant >> shift) + ((a.mant >> (shift - 1)) & 1);
+        (rounded, true)
+    }
+}
+
+macro_rules! impl_powi {
+    ($Inner:ident, |$val:ident| $neg_abs:expr) => {
+        // Returns (val / 2^frac_nbits)^exp, where frac_nbits ≤ 128, and a
+        // bool indicating overflow, or None if val is zero and exp is
+        // negative.
+        pub const fn $Inner($val: $Inner, frac_nbits: u32, exp: i64) -> Option<($Inner, bool)> {
+            let (neg, abs) = $neg_abs;
+            let (abs, fits) = if abs == 0 {
+                if exp < 0 {
+                    return None;
+                } else if exp > 0 {
+                    return Some((0, false));
+                }
+                round(ONE, frac_nbits)
+            } else {
+                round(pow(abs as u128, frac_nbits, exp), frac_nbits)
+            };
+            let neg = neg && exp % 2 != 0;
+            let max = if !neg {
+                $Inner::MAX as u128
+            } else if $Inner::MIN == 0 {
+                0
+            } else {
+                $Inner::MAX as u128 + 1
+            };
+            let wrapped = if neg { abs.wrapping_neg() } else { abs };
+            Some((wrapped as $Inner, !fits || abs > max))
+        }
+    };
+}
+
+impl_powi! { i8, |val| (val < 0, val.unsigned_abs()) }
+impl_powi! { i16, |val| (val < 0, val.unsigned_abs()) }
+impl_powi! { i32, |val| (val < 0, val.unsigned_abs()) }
+impl_powi! { i64, |val| (val < 0, val.unsigned_abs()) }
+impl_powi! { i128, |val| (val < 0, val.unsigned_abs()) }
+impl_powi! { u8, |val| (false, val) }
+impl_powi! { u16, |val| (false, val) }
+impl_powi! { u32, |val| (false, val) }
+impl_powi! { u64, |val| (false, val) }
+impl_powi! { u128, |val| (false, val) }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F8, I16F16, I32F32, I8F24, I8F8, U0F8, U16F16, U1F7};
+
+    // Checks powi against f64 for a grid of inputs and small exponents. The
+    // error must be less than one DELTA plus the error of f64::powi itself.
+    macro_rules! check_grid {
+        ($Fix:ty, $lo:expr, $hi:expr, $step:expr) => {
+            let delta = <$Fix>::DELTA.to_num::<f64>();
+            let max = <$Fix>::MAX.to_num::<f64>();
+            let min = <$Fix>::MIN.to_num::<f64>();
+            let mut x: f64 = $lo;
+            while x <= $hi {
+                let fix = <$Fix>::from_num(x);
+                let f = fix.to_num::<f64>();
+                for exp in -6..=6 {
+                    let expected = f.powi(exp);
+                    let tol = delta + expected.abs() * 16.0 * f64::EPSILON;
+                    match fix.checked_powi(exp) {
+                        Some(val) => {
+                            let err = (val.to_num::<f64>() - expected).abs();
+                            assert!(err < tol, "{fix}.powi({exp}) = {val}, error {err}");
+                        }
+                        None => assert!(
+                            !expected.is_finite() || expected < min + tol || expected > max - tol,
+                            "{fix}.powi({exp}) overflowed"
+                        ),
+                    }
+                }
+                x += $step;
+            }
+        };
+    }
+
+    #[test]
+    fn grid() {
+        check_grid!(I8F8, -128.0, 127.0, 0.0625);
+        check_grid!(I16F16, -40.0, 40.0, 0.0123);
+        check_grid!(U16F16, 0.0, 40.0, 0.0123);
+        check_grid!(I32F32, -300.0, 300.0, 0.0773);
+        check_grid!(I8F24, -2.0, 2.0, 0.000_77);
+    }
+
+    #[test]
+    fn exact() {
+        let fix = I16F16::from_num(1.5);
+        assert_eq!(fix.powi(1), fix);
+        assert_eq!(fix.powi(2), I16F16::from_num(2.25));
+        assert_eq!(fix.powi(3), I16F16::from_num(3.375));
+        assert_eq!((-fix).powi(3), I16F16::from_num(-3.375));
+        assert_eq!((-fix).powi(2), I16F16::from_num(2.25));
+        for i in -16..15 {
+            assert_eq!(
+                I16F16::from_num(2).powi(i),
+                I16F16::from_bits(1 << (16 + i))
+            );
+        }
+        // 1/3 = 0.0101... rounds to nearest, while recip rounds towards zero
+        let three = I16F16::from_num(3);
+        assert_eq!(three.powi(-1), I16F16::from_bits(21845));
+        assert_eq!(three.recip(), I16F16::from_bits(21845));
+        let six = I16F16::from_num(6);
+        assert_eq!(six.powi(-1), I16F16::from_bits(10923));
+        assert_eq!(six.recip(), I16F16::from_bits(10922));
+        assert_eq!(I16F16::from_num(0.25).powi(-1), I16F16::from_num(4));
+        // the positive power 3^10 = 59049 would overflow, but its reciprocal
+        // is computed with enough precision
+        assert_eq!(three.powi(-10), I16F16::from_num(1.0 / 59049.0));
+    }
+
+    #[test]
+    fn large_exponents() {
+        let two = I16F16::from_num(2);
+        assert_eq!(two.checked_powi(14), Some(I16F16::from_num(16384)));
+        assert_eq!(two.checked_powi(15), None);
+        assert_eq!((-two).checked_powi(15), Some(I16F16::MIN));
+        assert_eq!((-two).checked_powi(16), None);
+        assert_eq!(two.saturating_powi(100), I16F16::MAX);
+        assert_eq!((-two).saturating_powi(101), I16F16::MIN);
+        assert_eq!((-two).saturating_powi(100), I16F16::MAX);
+        assert_eq!(two.wrapping_powi(16), I16F16::ZERO);
+        assert_eq!(two.powi(-18), I16F16::ZERO);
+        assert_eq!(two.powi(i32::MIN), I16F16::ZERO);
+        assert_eq!(two.checked_powi(i32::MAX), None);
+
+        assert_eq!(I16F16::ONE.powi(i32::MAX), I16F16::ONE);
+        assert_eq!(I16F16::ONE.powi(i32::MIN), I16F16::ONE);
+        assert_eq!(I16F16::NEG_ONE.powi(i32::MAX), I16F16::NEG_ONE);
+        assert_eq!(I16F16::NEG_ONE.powi(i32::MIN), I16F16::ONE);
+        let half = I16F16::from_num(0.5);
+        assert_eq!(half.powi(i32::MAX), I16F16::ZERO);
+        assert_eq!(half.checked_powi(i32::MIN), None);
+        assert_eq!(half.saturating_powi(i32::MIN), I16F16::MAX);
+
+        // (1 + 2^−16)^65536 is close to e
+        let val = (I16F16::ONE + I16F16::DELTA).powi(1 << 16);
+        assert_eq!(val, I16F16::from_num(2.7182610));
+    }
+
+    #[test]
+    fn zero_exponent() {
+        assert_eq!(I16F16::ZERO.powi(0), I16F16::ONE);
+        assert_eq!(I16F16::from_num(-7.5).powi(0), I16F16::ONE);
+        assert_eq!(U1F7::ZERO.powi(0), U1F7::ONE);
+        assert_eq!(I16F16::ZERO.powi(5), I16F16::ZERO);
+
+        // one cannot be represented
+        assert_eq!(I0F8::ZERO.checked_powi(0), None);
+        assert_eq!(I0F8::MIN.checked_powi(0), None);
+        assert_eq!(U0F8::MAX.checked_powi(0), None);
+        assert_eq!(I0F8::ZERO.saturating_powi(0), I0F8::MAX);
+        assert_eq!(I0F8::MIN.saturating_powi(0), I0F8::MAX);
+        assert_eq!(U0F8::ZERO.wrapping_powi(0), U0F8::ZERO);
+
+        // zero to a negative power
+        assert_eq!(I16F16::ZERO.checked_powi(-1), None);
+        assert_eq!(U16F16::ZERO.checked_powi(i32::MIN), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn zero_negative_exponent() {
+        let _ = I16F16::ZERO.powi(-2);
+    }
+}
@@ -22,6 +22,7 @@ use crate::{
 use az::{OverflowingAs, OverflowingCast, OverflowingCastFrom};
 use bytemuck::TransparentWrapper;
 use core::mem;
+use core::num::Wrapping;
 use half::{bf16 as half_bf16, f16 as half_f16};
 
 impl ToFixed for bool {
@@ -84,6 +85,99 @@ impl ToFixed for bool {
     }
 }
 
+// Implements ToFixed for a shared reference to a type by dereferencing. This is
+// done for each type separately rather than with a blanket implementation, so
+// that downstream crates can still implement ToFixed for references to their
+// own types.
+macro_rules! impl_to_fixed_ref {
+    ($({$($generics:tt)*})? $T:ty) => {
+        impl$(<$($generics)*>)? ToFixed for &$T {
+            /// Converts the referenced number to a fixed-point number.
+            ///
+            /// This dereferences `self` and is otherwise the same as the
+            /// conversion of the referenced value.
+            ///
+            /// # Panics
+            ///
+            /// Panics in the same cases as the conversion of the referenced
+            /// value.
+            #[inline]
+            #[track_caller]
+            fn to_fixed<F: Fixed>(self) -> F {
+                ToFixed::to_fixed(*self)
+            }
+
+            /// Converts the referenced number to a fixed-point number if it fits,
+            /// otherwise returns [`None`].
+            #[inline]
+            fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
+                ToFixed::checked_to_fixed(*self)
+            }
+
+            /// Converts the referenced number to a fixed-point number, saturating if
+            /// it does not fit.
+            #[inline]
+            fn saturating_to_fixed<F: Fixed>(self) -> F {
+                ToFixed::saturating_to_fixed(*self)
+            }
+
+            /// Converts the referenced number to a fixed-point number, wrapping if it
+            /// does not fit.
+            ///
+            /// # Panics
+            ///
+            /// Panics in the same cases as the conversion of the referenced
+            /// value.
+            #[inline]
+            #[track_caller]
+            fn wrapping_to_fixed<F: Fixed>(self) -> F {
+                ToFixed::wrapping_to_fixed(*self)
+            }
+
+            /// Converts the referenced number to a fixed-point number.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a [`bool`]
+            /// indicating whether an overflow has occurred. On overflow, the
+            /// wrapped value is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics in the same cases as the conversion of the referenced
+            /// value.
+            #[inline]
+            #[track_caller]
+            fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
+                ToFixed::overflowing_to_fixed(*self)
+            }
+
+            /// Converts the referenced number to a fixed-point number, panicking if
+            /// it does not fit.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the value does not fit, even when debug assertions
+            /// are not enabled.
+            #[inline]
+            #[track_caller]
+            fn unwrapped_to_fixed<F: Fixed>(self) -> F {
+                ToFixed::unwrapped_to_fixed(*self)
+            }
+        }
+    };
+}
+
+impl_to_fixed_ref! { bool }
+impl_to_fixed_ref! { {const FRAC: i32} FixedI8<FRAC> }
+impl_to_fixed_ref! { {const FRAC: i32} FixedI16<FRAC> }
+impl_to_fixed_ref! { {const FRAC: i32} FixedI32<FRAC> }
+impl_to_fixed_ref! { {const FRAC: i32} FixedI64<FRAC> }
+impl_to_fixed_ref! { {const FRAC: i32} FixedI128<FRAC> }
+impl_to_fixed_ref! { {const FRAC: i32} FixedU8<FRAC> }
+impl_to_fixed_ref! { {const FRAC: i32} FixedU16<FRAC> }
+impl_to_fixed_ref! { {const FRAC: i32} FixedU32<FRAC> }
+impl_to_fixed_ref! { {const FRAC: i32} FixedU64<FRAC> }
+impl_to_fixed_ref! { {const FRAC: i32} FixedU128<FRAC> }
+
 macro_rules! impl_int {
     ($Int:ident as $IntAs:ident, $AsEquiv:ident) => {
         impl FromFixed for $Int {
@@ -218,6 +312,66 @@ macro_rules! impl_int {
                 $AsEquiv::<0>::from_bits(self as $IntAs).unwrapped_to_fixed()
             }
         }
+
+        impl ToFixed for Wrapping<$Int> {
+            /// Converts a wrapped integer to a fixed-point number, wrapping if
+            /// it does not fit.
+            ///
+            /// Unlike the conversion from a bare integer, this never panics on
+            /// overflow, even when debug assertions are enabled; the integer
+            /// is already in a [`Wrapping`], so the conversion wraps too.
+            #[inline]
+            fn to_fixed<F: Fixed>(self) -> F {
+                self.0.wrapping_to_fixed()
+            }
+
+            /// Converts a wrapped integer to a fixed-point number if it fits,
+            /// otherwise returns [`None`].
+            #[inline]
+            fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
+                self.0.checked_to_fixed()
+            }
+
+            /// Converts a wrapped integer to a fixed-point number, saturating
+            /// if it does not fit.
+            #[inline]
+            fn saturating_to_fixed<F: Fixed>(self) -> F {
+                self.0.saturating_to_fixed()
+            }
+
+            /// Converts a wrapped integer to a fixed-point number, wrapping if
+            /// it does not fit.
+            #[inline]
+            fn wrapping_to_fixed<F: Fixed>(self) -> F {
+                self.0.wrapping_to_fixed()
+            }
+
+            /// Converts a wrapped integer to a fixed-point number.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a [`bool`]
+            /// indicating whether an overflow has occurred. On overflow, the
+            /// wrapped value is returned.
+            #[inline]
+            fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
+                self.0.overflowing_to_fixed()
+            }
+
+            /// Converts a wrapped integer to a fixed-point number, panicking
+            /// if it does not fit.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the value does not fit, even when debug assertions
+            /// are not enabled.
+            #[inline]
+            #[track_caller]
+            fn unwrapped_to_fixed<F: Fixed>(self) -> F {
+                self.0.unwrapped_to_fixed()
+            }
+        }
+
+        impl_to_fixed_ref! { $Int }
+        impl_to_fixed_ref! { Wrapping<$Int> }
     };
 }
 
@@ -517,19 +671,16 @@ it panics; if wrapping is required use [`wrapping_to_fixed`] instead.
                 "Converts a floating-point number to a fixed-point
 number, saturating if it does not fit.
 
-Rounding is to the nearest, with ties rounded to even.
-
-# Panics
-
-Panics if `self` is [NaN].
+Rounding is to the nearest, with ties rounded to even. Infinite values
+saturate to [`F::MIN`][Fixed::MIN] or [`F::MAX`][Fixed::MAX], and [NaN]
+is converted to zero.
 
 [NaN]: ", stringify!($Float), "::is_nan
 ";
                 #[inline]
-                #[track_caller]
                 fn saturating_to_fixed<F: Fixed>(self) -> F {
                     if self.is_nan() {
-                        panic!("NaN");
+                        return F::ZERO;
                     }
                     if self.is_finite() {
                         let (wrapped, overflow) = ToFixed::overflowing_to_fixed(self);
@@ -611,6 +762,8 @@ when debug assertions are not enabled.
                 }
             }
         }
+
+        impl_to_fixed_ref! { $Float }
     };
 }
 
@@ -0,0 +1,422 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::traits::{Fixed, FixedBoundFrac};
+use core::ops::{Add, Mul, Sub};
+
+/// A closed interval of fixed-point numbers.
+///
+/// The interval contains all numbers <i>x</i> such that
+/// `lo`&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;`hi`, and is never empty.
+///
+/// Arithmetic on intervals is conservative: the result of an operation
+/// contains the exact result of the operation for every pair of operands taken
+/// from the two intervals. Where the exact bounds cannot be represented, the
+/// lower bound is rounded down and the upper bound is rounded up, so the
+/// interval can be used to track error bounds.
+///
+/// The [`Add`], [`Sub`] and [`Mul`] operators panic if a bound overflows, even
+/// when debug assertions are not enabled, as a wrapped bound would no longer
+/// contain the exact result. Use [`checked_add`], [`checked_sub`] and
+/// [`checked_mul`] to handle overflow.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::{types::I16F16, FixedRange};
+/// // 1.5 ± 0.25 and 2 ± 0.5
+/// let a = FixedRange::new(I16F16::from_num(1.25), I16F16::from_num(1.75));
+/// let b = FixedRange::new(I16F16::from_num(1.5), I16F16::from_num(2.5));
+/// let sum = a + b;
+/// assert_eq!((sum.lo(), sum.hi()), (I16F16::from_num(2.75), I16F16::from_num(4.25)));
+/// let prod = a * b;
+/// assert_eq!(prod.lo(), I16F16::from_num(1.875));
+/// assert_eq!(prod.hi(), I16F16::from_num(4.375));
+/// assert!(prod.contains(I16F16::from_num(3)));
+/// ```
+///
+/// [`checked_add`]: FixedRange::checked_add
+/// [`checked_mul`]: FixedRange::checked_mul
+/// [`checked_sub`]: FixedRange::checked_sub
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct FixedRange<F> {
+    lo: F,
+    hi: F,
+}
+
+impl<F: Fixed> FixedRange<F> {
+    /// Creates an interval from its lower and upper bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo`&nbsp;>&nbsp;`hi`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, FixedRange};
+    /// let r = FixedRange::new(I16F16::from_num(-1), I16F16::from_num(2));
+    /// assert_eq!(r.lo(), -1);
+    /// assert_eq!(r.hi(), 2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn new(lo: F, hi: F) -> FixedRange<F> {
+        match FixedRange::checked_new(lo, hi) {
+            Some(range) => range,
+            None => panic!("lower bound is greater than upper bound"),
+        }
+    }
+
+    /// Creates an interval from its lower and upper bounds, returning [`None`]
+    /// if `lo`&nbsp;>&nbsp;`hi`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, FixedRange};
+    /// let (one, two) = (I16F16::from_num(1), I16F16::from_num(2));
+    /// assert!(FixedRange::checked_new(one, two).is_some());
+    /// assert!(FixedRange::checked_new(two, one).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_new(lo: F, hi: F) -> Option<FixedRange<F>> {
+        if lo <= hi {
+            Some(FixedRange { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    /// Creates an interval that contains only `val`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, FixedRange};
+    /// let r = FixedRange::point(I16F16::from_num(1.5));
+    /// assert_eq!(r.lo(), r.hi());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn point(val: F) -> FixedRange<F> {
+        FixedRange { lo: val, hi: val }
+    }
+
+    /// Returns the lower bound.
+    #[inline]
+    #[must_use]
+    pub fn lo(self) -> F {
+        self.lo
+    }
+
+    /// Returns the upper bound.
+    #[inline]
+    #[must_use]
+    pub fn hi(self) -> F {
+        self.hi
+    }
+
+    /// Returns [`true`] if the interval contains `val`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, FixedRange};
+    /// let r = FixedRange::new(I16F16::from_num(-1), I16F16::from_num(2));
+    /// assert!(r.contains(I16F16::from_num(-1)));
+    /// assert!(r.contains(I16F16::from_num(2)));
+    /// assert!(!r.contains(I16F16::from_num(2) + I16F16::DELTA));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains(self, val: F) -> bool {
+        self.lo <= val && val <= self.hi
+    }
+
+    /// Returns the intersection of two intervals, or [`None`] if they do not
+    /// overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, FixedRange};
+    /// let a = FixedRange::new(I16F16::from_num(-1), I16F16::from_num(2));
+    /// let b = FixedRange::new(I16F16::from_num(1), I16F16::from_num(3));
+    /// let c = FixedRange::new(I16F16::from_num(5), I16F16::from_num(6));
+    /// let both = FixedRange::new(I16F16::from_num(1), I16F16::from_num(2));
+    /// assert_eq!(a.intersect(b), Some(both));
+    /// assert_eq!(a.intersect(c), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn intersect(self, other: FixedRange<F>) -> Option<FixedRange<F>> {
+        FixedRange::checked_new(self.lo.max(other.lo), self.hi.min(other.hi))
+    }
+
+    /// Checked interval addition. Returns the interval containing all the
+    /// sums, or [`None`] if a bound overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, FixedRange};
+    /// let a = FixedRange::new(I16F16::from_num(-1), I16F16::from_num(2));
+    /// let b = FixedRange::new(I16F16::from_num(1), I16F16::from_num(3));
+    /// let sum = a.checked_add(b).unwrap();
+    /// assert_eq!((sum.lo(), sum.hi()), (I16F16::from_num(0), I16F16::from_num(5)));
+    /// assert!(a.checked_add(FixedRange::point(I16F16::MAX)).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, rhs: FixedRange<F>) -> Option<FixedRange<F>> {
+        Some(FixedRange {
+            lo: self.lo.checked_add(rhs.lo)?,
+            hi: self.hi.checked_add(rhs.hi)?,
+        })
+    }
+
+    /// Checked interval subtraction. Returns the interval containing all the
+    /// differences, or [`None`] if a bound overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I16F16, FixedRange};
+    /// let a = FixedRange::new(I16F16::from_num(-1), I16F16::from_num(2));
+    /// let b = FixedRange::new(I16F16::from_num(1), I16F16::from_num(3));
+    /// let diff = a.checked_sub(b).unwrap();
+    /// assert_eq!((diff.lo(), diff.hi()), (I16F16::from_num(-4), I16F16::from_num(1)));
+    /// assert!(a.checked_sub(FixedRange::point(I16F16::MAX)).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, rhs: FixedRange<F>) -> Option<FixedRange<F>> {
+        Some(FixedRange {
+            lo: self.lo.checked_sub(rhs.hi)?,
+            hi: self.hi.checked_sub(rhs.lo)?,
+        })
+    }
+}
+
+impl<F: FixedBoundFrac> FixedRange<F> {
+    /// Checked interval multiplication. Returns the interval containing all
+    /// the products, or [`None`] if a bound overflows.
+    ///
+    /// The lower bound is rounded down and the upper bound is rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use fixed::{types::I4F4, FixedRange};
+    /// let a = FixedRange::new(I4F4::from_num(-1), I4F4::from_bits(3));
+    /// let b = FixedRange::point(I4F4::from_num(0.5));
+    /// let prod = a.checked_mul(b).unwrap();
+    /// // 3/16 × 1/2 = 3/32 is rounded up to 2/16
+    /// assert_eq!((prod.lo(), prod.hi()), (I4F4::from_num(-0.5), I4F4::from_bits(2)));
+    /// assert!(a.checked_mul(FixedRange::point(I4F4::MIN)).is_none());
+    /// ```
+    #[must_use]
+    pub fn checked_mul(self, rhs: FixedRange<F>) -> Option<FixedRange<F>> {
+        let corners = [
+            (self.lo, rhs.lo),
+            (self.lo, rhs.hi),
+            (self.hi, rhs.lo),
+            (self.hi, rhs.hi),
+        ];
+        let mut lo = F::MAX;
+        let mut hi = F::MIN;
+        for (a, b) in corners {
+            let floor = a.checked_mul(b)?;
+            // The wrapped integer product of the bits has the same fractional
+            // bits as the exact product, so the product is exact if and only
+            // if they are all zero.
+            let exact = a.wrapping_mul_int(b.to_bits()).frac() == F::ZERO;
+            let ceil = if exact {
+                floor
+            } else {
+                floor.checked_add(F::DELTA)?
+            };
+            lo = lo.min(floor);
+            hi = hi.max(ceil);
+        }
+        Some(FixedRange { lo, hi })
+    }
+}
+
+impl<F: Fixed> Add for FixedRange<F> {
+    type Output = FixedRange<F>;
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: FixedRange<F>) -> FixedRange<F> {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None => panic!("overflow"),
+        }
+    }
+}
+
+impl<F: Fixed> Sub for FixedRange<F> {
+    type Output = FixedRange<F>;
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: FixedRange<F>) -> FixedRange<F> {
+        match self.checked_sub(rhs) {
+            Some(diff) => diff,
+            None => panic!("overflow"),
+        }
+    }
+}
+
+impl<F: FixedBoundFrac> Mul for FixedRange<F> {
+    type Output = FixedRange<F>;
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: FixedRange<F>) -> FixedRange<F> {
+        match self.checked_mul(rhs) {
+            Some(prod) => prod,
+            None => panic!("overflow"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I4F4, U4F4};
+    use crate::FixedRange;
+
+    #[test]
+    fn add_bounds_exact_result() {
+        let vals = [-8.0, -1.9375, -0.5, -0.0625, 0.0, 0.0625, 0.75, 3.5, 7.9375];
+        for &a_lo in &vals {
+            for &a_hi in vals.iter().filter(|&&x| x >= a_lo) {
+                let a = FixedRange::new(I4F4::from_num(a_lo), I4F4::from_num(a_hi));
+                for &b_val in &vals {
+                    let b = FixedRange::point(I4F4::from_num(b_val));
+                    let Some(sum) = a.checked_add(b) else {
+                        assert!(a_lo + b_val < -8.0 || a_hi + b_val > 7.9375);
+                        continue;
+                    };
+                    for x in [a_lo, a_hi, (a_lo + a_hi) / 2.0] {
+                        let exact = x + b_val;
+                        assert!(sum.lo().to_num::<f64>() <= exact);
+                        assert!(exact <= sum.hi().to_num::<f64>());
+                    }
+                    let diff = sum - b;
+                    assert_eq!(diff, a);
+                }
+            }
+        }
+    }
+
+    fn ranges() -> impl Iterator<Item = FixedRange<I4F4>> + Clone {
+        // intervals with bounds that are multiples of 11/16
+        let bounds = (i8::MIN..=i8::MAX).step_by(11).map(I4F4::from_bits);
+        bounds.clone().flat_map(move |lo| {
+            bounds
+                .clone()
+                .filter(move |&hi| hi >= lo)
+                .map(move |hi| FixedRange::new(lo, hi))
+        })
+    }
+
+    #[test]
+    fn mul_bounds_exact_result() {
+        let mut checked = 0;
+        for a in ranges() {
+            for b in ranges() {
+                let Some(prod) = a.checked_mul(b) else {
+                    continue;
+                };
+                checked += 1;
+                let mid = b.lo().mean(b.hi());
+                for x in [a.lo(), a.hi()] {
+                    for y in [b.lo(), b.hi(), mid] {
+                        let exact = x.to_num::<f64>() * y.to_num::<f64>();
+                        assert!(prod.lo().to_num::<f64>() <= exact, "{a:?} * {b:?}");
+                        assert!(exact <= prod.hi().to_num::<f64>(), "{a:?} * {b:?}");
+                    }
+                }
+                // the bounds are the exact bounds rounded outwards
+                let corners = [
+                    a.lo().to_num::<f64>() * b.lo().to_num::<f64>(),
+                    a.lo().to_num::<f64>() * b.hi().to_num::<f64>(),
+                    a.hi().to_num::<f64>() * b.lo().to_num::<f64>(),
+                    a.hi().to_num::<f64>() * b.hi().to_num::<f64>(),
+                ];
+                let min = corners.into_iter().fold(f64::INFINITY, f64::min);
+                let max = corners.into_iter().fold(f64::NEG_INFINITY, f64::max);
+                assert_eq!(prod.lo(), I4F4::from_num((min * 16.0).floor() / 16.0));
+                assert_eq!(prod.hi(), I4F4::from_num((max * 16.0).ceil() / 16.0));
+            }
+        }
+        assert!(checked > 1000);
+    }
+
+    #[test]
+    fn mul_unsigned() {
+        let a = FixedRange::new(U4F4::from_bits(1), U4F4::from_bits(3));
+        let b = FixedRange::new(U4F4::from_bits(5), U4F4::from_num(2));
+        let prod = a * b;
+        // 1/16 × 5/16 = 5/256 rounds down to 0
+        assert_eq!(prod.lo(), U4F4::ZERO);
+        assert_eq!(prod.hi(), U4F4::from_bits(6));
+        assert_eq!(
+            a.checked_mul(FixedRange::point(U4F4::MAX)),
+            Some(FixedRange::new(U4F4::from_bits(15), U4F4::from_bits(48)))
+        );
+        assert!(FixedRange::point(U4F4::MAX).checked_mul(b).is_none());
+    }
+
+    #[test]
+    fn intersect() {
+        let a = FixedRange::new(I4F4::from_num(-1), I4F4::from_num(1));
+        let b = FixedRange::new(I4F4::from_num(1), I4F4::from_num(2));
+        assert_eq!(a.intersect(b), Some(FixedRange::point(I4F4::from_num(1))));
+        assert_eq!(a.intersect(a), Some(a));
+        let c = FixedRange::new(I4F4::from_num(1) + I4F4::DELTA, I4F4::from_num(2));
+        assert_eq!(a.intersect(c), None);
+        assert!(!a.contains(c.lo()));
+    }
+}
@@ -0,0 +1,345 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use core::cmp::Ordering;
+
+/**
+A rounding mode.
+
+This can be used to choose how a conversion rounds at run time, for example
+with [`from_num_dyn`].
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I32F0, Round};
+// the mode could come from a configuration file
+let mode = Round::TowardZero;
+assert_eq!(I32F0::from_num_dyn(-2.75, mode), -2);
+```
+
+[`from_num_dyn`]: crate::FixedI32::from_num_dyn
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Round {
+    /// Round to the nearest, with ties rounding away from zero.
+    Nearest,
+    /// Round towards zero.
+    TowardZero,
+    /// Round towards &minus;∞.
+    TowardNegInf,
+    /// Round towards +∞.
+    TowardPosInf,
+    /// Round to the nearest, with ties rounding to even.
+    ToEven,
+}
+
+impl Round {
+    // Returns whether a magnitude truncated to q has to be incremented, where
+    // rem is the comparison of the discarded part with one half, and exact is
+    // whether the discarded part is zero.
+    #[inline]
+    pub(crate) const fn round_up(
+        self,
+        neg: bool,
+        q_is_odd: bool,
+        rem: Ordering,
+        exact: bool,
+    ) -> bool {
+        match self {
+            Round::Nearest => !matches!(rem, Ordering::Less),
+            Round::TowardZero => false,
+            Round::TowardNegInf => neg && !exact,
+            Round::TowardPosInf => !neg && !exact,
+            Round::ToEven => match rem {
+                Ordering::Less => false,
+                Ordering::Equal => q_is_odd,
+                Ordering::Greater => true,
+            },
+        }
+    }
+}
+
+// Rounds abs × 2^-shift to an integer using the rounding mode, where neg is the
+// sign of the number with magnitude abs. The result cannot overflow, as it is
+// at most 2^127 when shift > 0.
+pub(crate) const fn round_abs(neg: bool, abs: u128, shift: u32, mode: Round) -> u128 {
+    if shift == 0 {
+        return abs;
+    }
+    let (q, rem_cmp_half) = if shift > 128 {
+        // everything is discarded and less than one half
+        (0, Ordering::Less)
+    } else {
+        let (q, rem) = if shift == 128 {
+            (0, abs)
+        } else {
+            (abs >> shift, abs & ((1 << shift) - 1))
+        };
+        let half = 1 << (shift - 1);
+        let rem_cmp_half = if rem < half {
+            Ordering::Less
+        } else if rem == half {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        };
+        (q, rem_cmp_half)
+    };
+    let exact = if shift >= 128 {
+        abs == 0
+    } else {
+        abs & ((1 << shift) - 1) == 0
+    };
+    q + mode.round_up(neg, q & 1 != 0, rem_cmp_half, exact) as u128
+}
+
+// Converts src to a number with frac fractional bits using the rounding mode.
+// Returns the sign, the magnitude wrapped to a u128, and whether the magnitude
+// does not fit in a u128.
+#[track_caller]
+pub(crate) fn from_f64(src: f64, frac: i32, mode: Round) -> (bool, u128, bool) {
+    assert!(!src.is_nan(), "NaN");
+    assert!(src.is_finite(), "infinite");
+    let bits = src.to_bits();
+    let neg = bits >> 63 != 0;
+    let biased_exp = ((bits >> 52) & 0x7FF) as i64;
+    let mant = bits & ((1 << 52) - 1);
+    let (mant, exp) = if biased_exp == 0 {
+        (mant, -1074)
+    } else {
+        (mant | (1 << 52), biased_exp - 1075)
+    };
+    if mant == 0 {
+        return (false, 0, false);
+    }
+    let mant = u128::from(mant);
+    // src × 2^frac = mant × 2^shift
+    let shift = exp + i64::from(frac);
+    if shift >= 0 {
+        if shift >= 128 {
+            return (neg, 0, true);
+        }
+        let shift = shift as u32;
+        return (neg, mant << shift, mant.leading_zeros() < shift);
+    }
+    let shift = u32::try_from(shift.unsigned_abs()).unwrap_or(u32::MAX);
+    (neg, round_abs(neg, mant, shift, mode), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F32, I16F16, I32F0, I8F8, U0F8, U16F16, U8F8};
+    use crate::FixedI32;
+    use crate::Round;
+
+    const MODES: [Round; 5] = [
+        Round::Nearest,
+        Round::TowardZero,
+        Round::TowardNegInf,
+        Round::TowardPosInf,
+        Round::ToEven,
+    ];
+
+    #[test]
+    fn ties() {
+        // DELTA is 1/256, so these are halfway between two representable values
+        let delta = 1.0 / 256.0;
+        let f = I8F8::from_bits;
+        let expected = [
+            // Nearest, TowardZero, TowardNegInf, TowardPosInf, ToEven
+            (2.5 * delta, [f(3), f(2), f(2), f(3), f(2)]),
+            (3.5 * delta, [f(4), f(3), f(3), f(4), f(4)]),
+            (-2.5 * delta, [f(-3), f(-2), f(-3), f(-2), f(-2)]),
+            (-3.5 * delta, [f(-4), f(-3), f(-4), f(-3), f(-4)]),
+            (0.5 * delta, [f(1), f(0), f(0), f(1), f(0)]),
+            (-0.5 * delta, [f(-1), f(0), f(-1), f(0), f(0)]),
+        ];
+        for (src, vals) in expected {
+            for (mode, val) in MODES.into_iter().zip(vals) {
+                assert_eq!(I8F8::from_num_dyn(src, mode), val, "{src} {mode:?}");
+            }
+        }
+
+        let u = U8F8::from_bits;
+        let vals = [u(3), u(2), u(2), u(3), u(2)];
+        for (mode, val) in MODES.into_iter().zip(vals) {
+            assert_eq!(U8F8::from_num_dyn(2.5 * delta, mode), val);
+        }
+        // ties between integers, and with negative FRAC between multiples of 4
+        let vals = [3, 2, 2, 3, 2];
+        for (mode, val) in MODES.into_iter().zip(vals) {
+            assert_eq!(I32F0::from_num_dyn(2.5, mode), val);
+            assert_eq!(FixedI32::<-2>::from_num_dyn(10.0, mode), 4 * val);
+        }
+    }
+
+    #[test]
+    fn fixed_ties() {
+        let delta = 1.0 / 256.0;
+        let f = I8F8::from_bits;
+        let expected = [
+            // Nearest, TowardZero, TowardNegInf, TowardPosInf, ToEven
+            (2.5 * delta, [f(3), f(2), f(2), f(3), f(2)]),
+            (3.5 * delta, [f(4), f(3), f(3), f(4), f(4)]),
+            (-2.5 * delta, [f(-3), f(-2), f(-3), f(-2), f(-2)]),
+            (-3.5 * delta, [f(-4), f(-3), f(-4), f(-3), f(-4)]),
+            (-0.5 * delta, [f(-1), f(0), f(-1), f(0), f(0)]),
+        ];
+        for (src, vals) in expected {
+            let src = I16F16::from_num(src);
+            for (mode, val) in MODES.into_iter().zip(vals) {
+                assert_eq!(I8F8::from_num_round(src, mode), val, "{src} {mode:?}");
+                assert_eq!(src.to_num_round::<I8F8>(mode), val, "{src} {mode:?}");
+                let rounded = src.round_to_frac_round(8, mode);
+                assert_eq!(rounded, I16F16::from_num(val), "{src} {mode:?}");
+            }
+        }
+
+        let src = U16F16::from_num(2.5 * delta);
+        let u = U8F8::from_bits;
+        let vals = [u(3), u(2), u(2), u(3), u(2)];
+        for (mode, val) in MODES.into_iter().zip(vals) {
+            assert_eq!(U8F8::from_num_round(src, mode), val);
+            assert_eq!(src.round_to_frac_round(8, mode), U16F16::from_num(val));
+        }
+        // ties between integers, and with negative FRAC between multiples of 4
+        let vals = [3, 2, 2, 3, 2];
+        for (mode, val) in MODES.into_iter().zip(vals) {
+            assert_eq!(I32F0::from_num_round(I16F16::from_num(2.5), mode), val);
+            assert_eq!(I8F8::from_num(2.5).round_to_frac_round(0, mode), val);
+            let ten = I32F0::from_num(10);
+            assert_eq!(FixedI32::<-2>::from_num_round(ten, mode), 4 * val);
+            assert_eq!(ten.round_to_frac_round(-2, mode), 4 * val);
+        }
+    }
+
+    #[test]
+    fn fixed_not_ties() {
+        for mode in MODES {
+            // no fractional bits are discarded
+            let x = I8F8::from_num(-1.75);
+            assert_eq!(I16F16::from_num_round(x, mode), -1.75);
+            assert_eq!(x.round_to_frac_round(8, mode), x);
+            assert_eq!(x.round_to_frac_round(i32::MAX, mode), x);
+            // everything is discarded
+            let x = I16F16::from_num(0.25);
+            let up = matches!(mode, Round::TowardPosInf);
+            let down = matches!(mode, Round::TowardNegInf);
+            let toward_zero = if up { -x } else { x };
+            assert_eq!(toward_zero.round_to_frac_round(i32::MIN, mode), 0);
+            assert_eq!(x.round_to_frac_round(-14, mode), i32::from(up) << 14);
+            assert_eq!((-x).round_to_frac_round(-14, mode), -i32::from(down) << 14);
+        }
+        let x = I16F16::from_num(1.0 + 1.0 / 3.0);
+        assert_eq!(
+            U8F8::from_num_round(x, Round::TowardPosInf),
+            U8F8::from_num(x) + U8F8::DELTA
+        );
+        assert_eq!(U8F8::from_num_round(x, Round::Nearest), U8F8::from_num(x));
+    }
+
+    #[test]
+    fn to_frac() {
+        // 0.3 is 0.0100110011... in binary
+        let x = I16F16::from_num(0.3);
+        assert_eq!(x.round_to_frac(2), 0.25);
+        assert_eq!(x.ceil_to_frac(2), 0.5);
+        assert_eq!(x.floor_to_frac(2), 0.25);
+        assert_eq!(x.trunc_to_frac(2), 0.25);
+        assert_eq!((-x).round_to_frac(2), -0.25);
+        assert_eq!((-x).ceil_to_frac(2), -0.25);
+        assert_eq!((-x).floor_to_frac(2), -0.5);
+        assert_eq!((-x).trunc_to_frac(2), -0.25);
+
+        // ties to even
+        for (val, even) in [(0.125, 0.0), (0.375, 0.5), (0.625, 0.5), (0.875, 1.0)] {
+            let val = I16F16::from_num(val);
+            assert_eq!(val.round_to_frac(2), even);
+            assert_eq!((-val).round_to_frac(2), -even);
+        }
+        let val = U8F8::from_num(2.5);
+        assert_eq!(val.round_to_frac(0), 2);
+        assert_eq!(val.ceil_to_frac(0), 3);
+        assert_eq!(val.trunc_to_frac(0), 2);
+
+        // frac_bits equal to and exceeding FRAC
+        for frac_bits in [16, 17, i32::MAX] {
+            assert_eq!(x.round_to_frac(frac_bits), x);
+            assert_eq!(x.ceil_to_frac(frac_bits), x);
+            assert_eq!((-x).floor_to_frac(frac_bits), -x);
+            assert_eq!((-x).trunc_to_frac(frac_bits), -x);
+        }
+
+        // negative frac_bits round to multiples of powers of two
+        let x = I16F16::from_num(-100.5);
+        assert_eq!(x.round_to_frac(-3), -104);
+        assert_eq!(x.ceil_to_frac(-3), -96);
+        assert_eq!(x.floor_to_frac(-3), -104);
+        assert_eq!(x.trunc_to_frac(-3), -96);
+        assert_eq!(I16F16::from_num(12).round_to_frac(-3), 16);
+        assert_eq!(I16F16::from_num(-20).round_to_frac(-3), -16);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn round_to_frac_overflow() {
+        let _ = I8F8::MAX.round_to_frac_round(0, Round::Nearest);
+    }
+
+    #[test]
+    fn not_ties() {
+        for mode in MODES {
+            // exact values are unchanged
+            assert_eq!(I16F16::from_num_dyn(-1.75, mode), -1.75);
+            assert_eq!(U0F8::from_num_dyn(0.5, mode), 0.5);
+            assert_eq!(I16F16::from_num_dyn(0.0, mode), 0);
+            assert_eq!(I16F16::from_num_dyn(-0.0, mode), 0);
+            assert_eq!(I16F16::from_num_dyn(-32768.0, mode), I16F16::MIN);
+            // the smallest subnormal number only rounds up away from zero
+            let tiny = f64::from_bits(1);
+            let up = matches!(mode, Round::TowardPosInf);
+            let down = matches!(mode, Round::TowardNegInf);
+            assert_eq!(I0F32::from_num_dyn(tiny, mode).to_bits(), i32::from(up));
+            assert_eq!(I0F32::from_num_dyn(-tiny, mode).to_bits(), -i32::from(down));
+        }
+        let x = 1.0 + 1.0 / 3.0;
+        assert_eq!(U16F16::from_num_dyn(x, Round::Nearest), U16F16::from_num(x));
+        assert_eq!(U16F16::from_num_dyn(x, Round::ToEven), U16F16::from_num(x));
+        assert_eq!(
+            U16F16::from_num_dyn(x, Round::TowardPosInf),
+            U16F16::from_num(x) + U16F16::DELTA
+        );
+        assert_eq!(
+            U16F16::from_num_dyn(x, Round::TowardZero),
+            U16F16::from_num(x)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn nan() {
+        let _ = I16F16::from_num_dyn(f64::NAN, Round::Nearest);
+    }
+
+    #[test]
+    #[should_panic(expected = "infinite")]
+    fn infinite() {
+        let _ = I16F16::from_num_dyn(f64::NEG_INFINITY, Round::TowardZero);
+    }
+}
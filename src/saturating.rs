@@ -508,9 +508,7 @@ impl<F: Fixed> Saturating<F> {
     /// and
     /// <code>FixedU32::[saturating\_from\_num][FixedU32::saturating_from_num]</code>.
     ///
-    /// # Panics
-    ///
-    /// For floating-point numbers, panics if the value is NaN.
+    /// For floating-point numbers, NaN is converted to zero.
     ///
     /// # Examples
     ///
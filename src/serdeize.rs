@@ -32,8 +32,53 @@ use {
     },
 };
 
+/// Serializes a fixed-point number as its raw underlying integer.
+///
+/// The serialized representation of a fixed-point number depends on the
+/// enabled features: it is a struct with a `bits` field, or with the
+/// `serde-str` feature, a string for human-readable formats. Wrapping a
+/// fixed-point number in `SerdeBits` instead always serializes it as the
+/// bare underlying integer, regardless of the enabled features and of
+/// [`is_human_readable`]. This gives a stable wire format, for example
+/// when using compact binary formats on embedded targets.
+///
+/// The underlying value can be retrieved through the `.0` index.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::{types::I16F16, SerdeBits};
+/// let val = SerdeBits(I16F16::from_num(-1.5));
+/// let mut buf = [0; 8];
+/// let bytes = postcard::to_slice(&val, &mut buf).unwrap();
+/// // zigzag varint encoding of the bits -0x18000
+/// assert_eq!(bytes, [0xFF, 0xFF, 0x0B]);
+/// let back: SerdeBits<I16F16> = postcard::from_bytes(bytes).unwrap();
+/// assert_eq!(back, val);
+/// ```
+///
+/// [`is_human_readable`]: serde::Serializer::is_human_readable
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, Hash, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SerdeBits<F>(pub F);
+
 macro_rules! serde_fixed {
     ($Fixed:ident($nbits:expr) is $TBits:ident name $Name:expr) => {
+        impl<const FRAC: i32> Serialize for SerdeBits<$Fixed<FRAC>> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.to_bits().serialize(serializer)
+            }
+        }
+
+        impl<'de, const FRAC: i32> Deserialize<'de> for SerdeBits<$Fixed<FRAC>> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $TBits::deserialize(deserializer).map(|bits| SerdeBits($Fixed::from_bits(bits)))
+            }
+        }
+
         #[cfg(not(feature = "serde-str"))]
         impl<const FRAC: i32> Serialize for $Fixed<FRAC> {
             fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -228,3 +273,44 @@ impl<'de> Deserialize<'de> for Field {
         deserializer.deserialize_identifier(FieldVisitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F8, I16F16, I64F64, U0F128, U24F8};
+    use crate::SerdeBits;
+
+    #[test]
+    fn bits_postcard_round_trip() {
+        let mut buf = [0; 32];
+        let mut bits_buf = [0; 32];
+
+        let val = SerdeBits(I16F16::from_num(-1.5));
+        let bytes = postcard::to_slice(&val, &mut buf).unwrap();
+        let bits_bytes = postcard::to_slice(&val.0.to_bits(), &mut bits_buf).unwrap();
+        assert_eq!(bytes, bits_bytes);
+        assert_eq!(bytes, [0xFF, 0xFF, 0x0B]);
+        let back: SerdeBits<I16F16> = postcard::from_bytes(bytes).unwrap();
+        assert_eq!(back, val);
+
+        let val = SerdeBits(I0F8::MIN);
+        let bytes = postcard::to_slice(&val, &mut buf).unwrap();
+        assert_eq!(bytes, [0x80]);
+        assert_eq!(postcard::from_bytes::<SerdeBits<I0F8>>(bytes), Ok(val));
+
+        let val = SerdeBits(U24F8::from_num(300.25));
+        let bytes = postcard::to_slice(&val, &mut buf).unwrap();
+        let bits_bytes = postcard::to_slice(&val.0.to_bits(), &mut bits_buf).unwrap();
+        assert_eq!(bytes, bits_bytes);
+        assert_eq!(postcard::from_bytes::<SerdeBits<U24F8>>(bytes), Ok(val));
+
+        for val in [I64F64::MIN, I64F64::MAX, I64F64::DELTA, -I64F64::DELTA] {
+            let val = SerdeBits(val);
+            let bytes = postcard::to_slice(&val, &mut buf).unwrap();
+            assert_eq!(postcard::from_bytes::<SerdeBits<I64F64>>(bytes), Ok(val));
+        }
+        let val = SerdeBits(U0F128::MAX);
+        let bytes = postcard::to_slice(&val, &mut buf).unwrap();
+        assert_eq!(bytes.len(), 19);
+        assert_eq!(postcard::from_bytes::<SerdeBits<U0F128>>(bytes), Ok(val));
+    }
+}
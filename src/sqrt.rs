@@ -240,6 +240,72 @@ impl_sqrt! { u32, NonZeroU32 }
 impl_sqrt! { u64, NonZeroU64 }
 impl_sqrt! { u128, NonZeroU128 }
 
+// The reciprocal square root r = 1/√x is computed from a normalized input.
+//
+//   * The input bits b are shifted left by sh so that the normalized
+//     m = b << sh has one of its two most significant bits set.
+//   * m is interpreted as f = m >> p, with p = nbits (f in [1/4, 1)) or, when
+//     the parity requires it and m cannot be shifted further, with
+//     p = nbits - 1 (f in [1, 2)).
+//   * Then x = f × 2^E, with E = p - sh - frac_nbits chosen to be even.
+//   * s = √f is computed with p fractional bits, and q = 1/s with nbits - 2
+//     fractional bits, so that q is in (1/2, 2] and fits.
+//   * r = q × 2^(-E/2), so the result bits are q shifted left by
+//     frac_nbits - E/2 - (nbits - 2).
+//
+// Both s and q are rounded down. Since s is rounded down, 1/s is larger than
+// 1/√f by less than one unit of q, and q is then rounded down by less than one
+// unit, so q is within one unit of the exact value. The shift at the end can
+// be left by at most one bit without overflow, so the final error is less than
+// two units.
+pub mod rsqrt {
+    use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+
+    macro_rules! impl_rsqrt {
+        ($u:ident, $NZ:ident) => {
+            pub const fn $u(val: $NZ, frac_nbits: u32) -> ($u, bool) {
+                let leading = val.leading_zeros();
+                let (sh, p) = if leading % 2 == frac_nbits % 2 {
+                    (leading, $u::BITS)
+                } else if leading > 0 {
+                    (leading - 1, $u::BITS)
+                } else {
+                    (0, $u::BITS - 1)
+                };
+                let m = match $NZ::new(val.get() << sh) {
+                    Some(m) => m,
+                    None => unreachable!(),
+                };
+                let s = super::$u(m, p);
+                let (q, _) = crate::arith::$u::overflowing_div(1 << ($u::BITS - 2), s, p);
+                let half_exp = (p as i32 - sh as i32 - frac_nbits as i32) / 2;
+                let shl = frac_nbits as i32 - half_exp - ($u::BITS as i32 - 2);
+                if shl >= 0 {
+                    let shl = shl as u32;
+                    if shl >= $u::BITS {
+                        (0, true)
+                    } else {
+                        (q << shl, q.leading_zeros() < shl)
+                    }
+                } else {
+                    let shr = shl.unsigned_abs();
+                    if shr >= $u::BITS {
+                        (0, false)
+                    } else {
+                        (q >> shr, false)
+                    }
+                }
+            }
+        };
+    }
+
+    impl_rsqrt! { u8, NonZeroU8 }
+    impl_rsqrt! { u16, NonZeroU16 }
+    impl_rsqrt! { u32, NonZeroU32 }
+    impl_rsqrt! { u64, NonZeroU64 }
+    impl_rsqrt! { u128, NonZeroU128 }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -260,6 +326,62 @@ mod tests {
         }};
     }
 
+    // Returns the floor of the square root of val.
+    fn isqrt(val: u32) -> u32 {
+        let mut root = 0u32;
+        while (root + 1) * (root + 1) <= val {
+            root += 1;
+        }
+        root
+    }
+
+    macro_rules! check_exhaustive_8 {
+        ($($FRAC:literal),*) => {$(
+            for bits in 0..=u8::MAX {
+                let val = FixedU8::<$FRAC>::from_bits(bits);
+                let root = isqrt(u32::from(bits) << $FRAC);
+                let expected = FixedU8::<$FRAC>::from_bits(root as u8);
+                assert_eq!(val.overflowing_sqrt(), (expected, false));
+                assert_eq!(val.checked_sqrt(), Some(expected));
+                assert_eq!(val.saturating_sqrt(), expected);
+                assert_eq!(val.wrapping_sqrt(), expected);
+                assert_eq!(val.unwrapped_sqrt(), expected);
+                assert_eq!(val.sqrt(), expected);
+            }
+            for bits in 0..=i8::MAX {
+                let val = FixedI8::<$FRAC>::from_bits(bits);
+                let root = isqrt((bits as u32) << $FRAC);
+                let wrapped = FixedI8::<$FRAC>::from_bits(root as i8);
+                let overflow = root > i8::MAX as u32;
+                assert_eq!(val.overflowing_sqrt(), (wrapped, overflow));
+                assert_eq!(val.wrapping_sqrt(), wrapped);
+                if overflow {
+                    assert_eq!(val.checked_sqrt(), None);
+                    assert_eq!(val.saturating_sqrt(), FixedI8::<$FRAC>::MAX);
+                } else {
+                    assert_eq!(val.checked_sqrt(), Some(wrapped));
+                    assert_eq!(val.saturating_sqrt(), wrapped);
+                    assert_eq!(val.unwrapped_sqrt(), wrapped);
+                }
+            }
+            for bits in i8::MIN..0 {
+                let val = FixedI8::<$FRAC>::from_bits(bits);
+                assert_eq!(val.checked_sqrt(), None);
+            }
+        )*};
+    }
+
+    #[test]
+    fn exhaustive_8() {
+        check_exhaustive_8!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "square root of negative number")]
+    fn negative_panics() {
+        let _ = FixedI8::<4>::from_num(-1).sqrt();
+    }
+
     #[test]
     fn check_max_8() {
         check_sqrt!(FixedU8::<0>::MAX);
@@ -360,6 +482,60 @@ mod tests {
         assert!(FixedI128::<128>::MAX.checked_sqrt().is_none());
     }
 
+    // Checks that the error of rsqrt is < 2 DELTA, and that overflow is only
+    // reported when the exact value is close to or beyond the maximum.
+    macro_rules! seq_frac {
+        ($check:ident, $Fixed:ident, $bits:ident, $($FRAC:literal),*) => {
+            $($check!($Fixed, $bits, $FRAC);)*
+        };
+    }
+
+    macro_rules! check_rsqrt {
+        ($Fixed:ident, $bits:ident, $FRAC:expr) => {{
+            type F = $Fixed<$FRAC>;
+            let delta = F::DELTA.to_num::<f64>();
+            for bits in 1..=$bits::MAX {
+                let x = F::from_bits(bits);
+                let exact = 1.0 / x.to_num::<f64>().sqrt();
+                match x.checked_rsqrt() {
+                    Some(r) => assert!((r.to_num::<f64>() - exact).abs() < 2.0 * delta),
+                    None => assert!(exact > F::MAX.to_num::<f64>() - 2.0 * delta),
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn rsqrt_error() {
+        seq_frac!(check_rsqrt, FixedU8, u8, 0, 1, 2, 3, 4, 5, 6, 7, 8);
+        seq_frac!(check_rsqrt, FixedI8, i8, 0, 1, 2, 3, 4, 5, 6, 7, 8);
+        seq_frac!(check_rsqrt, FixedU16, u16, 0, 1, 7, 8, 9, 15, 16);
+        seq_frac!(check_rsqrt, FixedI16, i16, 0, 1, 7, 8, 14, 15, 16);
+
+        assert_eq!(FixedU32::<16>::from_num(4).rsqrt(), 0.5);
+        assert_eq!(FixedI64::<32>::from_num(0.0625).rsqrt(), 4);
+        assert_eq!(
+            FixedU128::<64>::from_num(1u64 << 40).rsqrt(),
+            1.0 / f64::from(1 << 20)
+        );
+        let x = FixedU128::<100>::from_num(3);
+        let r = x.rsqrt();
+        let err = (r * r * x).dist(FixedU128::<100>::ONE);
+        assert!(err < FixedU128::<100>::from_bits(8));
+        assert!(FixedI32::<16>::from_num(-1).checked_rsqrt().is_none());
+        assert!(FixedU32::<16>::ZERO.checked_rsqrt().is_none());
+        assert_eq!(
+            FixedU32::<32>::DELTA.saturating_rsqrt(),
+            FixedU32::<32>::MAX
+        );
+        assert_eq!(
+            FixedI32::<30>::DELTA.saturating_rsqrt(),
+            FixedI32::<30>::MAX
+        );
+        assert_eq!(FixedU32::<16>::ZERO.saturating_rsqrt(), FixedU32::<16>::MAX);
+        assert_eq!(FixedI8::<8>::ZERO.saturating_rsqrt(), FixedI8::<8>::MAX);
+    }
+
     #[test]
     fn check_two_8() {
         assert_eq!(FixedU8::<0>::from_num(2).sqrt(), FixedU8::<0>::SQRT_2);
@@ -0,0 +1,100 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+use core::iter::Step;
+
+// Ranges of fixed-point numbers step by DELTA, so they step exactly like ranges
+// of the underlying integers.
+macro_rules! impl_step {
+    ($Fixed:ident($Inner:ident)) => {
+        impl<const FRAC: i32> Step for $Fixed<FRAC> {
+            #[inline]
+            fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+                <$Inner as Step>::steps_between(&start.to_bits(), &end.to_bits())
+            }
+
+            #[inline]
+            fn forward_checked(start: Self, count: usize) -> Option<Self> {
+                match <$Inner as Step>::forward_checked(start.to_bits(), count) {
+                    Some(bits) => Some(Self::from_bits(bits)),
+                    None => None,
+                }
+            }
+
+            #[inline]
+            fn backward_checked(start: Self, count: usize) -> Option<Self> {
+                match <$Inner as Step>::backward_checked(start.to_bits(), count) {
+                    Some(bits) => Some(Self::from_bits(bits)),
+                    None => None,
+                }
+            }
+        }
+    };
+}
+
+impl_step! { FixedI8(i8) }
+impl_step! { FixedI16(i16) }
+impl_step! { FixedI32(i32) }
+impl_step! { FixedI64(i64) }
+impl_step! { FixedI128(i128) }
+impl_step! { FixedU8(u8) }
+impl_step! { FixedU16(u16) }
+impl_step! { FixedU32(u32) }
+impl_step! { FixedU64(u64) }
+impl_step! { FixedU128(u128) }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F8, I16F16, U0F128, U4F4};
+
+    #[test]
+    fn forward_and_reverse() {
+        let start = I16F16::from_num(-1);
+        let end = start + I16F16::DELTA * 4;
+        let mut forward = start..end;
+        let mut reverse = (start..end).rev();
+        for i in 0..4 {
+            assert_eq!(forward.next(), Some(start + I16F16::DELTA * i));
+            assert_eq!(reverse.next(), Some(end - I16F16::DELTA * (i + 1)));
+        }
+        assert_eq!(forward.next(), None);
+        assert_eq!(reverse.next(), None);
+
+        let start = U4F4::from_num(15);
+        let forward = start..=U4F4::MAX;
+        assert_eq!(forward.clone().count(), 16);
+        assert!(forward
+            .rev()
+            .eq((0..16).rev().map(|i| start + U4F4::from_bits(i))));
+        assert_eq!((start..=U4F4::MAX).next_back(), Some(U4F4::MAX));
+    }
+
+    #[test]
+    fn full_range() {
+        assert_eq!((I0F8::MIN..=I0F8::MAX).count(), 256);
+        assert_eq!((I0F8::MIN..=I0F8::MAX).rev().nth(255), Some(I0F8::MIN));
+        assert_eq!(
+            (U0F128::MAX - U0F128::DELTA..=U0F128::MAX).rev().nth(1),
+            Some(U0F128::MAX - U0F128::DELTA)
+        );
+        let mut empty = I16F16::ONE..I16F16::ZERO;
+        assert_eq!(empty.next(), None);
+        assert_eq!(empty.next_back(), None);
+    }
+}
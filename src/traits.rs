@@ -24,8 +24,8 @@ pub use crate::traits_bits::{
 use crate::{
     helpers::Sealed,
     types::extra::{If, True},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8, ParseFixedError,
+    ArithError, BufferTooSmall, FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128,
+    FixedU16, FixedU32, FixedU64, FixedU8, ParseFixedError, Round,
 };
 #[cfg(feature = "arbitrary")]
 use arbitrary::Arbitrary;
@@ -34,6 +34,7 @@ use az::OverflowingCastFrom;
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Contiguous, Pod, TransparentWrapper};
 use core::{
+    cmp::Ordering,
     fmt::{Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex},
     hash::Hash,
     iter::{Product, Sum},
@@ -53,7 +54,7 @@ use half::{bf16 as half_bf16, f16 as half_f16};
 #[cfg(feature = "num-traits")]
 use num_traits::{
     bounds::Bounded,
-    cast::{FromPrimitive, ToPrimitive},
+    cast::{FromPrimitive, NumCast, ToPrimitive},
     float::FloatConst,
     identities::Zero,
     ops::{
@@ -133,7 +134,7 @@ where
 ///   * [`WrappingAdd`], [`WrappingSub`], [`WrappingNeg`],
 ///     [`WrappingMul`], [`WrappingShl`], [`WrappingShr`]
 ///   * [`OverflowingAdd`], [`OverflowingSub`], [`OverflowingMul`]
-///   * [`ToPrimitive`], [`FromPrimitive`]
+///   * [`ToPrimitive`], [`FromPrimitive`], [`NumCast`]
 ///   * [`FloatConst`]
 ///
 /// The following are supertraits of [`FixedBoundFrac`] as they have extra
@@ -150,6 +151,9 @@ where
 ///   * [`One`] and [`ConstOne`] because not all fixed-point numbers can
 ///     represent the value 1
 ///   * [`Num`] because it has [`One`] as a supertrait
+///   * [`PrimInt`] because it has [`Num`] as a supertrait
+///   * [`Saturating`] because it is superseded by [`SaturatingAdd`] and
+///     [`SaturatingSub`]
 ///   * [`MulAdd`], [`MulAddAssign`] because
 ///     <code>[MulAdd][`MulAdd`]::[mul\_add][`mul_add`]</code> conflicts with
 ///     <code>[Fixed]::[mul\_add][Fixed::mul_add]</code>
@@ -166,6 +170,8 @@ where
 /// [`MulAdd`]: num_traits::ops::mul_add::MulAdd
 /// [`Num`]: num_traits::Num
 /// [`One`]: num_traits::identities::One
+/// [`PrimInt`]: num_traits::int::PrimInt
+/// [`Saturating`]: num_traits::ops::saturating::Saturating
 /// [`Signed`]: num_traits::sign::Signed
 /// [`ToBytes`]: num_traits::ops::bytes::ToBytes
 /// [`Unsigned`]: num_traits::sign::Unsigned
@@ -188,7 +194,7 @@ pub trait FixedOptionalNum: Sealed {}
 ///   * [`WrappingAdd`], [`WrappingSub`], [`WrappingNeg`],
 ///     [`WrappingMul`], [`WrappingShl`], [`WrappingShr`]
 ///   * [`OverflowingAdd`], [`OverflowingSub`], [`OverflowingMul`]
-///   * [`ToPrimitive`], [`FromPrimitive`]
+///   * [`ToPrimitive`], [`FromPrimitive`], [`NumCast`]
 ///   * [`FloatConst`]
 ///
 /// The following are supertraits of [`FixedBoundFrac`] as they have extra
@@ -205,6 +211,9 @@ pub trait FixedOptionalNum: Sealed {}
 ///   * [`One`] and [`ConstOne`] because not all fixed-point numbers can
 ///     represent the value 1
 ///   * [`Num`] because it has [`One`] as a supertrait
+///   * [`PrimInt`] because it has [`Num`] as a supertrait
+///   * [`Saturating`] because it is superseded by [`SaturatingAdd`] and
+///     [`SaturatingSub`]
 ///   * [`MulAdd`], [`MulAddAssign`] because
 ///     <code>[MulAdd][`MulAdd`]::[mul\_add][`mul_add`]</code> conflicts with
 ///     <code>[Fixed]::[mul\_add][Fixed::mul_add]</code>
@@ -221,6 +230,8 @@ pub trait FixedOptionalNum: Sealed {}
 /// [`MulAdd`]: num_traits::ops::mul_add::MulAdd
 /// [`Num`]: num_traits::Num
 /// [`One`]: num_traits::identities::One
+/// [`PrimInt`]: num_traits::int::PrimInt
+/// [`Saturating`]: num_traits::ops::saturating::Saturating
 /// [`Signed`]: num_traits::sign::Signed
 /// [`ToBytes`]: num_traits::ops::bytes::ToBytes
 /// [`Unsigned`]: num_traits::sign::Unsigned
@@ -235,7 +246,7 @@ where
     Self: WrappingAdd + WrappingSub + WrappingNeg + WrappingMul,
     Self: WrappingShl + WrappingShr,
     Self: OverflowingAdd + OverflowingSub + OverflowingMul,
-    Self: ToPrimitive + FromPrimitive + FloatConst,
+    Self: ToPrimitive + FromPrimitive + NumCast + FloatConst,
 {
 }
 
@@ -350,6 +361,43 @@ where
 /// assert_eq!(val2, Some(Fixed::from_num(8.5)));
 /// ```
 ///
+/// Since [`Ord`] is a supertrait of `Fixed`, the [`min`][Ord::min],
+/// [`max`][Ord::max] and [`clamp`][Ord::clamp] methods can be used in generic
+/// code without naming a concrete type. There is no separate `saturate` method:
+/// to clamp a value of another type into the representable range, use
+/// [`saturating_from_num`][Fixed::saturating_from_num], which returns
+/// [`MIN`][Fixed::MIN] or [`MAX`][Fixed::MAX] if the value does not fit.
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::{
+///     traits::Fixed,
+///     types::{I8F8, U16F16},
+/// };
+///
+/// fn limit<F: Fixed>(val: F, lo: F, hi: F) -> F {
+///     val.clamp(lo, hi)
+/// }
+///
+/// fn saturate<F: Fixed>(val: f64) -> F {
+///     F::saturating_from_num(val)
+/// }
+///
+/// let lo = I8F8::from_num(-1.5);
+/// let hi = I8F8::from_num(2.25);
+/// assert_eq!(limit(I8F8::from_num(-3), lo, hi), lo);
+/// assert_eq!(limit(I8F8::from_num(0.5), lo, hi), 0.5);
+/// assert_eq!(limit(I8F8::from_num(7), lo, hi), hi);
+/// let (lo, hi) = (U16F16::from_num(1), U16F16::from_num(4));
+/// assert_eq!(limit(U16F16::ZERO, lo, hi), lo);
+/// assert_eq!(limit(U16F16::MAX, lo, hi), hi);
+/// assert_eq!(saturate::<U16F16>(-2.0), U16F16::ZERO);
+/// assert_eq!(saturate::<I8F8>(1e6), I8F8::MAX);
+/// assert_eq!(saturate::<I8F8>(0.5), 0.5);
+/// ```
+///
 /// The following example fails to compile, since the compiler cannot
 /// infer that 500 in the `checked_mul_int` call is of type `F::Bits`.
 ///
@@ -732,6 +780,15 @@ where
     /// <code>FixedU32::[from\_bits][FixedU32::from_bits]</code>.
     fn from_bits(bits: Self::Bits) -> Self;
 
+    /// Creates a fixed-point number from its bitwise representation, checking
+    /// that reserved bits are clear.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_bits\_masked][FixedI32::from_bits_masked]</code>
+    /// and
+    /// <code>FixedU32::[from\_bits\_masked][FixedU32::from_bits_masked]</code>.
+    fn from_bits_masked(bits: Self::Bits, reserved_mask: Self::Bits) -> Option<Self>;
+
     /// Creates an integer that has a bitwise representation identical
     /// to the given fixed-point number.
     ///
@@ -765,6 +822,20 @@ where
     #[must_use]
     fn to_le(self) -> Self;
 
+    /// Returns the underlying integer representation in big endian.
+    ///
+    /// See also <code>FixedI32::[to\_bits\_be][FixedI32::to_bits_be]</code>
+    /// and <code>FixedU32::[to\_bits\_be][FixedU32::to_bits_be]</code>.
+    #[must_use]
+    fn to_bits_be(self) -> Self::Bits;
+
+    /// Returns the underlying integer representation in little endian.
+    ///
+    /// See also <code>FixedI32::[to\_bits\_le][FixedI32::to_bits_le]</code>
+    /// and <code>FixedU32::[to\_bits\_le][FixedU32::to_bits_le]</code>.
+    #[must_use]
+    fn to_bits_le(self) -> Self::Bits;
+
     ///Reverses the byte order of the fixed-point number.
     ///
     /// See also <code>FixedI32::[swap\_bytes][FixedI32::swap_bytes]</code> and
@@ -817,6 +888,33 @@ where
     /// and <code>FixedU32::[to\_ne\_bytes][FixedU32::to_ne_bytes]</code>.
     fn to_ne_bytes(self) -> [u8; size_of::<Self>()];
 
+    /// Returns a canonical 16-byte representation of this fixed-point number,
+    /// with the big-endian bytes zero-padded at the start.
+    ///
+    /// The representation is only unique among numbers of a single type.
+    ///
+    /// See also
+    /// <code>FixedI32::[to\_canonical\_bytes][FixedI32::to_canonical_bytes]</code>
+    /// and
+    /// <code>FixedU32::[to\_canonical\_bytes][FixedU32::to_canonical_bytes]</code>.
+    fn to_canonical_bytes(self) -> [u8; 16];
+
+    /// Writes the memory representation of a slice of fixed-point numbers as
+    /// a contiguous sequence of bytes in big-endian byte order.
+    ///
+    /// See also
+    /// <code>FixedI32::[write\_be\_slice][FixedI32::write_be_slice]</code> and
+    /// <code>FixedU32::[write\_be\_slice][FixedU32::write_be_slice]</code>.
+    fn write_be_slice(values: &[Self], out: &mut [u8]) -> Result<usize, BufferTooSmall>;
+
+    /// Writes the memory representation of a slice of fixed-point numbers as
+    /// a contiguous sequence of bytes in little-endian byte order.
+    ///
+    /// See also
+    /// <code>FixedI32::[write\_le\_slice][FixedI32::write_le_slice]</code> and
+    /// <code>FixedU32::[write\_le\_slice][FixedU32::write_le_slice]</code>.
+    fn write_le_slice(values: &[Self], out: &mut [u8]) -> Result<usize, BufferTooSmall>;
+
     /// Creates a fixed-point number from another number.
     ///
     /// Returns the same value as
@@ -858,6 +956,96 @@ where
     /// <code>FixedU32::[checked\_to\_num][FixedU32::checked_to_num]</code>.
     fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst>;
 
+    /// Returns [`true`] if the fixed-point number can be converted to another
+    /// number without overflow.
+    ///
+    /// Returns the same value as
+    /// <code>Dst::[checked\_from\_fixed][FromFixed::checked_from_fixed]\(self).[is\_some][Option::is_some]\()</code>.
+    ///
+    /// See also <code>FixedI32::[fits\_in][FixedI32::fits_in]</code> and
+    /// <code>FixedU32::[fits\_in][FixedU32::fits_in]</code>.
+    fn fits_in<Dst: FromFixed>(self) -> bool;
+
+    /// Returns [`true`] if the fixed-point number can be converted to another
+    /// number and back without changing its value.
+    ///
+    /// See also
+    /// <code>FixedI32::[roundtrips\_through][FixedI32::roundtrips_through]</code>
+    /// and
+    /// <code>FixedU32::[roundtrips\_through][FixedU32::roundtrips_through]</code>.
+    fn roundtrips_through<Dst: FromFixed + ToFixed>(self) -> bool;
+
+    /// Converts the fixed-point number to [`f64`] and scales it by 10 to the
+    /// power of `decimal_exp`.
+    ///
+    /// See also
+    /// <code>FixedI32::[to\_f64\_scaled][FixedI32::to_f64_scaled]</code> and
+    /// <code>FixedU32::[to\_f64\_scaled][FixedU32::to_f64_scaled]</code>.
+    #[must_use]
+    fn to_f64_scaled(self, decimal_exp: i32) -> f64;
+
+    /// Creates a fixed-point number from another number if it fits,
+    /// otherwise returns `default`.
+    ///
+    /// Returns the same value as
+    /// <code>src.[checked\_to\_fixed][ToFixed::checked_to_fixed]\().[unwrap\_or][Option::unwrap_or]\(default)</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_or][FixedI32::from_num_or]</code> and
+    /// <code>FixedU32::[from\_num\_or][FixedU32::from_num_or]</code>.
+    fn from_num_or<Src: ToFixed>(src: Src, default: Self) -> Self;
+
+    /// Creates a fixed-point number from an optional number.
+    ///
+    /// Returns the same value as
+    /// <code>src.[map][Option::map]\(|x| x.[to\_fixed][ToFixed::to_fixed]\())</code>.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_opt][FixedI32::from_num_opt]</code> and
+    /// <code>FixedU32::[from\_num\_opt][FixedU32::from_num_opt]</code>.
+    #[track_caller]
+    fn from_num_opt<Src: ToFixed>(src: Option<Src>) -> Option<Self>;
+
+    /// Creates a fixed-point number from an [`f64`], rounding non-zero values
+    /// that are too small to be represented away from zero instead of to
+    /// zero.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_nonzero][FixedI32::from_num_nonzero]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_nonzero][FixedU32::from_num_nonzero]</code>.
+    #[track_caller]
+    fn from_num_nonzero(src: f64) -> Self;
+
+    /// Creates a fixed-point number from an [`f64`], rounding with a rounding
+    /// mode chosen at run time.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_dyn][FixedI32::from_num_dyn]</code> and
+    /// <code>FixedU32::[from\_num\_dyn][FixedU32::from_num_dyn]</code>.
+    #[track_caller]
+    fn from_num_dyn(src: f64, mode: Round) -> Self;
+
+    /// Creates a fixed-point number from another fixed-point number, rounding
+    /// with the given rounding mode.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_round][FixedI32::from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_round][FixedU32::from_num_round]</code>.
+    #[track_caller]
+    fn from_num_round<Src: Fixed>(src: Src, mode: Round) -> Self;
+
+    /// Converts a fixed-point number to another fixed-point number, rounding
+    /// with the given rounding mode.
+    ///
+    /// See also
+    /// <code>FixedI32::[to\_num\_round][FixedI32::to_num_round]</code> and
+    /// <code>FixedU32::[to\_num\_round][FixedU32::to_num_round]</code>.
+    #[track_caller]
+    #[must_use]
+    fn to_num_round<Dst: Fixed>(self, mode: Round) -> Dst;
+
     /// Creates a fixed-point number from another number, saturating the
     /// value if it does not fit.
     ///
@@ -1267,6 +1455,25 @@ where
     /// <code>FixedU32::[trailing\_zeros][FixedU32::trailing_zeros]</code>.
     fn trailing_zeros(self) -> u32;
 
+    /// Returns the number of leading bits that are redundant in all the
+    /// elements of a slice, which is the common shift used in block floating
+    /// point.
+    ///
+    /// See also
+    /// <code>FixedI32::[block\_leading\_zeros][FixedI32::block_leading_zeros]</code>
+    /// and
+    /// <code>FixedU32::[block\_leading\_zeros][FixedU32::block_leading_zeros]</code>.
+    fn block_leading_zeros(values: &[Self]) -> u32;
+
+    /// Shifts all the elements of a slice left by the same amount in place,
+    /// saturating the elements that overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[shift\_block\_left][FixedI32::shift_block_left]</code>
+    /// and
+    /// <code>FixedU32::[shift\_block\_left][FixedU32::shift_block_left]</code>.
+    fn shift_block_left(values: &mut [Self], n: u32);
+
     /// Integer base-2 logarithm, rounded down.
     ///
     /// See also <code>FixedI32::[int\_log2][FixedI32::int_log2]</code> and
@@ -1289,6 +1496,30 @@ where
     #[doc(alias("checked_ilog2"))]
     fn checked_int_log2(self) -> Option<i32>;
 
+    /// Integer square root, rounded down.
+    ///
+    /// See also <code>FixedI32::[int\_sqrt][FixedI32::int_sqrt]</code> and
+    /// <code>FixedU32::[int\_sqrt][FixedU32::int_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed-point number is negative.
+    #[doc(alias("isqrt"))]
+    #[track_caller]
+    #[must_use]
+    fn int_sqrt(self) -> Self;
+
+    /// Checked integer square root, rounded down. Returns the square root or
+    /// [`None`] if the fixed-point number is negative.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_int\_sqrt][FixedI32::checked_int_sqrt]</code>
+    /// and
+    /// <code>FixedU32::[checked\_int\_sqrt][FixedU32::checked_int_sqrt]</code>.
+    #[doc(alias("checked_isqrt"))]
+    #[must_use]
+    fn checked_int_sqrt(self) -> Option<Self>;
+
     /// Reverses the order of the bits of the fixed-point number.
     ///
     /// See also <code>FixedI32::[reverse\_bits][FixedI32::reverse_bits]</code>
@@ -1310,12 +1541,42 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn rotate_right(self, n: u32) -> Self;
 
+    /// Shifts to the right by `n` bits, filling the vacated bits on the left
+    /// with zeros even for signed numbers.
+    ///
+    /// See also <code>FixedI32::[logical\_shr][FixedI32::logical_shr]</code>
+    /// and <code>FixedU32::[logical\_shr][FixedU32::logical_shr]</code>.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn logical_shr(self, n: u32) -> Self;
+
     /// Returns [`true`] if the number is zero.
     ///
     /// See also <code>FixedI32::[is\_zero][FixedI32::is_zero]</code> and
     /// <code>FixedU32::[is\_zero][FixedU32::is_zero]</code>.
     fn is_zero(self) -> bool;
 
+    /// Returns [`true`] if the number is normalized, that is if
+    /// &minus;1&nbsp;≤&nbsp;`self`&nbsp;<&nbsp;1 for signed numbers or
+    /// 0&nbsp;≤&nbsp;`self`&nbsp;<&nbsp;1 for unsigned numbers.
+    ///
+    /// See also <code>FixedI32::[is\_normalized][FixedI32::is_normalized]</code>
+    /// and <code>FixedU32::[is\_normalized][FixedU32::is_normalized]</code>.
+    fn is_normalized(self) -> bool;
+
+    /// Returns the sign of `self` as an integer of the underlying type.
+    ///
+    /// See also <code>FixedI32::[signum\_int][FixedI32::signum_int]</code>
+    /// and <code>FixedU32::[signum\_int][FixedU32::signum_int]</code>.
+    fn signum_int(self) -> Self::Bits;
+
+    /// Returns the sign of `self` as an [`Ordering`] compared to zero.
+    ///
+    /// See also <code>FixedI32::[sign][FixedI32::sign]</code> and
+    /// <code>FixedU32::[sign][FixedU32::sign]</code>.
+    #[must_use]
+    fn sign(self) -> Ordering;
+
     /// Returns the distance from `self` to `other`.
     ///
     /// See also <code>FixedI32::[dist][FixedI32::dist]</code> and
@@ -1323,6 +1584,15 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn dist(self, other: Self) -> Self;
 
+    /// Returns the positive difference between `self` and `other`.
+    ///
+    /// See also
+    /// <code>FixedI32::[positive\_diff][FixedI32::positive_diff]</code> and
+    /// <code>FixedU32::[positive\_diff][FixedU32::positive_diff]</code>.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn positive_diff(self, other: Self) -> Self;
+
     /// Returns the absolute value of the difference between `self` and `other`
     /// using an unsigned type without any wrapping or panicking.
     ///
@@ -1331,6 +1601,44 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn abs_diff(self, other: Self) -> Self::Unsigned;
 
+    /// Returns the number of representable steps between `self` and `other`.
+    ///
+    /// See also
+    /// <code>FixedI32::[ulps\_between][FixedI32::ulps_between]</code> and
+    /// <code>FixedU32::[ulps\_between][FixedU32::ulps_between]</code>.
+    #[must_use]
+    fn ulps_between(self, other: Self) -> <Self::Unsigned as Fixed>::Bits;
+
+    /// Returns the next representable number after `self`, saturating at
+    /// [`MAX`][Self::MAX].
+    ///
+    /// See also <code>FixedI32::[next\_up][FixedI32::next_up]</code> and
+    /// <code>FixedU32::[next\_up][FixedU32::next_up]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_up(self) -> Self;
+
+    /// Returns the previous representable number before `self`, saturating at
+    /// [`MIN`][Self::MIN].
+    ///
+    /// See also <code>FixedI32::[next\_down][FixedI32::next_down]</code> and
+    /// <code>FixedU32::[next\_down][FixedU32::next_down]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn next_down(self) -> Self;
+
+    /// Increments by [`DELTA`][Self::DELTA], wrapping on overflow.
+    ///
+    /// See also <code>FixedI32::[inc][FixedI32::inc]</code> and
+    /// <code>FixedU32::[inc][FixedU32::inc]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn inc(self) -> Self;
+
+    /// Decrements by [`DELTA`][Self::DELTA], wrapping on overflow.
+    ///
+    /// See also <code>FixedI32::[dec][FixedI32::dec]</code> and
+    /// <code>FixedU32::[dec][FixedU32::dec]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn dec(self) -> Self;
+
     /// Returns the mean of `self` and `other`.
     ///
     /// See also <code>FixedI32::[mean][FixedI32::mean]</code> and
@@ -1345,6 +1653,22 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn hypot(self, other: Self) -> Self;
 
+    /// Computes the Euclidean norm of a vector.
+    ///
+    /// See also <code>FixedI32::[norm][FixedI32::norm]</code> and
+    /// <code>FixedU32::[norm][FixedU32::norm]</code>.
+    #[track_caller]
+    #[must_use]
+    fn norm(values: &[Self]) -> Self;
+
+    /// Approximates the hypotenuse of a right triangle with a relative error
+    /// within about 4%.
+    ///
+    /// See also <code>FixedI32::[fast\_hypot][FixedI32::fast_hypot]</code> and
+    /// <code>FixedU32::[fast\_hypot][FixedU32::fast_hypot]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn fast_hypot(self, other: Self) -> Self;
+
     /// Returns the next multiple of `other`.
     ///
     /// See also
@@ -1359,6 +1683,19 @@ where
     #[must_use]
     fn next_multiple_of(self, other: Self) -> Self;
 
+    /// Square. Returns `self` × `self`.
+    ///
+    /// See also <code>FixedI32::[square][FixedI32::square]</code> and
+    /// <code>FixedU32::[square][FixedU32::square]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn square(self) -> Self;
+
     /// Multiply and add. Returns `self` × `mul` + `add`.
     ///
     /// See also <code>FixedI32::[mul\_add][FixedI32::mul_add]</code> and
@@ -1370,6 +1707,50 @@ where
         add: Self,
     ) -> Self;
 
+    /// Multiply and subtract. Returns `self` × `mul` &minus; `sub`.
+    ///
+    /// See also
+    /// <code>FixedI32::[mul\_sub][FixedI32::mul_sub]</code>
+    /// and
+    /// <code>FixedU32::[mul\_sub][FixedU32::mul_sub]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_sub<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        sub: Self,
+    ) -> Self;
+
+    /// Multiply and divide. Returns `self` × `mul` / `div`.
+    ///
+    /// See also
+    /// <code>FixedI32::[mul\_div][FixedI32::mul_div]</code>
+    /// and
+    /// <code>FixedU32::[mul\_div][FixedU32::mul_div]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        div: <Self::Bits as FixedBits>::Fixed<DIV_FRAC>,
+    ) -> Self;
+
+    /// Negated multiply and add. Returns `add` &minus; `self` × `mul`.
+    ///
+    /// See also
+    /// <code>FixedI32::[neg\_mul\_add][FixedI32::neg_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[neg\_mul\_add][FixedU32::neg_mul_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn neg_mul_add<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        add: Self,
+    ) -> Self;
+
     /// Adds `self` to the product `a`&nbsp;×&nbsp;`b`.
     ///
     /// See also <code>FixedI32::[add\_prod][FixedI32::add_prod]</code> and
@@ -1381,6 +1762,34 @@ where
         b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
     ) -> Self;
 
+    /// Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`.
+    ///
+    /// See also
+    /// <code>FixedI32::[add\_prod2][FixedI32::add_prod2]</code>
+    /// and
+    /// <code>FixedU32::[add\_prod2][FixedU32::add_prod2]</code>.
+    #[must_use]
+    fn add_prod2<const A_FRAC: i32, const B_FRAC: i32, const C_FRAC: i32, const D_FRAC: i32>(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+        c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+        d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+    ) -> Self;
+
+    /// Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`.
+    ///
+    /// See also
+    /// <code>FixedI32::[sub\_prod][FixedI32::sub_prod]</code>
+    /// and
+    /// <code>FixedU32::[sub\_prod][FixedU32::sub_prod]</code>.
+    #[must_use]
+    fn sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+    ) -> Self;
+
     /// Multiply and accumulate. Adds (`a` × `b`) to `self`.
     ///
     /// See also <code>FixedI32::[mul\_acc][FixedI32::mul_acc]</code> and
@@ -1403,6 +1812,104 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn rem_euclid(self, rhs: Self) -> Self;
 
+    /// Remainder for Euclidean division by a power of two. Returns `self`
+    /// modulo 2<sup>`exp`</sup>.
+    ///
+    /// See also <code>FixedI32::[rem\_pow2][FixedI32::rem_pow2]</code> and
+    /// <code>FixedU32::[rem\_pow2][FixedU32::rem_pow2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn rem_pow2(self, exp: u32) -> Self;
+
+    /// Returns [`true`] if `self` is an integer multiple of `other`.
+    ///
+    /// See also
+    /// <code>FixedI32::[is\_multiple\_of][FixedI32::is_multiple_of]</code>
+    /// and
+    /// <code>FixedU32::[is\_multiple\_of][FixedU32::is_multiple_of]</code>.
+    #[must_use]
+    fn is_multiple_of(self, other: Self) -> bool;
+
+    /// Rounds to `frac_bits` fractional bits using the given rounding mode.
+    ///
+    /// See also
+    /// <code>FixedI32::[round\_to\_frac\_round][FixedI32::round_to_frac_round]</code>
+    /// and
+    /// <code>FixedU32::[round\_to\_frac\_round][FixedU32::round_to_frac_round]</code>.
+    #[track_caller]
+    #[must_use]
+    fn round_to_frac_round(self, frac_bits: i32, mode: Round) -> Self;
+
+    /// Rounds to the nearest multiple of 2<sup>&minus;`frac_bits`</sup>,
+    /// with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[round\_to\_frac][FixedI32::round_to_frac]</code> and
+    /// <code>FixedU32::[round\_to\_frac][FixedU32::round_to_frac]</code>.
+    #[track_caller]
+    #[must_use]
+    fn round_to_frac(self, frac_bits: i32) -> Self;
+
+    /// Rounds up to the next multiple of 2<sup>&minus;`frac_bits`</sup>.
+    ///
+    /// See also
+    /// <code>FixedI32::[ceil\_to\_frac][FixedI32::ceil_to_frac]</code> and
+    /// <code>FixedU32::[ceil\_to\_frac][FixedU32::ceil_to_frac]</code>.
+    #[track_caller]
+    #[must_use]
+    fn ceil_to_frac(self, frac_bits: i32) -> Self;
+
+    /// Rounds down to the previous multiple of
+    /// 2<sup>&minus;`frac_bits`</sup>.
+    ///
+    /// See also
+    /// <code>FixedI32::[floor\_to\_frac][FixedI32::floor_to_frac]</code> and
+    /// <code>FixedU32::[floor\_to\_frac][FixedU32::floor_to_frac]</code>.
+    #[track_caller]
+    #[must_use]
+    fn floor_to_frac(self, frac_bits: i32) -> Self;
+
+    /// Rounds towards zero to a multiple of
+    /// 2<sup>&minus;`frac_bits`</sup>.
+    ///
+    /// See also
+    /// <code>FixedI32::[trunc\_to\_frac][FixedI32::trunc_to_frac]</code> and
+    /// <code>FixedU32::[trunc\_to\_frac][FixedU32::trunc_to_frac]</code>.
+    #[track_caller]
+    #[must_use]
+    fn trunc_to_frac(self, frac_bits: i32) -> Self;
+
+    /// Division by an integer, returning both the quotient and the remainder.
+    ///
+    /// See also
+    /// <code>FixedI32::[div\_rem\_int][FixedI32::div_rem_int]</code> and
+    /// <code>FixedU32::[div\_rem\_int][FixedU32::div_rem_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_rem_int(self, rhs: Self::Bits) -> (Self, Self);
+
+    /// Multiplies by a power of ten. Returns `self`&nbsp;×&nbsp;10<sup>`exp`</sup>.
+    ///
+    /// If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+    /// result is rounded to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[scale\_pow10][FixedI32::scale_pow10]</code>
+    /// and
+    /// <code>FixedU32::[scale\_pow10][FixedU32::scale_pow10]</code>.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn scale_pow10(self, exp: i32) -> Self;
+
     /// Checked negation. Returns the negated value, or [`None`] on overflow.
     ///
     /// See also <code>FixedI32::[checked\_neg][FixedI32::checked_neg]</code>
@@ -1416,6 +1923,19 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_add(self, rhs: Self) -> Option<Self>;
 
+    /// Sums the values of an iterator with overflow detection. Returns the
+    /// sum, or [`ArithError`] on overflow.
+    ///
+    /// See also <code>FixedI32::[try\_sum][FixedI32::try_sum]</code> and
+    /// <code>FixedU32::[try\_sum][FixedU32::try_sum]</code>.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithError`] if an intermediate sum overflows.
+    fn try_sum<I>(iter: I) -> Result<Self, ArithError>
+    where
+        I: IntoIterator<Item = Self>;
+
     /// Checked subtraction. Returns the difference, or [`None`] on overflow.
     ///
     /// See also <code>FixedI32::[checked\_sub][FixedI32::checked_sub]</code>
@@ -1430,6 +1950,13 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_mul(self, rhs: Self) -> Option<Self>;
 
+    /// Checked square. Returns `self` × `self`, or [`None`] on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_square][FixedI32::checked_square]</code>
+    /// and <code>FixedU32::[checked\_square][FixedU32::checked_square]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_square(self) -> Option<Self>;
+
     /// Checked remainder. Returns the remainder, or [`None`] if the
     /// divisor is zero.
     ///
@@ -1461,7 +1988,49 @@ where
         add: Self,
     ) -> Option<Self>;
 
-    /// Adds `self` to the product `a`&nbsp;×&nbsp;`b`, returning [`None`] on overflow.
+    /// Checked multiply and subtract. Returns `self` × `mul` &minus; `sub`,
+    /// or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_sub][FixedI32::checked_mul_sub]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_sub][FixedU32::checked_mul_sub]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_sub<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        sub: Self,
+    ) -> Option<Self>;
+
+    /// Checked multiply and divide. Returns `self` × `mul` / `div`, or
+    /// [`None`] if `div` is zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_div][FixedI32::checked_mul_div]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_div][FixedU32::checked_mul_div]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        div: <Self::Bits as FixedBits>::Fixed<DIV_FRAC>,
+    ) -> Option<Self>;
+
+    /// Checked negated multiply and add. Returns `add` &minus; `self` ×
+    /// `mul`, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_neg\_mul\_add][FixedI32::checked_neg_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[checked\_neg\_mul\_add][FixedU32::checked_neg_mul_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_neg_mul_add<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        add: Self,
+    ) -> Option<Self>;
+
+    /// Adds `self` to the product `a`&nbsp;×&nbsp;`b`, returning [`None`] on overflow.
     ///
     /// See also
     /// <code>FixedI32::[checked\_add\_prod][FixedI32::checked_add_prod]</code>
@@ -1474,6 +2043,41 @@ where
         b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
     ) -> Option<Self>;
 
+    /// Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`,
+    /// returning [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_add\_prod2][FixedI32::checked_add_prod2]</code>
+    /// and
+    /// <code>FixedU32::[checked\_add\_prod2][FixedU32::checked_add_prod2]</code>.
+    #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
+    fn checked_add_prod2<
+        const A_FRAC: i32,
+        const B_FRAC: i32,
+        const C_FRAC: i32,
+        const D_FRAC: i32,
+    >(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+        c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+        d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+    ) -> Option<Self>;
+
+    /// Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`, returning
+    /// [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sub\_prod][FixedI32::checked_sub_prod]</code>
+    /// and
+    /// <code>FixedU32::[checked\_sub\_prod][FixedU32::checked_sub_prod]</code>.
+    #[must_use]
+    fn checked_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+    ) -> Option<Self>;
+
     /// Checked multiply and accumulate. Adds (`a` × `b`) to `self`, or returns
     /// [`None`] on overflow.
     ///
@@ -1488,6 +2092,26 @@ where
         b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
     ) -> Option<()>;
 
+    /// Checked multiply and accumulate over slices. Adds the sum of the
+    /// products `a[i]`&nbsp;×&nbsp;`b[i]` to `self`, or returns [`None`] on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_acc\_slice][FixedI32::checked_mul_acc_slice]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_acc\_slice][FixedU32::checked_mul_acc_slice]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[track_caller]
+    #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
+    fn checked_mul_acc_slice<const A_FRAC: i32, const B_FRAC: i32>(
+        &mut self,
+        a: &[<Self::Bits as FixedBits>::Fixed<A_FRAC>],
+        b: &[<Self::Bits as FixedBits>::Fixed<B_FRAC>],
+    ) -> Option<()>;
+
     /// Checked remainder for Euclidean division. Returns the
     /// remainder, or [`None`] if the divisor is zero.
     ///
@@ -1519,6 +2143,30 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_div_int(self, rhs: Self::Bits) -> Option<Self>;
 
+    /// Checked division by an integer, returning both the quotient and the
+    /// remainder. Returns [`None`] if the divisor is zero or if the division
+    /// results in overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_div\_rem\_int][FixedI32::checked_div_rem_int]</code>
+    /// and
+    /// <code>FixedU32::[checked\_div\_rem\_int][FixedU32::checked_div_rem_int]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_div_rem_int(self, rhs: Self::Bits) -> Option<(Self, Self)>;
+
+    /// Checked multiplication by a power of ten. Returns
+    /// `self`&nbsp;×&nbsp;10<sup>`exp`</sup>, or [`None`] on overflow.
+    ///
+    /// If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+    /// result is rounded to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_scale\_pow10][FixedI32::checked_scale_pow10]</code>
+    /// and
+    /// <code>FixedU32::[checked\_scale\_pow10][FixedU32::checked_scale_pow10]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_scale_pow10(self, exp: i32) -> Option<Self>;
+
     /// Checked shift left. Returns the shifted number, or [`None`] if
     /// `rhs`&nbsp;≥ the number of bits.
     ///
@@ -1543,6 +2191,16 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_dist(self, other: Self) -> Option<Self>;
 
+    /// Checked positive difference. Returns the positive difference between
+    /// `self` and `other`, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_positive\_diff][FixedI32::checked_positive_diff]</code>
+    /// and
+    /// <code>FixedU32::[checked\_positive\_diff][FixedU32::checked_positive_diff]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_positive_diff(self, other: Self) -> Option<Self>;
+
     /// Compute the hypotenuse of a right triange, returning [`None`] on
     /// overflow.
     ///
@@ -1552,6 +2210,32 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_hypot(self, other: Self) -> Option<Self>;
 
+    /// Checked Euclidean norm of a vector, returning [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_norm][FixedI32::checked_norm]</code> and
+    /// <code>FixedU32::[checked\_norm][FixedU32::checked_norm]</code>.
+    #[must_use]
+    fn checked_norm(values: &[Self]) -> Option<Self>;
+
+    /// Checked increment. Returns `self`&nbsp;+&nbsp;[`DELTA`][Self::DELTA],
+    /// or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_inc][FixedI32::checked_inc]</code> and
+    /// <code>FixedU32::[checked\_inc][FixedU32::checked_inc]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_inc(self) -> Option<Self>;
+
+    /// Checked decrement. Returns
+    /// `self`&nbsp;&minus;&nbsp;[`DELTA`][Self::DELTA], or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_dec][FixedI32::checked_dec]</code> and
+    /// <code>FixedU32::[checked\_dec][FixedU32::checked_dec]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_dec(self) -> Option<Self>;
+
     /// Saturated negation. Returns the negated value, saturating on overflow.
     ///
     /// See also
@@ -1584,6 +2268,15 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn saturating_mul(self, rhs: Self) -> Self;
 
+    /// Saturating square. Returns `self` × `self`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_square][FixedI32::saturating_square]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_square][FixedU32::saturating_square]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_square(self) -> Self;
+
     /// Saturating next multiple of `other`.
     ///
     /// See also
@@ -1611,6 +2304,74 @@ where
         add: Self,
     ) -> Self;
 
+    /// Saturating multiply and subtract. Returns `self` × `mul` &minus;
+    /// `sub`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_sub][FixedI32::saturating_mul_sub]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_sub][FixedU32::saturating_mul_sub]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_sub<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        sub: Self,
+    ) -> Self;
+
+    /// Saturating multiply and divide. Returns `self` × `mul` / `div`,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_mul\_div][FixedI32::saturating_mul_div]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_mul\_div][FixedU32::saturating_mul_div]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        div: <Self::Bits as FixedBits>::Fixed<DIV_FRAC>,
+    ) -> Self;
+
+    /// Saturating negated multiply and add. Returns `add` &minus; `self` ×
+    /// `mul`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_neg\_mul\_add][FixedI32::saturating_neg_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_neg\_mul\_add][FixedU32::saturating_neg_mul_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_neg_mul_add<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        add: Self,
+    ) -> Self;
+
+    /// Multiply and add, clamped to a range. Returns `self` × `mul` + `add`,
+    /// clamped to the range `lo`&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;`hi`.
+    ///
+    /// See also
+    /// <code>FixedI32::[clamped\_mul\_add][FixedI32::clamped_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[clamped\_mul\_add][FixedU32::clamped_mul_add]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo`&nbsp;>&nbsp;`hi`.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn clamped_mul_add<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        add: Self,
+        lo: Self,
+        hi: Self,
+    ) -> Self;
+
     /// Adds `self` to the product `a`&nbsp;×&nbsp;`b`, saturating on overflow.
     ///
     /// See also
@@ -1624,6 +2385,41 @@ where
         b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
     ) -> Self;
 
+    /// Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_add\_prod2][FixedI32::saturating_add_prod2]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_add\_prod2][FixedU32::saturating_add_prod2]</code>.
+    #[must_use]
+    fn saturating_add_prod2<
+        const A_FRAC: i32,
+        const B_FRAC: i32,
+        const C_FRAC: i32,
+        const D_FRAC: i32,
+    >(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+        c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+        d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+    ) -> Self;
+
+    /// Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`, saturating on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sub\_prod][FixedI32::saturating_sub_prod]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_sub\_prod][FixedU32::saturating_sub_prod]</code>.
+    #[must_use]
+    fn saturating_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+    ) -> Self;
+
     /// Saturating multiply and add. Adds (`a` × `b`) to `self`, saturating on overflow.
     ///
     /// See also
@@ -1661,6 +2457,19 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn saturating_div_int(self, rhs: Self::Bits) -> Self;
 
+    /// Saturating multiplication by a power of ten. Returns
+    /// `self`&nbsp;×&nbsp;10<sup>`exp`</sup>, saturating on overflow.
+    ///
+    /// If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+    /// result is rounded to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_scale\_pow10][FixedI32::saturating_scale_pow10]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_scale\_pow10][FixedU32::saturating_scale_pow10]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_scale_pow10(self, exp: i32) -> Self;
+
     /// Saturating distance. Returns the distance from `self` to `other`,
     /// saturating on overflow.
     ///
@@ -1670,6 +2479,16 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn saturating_dist(self, other: Self) -> Self;
 
+    /// Saturating positive difference. Returns the positive difference between
+    /// `self` and `other`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_positive\_diff][FixedI32::saturating_positive_diff]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_positive\_diff][FixedU32::saturating_positive_diff]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_positive_diff(self, other: Self) -> Self;
+
     /// Compute the hypotenuse of a right triange, saturating on overflow.
     ///
     /// See also
@@ -1679,6 +2498,33 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn saturating_hypot(self, other: Self) -> Self;
 
+    /// Saturating Euclidean norm of a vector, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_norm][FixedI32::saturating_norm]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_norm][FixedU32::saturating_norm]</code>.
+    #[must_use]
+    fn saturating_norm(values: &[Self]) -> Self;
+
+    /// Saturating increment. Returns `self`&nbsp;+&nbsp;[`DELTA`][Self::DELTA],
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_inc][FixedI32::saturating_inc]</code> and
+    /// <code>FixedU32::[saturating\_inc][FixedU32::saturating_inc]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_inc(self) -> Self;
+
+    /// Saturating decrement. Returns
+    /// `self`&nbsp;&minus;&nbsp;[`DELTA`][Self::DELTA], saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_dec][FixedI32::saturating_dec]</code> and
+    /// <code>FixedU32::[saturating\_dec][FixedU32::saturating_dec]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_dec(self) -> Self;
+
     /// Wrapping negation. Returns the negated value, wrapping on overflow.
     ///
     /// See also <code>FixedI32::[wrapping\_neg][FixedI32::wrapping_neg]</code>
@@ -1707,6 +2553,15 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn wrapping_mul(self, rhs: Self) -> Self;
 
+    /// Wrapping square. Returns `self` × `self`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_square][FixedI32::wrapping_square]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_square][FixedU32::wrapping_square]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_square(self) -> Self;
+
     /// Wrapping next multiple of `other`.
     ///
     /// See also
@@ -1734,6 +2589,53 @@ where
         add: Self,
     ) -> Self;
 
+    /// Wrapping multiply and subtract. Returns `self` × `mul` &minus; `sub`,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_mul\_sub][FixedI32::wrapping_mul_sub]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_mul\_sub][FixedU32::wrapping_mul_sub]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_mul_sub<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        sub: Self,
+    ) -> Self;
+
+    /// Wrapping multiply and divide. Returns `self` × `mul` / `div`,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_mul\_div][FixedI32::wrapping_mul_div]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_mul\_div][FixedU32::wrapping_mul_div]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        div: <Self::Bits as FixedBits>::Fixed<DIV_FRAC>,
+    ) -> Self;
+
+    /// Wrapping negated multiply and add. Returns `add` &minus; `self` ×
+    /// `mul`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_neg\_mul\_add][FixedI32::wrapping_neg_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_neg\_mul\_add][FixedU32::wrapping_neg_mul_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_neg_mul_add<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        add: Self,
+    ) -> Self;
+
     /// Adds `self` to the product `a`&nbsp;×&nbsp;`b`, wrapping on overflow.
     ///
     /// See also
@@ -1747,6 +2649,41 @@ where
         b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
     ) -> Self;
 
+    /// Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_add\_prod2][FixedI32::wrapping_add_prod2]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_add\_prod2][FixedU32::wrapping_add_prod2]</code>.
+    #[must_use]
+    fn wrapping_add_prod2<
+        const A_FRAC: i32,
+        const B_FRAC: i32,
+        const C_FRAC: i32,
+        const D_FRAC: i32,
+    >(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+        c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+        d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+    ) -> Self;
+
+    /// Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sub\_prod][FixedI32::wrapping_sub_prod]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_sub\_prod][FixedU32::wrapping_sub_prod]</code>.
+    #[must_use]
+    fn wrapping_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+    ) -> Self;
+
     /// Wrapping multiply and accumulate. Adds (`a` × `b`) to `self`, wrapping on overflow.
     ///
     /// See also
@@ -1784,6 +2721,19 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn wrapping_div_int(self, rhs: Self::Bits) -> Self;
 
+    /// Wrapping multiplication by a power of ten. Returns
+    /// `self`&nbsp;×&nbsp;10<sup>`exp`</sup>, wrapping on overflow.
+    ///
+    /// If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+    /// result is rounded to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_scale\_pow10][FixedI32::wrapping_scale_pow10]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_scale\_pow10][FixedU32::wrapping_scale_pow10]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_scale_pow10(self, exp: i32) -> Self;
+
     /// Wrapping shift left. Wraps `rhs` if `rhs`&nbsp;≥ the number of
     /// bits, then shifts and returns the number.
     ///
@@ -1809,6 +2759,16 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn wrapping_dist(self, other: Self) -> Self;
 
+    /// Wrapping positive difference. Returns the positive difference between
+    /// `self` and `other`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_positive\_diff][FixedI32::wrapping_positive_diff]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_positive\_diff][FixedU32::wrapping_positive_diff]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_positive_diff(self, other: Self) -> Self;
+
     /// Compute the hypotenuse of a right triange, wrapping on overflow.
     ///
     /// See also
@@ -1915,6 +2875,44 @@ where
         add: Self,
     ) -> Self;
 
+    /// Unwrapped multiply and subtract. Returns `self` × `mul` &minus;
+    /// `sub`, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_mul\_sub][FixedI32::unwrapped_mul_sub]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_mul\_sub][FixedU32::unwrapped_mul_sub]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_mul_sub<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        sub: Self,
+    ) -> Self;
+
+    /// Unwrapped negated multiply and add. Returns `add` &minus; `self` ×
+    /// `mul`, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_neg\_mul\_add][FixedI32::unwrapped_neg_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_neg\_mul\_add][FixedU32::unwrapped_neg_mul_add]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_neg_mul_add<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        add: Self,
+    ) -> Self;
+
     /// Adds `self` to the product `a`&nbsp;×&nbsp;`b`, panicking on overflow.
     ///
     /// See also
@@ -1933,6 +2931,51 @@ where
         b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
     ) -> Self;
 
+    /// Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`,
+    /// panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_add\_prod2][FixedI32::unwrapped_add_prod2]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_add\_prod2][FixedU32::unwrapped_add_prod2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_add_prod2<
+        const A_FRAC: i32,
+        const B_FRAC: i32,
+        const C_FRAC: i32,
+        const D_FRAC: i32,
+    >(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+        c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+        d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+    ) -> Self;
+
+    /// Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`, panicking on
+    /// overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sub\_prod][FixedI32::unwrapped_sub_prod]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_sub\_prod][FixedU32::unwrapped_sub_prod]</code>.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+    ) -> Self;
+
     /// Unwrapped multiply and accumulate. Adds (`a` × `b`) to `self`, panicking on overflow.
     ///
     /// See also
@@ -1995,6 +3038,24 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn unwrapped_div_int(self, rhs: Self::Bits) -> Self;
 
+    /// Unwrapped multiplication by a power of ten. Returns
+    /// `self`&nbsp;×&nbsp;10<sup>`exp`</sup>, panicking on overflow.
+    ///
+    /// If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+    /// result is rounded to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_scale\_pow10][FixedI32::unwrapped_scale_pow10]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_scale\_pow10][FixedU32::unwrapped_scale_pow10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_scale_pow10(self, exp: i32) -> Self;
+
     /// Unwrapped shift left. Panics if `rhs`&nbsp;≥ the number of bits.
     ///
     /// See also
@@ -2035,6 +3096,21 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn unwrapped_dist(self, other: Self) -> Self;
 
+    /// Unwrapped positive difference. Returns the positive difference between
+    /// `self` and `other`, panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_positive\_diff][FixedI32::unwrapped_positive_diff]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_positive\_diff][FixedU32::unwrapped_positive_diff]</code>.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn unwrapped_positive_diff(self, other: Self) -> Self;
+
     /// Compute the hypotenuse of a right triange, panicking on overflow.
     ///
     /// # Panics
@@ -2095,6 +3171,19 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn overflowing_mul(self, rhs: Self) -> (Self, bool);
 
+    /// Overflowing square.
+    ///
+    /// Returns a [tuple] of `self` × `self` and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_square][FixedI32::overflowing_square]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_square][FixedU32::overflowing_square]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_square(self) -> (Self, bool);
+
     /// Overflowing next multiple of `other`.
     ///
     /// Returns a [tuple] of the next multiple and a [`bool`], indicating
@@ -2129,6 +3218,40 @@ where
         add: Self,
     ) -> (Self, bool);
 
+    /// Overflowing multiply and subtract.
+    ///
+    /// Returns a [tuple] of `self` × `mul` &minus; `sub` and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_mul\_sub][FixedI32::overflowing_mul_sub]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_mul\_sub][FixedU32::overflowing_mul_sub]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_mul_sub<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        sub: Self,
+    ) -> (Self, bool);
+
+    /// Overflowing negated multiply and add.
+    ///
+    /// Returns a [tuple] of `add` &minus; `self` × `mul` and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_neg\_mul\_add][FixedI32::overflowing_neg_mul_add]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_neg\_mul\_add][FixedU32::overflowing_neg_mul_add]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_neg_mul_add<const MUL_FRAC: i32>(
+        self,
+        mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+        add: Self,
+    ) -> (Self, bool);
+
     /// Adds `self` to the product `a`&nbsp;×&nbsp;`b`.
     ///
     /// Returns a [tuple] of the result and a [`bool`] indicating whether an
@@ -2145,6 +3268,45 @@ where
         b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
     ) -> (Self, bool);
 
+    /// Adds `self` to the products `a`&nbsp;×&nbsp;`b` and `c`&nbsp;×&nbsp;`d`.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`] indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_add\_prod2][FixedI32::overflowing_add_prod2]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_add\_prod2][FixedU32::overflowing_add_prod2]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_add_prod2<
+        const A_FRAC: i32,
+        const B_FRAC: i32,
+        const C_FRAC: i32,
+        const D_FRAC: i32,
+    >(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+        c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+        d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+    ) -> (Self, bool);
+
+    /// Subtracts the product `a`&nbsp;×&nbsp;`b` from `self`.
+    ///
+    /// Returns a [tuple] of the result and a [`bool`] indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sub\_prod][FixedI32::overflowing_sub_prod]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_sub\_prod][FixedU32::overflowing_sub_prod]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+        self,
+        a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+        b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+    ) -> (Self, bool);
+
     /// Overflowing multiply and accumulate. Adds (`a` × `b`) to `self`,
     /// wrapping and returning [`true`] if overflow occurs.
     ///
@@ -2190,6 +3352,22 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn overflowing_div_int(self, rhs: Self::Bits) -> (Self, bool);
 
+    /// Overflowing multiplication by a power of ten.
+    ///
+    /// Returns a [tuple] of `self`&nbsp;×&nbsp;10<sup>`exp`</sup> and a [`bool`]
+    /// indicating whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// If `exp` is negative, this divides by 10<sup>&minus;`exp`</sup>, and the
+    /// result is rounded to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_scale\_pow10][FixedI32::overflowing_scale_pow10]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_scale\_pow10][FixedU32::overflowing_scale_pow10]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_scale_pow10(self, exp: i32) -> (Self, bool);
+
     /// Overflowing shift left.
     ///
     /// Returns a [tuple] of the shifted value and a [`bool`],
@@ -2227,6 +3405,19 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn overflowing_dist(self, other: Self) -> (Self, bool);
 
+    /// Overflowing positive difference.
+    ///
+    /// Returns a [tuple] of the positive difference between `self` and `other`
+    /// and a [`bool`], indicating whether an overflow has occurred. On
+    /// overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_positive\_diff][FixedI32::overflowing_positive_diff]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_positive\_diff][FixedU32::overflowing_positive_diff]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_positive_diff(self, other: Self) -> (Self, bool);
+
     /// Compute the hypotenuse of a right triange.
     ///
     /// Returns a [tuple] of the hypotenuse and a [`bool`], indicating whether
@@ -2385,6 +3576,46 @@ where
     /// and <code>FixedU32::[from\_str\_hex][FixedU32::from_str_hex]</code>.
     fn from_str_hex(src: &str) -> Result<Self, ParseFixedError>;
 
+    /// Parses a string slice containing a decimal percentage such as “`12.5%`”
+    /// to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_percent\_str][FixedI32::from_percent_str]</code>
+    /// and
+    /// <code>FixedU32::[from\_percent\_str][FixedU32::from_percent_str]</code>.
+    fn from_percent_str(src: &str) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing a decimal number with grouped digits
+    /// such as “`1,234.5`” to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_grouped\_str][FixedI32::from_grouped_str]</code>
+    /// and
+    /// <code>FixedU32::[from\_grouped\_str][FixedU32::from_grouped_str]</code>.
+    #[track_caller]
+    fn from_grouped_str(src: &str, group: char, decimal: char) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number, using the given characters as the decimal
+    /// separator and as the exponent separator, for example “`1,5`”.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_str\_with\_separators][FixedI32::from_str_with_separators]</code>
+    /// and
+    /// <code>FixedU32::[from\_str\_with\_separators][FixedU32::from_str_with_separators]</code>.
+    #[track_caller]
+    fn from_str_with_separators(
+        src: &str,
+        decimal: char,
+        exp: char,
+    ) -> Result<Self, ParseFixedError>;
+
     /// Parses a string slice containing decimal digits to return a
     /// fixed-point number, saturating on overflow.
     ///
@@ -2429,6 +3660,23 @@ where
     /// <code>FixedU32::[saturating\_from\_str\_hex][FixedU32::saturating_from_str_hex]</code>.
     fn saturating_from_str_hex(src: &str) -> Result<Self, ParseFixedError>;
 
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number, using the given characters as the decimal
+    /// separator and as the exponent separator, saturating on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_str\_with\_separators][FixedI32::saturating_from_str_with_separators]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_str\_with\_separators][FixedU32::saturating_from_str_with_separators]</code>.
+    #[track_caller]
+    fn saturating_from_str_with_separators(
+        src: &str,
+        decimal: char,
+        exp: char,
+    ) -> Result<Self, ParseFixedError>;
+
     /// Parses a string slice containing decimal digits to return a
     /// fixed-point number, wrapping on overflow.
     ///
@@ -2642,7 +3890,7 @@ where
     #[doc(alias("checked_ilog"))]
     fn checked_int_log(self, base: u32) -> Option<i32>;
 
-    /// Returns the reciprocal.
+    /// Returns the reciprocal, rounded towards zero.
     ///
     /// See also <code>FixedI32::[recip][FixedI32::recip]</code> and
     /// <code>FixedU32::[recip][FixedU32::recip]</code>.
@@ -2654,6 +3902,18 @@ where
     #[must_use]
     fn recip(self) -> Self;
 
+    /// Integer power. Returns `self` raised to the power of `exp`.
+    ///
+    /// See also <code>FixedI32::[powi][FixedI32::powi]</code> and
+    /// <code>FixedU32::[powi][FixedU32::powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[track_caller]
+    #[must_use]
+    fn powi(self, exp: i32) -> Self;
+
     /// Euclidean division by an integer.
     ///
     /// See also <code>FixedI32::[div\_euclid][FixedI32::div_euclid]</code> and
@@ -2666,6 +3926,20 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn div_euclid(self, rhs: Self) -> Self;
 
+    /// Division rounding to the nearest, with ties rounding to even.
+    ///
+    /// See also
+    /// <code>FixedI32::[div\_round][FixedI32::div_round]</code>
+    /// and
+    /// <code>FixedU32::[div\_round][FixedU32::div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_round(self, rhs: Self) -> Self;
+
     /// Euclidean division by an integer.
     ///
     /// See also
@@ -2702,6 +3976,96 @@ where
     /// Panics if the number is negative.
     fn sqrt(self) -> Self;
 
+    /// Returns the reciprocal square root.
+    ///
+    /// See also <code>FixedI32::[rsqrt][FixedI32::rsqrt]</code> and
+    /// <code>FixedU32::[rsqrt][FixedU32::rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    fn rsqrt(self) -> Self;
+
+    /// Returns the sine, with the angle in radians.
+    ///
+    /// See also <code>FixedI32::[sin][FixedI32::sin]</code> and
+    /// <code>FixedU32::[sin][FixedU32::sin]</code>.
+    fn sin(self) -> Self;
+
+    /// Returns the cosine, with the angle in radians.
+    ///
+    /// See also <code>FixedI32::[cos][FixedI32::cos]</code> and
+    /// <code>FixedU32::[cos][FixedU32::cos]</code>.
+    fn cos(self) -> Self;
+
+    /// Returns the sine and cosine, with the angle in radians.
+    ///
+    /// See also <code>FixedI32::[sin\_cos][FixedI32::sin_cos]</code> and
+    /// <code>FixedU32::[sin\_cos][FixedU32::sin_cos]</code>.
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Returns the arctangent in radians.
+    ///
+    /// See also <code>FixedI32::[atan][FixedI32::atan]</code> and
+    /// <code>FixedU32::[atan][FixedU32::atan]</code>.
+    fn atan(self) -> Self;
+
+    /// Returns <i>e</i> raised to the power of `self`.
+    ///
+    /// See also <code>FixedI32::[exp][FixedI32::exp]</code> and
+    /// <code>FixedU32::[exp][FixedU32::exp]</code>.
+    fn exp(self) -> Self;
+
+    /// Returns 2 raised to the power of `self`.
+    ///
+    /// See also <code>FixedI32::[exp2][FixedI32::exp2]</code> and
+    /// <code>FixedU32::[exp2][FixedU32::exp2]</code>.
+    fn exp2(self) -> Self;
+
+    /// Natural logarithm.
+    ///
+    /// See also <code>FixedI32::[ln][FixedI32::ln]</code> and
+    /// <code>FixedU32::[ln][FixedU32::ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is ≤&nbsp;0.
+    #[track_caller]
+    fn ln(self) -> Self;
+
+    /// Base-2 logarithm.
+    ///
+    /// See also <code>FixedI32::[log2][FixedI32::log2]</code> and
+    /// <code>FixedU32::[log2][FixedU32::log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is ≤&nbsp;0.
+    #[track_caller]
+    fn log2(self) -> Self;
+
+    /// Base-10 logarithm.
+    ///
+    /// See also <code>FixedI32::[log10][FixedI32::log10]</code> and
+    /// <code>FixedU32::[log10][FixedU32::log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is ≤&nbsp;0.
+    #[track_caller]
+    fn log10(self) -> Self;
+
+    /// Logarithm to the specified base.
+    ///
+    /// See also <code>FixedI32::[log][FixedI32::log]</code> and
+    /// <code>FixedU32::[log][FixedU32::log]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number or the base is ≤&nbsp;0, or if the base is one.
+    #[track_caller]
+    fn log(self, base: Self) -> Self;
+
     /// Linear interpolation between `start` and `end`.
     ///
     /// See also <code>FixedI32::[lerp][FixedI32::lerp]</code> and
@@ -2710,6 +4074,15 @@ where
     #[must_use]
     fn lerp(self, start: Self, end: Self) -> Self;
 
+    /// Linear interpolation between `start` and `end`, with `self` clamped to
+    /// the range 0&nbsp;≤&nbsp;`self`&nbsp;≤&nbsp;1 first.
+    ///
+    /// See also
+    /// <code>FixedI32::[lerp\_clamped][FixedI32::lerp_clamped]</code> and
+    /// <code>FixedU32::[lerp\_clamped][FixedU32::lerp_clamped]</code>.
+    #[must_use]
+    fn lerp_clamped(self, start: Self, end: Self) -> Self;
+
     /// Overflowing division.
     ///
     /// Returns a [tuple] of the quotient and a [`bool`], indicating
@@ -2785,6 +4158,14 @@ where
     /// <code>FixedU32::[checked\_recip][FixedU32::checked_recip]</code>.
     fn checked_recip(self) -> Option<Self>;
 
+    /// Checked integer power. Returns `self` raised to the power of `exp`,
+    /// or [`None`] if `self` is zero and `exp` is negative or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_powi][FixedI32::checked_powi]</code> and
+    /// <code>FixedU32::[checked\_powi][FixedU32::checked_powi]</code>.
+    fn checked_powi(self, exp: i32) -> Option<Self>;
+
     /// Checked remainder for Euclidean division. Returns the
     /// remainder, or [`None`] if the divisor is zero or the division
     /// results in overflow.
@@ -2796,6 +4177,17 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn checked_div_euclid(self, rhs: Self) -> Option<Self>;
 
+    /// Checked division rounding to the nearest, with ties rounding to
+    /// even. Returns the quotient, or [`None`] if the divisor is zero or on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_div\_round][FixedI32::checked_div_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_div\_round][FixedU32::checked_div_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn checked_div_round(self, rhs: Self) -> Option<Self>;
+
     /// Checked fixed-point remainder for division by an integer.
     /// Returns the remainder, or [`None`] if the divisor is zero or
     /// if the division results in overflow.
@@ -2835,6 +4227,91 @@ where
     /// and <code>FixedU32::[checked\_sqrt][FixedU32::checked_sqrt]</code>.
     fn checked_sqrt(self) -> Option<Self>;
 
+    /// Checked reciprocal square root. Returns [`None`] for zero or negative
+    /// numbers or on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_rsqrt][FixedI32::checked_rsqrt]</code>
+    /// and <code>FixedU32::[checked\_rsqrt][FixedU32::checked_rsqrt]</code>.
+    fn checked_rsqrt(self) -> Option<Self>;
+
+    /// Checked sine. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sin][FixedI32::checked_sin]</code> and
+    /// <code>FixedU32::[checked\_sin][FixedU32::checked_sin]</code>.
+    fn checked_sin(self) -> Option<Self>;
+
+    /// Checked cosine. Returns [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_cos][FixedI32::checked_cos]</code> and
+    /// <code>FixedU32::[checked\_cos][FixedU32::checked_cos]</code>.
+    fn checked_cos(self) -> Option<Self>;
+
+    /// Checked sine and cosine. Returns [`None`] if either overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sin\_cos][FixedI32::checked_sin_cos]</code> and
+    /// <code>FixedU32::[checked\_sin\_cos][FixedU32::checked_sin_cos]</code>.
+    fn checked_sin_cos(self) -> Option<(Self, Self)>;
+
+    /// Checked four-quadrant arctangent. Returns the angle of the point
+    /// (`other`,&nbsp;`self`) in radians, or [`None`] if the angle does not
+    /// fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_atan2][FixedI32::checked_atan2]</code> and
+    /// <code>FixedU32::[checked\_atan2][FixedU32::checked_atan2]</code>.
+    fn checked_atan2(self, other: Self) -> Option<Self>;
+
+    /// Checked exponential. Returns <i>e</i> raised to the power of `self`,
+    /// or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp][FixedI32::checked_exp]</code> and
+    /// <code>FixedU32::[checked\_exp][FixedU32::checked_exp]</code>.
+    fn checked_exp(self) -> Option<Self>;
+
+    /// Checked base-2 exponential. Returns 2 raised to the power of `self`,
+    /// or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp2][FixedI32::checked_exp2]</code> and
+    /// <code>FixedU32::[checked\_exp2][FixedU32::checked_exp2]</code>.
+    fn checked_exp2(self) -> Option<Self>;
+
+    /// Checked natural logarithm. Returns the natural logarithm, or [`None`]
+    /// if the number is ≤&nbsp;0 or on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_ln][FixedI32::checked_ln]</code> and
+    /// <code>FixedU32::[checked\_ln][FixedU32::checked_ln]</code>.
+    fn checked_ln(self) -> Option<Self>;
+
+    /// Checked base-2 logarithm. Returns the base-2 logarithm, or [`None`]
+    /// if the number is ≤&nbsp;0 or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log2][FixedI32::checked_log2]</code> and
+    /// <code>FixedU32::[checked\_log2][FixedU32::checked_log2]</code>.
+    fn checked_log2(self) -> Option<Self>;
+
+    /// Checked base-10 logarithm. Returns the base-10 logarithm, or
+    /// [`None`] if the number is ≤&nbsp;0 or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log10][FixedI32::checked_log10]</code> and
+    /// <code>FixedU32::[checked\_log10][FixedU32::checked_log10]</code>.
+    fn checked_log10(self) -> Option<Self>;
+
+    /// Checked logarithm to the specified base. Returns the logarithm, or
+    /// [`None`] if the number or the base is ≤&nbsp;0, if the base is one, or
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log][FixedI32::checked_log]</code> and
+    /// <code>FixedU32::[checked\_log][FixedU32::checked_log]</code>.
+    fn checked_log(self, base: Self) -> Option<Self>;
+
     /// Checked linear interpolation between `start` and `end`. Returns [`None`]
     /// on overflow.
     ///
@@ -2878,6 +4355,20 @@ where
     #[must_use]
     fn saturating_recip(self) -> Self;
 
+    /// Saturating integer power. Returns `self` raised to the power of
+    /// `exp`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_powi][FixedI32::saturating_powi]</code> and
+    /// <code>FixedU32::[saturating\_powi][FixedU32::saturating_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[track_caller]
+    #[must_use]
+    fn saturating_powi(self, exp: i32) -> Self;
+
     /// Saturating Euclidean division. Returns the quotient, saturating on overflow.
     ///
     /// See also
@@ -2892,6 +4383,21 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn saturating_div_euclid(self, rhs: Self) -> Self;
 
+    /// Saturating division rounding to the nearest, with ties rounding
+    /// to even. Returns the quotient, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_div\_round][FixedI32::saturating_div_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_div\_round][FixedU32::saturating_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn saturating_div_round(self, rhs: Self) -> Self;
+
     /// Saturating Euclidean division by an integer. Returns the
     /// quotient, saturating on overflow.
     ///
@@ -2930,8 +4436,36 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if the number is negative.
-    fn saturating_sqrt(self) -> Self;
+    /// Panics if the number is negative.
+    fn saturating_sqrt(self) -> Self;
+
+    /// Saturating reciprocal square root. Returns the reciprocal square root,
+    /// saturating on overflow. Zero saturates to the maximum value.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_rsqrt][FixedI32::saturating_rsqrt]</code> and
+    /// <code>FixedU32::[saturating\_rsqrt][FixedU32::saturating_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    fn saturating_rsqrt(self) -> Self;
+
+    /// Saturating exponential. Returns <i>e</i> raised to the power of
+    /// `self`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp][FixedI32::saturating_exp]</code> and
+    /// <code>FixedU32::[saturating\_exp][FixedU32::saturating_exp]</code>.
+    fn saturating_exp(self) -> Self;
+
+    /// Saturating base-2 exponential. Returns 2 raised to the power of
+    /// `self`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp2][FixedI32::saturating_exp2]</code> and
+    /// <code>FixedU32::[saturating\_exp2][FixedU32::saturating_exp2]</code>.
+    fn saturating_exp2(self) -> Self;
 
     /// Linear interpolation between `start` and `end`, saturating on overflow.
     ///
@@ -2975,6 +4509,20 @@ where
     #[must_use]
     fn wrapping_recip(self) -> Self;
 
+    /// Wrapping integer power. Returns `self` raised to the power of `exp`,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powi][FixedI32::wrapping_powi]</code> and
+    /// <code>FixedU32::[wrapping\_powi][FixedU32::wrapping_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_powi(self, exp: i32) -> Self;
+
     /// Wrapping Euclidean division. Returns the quotient, wrapping on overflow.
     ///
     /// See also
@@ -2989,6 +4537,21 @@ where
     #[must_use = "this returns the result of the operation, without modifying the original"]
     fn wrapping_div_euclid(self, rhs: Self) -> Self;
 
+    /// Wrapping division rounding to the nearest, with ties rounding to
+    /// even. Returns the quotient, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_div\_round][FixedI32::wrapping_div_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_div\_round][FixedU32::wrapping_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn wrapping_div_round(self, rhs: Self) -> Self;
+
     /// Wrapping Euclidean division by an integer. Returns the
     /// quotient, wrapping on overflow.
     ///
@@ -3032,6 +4595,43 @@ where
     /// Panics if the number is negative.
     fn wrapping_sqrt(self) -> Self;
 
+    /// Returns the sine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin][FixedI32::wrapping_sin]</code> and
+    /// <code>FixedU32::[wrapping\_sin][FixedU32::wrapping_sin]</code>.
+    fn wrapping_sin(self) -> Self;
+
+    /// Returns the cosine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cos][FixedI32::wrapping_cos]</code> and
+    /// <code>FixedU32::[wrapping\_cos][FixedU32::wrapping_cos]</code>.
+    fn wrapping_cos(self) -> Self;
+
+    /// Returns the sine and cosine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin\_cos][FixedI32::wrapping_sin_cos]</code> and
+    /// <code>FixedU32::[wrapping\_sin\_cos][FixedU32::wrapping_sin_cos]</code>.
+    fn wrapping_sin_cos(self) -> (Self, Self);
+
+    /// Wrapping exponential. Returns <i>e</i> raised to the power of `self`,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp][FixedI32::wrapping_exp]</code> and
+    /// <code>FixedU32::[wrapping\_exp][FixedU32::wrapping_exp]</code>.
+    fn wrapping_exp(self) -> Self;
+
+    /// Wrapping base-2 exponential. Returns 2 raised to the power of
+    /// `self`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp2][FixedI32::wrapping_exp2]</code> and
+    /// <code>FixedU32::[wrapping\_exp2][FixedU32::wrapping_exp2]</code>.
+    fn wrapping_exp2(self) -> Self;
+
     /// Linear interpolation between `start` and `end`, wrapping on overflow.
     ///
     /// See also
@@ -3226,6 +4826,20 @@ where
     /// Panics if the number is negative.
     fn overflowing_sqrt(self) -> (Self, bool);
 
+    /// Returns a [tuple] of the reciprocal square root and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_rsqrt][FixedI32::overflowing_rsqrt]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_rsqrt][FixedU32::overflowing_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    fn overflowing_rsqrt(self) -> (Self, bool);
+
     /// Overflowing linear interpolation between `start` and `end`.
     ///
     /// Returns a [tuple] of the interpolated value and a [`bool`], indicating
@@ -3322,6 +4936,19 @@ where
     #[must_use]
     fn signum(self) -> Self;
 
+    /// Returns a number with the magnitude of `self` and the sign of `sign`.
+    ///
+    /// See also <code>FixedI32::[copysign][FixedI32::copysign]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if `self` is
+    /// [`MIN`][Fixed::MIN] and `sign` is not negative. When debug assertions
+    /// are not enabled, [`MIN`][Fixed::MIN] is returned in that case.
+    #[track_caller]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn copysign(self, sign: Self) -> Self;
+
     /// Addition with an unsigned fixed-point number.
     ///
     /// See also <code>FixedI32::[add\_unsigned][FixedI32::add_unsigned]</code>.
@@ -3354,6 +4981,16 @@ where
     /// <code>FixedI32::[checked\_signum][FixedI32::checked_signum]</code>.
     fn checked_signum(self) -> Option<Self>;
 
+    /// Checked sign copy. Returns a number with the magnitude of `self` and
+    /// the sign of `sign`, or [`None`] on overflow.
+    ///
+    /// Overflow can only occur when `self` is [`MIN`][Fixed::MIN] and `sign`
+    /// is not negative.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_copysign][FixedI32::checked_copysign]</code>.
+    fn checked_copysign(self, sign: Self) -> Option<Self>;
+
     /// Checked addition with an unsigned fixed-point number. Returns the sum,
     /// or [`None`] on overflow.
     ///
@@ -3604,6 +5241,14 @@ where
     #[must_use]
     fn sub_signed(self, rhs: Self::Signed) -> Self;
 
+    /// Multiplication by a signed integer, returning a signed fixed-point
+    /// number.
+    ///
+    /// See also
+    /// <code>FixedU32::[mul\_signed\_int][FixedU32::mul_signed_int]</code>.
+    #[must_use]
+    fn mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Self::Signed;
+
     /// Returns the smallest power of two that is ≥&nbsp;`self`, or [`None`] if the
     /// next power of two is too large to represent.
     ///
@@ -3626,6 +5271,22 @@ where
     #[must_use]
     fn checked_sub_signed(self, rhs: Self::Signed) -> Option<Self>;
 
+    /// Checked multiplication by a signed integer. Returns the signed
+    /// product, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedU32::[checked\_mul\_signed\_int][FixedU32::checked_mul_signed_int]</code>.
+    #[must_use]
+    fn checked_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Option<Self::Signed>;
+
+    /// Checked negation into the signed fixed-point type with the same number
+    /// of bits. Returns the negated value, or [`None`] if it does not fit.
+    ///
+    /// See also
+    /// <code>FixedU32::[checked\_neg\_to\_signed][FixedU32::checked_neg_to_signed]</code>.
+    #[must_use]
+    fn checked_neg_to_signed(self) -> Option<Self::Signed>;
+
     /// Saturating addition with an signed fixed-point number. Returns the
     /// sum, saturating on overflow.
     ///
@@ -3642,6 +5303,14 @@ where
     #[must_use]
     fn saturating_sub_signed(self, rhs: Self::Signed) -> Self;
 
+    /// Saturating multiplication by a signed integer. Returns the signed
+    /// product, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedU32::[saturating\_mul\_signed\_int][FixedU32::saturating_mul_signed_int]</code>.
+    #[must_use]
+    fn saturating_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Self::Signed;
+
     /// Returns the smallest power of two that is ≥&nbsp;`self`, wrapping
     /// to 0 if the next power of two is too large to represent.
     ///
@@ -3666,6 +5335,14 @@ where
     #[must_use]
     fn wrapping_sub_signed(self, rhs: Self::Signed) -> Self;
 
+    /// Wrapping multiplication by a signed integer. Returns the signed
+    /// product, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedU32::[wrapping\_mul\_signed\_int][FixedU32::wrapping_mul_signed_int]</code>.
+    #[must_use]
+    fn wrapping_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Self::Signed;
+
     /// Returns the smallest power of two that is ≥&nbsp;`self`, panicking
     /// if the next power of two is too large to represent.
     ///
@@ -3705,6 +5382,19 @@ where
     #[must_use]
     fn unwrapped_sub_signed(self, rhs: Self::Signed) -> Self;
 
+    /// Unwrapped multiplication by a signed integer. Returns the signed
+    /// product, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedU32::[unwrapped\_mul\_signed\_int][FixedU32::unwrapped_mul_signed_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Self::Signed;
+
     /// Overflowing addition with an signed fixed-point number.
     ///
     /// Returns a [tuple] of the sum and a [`bool`], indicating whether an
@@ -3724,6 +5414,18 @@ where
     /// <code>FixedU32::[overflowing\_sub\_signed][FixedU32::overflowing_sub_signed]</code>.
     #[must_use]
     fn overflowing_sub_signed(self, rhs: Self::Signed) -> (Self, bool);
+
+    /// Overflowing multiplication by a signed integer.
+    ///
+    /// Returns a [tuple] of the signed product and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedU32::[overflowing\_mul\_signed\_int][FixedU32::overflowing_mul_signed_int]</code>.
+    #[must_use]
+    fn overflowing_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits)
+        -> (Self::Signed, bool);
 }
 
 /// This trait provides lossless conversions that might be fallible.
@@ -3849,6 +5551,62 @@ where
     }
 }
 
+/// This trait provides addition of fixed-point numbers with different numbers
+/// of fractional bits.
+///
+/// The sum has the larger number of fractional bits of the two operands, and
+/// the associated type [`Output`][AddFrac::Output] names it, so generic code
+/// can refer to the type of the sum.
+///
+/// This trait is implemented for every pair of fixed-point numbers with the same
+/// underlying integer type, for example for <code>[FixedI32]\<4></code> and
+/// <code>[FixedI32]\<8></code>. The methods have the same behavior as the
+/// inherent methods of the fixed-point numbers, for example
+/// <code>FixedI32::[add\_frac][FixedI32::add_frac]</code>.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::traits::AddFrac;
+/// use fixed::FixedI32;
+/// type Sum = <FixedI32<4> as AddFrac<FixedI32<8>>>::Output;
+/// let a = FixedI32::<4>::from_num(1);
+/// let b = FixedI32::<8>::from_num(0.5);
+/// let sum: Sum = AddFrac::add_frac(a, b);
+/// assert_eq!(sum, FixedI32::<8>::from_num(1.5));
+/// ```
+pub trait AddFrac<Rhs = Self> {
+    /// The type of the sum, which has the larger number of fractional bits.
+    type Output;
+
+    /// Adds two fixed-point numbers with possibly different numbers of
+    /// fractional bits.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`overflowing_add_frac`] instead.
+    ///
+    /// [`overflowing_add_frac`]: AddFrac::overflowing_add_frac
+    fn add_frac(self, rhs: Rhs) -> Self::Output;
+
+    /// Checked addition of fixed-point numbers with possibly different numbers
+    /// of fractional bits. Returns the sum, or [`None`] on overflow.
+    fn checked_add_frac(self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Overflowing addition of fixed-point numbers with possibly different
+    /// numbers of fractional bits.
+    ///
+    /// Returns a [tuple] of the sum and a [`bool`] indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    fn overflowing_add_frac(self, rhs: Rhs) -> (Self::Output, bool);
+}
+
 /// This trait provides checked conversions from fixed-point numbers.
 ///
 /// This trait is implemented for conversions between integer
@@ -3945,6 +5703,17 @@ pub trait FromFixed {
 /// This trait is implemented for conversions between integer
 /// primitives, floating-point primitives and fixed-point numbers.
 ///
+/// It is also implemented for integer primitives inside
+/// [`core::num::Wrapping`]. For these, [`to_fixed`][ToFixed::to_fixed], and
+/// therefore [`from_num`][Fixed::from_num], wraps on overflow instead of
+/// panicking when debug assertions are enabled.
+///
+/// Finally, it is implemented for shared references to all of the above types,
+/// so that generic code holding references can use
+/// <code>[from\_num][Fixed::from_num]\(&amp;x)</code> without dereferencing.
+/// There is no blanket implementation for references, so other crates can
+/// implement this trait for references to their own types.
+///
 /// # Examples
 ///
 /// ```rust
@@ -3994,9 +5763,8 @@ pub trait ToFixed {
     ///
     /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
     ///
-    /// # Panics
-    ///
-    /// Panics if `self` is a floating-point number that is [NaN].
+    /// If `self` is a floating-point number that is [NaN], the result is
+    /// zero.
     ///
     /// [NaN]: f64::is_nan
     fn saturating_to_fixed<F: Fixed>(self) -> F;
@@ -4131,9 +5899,9 @@ pub trait FixedEquiv {
 }
 
 macro_rules! trait_delegate {
-    (fn $method:ident($($param:ident: $Param:ty),*$(,)?) -> $Ret:ty) => {
+    (fn $method:ident($($param:ident: $Param:ty),*$(,)?) $(-> $Ret:ty)?) => {
         #[inline]
-        fn $method($($param: $Param),*) -> $Ret {
+        fn $method($($param: $Param),*) $(-> $Ret)? {
             Self::$method($($param),*)
         }
     };
@@ -4222,11 +5990,14 @@ macro_rules! impl_fixed {
             const INT_BITS: i32 = Self::INT_BITS;
             const FRAC_BITS: i32 = Self::FRAC_BITS;
             trait_delegate! { fn from_bits(bits: Self::Bits) -> Self }
+            trait_delegate! { fn from_bits_masked(bits: Self::Bits, reserved_mask: Self::Bits) -> Option<Self> }
             trait_delegate! { fn to_bits(self) -> Self::Bits }
             trait_delegate! { fn from_be(fixed: Self) -> Self }
             trait_delegate! { fn from_le(fixed: Self) -> Self }
             trait_delegate! { fn to_be(self) -> Self }
             trait_delegate! { fn to_le(self) -> Self }
+            trait_delegate! { fn to_bits_be(self) -> Self::Bits }
+            trait_delegate! { fn to_bits_le(self) -> Self::Bits }
             trait_delegate! { fn swap_bytes(self) -> Self }
             trait_delegate! { fn from_be_bytes(bits: [u8; size_of::<Self>()]) -> Self }
             trait_delegate! { fn from_le_bytes(bits: [u8; size_of::<Self>()]) -> Self }
@@ -4234,10 +6005,32 @@ macro_rules! impl_fixed {
             trait_delegate! { fn to_be_bytes(self) -> [u8; size_of::<Self>()] }
             trait_delegate! { fn to_le_bytes(self) -> [u8; size_of::<Self>()] }
             trait_delegate! { fn to_ne_bytes(self) -> [u8; size_of::<Self>()] }
+            trait_delegate! { fn to_canonical_bytes(self) -> [u8; 16] }
+            trait_delegate! {
+                fn write_be_slice(values: &[Self], out: &mut [u8]) -> Result<usize, BufferTooSmall>
+            }
+            trait_delegate! {
+                fn write_le_slice(values: &[Self], out: &mut [u8]) -> Result<usize, BufferTooSmall>
+            }
             trait_delegate! { fn from_num<Src: ToFixed>(src: Src) -> Self }
             trait_delegate! { fn to_num<Dst: FromFixed>(self) -> Dst }
             trait_delegate! { fn checked_from_num<Src: ToFixed>(val: Src) -> Option<Self> }
             trait_delegate! { fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> }
+            #[inline]
+            fn fits_in<Dst: FromFixed>(self) -> bool {
+                self.fits_in::<Dst>()
+            }
+            #[inline]
+            fn roundtrips_through<Dst: FromFixed + ToFixed>(self) -> bool {
+                self.roundtrips_through::<Dst>()
+            }
+            trait_delegate! { fn to_f64_scaled(self, decimal_exp: i32) -> f64 }
+            trait_delegate! { fn from_num_or<Src: ToFixed>(src: Src, default: Self) -> Self }
+            trait_delegate! { fn from_num_opt<Src: ToFixed>(src: Option<Src>) -> Option<Self> }
+            trait_delegate! { fn from_num_nonzero(src: f64) -> Self }
+            trait_delegate! { fn from_num_dyn(src: f64, mode: Round) -> Self }
+            trait_delegate! { fn from_num_round<Src: Fixed>(src: Src, mode: Round) -> Self }
+            trait_delegate! { fn to_num_round<Dst: Fixed>(self, mode: Round) -> Dst }
             trait_delegate! { fn saturating_from_num<Src: ToFixed>(val: Src) -> Self }
             trait_delegate! { fn saturating_to_num<Dst: FromFixed>(self) -> Dst }
             trait_delegate! { fn wrapping_from_num<Src: ToFixed>(val: Src) -> Self }
@@ -4279,37 +6072,103 @@ macro_rules! impl_fixed {
             trait_delegate! { fn leading_zeros(self) -> u32 }
             trait_delegate! { fn trailing_ones(self) -> u32 }
             trait_delegate! { fn trailing_zeros(self) -> u32 }
+            trait_delegate! { fn block_leading_zeros(values: &[Self]) -> u32 }
+            trait_delegate! { fn shift_block_left(values: &mut [Self], n: u32) }
             trait_delegate! { fn int_log2(self) -> i32 }
             trait_delegate! { fn checked_int_log2(self) -> Option<i32> }
+            trait_delegate! { fn int_sqrt(self) -> Self }
+            trait_delegate! { fn checked_int_sqrt(self) -> Option<Self> }
             trait_delegate! { fn reverse_bits(self) -> Self }
             trait_delegate! { fn rotate_left(self, n: u32) -> Self }
             trait_delegate! { fn rotate_right(self, n: u32) -> Self }
+            trait_delegate! { fn logical_shr(self, n: u32) -> Self }
             trait_delegate! { fn is_zero(self) -> bool }
+            trait_delegate! { fn is_normalized(self) -> bool }
+            trait_delegate! { fn signum_int(self) -> Self::Bits }
+            trait_delegate! { fn sign(self) -> Ordering }
             trait_delegate! { fn dist(self, other: Self) -> Self }
+            trait_delegate! { fn positive_diff(self, other: Self) -> Self }
             trait_delegate! { fn abs_diff(self, other: Self) -> Self::Unsigned }
+            trait_delegate! { fn ulps_between(self, other: Self) -> <Self::Unsigned as Fixed>::Bits }
+            trait_delegate! { fn next_up(self) -> Self }
+            trait_delegate! { fn next_down(self) -> Self }
+            trait_delegate! { fn inc(self) -> Self }
+            trait_delegate! { fn dec(self) -> Self }
             trait_delegate! { fn mean(self, other: Self) -> Self }
             trait_delegate! { fn hypot(self, other: Self) -> Self }
+            trait_delegate! { fn norm(values: &[Self]) -> Self }
+            trait_delegate! { fn fast_hypot(self, other: Self) -> Self }
             trait_delegate! { fn next_multiple_of(self, other: Self) -> Self }
+            trait_delegate! { fn square(self) -> Self }
             trait_delegate! { fn mul_add<const MUL_FRAC: i32>(
                 self,
                 mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
                 add: Self,
             ) -> Self }
+            trait_delegate! { fn mul_sub<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                sub: Self,
+            ) -> Self }
+            trait_delegate! { fn mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                div: <Self::Bits as FixedBits>::Fixed<DIV_FRAC>,
+            ) -> Self }
+            trait_delegate! { fn neg_mul_add<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                add: Self,
+            ) -> Self }
             trait_delegate! { fn add_prod<const A_FRAC: i32, const B_FRAC: i32>(
                 self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
                 b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
             ) -> Self }
+            trait_delegate! { fn add_prod2<
+                const A_FRAC: i32,
+                const B_FRAC: i32,
+                const C_FRAC: i32,
+                const D_FRAC: i32,
+            >(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+                c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+                d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+            ) -> Self }
+            trait_delegate! { fn sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) -> Self }
             trait_delegate! { fn mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
                 &mut self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
                 b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
             ) }
             trait_delegate! { fn rem_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn rem_pow2(self, exp: u32) -> Self }
+            trait_delegate! { fn is_multiple_of(self, other: Self) -> bool }
+            trait_delegate! { fn round_to_frac_round(self, frac_bits: i32, mode: Round) -> Self }
+            trait_delegate! { fn round_to_frac(self, frac_bits: i32) -> Self }
+            trait_delegate! { fn ceil_to_frac(self, frac_bits: i32) -> Self }
+            trait_delegate! { fn floor_to_frac(self, frac_bits: i32) -> Self }
+            trait_delegate! { fn trunc_to_frac(self, frac_bits: i32) -> Self }
+            trait_delegate! { fn div_rem_int(self, rhs: Self::Bits) -> (Self, Self) }
+            trait_delegate! { fn scale_pow10(self, exp: i32) -> Self }
             trait_delegate! { fn checked_neg(self) -> Option<Self> }
             trait_delegate! { fn checked_add(self, rhs: Self) -> Option<Self> }
+            #[inline]
+            fn try_sum<I>(iter: I) -> Result<Self, ArithError>
+            where
+                I: IntoIterator<Item = Self>,
+            {
+                Self::try_sum(iter)
+            }
             trait_delegate! { fn checked_sub(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_square(self) -> Option<Self> }
             trait_delegate! { fn checked_rem(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_next_multiple_of(self, other: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul_add<const MUL_FRAC: i32>(
@@ -4317,38 +6176,121 @@ macro_rules! impl_fixed {
                 mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
                 add: Self,
             ) -> Option<Self> }
+            trait_delegate! { fn checked_mul_sub<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                sub: Self,
+            ) -> Option<Self> }
+            trait_delegate! { fn checked_mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                div: <Self::Bits as FixedBits>::Fixed<DIV_FRAC>,
+            ) -> Option<Self> }
+            trait_delegate! { fn checked_neg_mul_add<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                add: Self,
+            ) -> Option<Self> }
             trait_delegate! { fn checked_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
                 self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
                 b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
             ) -> Option<Self> }
+            trait_delegate! { fn checked_add_prod2<
+                const A_FRAC: i32,
+                const B_FRAC: i32,
+                const C_FRAC: i32,
+                const D_FRAC: i32,
+            >(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+                c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+                d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+            ) -> Option<Self> }
+            trait_delegate! { fn checked_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) -> Option<Self> }
             trait_delegate! { fn checked_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
                 &mut self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
                 b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
             ) -> Option<()> }
+            trait_delegate! { fn checked_mul_acc_slice<const A_FRAC: i32, const B_FRAC: i32>(
+                &mut self,
+                a: &[<Self::Bits as FixedBits>::Fixed<A_FRAC>],
+                b: &[<Self::Bits as FixedBits>::Fixed<B_FRAC>],
+            ) -> Option<()> }
             trait_delegate! { fn checked_rem_euclid(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_div_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_div_rem_int(self, rhs: Self::Bits) -> Option<(Self, Self)> }
+            trait_delegate! { fn checked_scale_pow10(self, exp: i32) -> Option<Self> }
             trait_delegate! { fn checked_shl(self, rhs: u32) -> Option<Self> }
             trait_delegate! { fn checked_shr(self, rhs: u32) -> Option<Self> }
             trait_delegate! { fn checked_dist(self, other: Self) -> Option<Self> }
+            trait_delegate! { fn checked_positive_diff(self, other: Self) -> Option<Self> }
             trait_delegate! { fn checked_hypot(self, other: Self) -> Option<Self> }
+            trait_delegate! { fn checked_norm(values: &[Self]) -> Option<Self> }
+            trait_delegate! { fn checked_inc(self) -> Option<Self> }
+            trait_delegate! { fn checked_dec(self) -> Option<Self> }
             trait_delegate! { fn saturating_neg(self) -> Self }
             trait_delegate! { fn saturating_add(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_sub(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_mul(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_square(self) -> Self }
             trait_delegate! { fn saturating_next_multiple_of(self, other: Self) -> Self }
             trait_delegate! { fn saturating_mul_add<const MUL_FRAC: i32>(
                 self,
                 mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
                 add: Self,
             ) -> Self }
+            trait_delegate! { fn saturating_mul_sub<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                sub: Self,
+            ) -> Self }
+            trait_delegate! { fn saturating_mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                div: <Self::Bits as FixedBits>::Fixed<DIV_FRAC>,
+            ) -> Self }
+            trait_delegate! { fn saturating_neg_mul_add<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                add: Self,
+            ) -> Self }
+            trait_delegate! { fn clamped_mul_add<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                add: Self,
+                lo: Self,
+                hi: Self,
+            ) -> Self }
             trait_delegate! { fn saturating_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
                 self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
                 b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
             ) -> Self }
+            trait_delegate! { fn saturating_add_prod2<
+                const A_FRAC: i32,
+                const B_FRAC: i32,
+                const C_FRAC: i32,
+                const D_FRAC: i32,
+            >(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+                c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+                d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+            ) -> Self }
+            trait_delegate! { fn saturating_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) -> Self }
             trait_delegate! { fn saturating_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
                 &mut self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
@@ -4356,23 +6298,61 @@ macro_rules! impl_fixed {
             ) }
             trait_delegate! { fn saturating_mul_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn saturating_div_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn saturating_scale_pow10(self, exp: i32) -> Self }
             trait_delegate! { fn saturating_dist(self, other: Self) -> Self }
+            trait_delegate! { fn saturating_positive_diff(self, other: Self) -> Self }
             trait_delegate! { fn saturating_hypot(self, other: Self) -> Self }
+            trait_delegate! { fn saturating_norm(values: &[Self]) -> Self }
+            trait_delegate! { fn saturating_inc(self) -> Self }
+            trait_delegate! { fn saturating_dec(self) -> Self }
             trait_delegate! { fn wrapping_neg(self) -> Self }
             trait_delegate! { fn wrapping_add(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_sub(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_mul(self, rhs: Self) -> Self }
+            trait_delegate! { fn wrapping_square(self) -> Self }
             trait_delegate! { fn wrapping_next_multiple_of(self, other: Self) -> Self }
             trait_delegate! { fn wrapping_mul_add<const MUL_FRAC: i32>(
                 self,
                 mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
                 add: Self,
             ) -> Self }
+            trait_delegate! { fn wrapping_mul_sub<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                sub: Self,
+            ) -> Self }
+            trait_delegate! { fn wrapping_mul_div<const MUL_FRAC: i32, const DIV_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                div: <Self::Bits as FixedBits>::Fixed<DIV_FRAC>,
+            ) -> Self }
+            trait_delegate! { fn wrapping_neg_mul_add<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                add: Self,
+            ) -> Self }
             trait_delegate! { fn wrapping_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
                 self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
                 b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
             ) -> Self }
+            trait_delegate! { fn wrapping_add_prod2<
+                const A_FRAC: i32,
+                const B_FRAC: i32,
+                const C_FRAC: i32,
+                const D_FRAC: i32,
+            >(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+                c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+                d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+            ) -> Self }
+            trait_delegate! { fn wrapping_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) -> Self }
             trait_delegate! { fn wrapping_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
                 &mut self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
@@ -4380,9 +6360,11 @@ macro_rules! impl_fixed {
             ) }
             trait_delegate! { fn wrapping_mul_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_div_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_scale_pow10(self, exp: i32) -> Self }
             trait_delegate! { fn wrapping_shl(self, rhs: u32) -> Self }
             trait_delegate! { fn wrapping_shr(self, rhs: u32) -> Self }
             trait_delegate! { fn wrapping_dist(self, other: Self) -> Self }
+            trait_delegate! { fn wrapping_positive_diff(self, other: Self) -> Self }
             trait_delegate! { fn wrapping_hypot(self, other: Self) -> Self }
             trait_delegate! { fn unwrapped_neg(self) -> Self }
             trait_delegate! { fn unwrapped_add(self, rhs: Self) -> Self }
@@ -4395,11 +6377,38 @@ macro_rules! impl_fixed {
                 mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
                 add: Self,
             ) -> Self }
+            trait_delegate! { fn unwrapped_mul_sub<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                sub: Self,
+            ) -> Self }
+            trait_delegate! { fn unwrapped_neg_mul_add<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                add: Self,
+            ) -> Self }
             trait_delegate! { fn unwrapped_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
                 self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
                 b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
             ) -> Self }
+            trait_delegate! { fn unwrapped_add_prod2<
+                const A_FRAC: i32,
+                const B_FRAC: i32,
+                const C_FRAC: i32,
+                const D_FRAC: i32,
+            >(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+                c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+                d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+            ) -> Self }
+            trait_delegate! { fn unwrapped_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) -> Self }
             trait_delegate! { fn unwrapped_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
                 &mut self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
@@ -4408,25 +6417,55 @@ macro_rules! impl_fixed {
             trait_delegate! { fn unwrapped_rem_euclid(self, rhs: Self) -> Self }
             trait_delegate! { fn unwrapped_mul_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn unwrapped_div_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn unwrapped_scale_pow10(self, exp: i32) -> Self }
             trait_delegate! { fn unwrapped_shl(self, rhs: u32) -> Self }
             trait_delegate! { fn unwrapped_shr(self, rhs: u32) -> Self }
             trait_delegate! { fn unwrapped_dist(self, other: Self) -> Self }
+            trait_delegate! { fn unwrapped_positive_diff(self, other: Self) -> Self }
             trait_delegate! { fn unwrapped_hypot(self, other: Self) -> Self }
             trait_delegate! { fn overflowing_neg(self) -> (Self, bool) }
             trait_delegate! { fn overflowing_add(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_sub(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_mul(self, rhs: Self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_square(self) -> (Self, bool) }
             trait_delegate! { fn overflowing_next_multiple_of(self, other: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_mul_add<const MUL_FRAC: i32>(
                 self,
                 mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
                 add: Self,
             ) -> (Self, bool) }
+            trait_delegate! { fn overflowing_mul_sub<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                sub: Self,
+            ) -> (Self, bool) }
+            trait_delegate! { fn overflowing_neg_mul_add<const MUL_FRAC: i32>(
+                self,
+                mul: <Self::Bits as FixedBits>::Fixed<MUL_FRAC>,
+                add: Self,
+            ) -> (Self, bool) }
             trait_delegate! { fn overflowing_add_prod<const A_FRAC: i32, const B_FRAC: i32>(
                 self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
                 b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
             ) -> (Self, bool) }
+            trait_delegate! { fn overflowing_add_prod2<
+                const A_FRAC: i32,
+                const B_FRAC: i32,
+                const C_FRAC: i32,
+                const D_FRAC: i32,
+            >(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+                c: <Self::Bits as FixedBits>::Fixed<C_FRAC>,
+                d: <Self::Bits as FixedBits>::Fixed<D_FRAC>,
+            ) -> (Self, bool) }
+            trait_delegate! { fn overflowing_sub_prod<const A_FRAC: i32, const B_FRAC: i32>(
+                self,
+                a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
+                b: <Self::Bits as FixedBits>::Fixed<B_FRAC>,
+            ) -> (Self, bool) }
             trait_delegate! { fn overflowing_mul_acc<const A_FRAC: i32, const B_FRAC: i32>(
                 &mut self,
                 a: <Self::Bits as FixedBits>::Fixed<A_FRAC>,
@@ -4434,9 +6473,11 @@ macro_rules! impl_fixed {
             ) -> bool }
             trait_delegate! { fn overflowing_mul_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_div_int(self, rhs: Self::Bits) -> (Self, bool) }
+            trait_delegate! { fn overflowing_scale_pow10(self, exp: i32) -> (Self, bool) }
             trait_delegate! { fn overflowing_shl(self, rhs: u32) -> (Self, bool) }
             trait_delegate! { fn overflowing_shr(self, rhs: u32) -> (Self, bool) }
             trait_delegate! { fn overflowing_dist(self, other: Self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_positive_diff(self, other: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_hypot(self, other: Self) -> (Self, bool) }
             trait_delegate! { unsafe fn unchecked_add(self, rhs: Self) -> Self }
             trait_delegate! { unsafe fn unchecked_sub(self, rhs: Self) -> Self }
@@ -4452,6 +6493,17 @@ macro_rules! impl_fixed {
             trait_delegate! { fn from_str_binary(src: &str) -> Result<Self, ParseFixedError> }
             trait_delegate! { fn from_str_octal(src: &str) -> Result<Self, ParseFixedError> }
             trait_delegate! { fn from_str_hex(src: &str) -> Result<Self, ParseFixedError> }
+            trait_delegate! { fn from_percent_str(src: &str) -> Result<Self, ParseFixedError> }
+            trait_delegate! {
+                fn from_grouped_str(src: &str, group: char, decimal: char) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn from_str_with_separators(
+                    src: &str,
+                    decimal: char,
+                    exp: char,
+                ) -> Result<Self, ParseFixedError>
+            }
             trait_delegate! {
                 fn saturating_from_str(src: &str) -> Result<Self, ParseFixedError>
             }
@@ -4464,6 +6516,13 @@ macro_rules! impl_fixed {
             trait_delegate! {
                 fn saturating_from_str_hex(src: &str) -> Result<Self, ParseFixedError>
             }
+            trait_delegate! {
+                fn saturating_from_str_with_separators(
+                    src: &str,
+                    decimal: char,
+                    exp: char,
+                ) -> Result<Self, ParseFixedError>
+            }
             trait_delegate! {
                 fn wrapping_from_str(src: &str) -> Result<Self, ParseFixedError>
             }
@@ -4497,35 +6556,74 @@ macro_rules! impl_fixed {
             trait_delegate! { fn checked_int_log10(self) -> Option<i32> }
             trait_delegate! { fn checked_int_log(self, base: u32) -> Option<i32> }
             trait_delegate! { fn recip(self) -> Self }
+            trait_delegate! { fn powi(self, exp: i32) -> Self }
             trait_delegate! { fn div_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn div_round(self, rhs: Self) -> Self }
             trait_delegate! { fn div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn rem_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn sqrt(self) -> Self }
+            trait_delegate! { fn rsqrt(self) -> Self }
+            trait_delegate! { fn sin(self) -> Self }
+            trait_delegate! { fn cos(self) -> Self }
+            trait_delegate! { fn sin_cos(self) -> (Self, Self) }
+            trait_delegate! { fn atan(self) -> Self }
+            trait_delegate! { fn exp(self) -> Self }
+            trait_delegate! { fn exp2(self) -> Self }
+            trait_delegate! { fn ln(self) -> Self }
+            trait_delegate! { fn log2(self) -> Self }
+            trait_delegate! { fn log10(self) -> Self }
+            trait_delegate! { fn log(self, base: Self) -> Self }
             trait_delegate! { fn lerp(self, start: Self, end: Self) -> Self }
+            trait_delegate! { fn lerp_clamped(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn inv_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn checked_div(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_recip(self) -> Option<Self> }
+            trait_delegate! { fn checked_powi(self, exp: i32) -> Option<Self> }
             trait_delegate! { fn checked_div_euclid(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_div_round(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_rem_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_div_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
             trait_delegate! { fn checked_sqrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_rsqrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_sin(self) -> Option<Self> }
+            trait_delegate! { fn checked_cos(self) -> Option<Self> }
+            trait_delegate! { fn checked_sin_cos(self) -> Option<(Self, Self)> }
+            trait_delegate! { fn checked_atan2(self, other: Self) -> Option<Self> }
+            trait_delegate! { fn checked_exp(self) -> Option<Self> }
+            trait_delegate! { fn checked_exp2(self) -> Option<Self> }
+            trait_delegate! { fn checked_ln(self) -> Option<Self> }
+            trait_delegate! { fn checked_log2(self) -> Option<Self> }
+            trait_delegate! { fn checked_log10(self) -> Option<Self> }
+            trait_delegate! { fn checked_log(self, base: Self) -> Option<Self> }
             trait_delegate! { fn checked_lerp(self, start: Self, end: Self) -> Option<Self> }
             trait_delegate! { fn checked_inv_lerp(self, start: Self, end: Self) -> Option<Self> }
             trait_delegate! { fn saturating_div(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_recip(self) -> Self }
+            trait_delegate! { fn saturating_powi(self, exp: i32) -> Self }
             trait_delegate! { fn saturating_div_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_div_round(self, rhs: Self) -> Self }
             trait_delegate! { fn saturating_div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn saturating_rem_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn saturating_sqrt(self) -> Self }
+            trait_delegate! { fn saturating_rsqrt(self) -> Self }
+            trait_delegate! { fn saturating_exp(self) -> Self }
+            trait_delegate! { fn saturating_exp2(self) -> Self }
             trait_delegate! { fn saturating_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn saturating_inv_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn wrapping_div(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_recip(self) -> Self }
+            trait_delegate! { fn wrapping_powi(self, exp: i32) -> Self }
             trait_delegate! { fn wrapping_div_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn wrapping_div_round(self, rhs: Self) -> Self }
             trait_delegate! { fn wrapping_div_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self }
             trait_delegate! { fn wrapping_sqrt(self) -> Self }
+            trait_delegate! { fn wrapping_sin(self) -> Self }
+            trait_delegate! { fn wrapping_cos(self) -> Self }
+            trait_delegate! { fn wrapping_sin_cos(self) -> (Self, Self) }
+            trait_delegate! { fn wrapping_exp(self) -> Self }
+            trait_delegate! { fn wrapping_exp2(self) -> Self }
             trait_delegate! { fn wrapping_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn wrapping_inv_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn unwrapped_div(self, rhs: Self) -> Self }
@@ -4543,6 +6641,7 @@ macro_rules! impl_fixed {
             trait_delegate! { fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
             trait_delegate! { fn overflowing_sqrt(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_rsqrt(self) -> (Self, bool) }
             trait_delegate! { fn overflowing_lerp(self, start: Self, end: Self) -> (Self, bool) }
             trait_delegate! {
                 fn overflowing_inv_lerp(self, start: Self, end: Self) -> (Self, bool)
@@ -4560,10 +6659,12 @@ macro_rules! impl_fixed {
                 trait_delegate! { fn unsigned_abs(self) -> Self::Unsigned }
                 trait_delegate! { fn unsigned_dist(self, other: Self) -> Self::Unsigned }
                 trait_delegate! { fn signum(self) -> Self }
+                trait_delegate! { fn copysign(self, sign: Self) -> Self }
                 trait_delegate! { fn add_unsigned(self, rhs: Self::Unsigned) -> Self }
                 trait_delegate! { fn sub_unsigned(self, rhs: Self::Unsigned) -> Self }
                 trait_delegate! { fn checked_abs(self) -> Option<Self> }
                 trait_delegate! { fn checked_signum(self) -> Option<Self> }
+                trait_delegate! { fn checked_copysign(self, sign: Self) -> Option<Self> }
                 trait_delegate! {
                     fn checked_add_unsigned(self, rhs: Self::Unsigned) -> Option<Self>
                 }
@@ -4602,23 +6703,40 @@ macro_rules! impl_fixed {
                 trait_delegate! { fn next_power_of_two(self) -> Self }
                 trait_delegate! { fn add_signed(self, rhs: Self::Signed) -> Self }
                 trait_delegate! { fn sub_signed(self, rhs: Self::Signed) -> Self }
+                trait_delegate! { fn mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Self::Signed }
                 trait_delegate! { fn checked_next_power_of_two(self) -> Option<Self> }
                 trait_delegate! { fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self> }
                 trait_delegate! { fn checked_sub_signed(self, rhs: Self::Signed) -> Option<Self> }
+                trait_delegate! {
+                    fn checked_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Option<Self::Signed>
+                }
+                trait_delegate! { fn checked_neg_to_signed(self) -> Option<Self::Signed> }
                 trait_delegate! { fn saturating_add_signed(self, rhs: Self::Signed) -> Self }
                 trait_delegate! { fn saturating_sub_signed(self, rhs: Self::Signed) -> Self }
+                trait_delegate! {
+                    fn saturating_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Self::Signed
+                }
                 trait_delegate! { fn wrapping_next_power_of_two(self) -> Self }
                 trait_delegate! { fn wrapping_add_signed(self, rhs: Self::Signed) -> Self }
                 trait_delegate! { fn wrapping_sub_signed(self, rhs: Self::Signed) -> Self }
+                trait_delegate! {
+                    fn wrapping_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Self::Signed
+                }
                 trait_delegate! { fn unwrapped_next_power_of_two(self) -> Self }
                 trait_delegate! { fn unwrapped_add_signed(self, rhs: Self::Signed) -> Self }
                 trait_delegate! { fn unwrapped_sub_signed(self, rhs: Self::Signed) -> Self }
+                trait_delegate! {
+                    fn unwrapped_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> Self::Signed
+                }
                 trait_delegate! {
                     fn overflowing_add_signed(self, rhs: Self::Signed) -> (Self, bool)
                 }
                 trait_delegate! {
                     fn overflowing_sub_signed(self, rhs: Self::Signed) -> (Self, bool)
                 }
+                trait_delegate! {
+                    fn overflowing_mul_signed_int(self, rhs: <Self::Signed as Fixed>::Bits) -> (Self::Signed, bool)
+                }
             }
         }
 
@@ -0,0 +1,589 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::int256::{self, U256};
+
+// All the internal computations are done with 126 fractional bits, so that an
+// i128 can hold values in the range −2 ≤ x < 2.
+const ONE: i128 = 1 << 126;
+
+// atan(2^−i) for 0 ≤ i < 42, with 126 fractional bits. For i ≥ 42,
+// atan(2^−i) rounds to 2^−i.
+const ATAN: [i128; 42] = [
+    0x3243_f6a8_885a_308d_3131_98a2_e037_0734,
+    0x1dac_6705_61bb_4f68_adfc_88bd_9787_51a0,
+    0x0fad_bafc_9640_6eb1_56dc_79ef_5f7a_217e,
+    0x07f5_6ea6_ab0b_db71_9644_bcc4_f9f4_4478,
+    0x03fe_ab76_e59f_bd38_db2c_9e4b_7038_b835,
+    0x01ff_d55b_ba97_624a_84ef_3aee_dbb5_18c4,
+    0x00ff_faaa_dddb_94d5_bbe7_8c56_4015_f760,
+    0x007f_ff55_56ee_ea5c_b403_11a8_fddf_3058,
+    0x003f_ffea_aab7_776e_52ec_4abe_dadb_53e0,
+    0x001f_fffd_5555_bbbb_a972_9ab7_aac0_8947,
+    0x000f_ffff_aaaa_addd_ddb9_4b96_8067_ef3b,
+    0x0007_ffff_f555_556e_eeee_a5ca_5d89_5893,
+    0x0003_ffff_feaa_aaab_7777_76e5_2e53_56f5,
+    0x0001_ffff_ffd5_5555_5bbb_bbba_9729_72d0,
+    0x0000_ffff_fffa_aaaa_aadd_dddd_db94_b94c,
+    0x0000_7fff_ffff_5555_5556_eeee_eeea_5ca6,
+    0x0000_3fff_ffff_eaaa_aaaa_b777_7777_6e53,
+    0x0000_1fff_ffff_fd55_5555_55bb_bbbb_bba9,
+    0x0000_0fff_ffff_ffaa_aaaa_aaad_dddd_ddde,
+    0x0000_07ff_ffff_fff5_5555_5555_6eee_eeef,
+    0x0000_03ff_ffff_fffe_aaaa_aaaa_ab77_7777,
+    0x0000_01ff_ffff_ffff_d555_5555_555b_bbbc,
+    0x0000_00ff_ffff_ffff_faaa_aaaa_aaaa_ddde,
+    0x0000_007f_ffff_ffff_ff55_5555_5555_56ef,
+    0x0000_003f_ffff_ffff_ffea_aaaa_aaaa_aab7,
+    0x0000_001f_ffff_ffff_fffd_5555_5555_5556,
+    0x0000_000f_ffff_ffff_ffff_aaaa_aaaa_aaab,
+    0x0000_0007_ffff_ffff_ffff_f555_5555_5555,
+    0x0000_0003_ffff_ffff_ffff_feaa_aaaa_aaab,
+    0x0000_0001_ffff_ffff_ffff_ffd5_5555_5555,
+    0x0000_0000_ffff_ffff_ffff_fffa_aaaa_aaab,
+    0x0000_0000_7fff_ffff_ffff_ffff_5555_5555,
+    0x0000_0000_3fff_ffff_ffff_ffff_eaaa_aaab,
+    0x0000_0000_1fff_ffff_ffff_ffff_fd55_5555,
+    0x0000_0000_0fff_ffff_ffff_ffff_ffaa_aaab,
+    0x0000_0000_07ff_ffff_ffff_ffff_fff5_5555,
+    0x0000_0000_03ff_ffff_ffff_ffff_fffe_aaab,
+    0x0000_0000_01ff_ffff_ffff_ffff_ffff_d555,
+    0x0000_0000_00ff_ffff_ffff_ffff_ffff_faab,
+    0x0000_0000_007f_ffff_ffff_ffff_ffff_ff55,
+    0x0000_0000_003f_ffff_ffff_ffff_ffff_ffeb,
+    0x0000_0000_001f_ffff_ffff_ffff_ffff_fffd,
+];
+
+// The number of CORDIC iterations. After these, the remaining angle is
+// < 2^−63, so a final linear step has an error < 2^−127.
+const ITERS: u32 = 64;
+
+// The product of cos(atan(2^−i)) for 0 ≤ i < ITERS, with 126 fractional bits.
+// Starting with this length compensates for the CORDIC gain.
+const INV_GAIN: i128 = 0x26dd_3b6a_10d7_9699_fd7e_424a_f5ff_503b;
+
+// 2/π with 256 fractional bits, rounded down
+const FRAC_2_PI: U256 = U256 {
+    lo: 0xdb62_9599_3c43_9041_fe51_63ab_debb_c561,
+    hi: 0xa2f9_836e_4e44_1529_fc27_57d1_f534_ddc0,
+};
+
+// π/2 with 126 fractional bits
+const FRAC_PI_2: u128 = 0x6487_ed51_10b4_611a_6263_3145_c06e_0e69;
+
+// Multiplies two numbers with 126 fractional bits.
+const fn mul(a: i128, b: i128) -> i128 {
+    let prod = int256::wide_mul_i128(a, b);
+    (prod.hi << 2) | (prod.lo >> 126) as i128
+}
+
+// Reduces abs / 2^frac_nbits modulo π/2, where frac_nbits ≤ 128.
+//
+// Returns the quadrant modulo 4, and the remaining angle with 126 fractional
+// bits in the range 0 ≤ angle < π/2.
+const fn reduce(abs: u128, frac_nbits: u32) -> (u32, i128) {
+    // abs × 2/π has 256 + frac_nbits fractional bits. The two bits just above
+    // the point are the quadrant, and the 128 bits just below the point are
+    // the position inside the quadrant. Truncating 2/π introduces an error of
+    // less than abs in the product, which is less than one unit of the 128-bit
+    // position.
+    let lo = int256::wide_mul_u128(abs, FRAC_2_PI.lo);
+    let hi = int256::wide_mul_u128(abs, FRAC_2_PI.hi);
+    let (mid, carry) = lo.hi.overflowing_add(hi.lo);
+    // abs × 2/π < 2^384, so this cannot overflow
+    let top = hi.hi + carry as u128;
+    let (quadrant, pos) = if frac_nbits == 0 {
+        (top & 3, mid)
+    } else if frac_nbits < 128 {
+        (
+            (top >> frac_nbits) & 3,
+            (mid >> frac_nbits) | (top << (128 - frac_nbits)),
+        )
+    } else {
+        (0, top)
+    };
+    let angle = int256::wide_mul_u128(pos, FRAC_PI_2).hi;
+    (quadrant as u32, angle as i128)
+}
+
+// Returns the sine and cosine of angle, where 0 ≤ angle < π/2, with 126
+// fractional bits.
+const fn cordic(mut angle: i128) -> (i128, i128) {
+    let mut x = INV_GAIN;
+    let mut y = 0;
+    let mut i = 0;
+    while i < ITERS {
+        let atan = if i < ATAN.len() as u32 {
+            ATAN[i as usize]
+        } else {
+            1 << (126 - i)
+        };
+        // round the shifted values to keep the accumulated error unbiased
+        let (dx, dy) = (shr_round(y, i), shr_round(x, i));
+        if angle >= 0 {
+            x -= dx;
+            y += dy;
+            angle -= atan;
+        } else {
+            x += dx;
+            y -= dy;
+            angle += atan;
+        }
+        i += 1;
+    }
+    // rotate by the small remaining angle
+    let sin = y + mul(x, angle);
+    let cos = x - mul(y, angle);
+    (clamp(sin), clamp(cos))
+}
+
+const fn shr_round(val: i128, shift: u32) -> i128 {
+    if shift == 0 {
+        val
+    } else {
+        ((val >> (shift - 1)) + 1) >> 1
+    }
+}
+
+const fn clamp(val: i128) -> i128 {
+    if val > ONE {
+        ONE
+    } else if val < -ONE {
+        -ONE
+    } else {
+        val
+    }
+}
+
+// Returns the sine and cosine of ±abs / 2^frac_nbits, with 126 fractional
+// bits.
+const fn sin_cos(neg: bool, abs: u128, frac_nbits: u32) -> (i128, i128) {
+    if abs == 0 {
+        return (0, ONE);
+    }
+    let (quadrant, angle) = reduce(abs, frac_nbits);
+    let (sin, cos) = cordic(angle);
+    let (sin, cos) = match quadrant {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    };
+    if neg {
+        (-sin, cos)
+    } else {
+        (sin, cos)
+    }
+}
+
+// Rounds val with val_frac fractional bits to frac_nbits fractional bits,
+// where frac_nbits ≤ 128.
+//
+// Returns the wrapped bits, and whether the result fits in an i128 or a u128
+// depending on unsigned.
+const fn round(val: i128, val_frac: u32, frac_nbits: u32, unsigned: bool) -> (u128, bool) {
+    if frac_nbits <= val_frac {
+        let rounded = shr_round(val, val_frac - frac_nbits);
+        return (rounded as u128, !unsigned || rounded >= 0);
+    }
+    let shift = frac_nbits - val_frac;
+    let wrapped = (val as u128) << shift;
+    let fits = if unsigned {
+        val >= 0 && (val as u128) >> (128 - shift) == 0
+    } else {
+        (wrapped as i128) >> shift == val
+    };
+    (wrapped, fits)
+}
+
+// The number of CORDIC iterations in vectoring mode. The vector is scaled so
+// that its components have about 125 significant bits, so more iterations
+// would not improve the result.
+const VEC_ITERS: u32 = 125;
+
+// Returns the angle of the vector (x, y) with 126 fractional bits, where
+// 0 ≤ angle ≤ π/2.
+const fn vector(x: u128, y: u128) -> i128 {
+    if y == 0 {
+        return 0;
+    }
+    if x == 0 {
+        return FRAC_PI_2 as i128;
+    }
+    // Scale so that the larger component is in the range 2^124 ≤ c < 2^125.
+    // With the CORDIC gain and the √2 factor of the vector length, the
+    // components then stay below 2^127.
+    let lz = if x > y {
+        x.leading_zeros()
+    } else {
+        y.leading_zeros()
+    };
+    let (mut x, mut y) = if lz >= 3 {
+        ((x << (lz - 3)) as i128, (y << (lz - 3)) as i128)
+    } else {
+        ((x >> (3 - lz)) as i128, (y >> (3 - lz)) as i128)
+    };
+    let mut angle = 0;
+    let mut i = 0;
+    while i < VEC_ITERS {
+        let atan = if i < ATAN.len() as u32 {
+            ATAN[i as usize]
+        } else {
+            1 << (126 - i)
+        };
+        let (dx, dy) = (shr_round(y, i), shr_round(x, i));
+        if y >= 0 {
+            x += dx;
+            y -= dy;
+            angle += atan;
+        } else {
+            x -= dx;
+            y += dy;
+            angle -= atan;
+        }
+        i += 1;
+    }
+    // the last iterations can overshoot slightly
+    if angle < 0 {
+        0
+    } else if angle > FRAC_PI_2 as i128 {
+        FRAC_PI_2 as i128
+    } else {
+        angle
+    }
+}
+
+// Returns the angle of the vector (x, y) with 125 fractional bits, where
+// −π < angle ≤ π.
+const fn atan2(x_neg: bool, x_abs: u128, y_neg: bool, y_abs: u128) -> i128 {
+    // π with 125 fractional bits has the same bits as π/2 with 126
+    const PI: i128 = FRAC_PI_2 as i128;
+    let angle = shr_round(vector(x_abs, y_abs), 1);
+    let angle = if x_neg { PI - angle } else { angle };
+    if y_neg {
+        -angle
+    } else {
+        angle
+    }
+}
+
+pub mod cordic {
+    macro_rules! impl_cordic {
+        ($Inner:ident, $unsigned:literal, |$val:ident| $neg_abs:expr) => {
+            // Returns the sine and cosine of val / 2^frac_nbits, where
+            // frac_nbits ≤ 128, each with a bool indicating overflow.
+            pub const fn $Inner($val: $Inner, frac_nbits: u32) -> (($Inner, bool), ($Inner, bool)) {
+                let (neg, abs) = $neg_abs;
+                let (sin, cos) = super::sin_cos(neg, abs as u128, frac_nbits);
+                let (sin, sin_fits) = super::round(sin, 126, frac_nbits, $unsigned);
+                let (cos, cos_fits) = super::round(cos, 126, frac_nbits, $unsigned);
+                (
+                    (sin as $Inner, !sin_fits || sin as $Inner as u128 != sin),
+                    (cos as $Inner, !cos_fits || cos as $Inner as u128 != cos),
+                )
+            }
+        };
+    }
+
+    impl_cordic! { i8, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_cordic! { i16, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_cordic! { i32, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_cordic! { i64, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_cordic! { i128, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_cordic! { u8, true, |val| (false, val) }
+    impl_cordic! { u16, true, |val| (false, val) }
+    impl_cordic! { u32, true, |val| (false, val) }
+    impl_cordic! { u64, true, |val| (false, val) }
+    impl_cordic! { u128, true, |val| (false, val) }
+}
+
+pub mod atan2 {
+    macro_rules! impl_atan2 {
+        ($Inner:ident, $unsigned:literal, |$val:ident| $neg_abs:expr) => {
+            // Returns the angle of the point (x, y), rounded to frac_nbits
+            // fractional bits, where frac_nbits ≤ 128, and a bool indicating
+            // overflow.
+            pub const fn $Inner(y: $Inner, x: $Inner, frac_nbits: u32) -> ($Inner, bool) {
+                let (y_neg, y_abs) = {
+                    let $val = y;
+                    $neg_abs
+                };
+                let (x_neg, x_abs) = {
+                    let $val = x;
+                    $neg_abs
+                };
+                let angle = super::atan2(x_neg, x_abs as u128, y_neg, y_abs as u128);
+                let (angle, fits) = super::round(angle, 125, frac_nbits, $unsigned);
+                (angle as $Inner, !fits || angle as $Inner as u128 != angle)
+            }
+        };
+    }
+
+    impl_atan2! { i8, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan2! { i16, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan2! { i32, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan2! { i64, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan2! { i128, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan2! { u8, true, |val| (false, val) }
+    impl_atan2! { u16, true, |val| (false, val) }
+    impl_atan2! { u32, true, |val| (false, val) }
+    impl_atan2! { u64, true, |val| (false, val) }
+    impl_atan2! { u128, true, |val| (false, val) }
+}
+
+pub mod atan {
+    macro_rules! impl_atan {
+        ($Inner:ident, $unsigned:literal, |$val:ident| $neg_abs:expr) => {
+            // Returns the arctangent of val / 2^frac_nbits, rounded to
+            // frac_nbits fractional bits, where frac_nbits ≤ 128. This cannot
+            // overflow, as |atan(val)| < π/2 and |atan(val)| ≤ |val|.
+            pub const fn $Inner($val: $Inner, frac_nbits: u32) -> $Inner {
+                let (neg, abs) = $neg_abs;
+                // 2^128 does not fit, but then |val| < 1 and dropping the
+                // least significant bit does not affect the result
+                let (abs, one) = if frac_nbits < 128 {
+                    (abs as u128, 1 << frac_nbits)
+                } else {
+                    (abs as u128 >> 1, 1 << 127)
+                };
+                let angle = super::atan2(false, one, neg, abs);
+                super::round(angle, 125, frac_nbits, $unsigned).0 as $Inner
+            }
+        };
+    }
+
+    impl_atan! { i8, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan! { i16, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan! { i32, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan! { i64, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan! { i128, false, |val| (val < 0, val.unsigned_abs()) }
+    impl_atan! { u8, true, |val| (false, val) }
+    impl_atan! { u16, true, |val| (false, val) }
+    impl_atan! { u32, true, |val| (false, val) }
+    impl_atan! { u64, true, |val| (false, val) }
+    impl_atan! { u128, true, |val| (false, val) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I0F8, I16F16, I2F126, I32F32, I4F60, I8F8, U0F8, U16F16, U1F7, U2F126};
+    use crate::{FixedI128, FixedI8, FixedU8};
+    use core::f64::consts::FRAC_PI_2;
+
+    // Checks sin and cos against f64 for a grid of inputs. The error must be
+    // less than one DELTA plus the error of the f64 functions themselves.
+    macro_rules! check_grid {
+        ($Fix:ty, $lo:expr, $hi:expr, $step:expr) => {
+            let delta = <$Fix>::DELTA.to_num::<f64>();
+            let tol = delta + 4.0 * f64::EPSILON;
+            let mut x: f64 = $lo;
+            while x <= $hi {
+                let fix = <$Fix>::from_num(x);
+                let f = fix.to_num::<f64>();
+                if let Some(sin) = fix.checked_sin() {
+                    let err = (sin.to_num::<f64>() - f.sin()).abs();
+                    assert!(err < tol, "sin({fix}) = {sin}, error {err}");
+                }
+                if let Some(cos) = fix.checked_cos() {
+                    let err = (cos.to_num::<f64>() - f.cos()).abs();
+                    assert!(err < tol, "cos({fix}) = {cos}, error {err}");
+                }
+                assert_eq!(
+                    fix.checked_sin_cos(),
+                    fix.checked_sin().zip(fix.checked_cos())
+                );
+                x += $step;
+            }
+        };
+    }
+
+    #[test]
+    fn grid() {
+        check_grid!(I8F8, -127.0, 127.0, 0.0625);
+        check_grid!(I16F16, -200.0, 200.0, 0.0123);
+        check_grid!(U16F16, 0.0, 200.0, 0.0123);
+        check_grid!(I32F32, -1.0e6, 1.0e6, 37.7);
+        check_grid!(I32F32, -10.0, 10.0, 0.000_37);
+        check_grid!(I4F60, -7.0, 7.0, 0.000_73);
+    }
+
+    #[test]
+    fn quadrants() {
+        type Fix = I32F32;
+        let quarter = Fix::FRAC_PI_2;
+        let angle = Fix::from_num(0.3);
+        let (sin, cos) = angle.sin_cos();
+        for k in -8..8 {
+            let (s, c) = (angle + quarter * k).sin_cos();
+            let (expected_sin, expected_cos) = match k.rem_euclid(4) {
+                0 => (sin, cos),
+                1 => (cos, -sin),
+                2 => (-sin, -cos),
+                _ => (-cos, sin),
+            };
+            // quarter * k is not exactly k × π/2, so allow some slack
+            assert!((s - expected_sin).abs() <= Fix::DELTA * 16, "k = {k}");
+            assert!((c - expected_cos).abs() <= Fix::DELTA * 16, "k = {k}");
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(I16F16::ZERO.sin_cos(), (I16F16::ZERO, I16F16::ONE));
+        assert_eq!(I16F16::DELTA.sin_cos(), (I16F16::DELTA, I16F16::ONE));
+        assert_eq!(I16F16::FRAC_PI_2.sin(), I16F16::ONE);
+        assert_eq!(I16F16::PI.cos(), I16F16::NEG_ONE);
+        assert_eq!((-I16F16::FRAC_PI_2).sin(), I16F16::NEG_ONE);
+        assert_eq!(I16F16::FRAC_PI_6.sin(), I16F16::from_num(0.5));
+        assert_eq!(I16F16::FRAC_PI_3.cos(), I16F16::from_num(0.5));
+        assert_eq!((-I16F16::MAX).sin(), -I16F16::MAX.sin());
+        assert_eq!((-I16F16::MAX).cos(), I16F16::MAX.cos());
+    }
+
+    #[test]
+    fn wide() {
+        // sin(1) and cos(1) with 126 fractional bits, rounded to nearest
+        let sin_1 = I2F126::from_bits(0x35da_a91e_1219_dc08_31ba_7a42_7143_cf0d);
+        let cos_1 = I2F126::from_bits(0x2294_501f_6a0d_1724_7091_9b65_da1c_6f4a);
+        let (sin, cos) = I2F126::ONE.sin_cos();
+        // the error is < 2^−119, that is < 2^7 DELTA
+        assert!(sin.to_bits().abs_diff(sin_1.to_bits()) < 1 << 7);
+        assert!(cos.to_bits().abs_diff(cos_1.to_bits()) < 1 << 7);
+        let (sin, cos) = U2F126::ONE.sin_cos();
+        assert!(sin.to_bits().abs_diff(sin_1.to_bits() as u128) < 1 << 7);
+        assert!(cos.to_bits().abs_diff(cos_1.to_bits() as u128) < 1 << 7);
+        // large angles are reduced without losing precision
+        let (sin, cos) = FixedI128::<0>::MAX.sin_cos();
+        assert_eq!((sin, cos), (FixedI128::<0>::ZERO, FixedI128::<0>::ONE));
+    }
+
+    #[test]
+    fn overflow() {
+        // no integer bits, so 1 cannot be represented
+        assert_eq!(FixedI8::<8>::ZERO.checked_cos(), None);
+        assert_eq!(FixedI8::<8>::ZERO.wrapping_cos(), FixedI8::<8>::ZERO);
+        assert_eq!(U0F8::ZERO.checked_cos(), None);
+        assert_eq!(
+            U0F8::from_num(0.5).checked_cos(),
+            Some(U0F8::from_bits(225))
+        );
+        // one integer bit is enough for signed −1 but not for 1
+        assert_eq!(FixedI8::<7>::ZERO.checked_cos(), None);
+        assert_eq!(FixedI8::<6>::ZERO.checked_cos(), Some(FixedI8::<6>::ONE));
+        assert_eq!(U1F7::ZERO.checked_cos(), Some(U1F7::ONE));
+        // negative results overflow for unsigned numbers
+        assert_eq!(FixedU8::<4>::from_num(4).checked_sin(), None);
+        assert_eq!(FixedU8::<4>::PI.checked_cos(), None);
+        assert_eq!(FixedU8::<4>::PI.checked_sin_cos(), None);
+        assert_eq!(
+            FixedU8::<4>::PI.wrapping_sin_cos(),
+            (
+                FixedU8::<4>::ZERO,
+                FixedU8::<4>::ZERO.wrapping_sub(FixedU8::<4>::ONE)
+            )
+        );
+    }
+    #[test]
+    fn atan2_axes() {
+        type Fix = I16F16;
+        // the constants are rounded down, but π/2 must be rounded up
+        let frac_pi_2 = Fix::from_num(FRAC_PI_2);
+        assert_eq!(Fix::ZERO.atan2(Fix::ONE), Fix::ZERO);
+        assert_eq!(Fix::ONE.atan2(Fix::ZERO), frac_pi_2);
+        assert_eq!(Fix::ZERO.atan2(Fix::NEG_ONE), Fix::PI);
+        assert_eq!(Fix::NEG_ONE.atan2(Fix::ZERO), -frac_pi_2);
+        assert_eq!(Fix::ZERO.atan2(Fix::ZERO), Fix::ZERO);
+        // the scale does not matter
+        assert_eq!(Fix::MAX.atan2(Fix::ZERO), frac_pi_2);
+        assert_eq!(Fix::ZERO.atan2(Fix::MIN), Fix::PI);
+        assert_eq!(Fix::MIN.atan2(Fix::ZERO), -frac_pi_2);
+        assert_eq!(Fix::DELTA.atan2(Fix::ZERO), frac_pi_2);
+        assert_eq!(U16F16::ONE.atan2(U16F16::ZERO), U16F16::from_num(FRAC_PI_2));
+        assert_eq!(U16F16::ZERO.atan2(U16F16::ONE), U16F16::ZERO);
+    }
+
+    #[test]
+    fn atan2_diagonals() {
+        let tol = I32F32::DELTA.to_num::<f64>() + 4.0 * f64::EPSILON;
+        let points = [
+            (1.0, 1.0),
+            (1.0, -1.0),
+            (-1.0, -1.0),
+            (-1.0, 1.0),
+            (3.0, 4.0),
+            (-5.0, 12.0),
+            (0.001, -1000.0),
+            (-0.001, -1000.0),
+            (-1000.0, 0.001),
+            (2.5, 2.5),
+        ];
+        for (y, x) in points {
+            let (fy, fx) = (I32F32::from_num(y), I32F32::from_num(x));
+            let expected = fy.to_num::<f64>().atan2(fx.to_num::<f64>());
+            let angle = fy.atan2(fx);
+            let err = (angle.to_num::<f64>() - expected).abs();
+            assert!(err < tol, "atan2({fy}, {fx}) = {angle}, error {err}");
+            assert_eq!(fy.checked_atan2(fx), Some(angle));
+        }
+    }
+
+    #[test]
+    fn atan2_grid() {
+        let tol = I16F16::DELTA.to_num::<f64>() + 4.0 * f64::EPSILON;
+        for y in -20..=20 {
+            for x in -20..=20 {
+                let (fy, fx) = (I16F16::from_num(y) / 7, I16F16::from_num(x) / 3);
+                let expected = fy.to_num::<f64>().atan2(fx.to_num::<f64>());
+                let err = (fy.atan2(fx).to_num::<f64>() - expected).abs();
+                assert!(err < tol, "atan2({fy}, {fx}), error {err}");
+                let err = (fy.atan().to_num::<f64>() - fy.to_num::<f64>().atan()).abs();
+                assert!(err < tol, "atan({fy}), error {err}");
+                if y >= 0 && x >= 0 {
+                    let (uy, ux) = (U16F16::from_num(fy), U16F16::from_num(fx));
+                    let err = (uy.atan2(ux).to_num::<f64>() - expected).abs();
+                    assert!(err < tol, "atan2({uy}, {ux}), error {err}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn atan2_overflow() {
+        // two integer bits including the sign bit hold ±π/2 but not π
+        type Fix = FixedI8<6>;
+        assert_eq!(
+            Fix::ONE.checked_atan2(Fix::ZERO),
+            Some(Fix::from_num(1.578125))
+        );
+        assert_eq!(Fix::ZERO.checked_atan2(Fix::NEG_ONE), None);
+        assert_eq!(Fix::NEG_ONE.checked_atan2(Fix::NEG_ONE), None);
+        // no integer bits
+        assert_eq!(I0F8::from_num(0.25).checked_atan2(I0F8::ZERO), None);
+        assert_eq!(
+            I0F8::from_num(0.125).checked_atan2(I0F8::from_num(0.25)),
+            Some(I0F8::from_num(0.46484375))
+        );
+        assert_eq!(U0F8::from_num(0.5).checked_atan2(U0F8::ZERO), None);
+        assert_eq!(U0F8::ZERO.checked_atan2(U0F8::ZERO), Some(U0F8::ZERO));
+        assert_eq!(U1F7::ONE.checked_atan2(U1F7::ZERO), Some(U1F7::FRAC_PI_2));
+        // atan never overflows
+        assert_eq!(I0F8::MIN.atan(), I0F8::from_num(-0.46484375));
+        assert_eq!(U0F8::MAX.atan(), U0F8::from_num(0.78515625));
+        assert_eq!(
+            FixedI128::<128>::MIN.atan(),
+            FixedI128::<128>::from_num(-0.5).atan()
+        );
+    }
+}
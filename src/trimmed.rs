@@ -0,0 +1,152 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+/// Displays the exact value of a fixed-point number without trailing zeros.
+///
+/// The [`Display`] implementation of the fixed-point number itself prints the
+/// shortest decimal that converts back to the same fixed-point number, which is
+/// not always the exact value. The [`Display`] implementation of this wrapper
+/// instead prints all the decimal digits required to represent the value
+/// exactly, which is always possible as every fixed-point number has a finite
+/// decimal expansion, but omits any trailing zeros.
+///
+/// If a precision is specified, the number is rounded to that many fractional
+/// digits like for the fixed-point number itself, but any trailing zeros are
+/// still omitted. By default, a number with no fractional digits left is
+/// printed without a radix point; the alternate flag (`#`) keeps one
+/// fractional digit, printing for example “`2.0`” instead of “`2`”. The
+/// width, fill, alignment and sign options are supported.
+///
+/// The underlying value can be retrieved through the `.0` index.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::{types::U8F8, Trimmed};
+/// // U8F8::MAX is exactly 255.99609375
+/// assert_eq!(format!("{}", U8F8::MAX), "255.996");
+/// assert_eq!(format!("{}", Trimmed(U8F8::MAX)), "255.99609375");
+/// let val = U8F8::from_num(1.5);
+/// assert_eq!(format!("{:.6}", val), "1.500000");
+/// assert_eq!(format!("{:.6}", Trimmed(val)), "1.5");
+/// assert_eq!(format!("{}", Trimmed(U8F8::from_num(2))), "2");
+/// assert_eq!(format!("{:#}", Trimmed(U8F8::from_num(2))), "2.0");
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Trimmed<F>(pub F);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::{I0F32, I16F16, I8F8, U0F128, U0F8, U128F0, U8F8},
+        Trimmed,
+    };
+    use std::format;
+
+    #[test]
+    fn exact() {
+        // the plain Display prints the shortest decimal that rounds back
+        assert_eq!(format!("{}", U0F8::from_bits(1)), "0.004");
+        assert_eq!(format!("{}", Trimmed(U0F8::from_bits(1))), "0.00390625");
+        assert_eq!(format!("{}", U0F8::MAX), "0.996");
+        assert_eq!(format!("{}", Trimmed(U0F8::MAX)), "0.99609375");
+        assert_eq!(format!("{}", Trimmed(U0F8::from_bits(0xF7))), "0.96484375");
+        assert_eq!(format!("{}", I8F8::MIN), "-128");
+        assert_eq!(format!("{}", Trimmed(I8F8::MIN)), "-128");
+        assert_eq!(format!("{}", Trimmed(I8F8::MAX)), "127.99609375");
+        assert_eq!(format!("{}", Trimmed(I0F32::MIN)), "-0.5");
+        assert_eq!(
+            format!("{}", Trimmed(I0F32::DELTA)),
+            "0.00000000023283064365386962890625"
+        );
+        assert_eq!(
+            format!("{}", Trimmed(U128F0::MAX)),
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(
+            format!("{}", Trimmed(U0F128::MAX)),
+            "0.9999999999999999999999999999999999999970612641229442812300781586\
+             5694438580545333610806978119622812073430395685136318206787109375"
+        );
+
+        // exhaustive check that the output parses back and has no trailing zeros
+        for bits in u16::MIN..=u16::MAX {
+            let val = U8F8::from_bits(bits);
+            let trimmed = format!("{}", Trimmed(val));
+            assert_eq!(trimmed.parse::<U8F8>(), Ok(val));
+            assert!(
+                !trimmed.ends_with('0') || !trimmed.contains('.'),
+                "{trimmed}"
+            );
+            assert!(!trimmed.ends_with('.'));
+            // the exact decimal has one digit per used fractional bit
+            let frac_digits = trimmed.split_once('.').map_or(0, |(_, frac)| frac.len());
+            let used_bits = if bits & 0xFF == 0 {
+                0
+            } else {
+                8 - (bits & 0xFF).trailing_zeros() as usize
+            };
+            assert_eq!(frac_digits, used_bits, "{trimmed}");
+        }
+    }
+
+    #[test]
+    fn trimmed_vs_full() {
+        let vals = [
+            (I16F16::from_num(1.5), "1.500000", "1.5"),
+            (I16F16::from_num(-0.25), "-0.250000", "-0.25"),
+            (I16F16::from_num(100), "100.000000", "100"),
+            (I16F16::from_num(3.125), "3.125000", "3.125"),
+            (I16F16::ZERO, "0.000000", "0"),
+        ];
+        for (val, full, trimmed) in vals {
+            assert_eq!(format!("{val:.6}"), full);
+            assert_eq!(format!("{:.6}", Trimmed(val)), trimmed);
+            assert_eq!(format!("{}", Trimmed(val)), trimmed);
+            // the exact values are short, so the plain Display agrees
+            assert_eq!(format!("{val}"), trimmed);
+        }
+        // rounding to the precision can leave trailing zeros to trim
+        let val = I16F16::from_num(1.99609375);
+        assert_eq!(format!("{val:.2}"), "2.00");
+        assert_eq!(format!("{:.2}", Trimmed(val)), "2");
+        assert_eq!(format!("{:#.2}", Trimmed(val)), "2.0");
+        assert_eq!(format!("{:.3}", Trimmed(val)), "1.996");
+        let val = I16F16::from_num(1.203125);
+        assert_eq!(format!("{val:.2}"), "1.20");
+        assert_eq!(format!("{:.2}", Trimmed(val)), "1.2");
+        assert_eq!(format!("{:.0}", Trimmed(val)), "1");
+    }
+
+    #[test]
+    fn options() {
+        let val = I16F16::from_num(-2.5);
+        assert_eq!(format!("{:#}", Trimmed(val)), "-2.5");
+        assert_eq!(format!("{:#}", Trimmed(I16F16::ZERO)), "0.0");
+        assert_eq!(format!("{:#}", Trimmed(I16F16::from_num(-7))), "-7.0");
+        assert_eq!(format!("{:+}", Trimmed(-val)), "+2.5");
+        assert_eq!(format!("{:8}|", Trimmed(val)), "    -2.5|");
+        assert_eq!(format!("{:<8}|", Trimmed(val)), "-2.5    |");
+        assert_eq!(format!("{:*^8}", Trimmed(val)), "**-2.5**");
+        assert_eq!(format!("{:08}", Trimmed(val)), "-00002.5");
+        assert_eq!(format!("{:#6}|", Trimmed(I16F16::ONE)), "   1.0|");
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright © 2018–2024 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/**
+An error which can be returned when a [`TryFrom`] conversion to or from a
+fixed-point number fails.
+
+# Examples
+
+```rust
+#![feature(generic_const_exprs)]
+# #![allow(incomplete_features)]
+
+use fixed::{types::I16F16, TryFromFixedError};
+fn check(val: Result<I16F16, TryFromFixedError>) {
+    match val {
+        Ok(val) => println!("converted: {val}"),
+        Err(error) => println!("conversion error: {error}"),
+    }
+}
+# check(Ok(I16F16::ZERO));
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromFixedError {
+    pub(crate) kind: TryFromErrorKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TryFromErrorKind {
+    Overflow,
+    HasFraction,
+}
+
+impl TryFromFixedError {
+    #[inline]
+    pub(crate) const fn message(self) -> &'static str {
+        use self::TryFromErrorKind::*;
+        match self.kind {
+            Overflow => "overflow",
+            HasFraction => "number has a fractional part",
+        }
+    }
+}
+
+impl Display for TryFromFixedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.message(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TryFromFixedError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
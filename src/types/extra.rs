@@ -16,7 +16,8 @@
 /*!
 Extra types that do not need to be handled directly.
 
-These types are used for `where` constraints.
+These types are used for `where` constraints, and the functions are used in
+the const expressions of some types.
 */
 
 /// Used for constraints conditional on a [`bool`].
@@ -60,3 +61,41 @@ pub struct If<const CONDITION: bool>;
 pub trait True {}
 
 impl True for If<true> {}
+
+/// Returns the larger of two numbers of fractional bits.
+///
+/// This is the number of fractional bits of the sum returned by methods such as
+/// <code>FixedI32::[add\_frac][crate::FixedI32::add_frac]</code>, so that the type
+/// of the sum can be named in generic code. The type can also be named as the
+/// associated type [`Output`] of the [`AddFrac`] trait.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// # #![allow(incomplete_features)]
+///
+/// use fixed::types::extra::max_frac;
+/// use fixed::FixedI32;
+/// fn sum<const A: i32, const B: i32>(
+///     a: FixedI32<A>,
+///     b: FixedI32<B>,
+/// ) -> FixedI32<{ max_frac(A, B) }> {
+///     a.add_frac(b)
+/// }
+///
+/// assert_eq!(max_frac(4, 8), 8);
+/// assert_eq!(sum(FixedI32::<4>::from_num(1), FixedI32::<8>::from_num(0.5)), 1.5);
+/// ```
+///
+/// [`AddFrac`]: crate::traits::AddFrac
+/// [`Output`]: crate::traits::AddFrac::Output
+#[inline]
+#[must_use]
+pub const fn max_frac(a: i32, b: i32) -> i32 {
+    if a >= b {
+        a
+    } else {
+        b
+    }
+}
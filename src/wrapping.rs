@@ -1420,7 +1420,7 @@ impl<F: FixedBoundFrac> Wrapping<F> {
     /// let den = Wrapping(I16F16::from_num(2));
     /// assert_eq!(num.div_euclid(den), Wrapping(I16F16::from_num(3)));
     /// let quarter = Wrapping(I16F16::from_num(0.25));
-    /// let check = (Wrapping(I16F16::MAX) * 4i32).round_to_zero();
+    /// let check = Wrapping(I16F16::from_bits((I16F16::MAX.to_bits() / 4) << 16));
     /// assert_eq!(Wrapping(I16F16::MAX).div_euclid(quarter), check);
     /// ```
     #[inline]